@@ -0,0 +1,119 @@
+//! Several settings applied as one unit.
+//!
+//! [`Jpf4826Client::set_many`](crate::Jpf4826Client::set_many) applies a
+//! list of [`SetOperation`]s in order. It reads the affected registers
+//! first and, if an operation fails, writes the previous values back, so a
+//! flaky link does not leave the controller half reconfigured. The
+//! [`SetReport`] tells what was applied and whether the rollback worked.
+
+// Rust guideline compliant 2026-10-16
+
+use crate::error::Jpf4826Error;
+use crate::registers::RegisterAddress;
+use crate::types::{PwmFrequency, WorkMode};
+
+/// One setting change, mirroring a setter of
+/// [`Jpf4826Client`](crate::Jpf4826Client).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::{Jpf4826Client, PwmFrequency, SetOperation, WorkMode};
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let report = client
+///     .set_many(&[
+///         SetOperation::TemperatureThreshold { low: 35, high: 55 },
+///         SetOperation::WorkMode(WorkMode::Shutdown),
+///         SetOperation::PwmFrequency(PwmFrequency::Hz25000),
+///     ])
+///     .await?;
+/// if let Some(error) = &report.error {
+///     eprintln!("Rolled back after: {}", error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOperation {
+    /// Number of fans, 0-4 (see
+    /// [`set_fan_count`](crate::Jpf4826Client::set_fan_count)).
+    FanCount(u8),
+    /// PWM frequency (see
+    /// [`set_pwm_frequency`](crate::Jpf4826Client::set_pwm_frequency)).
+    PwmFrequency(PwmFrequency),
+    /// Start and full speed temperatures in Celsius (see
+    /// [`set_temperature_threshold`](crate::Jpf4826Client::set_temperature_threshold)).
+    TemperatureThreshold {
+        /// Start temperature.
+        low: i16,
+        /// Full speed temperature.
+        high: i16,
+    },
+    /// ECO/work mode (see [`set_eco`](crate::Jpf4826Client::set_eco)).
+    WorkMode(WorkMode),
+    /// Manual speed percentage, 0-100 (see
+    /// [`set_fan_speed`](crate::Jpf4826Client::set_fan_speed)).
+    FanSpeed(u8),
+    /// Temperature-based speed control (see
+    /// [`set_auto_speed`](crate::Jpf4826Client::set_auto_speed)).
+    AutoSpeed,
+    /// Modbus address, 1-254 (see
+    /// [`set_addr`](crate::Jpf4826Client::set_addr)). Must be the last
+    /// operation.
+    Address(u8),
+}
+
+impl SetOperation {
+    /// Returns the register the operation writes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::SetOperation;
+    /// # use jpf4826_driver::registers::RegisterAddress;
+    /// assert_eq!(SetOperation::FanSpeed(50).register(), RegisterAddress::ManualSpeedControl);
+    /// assert_eq!(SetOperation::AutoSpeed.register(), RegisterAddress::ManualSpeedControl);
+    /// ```
+    pub fn register(&self) -> RegisterAddress {
+        match self {
+            SetOperation::FanCount(_) => RegisterAddress::FanQuantity,
+            SetOperation::PwmFrequency(_) => RegisterAddress::PwmFrequency,
+            SetOperation::TemperatureThreshold { .. } => RegisterAddress::CombinedTemperature,
+            SetOperation::WorkMode(_) => RegisterAddress::WorkMode,
+            SetOperation::FanSpeed(_) | SetOperation::AutoSpeed => {
+                RegisterAddress::ManualSpeedControl
+            }
+            SetOperation::Address(_) => RegisterAddress::ModbusAddress,
+        }
+    }
+}
+
+/// Outcome of [`Jpf4826Client::set_many`](crate::Jpf4826Client::set_many).
+#[derive(Debug, Default)]
+pub struct SetReport {
+    /// Number of operations applied, from the start of the list. Applied
+    /// operations stay applied only if [`error`](Self::error) is `None`.
+    pub applied: usize,
+    /// Error of the operation that failed, if any.
+    pub error: Option<Jpf4826Error>,
+    /// Registers written back to their previous value after the failure,
+    /// in the order written.
+    pub restored: Vec<RegisterAddress>,
+    /// Registers that could not be written back, with the error.
+    pub rollback_errors: Vec<(RegisterAddress, Jpf4826Error)>,
+}
+
+impl SetReport {
+    /// Returns whether every operation was applied.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns whether an operation failed and every register it may have
+    /// changed was restored.
+    pub fn is_rolled_back(&self) -> bool {
+        self.error.is_some() && self.rollback_errors.is_empty()
+    }
+}
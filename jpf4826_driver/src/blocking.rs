@@ -0,0 +1,467 @@
+//! Synchronous client for programs without an async runtime.
+//!
+//! [`Jpf4826Client`] wraps the async [`crate::Jpf4826Client`] together with
+//! a single-threaded tokio runtime of its own and blocks on every request.
+//! Its methods mirror the async client's and share its types and errors;
+//! see the async methods for the full documentation of each.
+//!
+//! The blocking client must not be used, or dropped, from within an async
+//! runtime: blocking there would stall the runtime's thread, and tokio
+//! panics when it notices. Async programs use the async client directly.
+//!
+//! Requires the `blocking` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use jpf4826_driver::blocking::Jpf4826Client;
+//!
+//! fn main() -> jpf4826_driver::Result<()> {
+//!     let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1)?;
+//!     let status = client.status()?;
+//!     println!("Temperature: {}°C", status.temperature_current.value);
+//!     client.set_fan_speed(60)?;
+//!     Ok(())
+//! }
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+
+use crate::batch::{SetOperation, SetReport};
+use crate::budget::WriteBudget;
+use crate::client::{ConnectOptions, DiscoveredDevice, ProbeResult};
+use crate::dump::RegisterDump;
+use crate::error::{Jpf4826Error, Result};
+use crate::frame::FrameObserver;
+use crate::health::BusHealth;
+use crate::link::LinkInfo;
+use crate::metrics::MetricsRecorder;
+use crate::model::DeviceModel;
+use crate::profile::{ApplyReport, ConfigMismatch, ControllerConfig, Profile};
+use crate::reconnect::ReconnectPolicy;
+use crate::registers::RegisterAddress;
+use crate::stats::ClientStats;
+use crate::timeout::TimeoutMode;
+use crate::types::{
+    ControllerStatus, FanIndex, FanIndexError, FanInfo, OperatingMode, OperatingModeState,
+    PwmFrequency, Temperature, TemperatureUnit, WorkMode,
+};
+
+/// Generates blocking versions of async client methods.
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&mut self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            #[doc = concat!(
+                "Blocking version of [`Jpf4826Client::", stringify!($name),
+                "`](crate::Jpf4826Client::", stringify!($name), ")."
+            )]
+            ///
+            /// # Errors
+            ///
+            /// Returns error as the async method does.
+            pub fn $name(&mut self $(, $arg: $ty)*) -> $ret {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// Synchronous JPF4826 client.
+///
+/// Owns the async client and a current-thread runtime driving it.
+pub struct Jpf4826Client {
+    inner: crate::Jpf4826Client,
+    runtime: Runtime,
+}
+
+impl Jpf4826Client {
+    /// Connects to the controller at `slave_addr` on the serial port
+    /// `port` with default settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`crate::Jpf4826Client::new`], or a serial error if
+    /// the runtime cannot be started.
+    pub fn new(port: &str, slave_addr: u8) -> Result<Self> {
+        Self::open(|| crate::Jpf4826Client::new(port, slave_addr))
+    }
+
+    /// Connects with a custom response timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`crate::Jpf4826Client::with_timeout`].
+    pub fn with_timeout(port: &str, slave_addr: u8, timeout: Duration) -> Result<Self> {
+        Self::open(|| crate::Jpf4826Client::with_timeout(port, slave_addr, timeout))
+    }
+
+    /// Connects at a non-default baud rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`crate::Jpf4826Client::new_with_baud`].
+    pub fn new_with_baud(port: &str, slave_addr: u8, baud_rate: u32) -> Result<Self> {
+        Self::open(|| crate::Jpf4826Client::new_with_baud(port, slave_addr, baud_rate))
+    }
+
+    /// Connects through a Modbus TCP gateway.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`crate::Jpf4826Client::new_tcp`].
+    pub fn new_tcp(socket_addr: std::net::SocketAddr, unit_id: u8) -> Result<Self> {
+        Self::open(|| crate::Jpf4826Client::new_tcp(socket_addr, unit_id))
+    }
+
+    /// Creates a client that opens the port on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`crate::Jpf4826Client::new_lazy`].
+    pub fn new_lazy(port: &str, slave_addr: u8, options: ConnectOptions) -> Result<Self> {
+        let runtime = new_runtime()?;
+        let inner = crate::Jpf4826Client::new_lazy(port, slave_addr, options)?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Creates a blocking mock client for testing (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub fn new_mock(
+        registers: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>,
+        slave_addr: u8,
+    ) -> Self {
+        let runtime = new_runtime().expect("runtime for the mock client");
+        let inner = runtime.block_on(crate::Jpf4826Client::new_mock(registers, slave_addr));
+        Self { inner, runtime }
+    }
+
+    /// Returns the mock's request counters, if this is a mock client
+    /// (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub fn mock_probe(&self) -> Option<crate::client::MockProbe> {
+        self.inner.mock_probe()
+    }
+
+    /// Starts the runtime and opens a client within it, so the port is
+    /// registered with this runtime's I/O driver.
+    fn open<F>(connect: impl FnOnce() -> F) -> Result<Self>
+    where
+        F: std::future::Future<Output = Result<crate::Jpf4826Client>>,
+    {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(connect())?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Blocking version of
+    /// [`Jpf4826Client::close`](crate::Jpf4826Client::close).
+    ///
+    /// # Errors
+    ///
+    /// Returns error as the async method does.
+    pub fn close(self) -> Result<()> {
+        self.runtime.block_on(self.inner.close())
+    }
+
+    /// Returns the wrapped async client, e.g. for settings without a
+    /// blocking counterpart.
+    pub fn get_ref(&self) -> &crate::Jpf4826Client {
+        &self.inner
+    }
+
+    /// Returns the wrapped async client mutably.
+    pub fn get_mut(&mut self) -> &mut crate::Jpf4826Client {
+        &mut self.inner
+    }
+
+    /// Returns true if the port is open.
+    pub fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    /// Sets the response timeout; see
+    /// [`crate::Jpf4826Client::set_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `timeout` is zero.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        self.inner.set_timeout(timeout)
+    }
+
+    /// Returns the response timeout.
+    pub fn timeout(&self) -> Duration {
+        self.inner.timeout()
+    }
+
+    /// Selects fixed or adaptive timeouts; see
+    /// [`crate::Jpf4826Client::set_timeout_mode`].
+    pub fn set_timeout_mode(&mut self, mode: TimeoutMode) {
+        self.inner.set_timeout_mode(mode);
+    }
+
+    /// Returns the timeout mode.
+    pub fn timeout_mode(&self) -> TimeoutMode {
+        self.inner.timeout_mode()
+    }
+
+    /// Sets the silence kept between requests; see
+    /// [`crate::Jpf4826Client::set_inter_frame_delay`].
+    pub fn set_inter_frame_delay(&mut self, delay: Duration) {
+        self.inner.set_inter_frame_delay(delay);
+    }
+
+    /// Returns the silence kept between requests.
+    pub fn inter_frame_delay(&self) -> Duration {
+        self.inner.inter_frame_delay()
+    }
+
+    /// Sets whether stale input is discarded before each request; see
+    /// [`crate::Jpf4826Client::set_flush_input`].
+    pub fn set_flush_input(&mut self, enabled: bool) {
+        self.inner.set_flush_input(enabled);
+    }
+
+    /// Returns whether stale input is discarded before each request.
+    pub fn flush_input(&self) -> bool {
+        self.inner.flush_input()
+    }
+
+    /// Sets how a lost serial port is reopened.
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.inner.set_reconnect_policy(policy);
+    }
+
+    /// Returns how a lost serial port is reopened.
+    pub fn reconnect_policy(&self) -> ReconnectPolicy {
+        self.inner.reconnect_policy()
+    }
+
+    /// Sets how often a failed request is repeated.
+    pub fn set_retries(&mut self, retries: u8) {
+        self.inner.set_retries(retries);
+    }
+
+    /// Returns how often a failed request is repeated.
+    pub fn retries(&self) -> u8 {
+        self.inner.retries()
+    }
+
+    /// Sets how often the Modbus layer repeats a timed-out or failed
+    /// request; see [`crate::Jpf4826Client::set_transport_retries`].
+    pub fn set_transport_retries(&mut self, retries: u8) {
+        self.inner.set_transport_retries(retries);
+    }
+
+    /// Returns how often the Modbus layer repeats a timed-out or failed
+    /// request.
+    pub fn transport_retries(&self) -> u8 {
+        self.inner.transport_retries()
+    }
+
+    /// Makes `set_fan_speed` refuse to leave temperature mode; see
+    /// [`crate::Jpf4826Client::set_strict_mode`].
+    pub fn set_strict_mode(&mut self, strict: bool) {
+        self.inner.set_strict_mode(strict);
+    }
+
+    /// Returns whether `set_fan_speed` refuses to leave temperature mode.
+    pub fn strict_mode(&self) -> bool {
+        self.inner.strict_mode()
+    }
+
+    /// Returns link health statistics.
+    pub fn link_info(&self) -> LinkInfo {
+        self.inner.link_info()
+    }
+
+    /// Returns a snapshot of the operation statistics.
+    pub fn stats(&self) -> ClientStats {
+        self.inner.stats()
+    }
+
+    /// Zeroes the operation statistics.
+    pub fn reset_stats(&self) {
+        self.inner.reset_stats();
+    }
+
+    /// Returns the bus health counters of the open connection.
+    pub fn bus_health(&self) -> BusHealth {
+        self.inner.bus_health()
+    }
+
+    /// Installs a recorder told about every transaction; see
+    /// [`crate::Jpf4826Client::set_metrics_recorder`].
+    pub fn set_metrics_recorder(&mut self, recorder: Arc<dyn MetricsRecorder>) {
+        self.inner.set_metrics_recorder(recorder);
+    }
+
+    /// Removes the metrics recorder, if any.
+    pub fn clear_metrics_recorder(&mut self) {
+        self.inner.clear_metrics_recorder();
+    }
+
+    /// Returns the installed metrics recorder.
+    pub fn metrics_recorder(&self) -> Option<&Arc<dyn MetricsRecorder>> {
+        self.inner.metrics_recorder()
+    }
+
+    /// Installs an observer of the raw Modbus frames; see
+    /// [`crate::Jpf4826Client::set_frame_observer`].
+    pub fn set_frame_observer(&mut self, observer: Arc<dyn FrameObserver>) {
+        self.inner.set_frame_observer(observer);
+    }
+
+    /// Removes the frame observer, if any.
+    pub fn clear_frame_observer(&mut self) {
+        self.inner.clear_frame_observer();
+    }
+
+    /// Returns the installed frame observer.
+    pub fn frame_observer(&self) -> Option<Arc<dyn FrameObserver>> {
+        self.inner.frame_observer()
+    }
+
+    /// Limits how often each register may be written; see
+    /// [`crate::Jpf4826Client::set_write_budget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error as the async method does.
+    pub fn set_write_budget(&mut self, budget: Option<WriteBudget>) -> Result<()> {
+        self.inner.set_write_budget(budget)
+    }
+
+    /// Returns the write budget, if any.
+    pub fn write_budget(&self) -> Option<WriteBudget> {
+        self.inner.write_budget()
+    }
+
+    /// Serves repeated reads from memory for `ttl`; see
+    /// [`crate::Jpf4826Client::set_cache_ttl`].
+    pub fn set_cache_ttl(&mut self, ttl: Duration) {
+        self.inner.set_cache_ttl(ttl);
+    }
+
+    /// Returns how long read responses are served from memory.
+    pub fn cache_ttl(&self) -> Duration {
+        self.inner.cache_ttl()
+    }
+
+    /// Returns the hardware revision the client was configured for.
+    pub fn model(&self) -> DeviceModel {
+        self.inner.model()
+    }
+
+    /// Blocking version of
+    /// [`Jpf4826Client::read_array`](crate::Jpf4826Client::read_array).
+    ///
+    /// # Errors
+    ///
+    /// Returns error as the async method does.
+    pub fn read_array<const N: usize>(&mut self, register: RegisterAddress) -> Result<[u16; N]> {
+        self.runtime.block_on(self.inner.read_array(register))
+    }
+
+    /// Blocking version of
+    /// [`Jpf4826Client::fan_speed`](crate::Jpf4826Client::fan_speed).
+    ///
+    /// # Errors
+    ///
+    /// Returns error as the async method does.
+    pub fn fan_speed<I>(&mut self, index: I) -> Result<u16>
+    where
+        I: TryInto<FanIndex>,
+        I::Error: Into<FanIndexError>,
+    {
+        self.runtime.block_on(self.inner.fan_speed(index))
+    }
+
+    blocking_methods! {
+        fn connect(&mut self) -> Result<()>;
+        fn read(&mut self, register: RegisterAddress, count: u16) -> Result<Vec<u16>>;
+        fn read_into(&mut self, register: RegisterAddress, buf: &mut [u16]) -> Result<usize>;
+        fn read_chunked(&mut self, addr: u16, count: usize) -> Result<Vec<u16>>;
+        fn temperature(&mut self) -> Result<Temperature>;
+        fn temperature_in(&mut self, unit: TemperatureUnit) -> Result<Temperature>;
+        fn second_temperature(&mut self) -> Result<Temperature>;
+        fn fan_speeds(&mut self) -> Result<[u16; 4]>;
+        fn fan_running(&mut self) -> Result<[bool; 4]>;
+        fn fan_count(&mut self) -> Result<u8>;
+        fn modbus_address(&mut self) -> Result<u8>;
+        fn work_mode(&mut self) -> Result<WorkMode>;
+        fn temperature_thresholds(&mut self) -> Result<(Temperature, Temperature)>;
+        fn pwm_frequency(&mut self) -> Result<PwmFrequency>;
+        fn operating_mode(&mut self) -> Result<OperatingMode>;
+        fn manual_speed(&mut self) -> Result<Option<u8>>;
+        fn mode_state(&mut self) -> Result<OperatingModeState>;
+        fn fan_status(&mut self) -> Result<Vec<FanInfo>>;
+        fn faulted_fans(&mut self) -> Result<Vec<u8>>;
+        fn status(&mut self) -> Result<ControllerStatus>;
+        fn get_config(&mut self) -> Result<ControllerConfig>;
+        fn write(&mut self, register: RegisterAddress, value: u16) -> Result<()>;
+        fn write_unchecked(&mut self, addr: u16, value: u16) -> Result<()>;
+        fn write_multiple(&mut self, register: RegisterAddress, values: &[u16]) -> Result<()>;
+        fn reset(&mut self) -> Result<()>;
+        fn reset_and_wait(&mut self, max_wait: Duration) -> Result<Duration>;
+        fn set_auto_speed(&mut self) -> Result<()>;
+        fn set_eco(&mut self, mode: WorkMode) -> Result<()>;
+        fn set_fan_speed(&mut self, speed_percent: u8) -> Result<()>;
+        fn set_manual_speed(&mut self, speed_percent: u8) -> Result<()>;
+        fn set_mode_state(&mut self, state: OperatingModeState) -> Result<()>;
+        fn identify(&mut self, duration: Duration) -> Result<()>;
+        fn set_fan_count(&mut self, count: u8) -> Result<()>;
+        fn disable_fault_detection(&mut self) -> Result<()>;
+        fn scan_bus(
+            &mut self,
+            range: RangeInclusive<u8>,
+            per_probe_timeout: Duration
+        ) -> Result<Vec<DiscoveredDevice>>;
+        fn probe(&mut self) -> Result<ProbeResult>;
+        fn dump_registers(&mut self) -> Result<RegisterDump>;
+        fn write_broadcast(&mut self, register: RegisterAddress, value: u16) -> Result<()>;
+        fn set_addr_broadcast(&mut self, addr: u8) -> Result<()>;
+        fn set_addr(&mut self, addr: u8) -> Result<()>;
+        fn change_address(&mut self, new_addr: u8) -> Result<()>;
+        fn set_pwm_frequency(&mut self, freq: PwmFrequency) -> Result<()>;
+        fn set_pwm_frequency_verified(&mut self, freq: PwmFrequency) -> Result<()>;
+        fn set_temperature_threshold(&mut self, low: i16, high: i16) -> Result<()>;
+        fn set_temperature_threshold_in(
+            &mut self,
+            low: i16,
+            high: i16,
+            unit: TemperatureUnit
+        ) -> Result<()>;
+        fn set_start_temperature(&mut self, low: i16) -> Result<()>;
+        fn set_full_speed_temperature(&mut self, high: i16) -> Result<()>;
+        fn set_many(&mut self, ops: &[SetOperation]) -> Result<SetReport>;
+        fn apply_config(&mut self, config: &ControllerConfig) -> Result<ApplyReport>;
+        fn apply_profile(&mut self, profile: &Profile) -> Result<ApplyReport>;
+        fn restore_config(&mut self, config: &ControllerConfig) -> Result<ApplyReport>;
+        fn restore_config_with_address(
+            &mut self,
+            config: &ControllerConfig
+        ) -> Result<ApplyReport>;
+        fn restore_factory_defaults(&mut self) -> Result<ApplyReport>;
+        fn verify_configuration(
+            &mut self,
+            expected: &ControllerConfig
+        ) -> Result<Vec<ConfigMismatch>>;
+    }
+}
+
+/// Starts the runtime driving one blocking client.
+fn new_runtime() -> Result<Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| Jpf4826Error::serial(format!("Cannot start the I/O runtime: {}", e)))
+}
@@ -0,0 +1,214 @@
+//! EEPROM write budgeting.
+//!
+//! The controller stores its configuration registers in non-volatile
+//! memory, which wears out after a limited number of writes. A control loop
+//! that rewrites the same settings every few seconds can exhaust it within
+//! months. A [`WriteBudget`] caps the writes per register in a sliding time
+//! window; excess writes are either rejected with an error (see
+//! [`Jpf4826Error::is_write_budget_exceeded`]) or delayed until the window
+//! has room again.
+//!
+//! Read-only registers and the reset command (0x0020) are not budgeted.
+//! Successful writes are counted in [`LinkInfo`](crate::LinkInfo) whether a
+//! budget is set or not.
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+#[cfg(doc)]
+use crate::error::Jpf4826Error;
+use crate::registers::RegisterAddress;
+
+/// What happens to a write that exceeds the budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BudgetPolicy {
+    /// Fail the write with a write budget error.
+    #[default]
+    Reject,
+    /// Wait until the window has room, then write.
+    Delay,
+}
+
+/// Maximum number of writes per register in a time window.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use jpf4826_driver::Jpf4826Client;
+/// # use jpf4826_driver::budget::{BudgetPolicy, WriteBudget};
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// // At most 10 writes per register and hour; wait instead of failing
+/// let budget = WriteBudget::new(10, Duration::from_secs(3600)).with_policy(BudgetPolicy::Delay);
+/// client.set_write_budget(Some(budget))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteBudget {
+    /// Writes allowed per register within `window`.
+    pub max_writes: u32,
+    /// Length of the sliding window.
+    pub window: Duration,
+    /// Handling of writes beyond the budget.
+    pub policy: BudgetPolicy,
+}
+
+impl WriteBudget {
+    /// Creates a budget that rejects writes beyond `max_writes` per
+    /// register within `window`.
+    pub fn new(max_writes: u32, window: Duration) -> Self {
+        Self {
+            max_writes,
+            window,
+            policy: BudgetPolicy::default(),
+        }
+    }
+
+    /// Sets how writes beyond the budget are handled.
+    pub fn with_policy(mut self, policy: BudgetPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+/// Returns true if writes to `addr` wear the controller's memory.
+pub(crate) fn is_budgeted(addr: u16) -> bool {
+    match RegisterAddress::from_addr(addr) {
+        Some(RegisterAddress::ResetController) => false,
+        Some(register) => !register.is_read_only(),
+        // Unknown registers are assumed to be persistent
+        None => true,
+    }
+}
+
+/// Sliding-window write history of one client.
+#[derive(Debug)]
+pub(crate) struct WriteLimiter {
+    budget: WriteBudget,
+    /// Times of recent writes, oldest first, by register address.
+    history: HashMap<u16, VecDeque<Instant>>,
+}
+
+impl WriteLimiter {
+    /// Creates a limiter with an empty history.
+    pub(crate) fn new(budget: WriteBudget) -> Self {
+        Self {
+            budget,
+            history: HashMap::new(),
+        }
+    }
+
+    /// Returns the budget being enforced.
+    pub(crate) fn budget(&self) -> WriteBudget {
+        self.budget
+    }
+
+    /// Returns the register that waits longest and how long, if writing
+    /// `count` registers from `addr` at `now` would exceed the budget.
+    pub(crate) fn wait_time(
+        &mut self,
+        addr: u16,
+        count: usize,
+        now: Instant,
+    ) -> Option<(u16, Duration)> {
+        let budget = self.budget;
+        let max_writes = usize::try_from(budget.max_writes).unwrap_or(usize::MAX);
+
+        registers(addr, count)
+            .filter_map(|register| {
+                let times = self.history.get_mut(&register)?;
+                while times
+                    .front()
+                    .is_some_and(|&time| now.duration_since(time) >= budget.window)
+                {
+                    times.pop_front();
+                }
+                if times.len() < max_writes {
+                    return None;
+                }
+                // The write becomes possible once enough old ones expire
+                let freed_by = times[times.len() - max_writes];
+                Some((register, freed_by + budget.window - now))
+            })
+            .max_by_key(|&(_, wait)| wait)
+    }
+
+    /// Records a write of `count` registers from `addr` at `now`.
+    pub(crate) fn record(&mut self, addr: u16, count: usize, now: Instant) {
+        for register in registers(addr, count) {
+            self.history.entry(register).or_default().push_back(now);
+        }
+    }
+}
+
+/// Returns the addresses of `count` consecutive registers from `addr`.
+pub(crate) fn register_range(addr: u16, count: usize) -> impl Iterator<Item = u16> {
+    (0..count).map_while(move |offset| addr.checked_add(u16::try_from(offset).ok()?))
+}
+
+/// Returns the budgeted registers among `count` registers from `addr`.
+fn registers(addr: u16, count: usize) -> impl Iterator<Item = u16> {
+    register_range(addr, count).filter(|&register| is_budgeted(register))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_is_budgeted() {
+        assert!(is_budgeted(RegisterAddress::CombinedTemperature.addr()));
+        assert!(is_budgeted(RegisterAddress::PwmFrequency.addr()));
+        assert!(!is_budgeted(RegisterAddress::Fan1Speed.addr()));
+        assert!(!is_budgeted(RegisterAddress::ResetController.addr()));
+        assert!(is_budgeted(0x0030));
+    }
+
+    #[test]
+    fn test_limiter_window_slides() {
+        let mut limiter = WriteLimiter::new(WriteBudget::new(2, WINDOW));
+        let start = Instant::now();
+
+        limiter.record(0x0005, 1, start);
+        limiter.record(0x0005, 1, start + Duration::from_secs(10));
+        assert_eq!(
+            limiter.wait_time(0x0005, 1, start + Duration::from_secs(20)),
+            Some((0x0005, Duration::from_secs(40)))
+        );
+        // Other registers have their own budget
+        assert_eq!(limiter.wait_time(0x0006, 1, start), None);
+
+        // The first write leaves the window after 60 s
+        assert_eq!(limiter.wait_time(0x0005, 1, start + WINDOW), None);
+        limiter.record(0x0005, 1, start + WINDOW);
+        assert_eq!(
+            limiter.wait_time(0x0005, 1, start + WINDOW),
+            Some((0x0005, Duration::from_secs(10)))
+        );
+    }
+
+    #[test]
+    fn test_limiter_checks_every_register_of_a_run() {
+        let mut limiter = WriteLimiter::new(WriteBudget::new(1, WINDOW));
+        let start = Instant::now();
+
+        limiter.record(0x0006, 1, start);
+        limiter.record(0x0005, 1, start + Duration::from_secs(30));
+        // 0x0005 waits 60 s, 0x0006 only 30 s
+        assert_eq!(
+            limiter.wait_time(0x0004, 3, start + Duration::from_secs(30)),
+            Some((0x0005, WINDOW))
+        );
+        // Read-only speed registers in a run are ignored
+        assert_eq!(limiter.wait_time(0x0007, 4, start), None);
+    }
+}
@@ -0,0 +1,126 @@
+//! Several controllers sharing one connection.
+//!
+//! A serial port can only be opened once, yet one RS485 segment carries
+//! controllers at different Modbus addresses. [`Jpf4826Bus`] owns a single
+//! [`Jpf4826Client`] and hands out a [`Jpf4826Device`] per address. Each
+//! request through a device locks the client and directs it to that
+//! device's address first, so requests from concurrent tasks are
+//! serialized and never reach the wrong controller.
+//!
+//! Unlike the [`manager`](crate::manager), the bus does not open or reopen
+//! the port itself; it uses whatever connection handling the wrapped client
+//! was built with.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use jpf4826_driver::{Jpf4826Bus, Jpf4826Client};
+//! # #[tokio::main]
+//! # async fn main() -> jpf4826_driver::Result<()> {
+//! let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+//! let bus = Jpf4826Bus::new(client);
+//! let intake = bus.device(3)?;
+//! let exhaust = bus.device(7)?;
+//!
+//! let (a, b) = tokio::join!(intake.status(), exhaust.status());
+//! println!("{}°C / {}°C", a?.temperature_current.value, b?.temperature_current.value);
+//!
+//! exhaust.lock().await.set_fan_speed(80).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::client::Jpf4826Client;
+use crate::error::{Jpf4826Error, Result};
+use crate::types::ControllerStatus;
+
+/// One connection shared by the controllers on a bus.
+///
+/// Cloning yields another handle to the same connection.
+#[derive(Clone)]
+pub struct Jpf4826Bus {
+    client: Arc<Mutex<Jpf4826Client>>,
+}
+
+impl Jpf4826Bus {
+    /// Wraps `client`; its own Modbus address only matters for requests
+    /// made before the first device is used.
+    pub fn new(client: Jpf4826Client) -> Self {
+        Self {
+            client: Arc::new(Mutex::new(client)),
+        }
+    }
+
+    /// Returns a handle to the controller at `addr`.
+    ///
+    /// Nothing is sent; a missing controller shows up as a timeout on the
+    /// first request.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Modbus address is out of range (1-254).
+    pub fn device(&self, addr: u8) -> Result<Jpf4826Device> {
+        if !(1..=254).contains(&addr) {
+            return Err(Jpf4826Error::invalid_address(addr));
+        }
+        Ok(Jpf4826Device {
+            client: self.client.clone(),
+            addr,
+        })
+    }
+}
+
+/// Handle to one controller on a [`Jpf4826Bus`].
+#[derive(Clone)]
+pub struct Jpf4826Device {
+    client: Arc<Mutex<Jpf4826Client>>,
+    addr: u8,
+}
+
+impl Jpf4826Device {
+    /// Returns the Modbus address of the controller.
+    pub fn addr(&self) -> u8 {
+        self.addr
+    }
+
+    /// Reads the controller status.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the read fails.
+    pub async fn status(&self) -> Result<ControllerStatus> {
+        self.lock().await.status().await
+    }
+
+    /// Locks the shared client for exclusive use, addressed to this
+    /// controller.
+    ///
+    /// All client methods are available through the guard; other devices
+    /// wait until it is dropped. Changing the Modbus address with
+    /// [`Jpf4826Client::set_addr`] is not tracked; take a new handle from
+    /// the bus instead.
+    pub async fn lock(&self) -> MutexGuard<'_, Jpf4826Client> {
+        let mut client = self.client.lock().await;
+        client.select_slave(self.addr);
+        client
+    }
+
+    /// Runs `f` with exclusive access to the client, addressed to this
+    /// controller.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`.
+    pub async fn with_client<T>(
+        &self,
+        f: impl AsyncFnOnce(&mut Jpf4826Client) -> Result<T>,
+    ) -> Result<T> {
+        f(&mut *self.lock().await).await
+    }
+}
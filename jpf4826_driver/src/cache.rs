@@ -0,0 +1,183 @@
+//! Short-lived cache of read responses.
+//!
+//! With a TTL set through
+//! [`Jpf4826Client::set_cache_ttl`](crate::Jpf4826Client::set_cache_ttl),
+//! complete read responses are kept for that long. A later read of the same
+//! registers, or of registers within a cached range, is answered from
+//! memory. Writes drop every cached range they overlap.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Most ranges kept at once; the oldest is evicted beyond this.
+const MAX_ENTRIES: usize = 16;
+
+/// Values of consecutive registers from one read response.
+struct Entry {
+    addr: u16,
+    values: Vec<u16>,
+    stored: Instant,
+}
+
+impl Entry {
+    /// Returns the address after the last register held.
+    fn end(&self) -> u32 {
+        u32::from(self.addr) + self.values.len() as u32
+    }
+
+    fn overlaps(&self, addr: u16, count: usize) -> bool {
+        u32::from(addr) < self.end() && u32::from(self.addr) < u32::from(addr) + count as u32
+    }
+}
+
+/// Read responses younger than the TTL, oldest first.
+pub(crate) struct ReadCache {
+    ttl: Duration,
+    entries: Vec<Entry>,
+}
+
+impl ReadCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Fills `buf` with the cached values of the registers from `addr`.
+    ///
+    /// Returns false, leaving `buf` untouched, unless one fresh range holds
+    /// all of them.
+    pub(crate) fn get_into(&mut self, addr: u16, buf: &mut [u16], now: Instant) -> bool {
+        if buf.is_empty() {
+            return false;
+        }
+        self.expire(now);
+        let end = u32::from(addr) + buf.len() as u32;
+        let Some(entry) = self
+            .entries
+            .iter()
+            .find(|entry| entry.addr <= addr && end <= entry.end())
+        else {
+            return false;
+        };
+        let start = usize::from(addr - entry.addr);
+        buf.copy_from_slice(&entry.values[start..start + buf.len()]);
+        true
+    }
+
+    /// Stores the values read from `addr`, replacing overlapping ranges.
+    pub(crate) fn insert(&mut self, addr: u16, values: &[u16], now: Instant) {
+        if values.is_empty() {
+            return;
+        }
+        self.expire(now);
+        // Older values of the same registers are superseded
+        self.invalidate(addr, values.len());
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(Entry {
+            addr,
+            values: values.to_vec(),
+            stored: now,
+        });
+    }
+
+    /// Drops every range overlapping the `count` registers from `addr`.
+    pub(crate) fn invalidate(&mut self, addr: u16, count: usize) {
+        self.entries.retain(|entry| !entry.overlaps(addr, count));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn expire(&mut self, now: Instant) {
+        let ttl = self.ttl;
+        self.entries
+            .retain(|entry| now.duration_since(entry.stored) < ttl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_millis(200);
+
+    fn cached(cache: &mut ReadCache, addr: u16, count: usize, now: Instant) -> Option<Vec<u16>> {
+        let mut buf = vec![0; count];
+        cache.get_into(addr, &mut buf, now).then_some(buf)
+    }
+
+    #[test]
+    fn test_serves_contained_ranges() {
+        let now = Instant::now();
+        let mut cache = ReadCache::new(TTL);
+        cache.insert(0x0000, &[71, 15, 1, 0xFFFF], now);
+
+        assert_eq!(
+            cached(&mut cache, 0x0000, 4, now),
+            Some(vec![71, 15, 1, 0xFFFF])
+        );
+        assert_eq!(cached(&mut cache, 0x0001, 2, now), Some(vec![15, 1]));
+        assert_eq!(cached(&mut cache, 0x0003, 2, now), None);
+        assert_eq!(cached(&mut cache, 0x0004, 1, now), None);
+        assert_eq!(cached(&mut cache, 0x0000, 0, now), None);
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let now = Instant::now();
+        let mut cache = ReadCache::new(TTL);
+        cache.insert(0x0000, &[71], now);
+
+        assert!(cached(&mut cache, 0x0000, 1, now + TTL / 2).is_some());
+        assert!(cached(&mut cache, 0x0000, 1, now + TTL).is_none());
+    }
+
+    #[test]
+    fn test_invalidate_drops_overlapping_ranges() {
+        let now = Instant::now();
+        let mut cache = ReadCache::new(TTL);
+        cache.insert(0x0000, &[71, 15, 1], now);
+        cache.insert(0x0006, &[4, 1400], now);
+
+        cache.invalidate(0x0002, 1);
+
+        assert!(cached(&mut cache, 0x0000, 1, now).is_none());
+        assert_eq!(cached(&mut cache, 0x0006, 2, now), Some(vec![4, 1400]));
+    }
+
+    #[test]
+    fn test_insert_supersedes_older_values() {
+        let now = Instant::now();
+        let mut cache = ReadCache::new(TTL);
+        cache.insert(0x0000, &[71, 15, 1], now);
+
+        cache.insert(0x0001, &[14], now);
+
+        assert_eq!(cached(&mut cache, 0x0001, 1, now), Some(vec![14]));
+        assert!(cached(&mut cache, 0x0000, 1, now).is_none());
+    }
+
+    #[test]
+    fn test_oldest_range_is_evicted() {
+        let now = Instant::now();
+        let mut cache = ReadCache::new(TTL);
+        for addr in 0..=MAX_ENTRIES as u16 {
+            cache.insert(addr, &[addr], now);
+        }
+
+        assert!(cached(&mut cache, 0, 1, now).is_none());
+        assert_eq!(cached(&mut cache, 1, 1, now), Some(vec![1]));
+    }
+}
@@ -1,18 +1,106 @@
 //! JPF4826 client implementation for Modbus-RTU communication.
 //!
 //! This module provides the main client interface for interacting with
-//! JPF4826 fan controllers via serial Modbus-RTU protocol.
+//! JPF4826 fan controllers via serial Modbus-RTU protocol, or Modbus TCP
+//! through a gateway.
+//!
+//! # Cancellation
+//!
+//! Every method may be dropped at an `.await`, e.g. as the losing branch
+//! of a `select!`. The client stays usable: a request dropped before its
+//! answer arrived makes the next request resynchronize the port first, so
+//! a late answer is never mistaken for a new one. A client on a stream
+//! supplied by the caller ([`with_transport`](Jpf4826Client::with_transport))
+//! cannot reopen it and discards the input left on it instead; only with
+//! [`set_flush_input(false)`](Jpf4826Client::set_flush_input) does it fail
+//! every later operation.
+//!
+//! A dropped write may or may not have reached the controller; read the
+//! register back if it matters. Beyond that, the guarantees are:
+//!
+//! - Reads, [`status`](Jpf4826Client::status) and every method that writes
+//!   a single frame are cancellation-safe: the frame is either applied as a
+//!   whole or not at all. This includes
+//!   [`set_temperature_threshold`](Jpf4826Client::set_temperature_threshold),
+//!   which writes both thresholds in one register.
+//! - [`apply_config`](Jpf4826Client::apply_config) and
+//!   [`apply_profile`](Jpf4826Client::apply_profile) write up to two frames.
+//!   Dropped between them, only the first is applied. Use
+//!   [`apply_config_until`](Jpf4826Client::apply_config_until) to stop
+//!   between frames and learn what was written.
+//! - [`restore_factory_defaults`](Jpf4826Client::restore_factory_defaults)
+//!   writes the configuration and then selects temperature mode. Dropped
+//!   before the last write, the fans keep their manual speed.
+//! - [`identify`](Jpf4826Client::identify) leaves the fans pulsing when
+//!   dropped; [`identify_until`](Jpf4826Client::identify_until) stops at a
+//!   cancel signal and restores the speed setting.
+//! - [`set_addr`](Jpf4826Client::set_addr) is not cancellation-safe. If it
+//!   is dropped while the write is in flight, the controller may already
+//!   answer at the new address while the client still uses the old one.
 
 // Rust guideline compliant 2026-01-27
 
+#[cfg(feature = "stream")]
+use crate::watch::StatusWatch;
 use crate::{
-    conversions::{celsius_to_register, parse_fan_fault_bitmap, register_to_celsius},
+    batch::{SetOperation, SetReport},
+    budget::{register_range, BudgetPolicy, WriteBudget, WriteLimiter},
+    cache::ReadCache,
+    conversions::{
+        celsius_to_register, checked_register_to_celsius, encode_combined_temperature,
+        parse_fan_fault_bitmap, parse_fan_status_bitmap, register_to_celsius_in_range,
+        register_to_modbus_address,
+    },
+    dump::{DumpValue, RegisterDump},
     error::{Jpf4826Error, Result},
-    modbus::DEFAULT_TIMEOUT,
-    registers::RegisterAddress,
-    types::{ControllerStatus, FanInfo, PwmFrequency, Temperature, TemperatureUnit, WorkMode},
+    frame::FrameObserver,
+    health::BusHealth,
+    link::LinkInfo,
+    metrics::{MetricsRecorder, OperationOutcome},
+    modbus::{
+        check_response_length, check_timeout, inter_frame_delay, is_valid_mode, SerialSettings,
+        BAUD_RATE, DEFAULT_TIMEOUT, MAX_READ_COUNT, MAX_WRITE_COUNT, SUPPORTED_BAUD_RATES,
+    },
+    model::DeviceModel,
+    profile::{
+        ApplyReport, ConfigError, ConfigMismatch, ControllerConfig, Profile, SpeedSetting,
+        Thresholds,
+    },
+    reconnect::ReconnectPolicy,
+    registers::{RegisterAccess, RegisterAddress},
+    stats::{ClientStats, Operation, StatsCounters},
+    timeout::TimeoutMode,
+    types::{
+        ControllerStatus, FanIndex, FanIndexError, FanInfo, FanStatus, OperatingMode,
+        OperatingModeState, PwmFrequency, Temperature, TemperatureUnit, WorkMode,
+    },
 };
-use std::time::Duration;
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Upper bound of the timeout applied by [`Jpf4826Client::probe`].
+pub const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Pause before each poll of [`Jpf4826Client::reset_and_wait`].
+pub const RESET_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// First register of the block read by [`Jpf4826Client::dump_registers`].
+const DUMP_BLOCK_START: u16 = 0x0000;
+
+/// Registers read one at a time by [`Jpf4826Client::dump_registers`]: the
+/// second temperature input and the neighbourhood of the reset command.
+const DUMP_EXTRA_ADDRESSES: [u16; 4] = [0x000F, 0x001F, 0x0020, 0x0021];
+
+/// Speeds alternated by [`Jpf4826Client::identify`], in percent.
+const IDENTIFY_PATTERN: [u16; 2] = [100, 20];
+
+/// How long [`Jpf4826Client::identify`] holds each speed.
+const IDENTIFY_PERIOD: Duration = Duration::from_secs(1);
+
+/// Value of register 0x0003 selecting temperature mode.
+const AUTO_SPEED: u16 = 0xFFFF;
 
 /// JPF4826 fan controller client.
 ///
@@ -40,48 +128,3025 @@ use std::time::Duration;
 /// ```
 pub struct Jpf4826Client {
     backend: ClientBackend,
+    /// Mode last selected through this client.
+    mode: OperatingMode,
+    /// Transaction statistics since creation.
+    link: LinkInfo,
+    /// Operation counters and latency, reset on request.
+    stats: StatsCounters,
+    /// Receiver of per-transaction metrics, if installed.
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    /// Write budget enforcement, if enabled.
+    limiter: Option<WriteLimiter>,
+    /// Recent read responses, if caching is enabled.
+    cache: Option<ReadCache>,
+    /// Hardware revision, selecting the register map quirks.
+    model: DeviceModel,
+    /// How often a failed request is repeated.
+    retries: u8,
+    /// Whether `set_fan_speed` refuses to leave temperature mode.
+    strict_mode: bool,
 }
 
 /// Internal backend abstraction for testing.
 enum ClientBackend {
     #[cfg(any(test, feature = "test-mock"))]
     Mock(MockBackend),
-    RealModbus(crate::modbus::ModbusRtuClient),
+    RealModbus(crate::modbus::ModbusClient),
+    /// Not opened yet; see [`Jpf4826Client::new_lazy`].
+    Pending(PendingConnection),
+}
+
+/// Parity of the serial line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parity {
+    /// No parity bit (JPF4826 default).
+    #[default]
+    None,
+    /// Even parity.
+    Even,
+    /// Odd parity.
+    Odd,
+}
+
+/// Number of data bits in each character on the serial line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataBits {
+    /// Seven data bits.
+    Seven,
+    /// Eight data bits (JPF4826 default, required by Modbus RTU).
+    #[default]
+    Eight,
+}
+
+/// Number of stop bits on the serial line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StopBits {
+    /// One stop bit (JPF4826 default).
+    #[default]
+    One,
+    /// Two stop bits.
+    Two,
+}
+
+/// Settings for opening the serial port.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::ConnectOptions;
+/// # use std::time::Duration;
+/// let options = ConnectOptions {
+///     timeout: Duration::from_secs(2),
+///     ..ConnectOptions::default()
+/// };
+/// assert_eq!(options.baud_rate, 9600);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectOptions {
+    /// Timeout for each Modbus operation.
+    pub timeout: Duration,
+    /// Baud rate of the serial port.
+    pub baud_rate: u32,
+    /// Data bits of the serial port.
+    pub data_bits: DataBits,
+    /// Parity of the serial port.
+    pub parity: Parity,
+    /// Stop bits of the serial port.
+    pub stop_bits: StopBits,
+    /// How often a failed request is repeated (see
+    /// [`Jpf4826Client::set_retries`]).
+    pub retries: u8,
+    /// Hardware revision of the controller.
+    pub model: DeviceModel,
+}
+
+impl Default for ConnectOptions {
+    /// Uses [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT), the JPF4826's
+    /// standard 9600 8N1, no retries and the original
+    /// [`DeviceModel::Jpf4826`].
+    fn default() -> Self {
+        let serial = SerialSettings::default();
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            baud_rate: serial.baud_rate,
+            data_bits: serial.data_bits,
+            parity: serial.parity,
+            stop_bits: serial.stop_bits,
+            retries: 0,
+            model: DeviceModel::default(),
+        }
+    }
+}
+
+/// Controller found by [`Jpf4826Client::scan_bus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscoveredDevice {
+    /// Modbus address the controller answered at.
+    pub addr: u8,
+    /// Current temperature, `None` if the sensor reports a fault.
+    pub temperature: Option<Temperature>,
+}
+
+/// Answer to [`Jpf4826Client::probe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeResult {
+    /// Whether the controller answered; always true, as a missing answer
+    /// is returned as an error.
+    pub responded: bool,
+    /// Time from sending the request to receiving the answer.
+    pub round_trip: Duration,
+}
+
+/// Builder for a [`Jpf4826Client`] with non-default connection settings.
+///
+/// Starts from [`ConnectOptions::default`], i.e. what
+/// [`Jpf4826Client::new`] uses.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::{Jpf4826ClientBuilder, Parity};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let mut client = Jpf4826ClientBuilder::new("/dev/ttyUSB0", 1)
+///     .with_baud_rate(19200)
+///     .with_parity(Parity::Even)
+///     .with_timeout(Duration::from_secs(2))
+///     .with_retries(2)
+///     .connect()
+///     .await?;
+/// let temp = client.temperature().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Jpf4826ClientBuilder {
+    port: String,
+    slave_addr: u8,
+    options: ConnectOptions,
+}
+
+impl Jpf4826ClientBuilder {
+    /// Creates a builder for the controller at `slave_addr` on `port`.
+    pub fn new(port: &str, slave_addr: u8) -> Self {
+        Self {
+            port: port.to_string(),
+            slave_addr,
+            options: ConnectOptions::default(),
+        }
+    }
+
+    /// Sets the baud rate (default: 9600).
+    pub fn with_baud_rate(mut self, baud_rate: u32) -> Self {
+        self.options.baud_rate = baud_rate;
+        self
+    }
+
+    /// Sets the number of data bits (default: eight).
+    ///
+    /// Modbus RTU frames need eight data bits; seven only suits adapters
+    /// or gateways that translate the line.
+    pub fn with_data_bits(mut self, data_bits: DataBits) -> Self {
+        self.options.data_bits = data_bits;
+        self
+    }
+
+    /// Sets the parity (default: none).
+    pub fn with_parity(mut self, parity: Parity) -> Self {
+        self.options.parity = parity;
+        self
+    }
+
+    /// Sets the number of stop bits (default: one).
+    pub fn with_stop_bits(mut self, stop_bits: StopBits) -> Self {
+        self.options.stop_bits = stop_bits;
+        self
+    }
+
+    /// Sets the timeout for each Modbus operation (default:
+    /// [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT)).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = timeout;
+        self
+    }
+
+    /// Sets how often a failed request is repeated (default: 0).
+    pub fn with_retries(mut self, retries: u8) -> Self {
+        self.options.retries = retries;
+        self
+    }
+
+    /// Sets the hardware revision of the controller.
+    pub fn with_model(mut self, model: DeviceModel) -> Self {
+        self.options.model = model;
+        self
+    }
+
+    /// Returns the settings the client will be created with.
+    pub fn options(&self) -> &ConnectOptions {
+        &self.options
+    }
+
+    /// Creates a client that opens the port on first use, as
+    /// [`Jpf4826Client::new_lazy`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Modbus address is out of range (1-254), the
+    /// baud rate is zero or the timeout is below
+    /// [`MIN_TIMEOUT`](crate::MIN_TIMEOUT).
+    pub fn build(self) -> Result<Jpf4826Client> {
+        if self.options.baud_rate == 0 {
+            return Err(Jpf4826Error::invalid_parameter(
+                "Baud rate must be greater than zero",
+            ));
+        }
+        Jpf4826Client::new_lazy(&self.port, self.slave_addr, self.options)
+    }
+
+    /// Creates the client and opens the serial port.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the settings are invalid (see [`build`](Self::build))
+    /// or the serial port cannot be opened. Invalid settings are reported
+    /// without touching the port.
+    pub async fn connect(self) -> Result<Jpf4826Client> {
+        let mut client = self.build()?;
+        client.connect().await?;
+        Ok(client)
+    }
+}
+
+/// Everything needed to open the connection of a lazy client.
+struct PendingConnection {
+    port: String,
+    slave_addr: u8,
+    serial: SerialSettings,
+    timeout_mode: TimeoutMode,
+    reconnect: ReconnectPolicy,
+    /// Inter-frame delay, if not derived from the baud rate.
+    inter_frame_delay: Option<Duration>,
+    transport_retries: u8,
+    frame_observer: Option<Arc<dyn FrameObserver>>,
+    flush_input: bool,
+    /// Bus to connect to instead of the serial port (test-only).
+    #[cfg(any(test, feature = "test-mock"))]
+    bus: Option<MockBus>,
+}
+
+impl PendingConnection {
+    async fn open(&self) -> Result<ClientBackend> {
+        #[cfg(any(test, feature = "test-mock"))]
+        if let Some(bus) = &self.bus {
+            return bus.open(self.slave_addr).map(ClientBackend::Mock);
+        }
+
+        let mut modbus_client =
+            crate::modbus::ModbusClient::new(&self.port, self.slave_addr, self.serial).await?;
+        modbus_client.set_timeout_mode(self.timeout_mode);
+        modbus_client.set_reconnect_policy(self.reconnect);
+        if let Some(delay) = self.inter_frame_delay {
+            modbus_client.set_inter_frame_delay(delay);
+        }
+        modbus_client.set_retries(self.transport_retries);
+        modbus_client.set_frame_observer(self.frame_observer.clone());
+        modbus_client.set_flush_input(self.flush_input);
+        Ok(ClientBackend::RealModbus(modbus_client))
+    }
+
+    /// Sets the policy the connection will be opened with.
+    ///
+    /// Fixed timeouts below [`MIN_TIMEOUT`](crate::MIN_TIMEOUT) are
+    /// ignored, as on an open connection.
+    fn set_timeout_mode(&mut self, mode: TimeoutMode) {
+        if is_valid_mode(mode) {
+            self.timeout_mode = mode;
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        crate::timeout::TimeoutState::new(self.timeout_mode, self.serial.baud_rate).current()
+    }
+}
+
+/// Register map of a mock controller.
+#[cfg(any(test, feature = "test-mock"))]
+type MockRegisters = std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>;
+
+/// Keeps the combined threshold register in step with the separate ones
+/// after a write to `addr`, as the controller does.
+#[cfg(any(test, feature = "test-mock"))]
+fn mirror_thresholds(registers: &mut std::collections::HashMap<u16, u16>, addr: u16) {
+    let combined = RegisterAddress::CombinedTemperature.addr();
+    let start = RegisterAddress::StartTemperature.addr();
+    let full = RegisterAddress::FullSpeedTemperature.addr();
+
+    if addr == combined {
+        let value = registers[&combined];
+        registers.insert(start, value >> 8);
+        registers.insert(full, value & 0xFF);
+    } else if addr == start || addr == full {
+        let low = registers.get(&start).copied().unwrap_or(0);
+        let high = registers.get(&full).copied().unwrap_or(0);
+        registers.insert(combined, (low & 0xFF) << 8 | (high & 0xFF));
+    }
 }
 
 #[cfg(any(test, feature = "test-mock"))]
 pub(crate) struct MockBackend {
-    pub controller: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>,
-    slave_addr: std::cell::Cell<u8>,
+    pub controller: MockRegisters,
+    /// Devices addressed by slave address, for clients on a [`MockBus`].
+    bus: Option<MockBus>,
+    slave_addr: u8,
+    probe: MockProbe,
+}
+
+/// Simulated RS485 bus with several mock controllers (test-only).
+///
+/// Clients connected to the bus see the controller at their current slave
+/// address; requests to an address without a device time out, and every
+/// request fails while the bus is offline. A controller whose address
+/// register is written moves to the new address.
+#[doc(hidden)]
+#[cfg(any(test, feature = "test-mock"))]
+#[derive(Debug, Clone, Default)]
+pub struct MockBus {
+    devices: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u8, MockRegisters>>>,
+    offline: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    connects: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[cfg(any(test, feature = "test-mock"))]
+impl MockBus {
+    /// Creates an empty bus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a controller answering at `addr`.
+    pub fn add_device(&self, addr: u8, registers: MockRegisters) {
+        self.devices.lock().unwrap().insert(addr, registers);
+    }
+
+    /// Detaches the controller at `addr`.
+    pub fn remove_device(&self, addr: u8) {
+        self.devices.lock().unwrap().remove(&addr);
+    }
+
+    /// Moves the controller at `from` to address `to`, as writing its
+    /// address register does.
+    fn move_device(&self, from: u8, to: u8) {
+        let mut devices = self.devices.lock().unwrap();
+        if let Some(registers) = devices.remove(&from) {
+            devices.insert(to, registers);
+        }
+    }
+
+    /// Simulates unplugging (`true`) or reconnecting the adapter.
+    ///
+    /// While offline, connecting fails with a serial error and requests on
+    /// existing connections fail with a Modbus error.
+    pub fn set_offline(&self, offline: bool) {
+        self.offline
+            .store(offline, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Returns the number of successful connections so far.
+    pub fn connects(&self) -> usize {
+        self.connects.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Opens a client on the bus, as opening the serial port would.
+    pub(crate) fn connect(&self, slave_addr: u8) -> Result<Jpf4826Client> {
+        self.open(slave_addr)
+            .map(|mock| Jpf4826Client::from_backend(ClientBackend::Mock(mock)))
+    }
+
+    fn open(&self, slave_addr: u8) -> Result<MockBackend> {
+        use std::sync::atomic::Ordering;
+
+        if self.offline.load(Ordering::SeqCst) {
+            return Err(Jpf4826Error::serial("mock bus offline"));
+        }
+        self.connects.fetch_add(1, Ordering::SeqCst);
+        Ok(MockBackend {
+            controller: MockRegisters::default(),
+            bus: Some(self.clone()),
+            slave_addr,
+            probe: MockProbe::default(),
+        })
+    }
+
+    fn all_devices(&self) -> Result<Vec<MockRegisters>> {
+        if self.offline.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(Jpf4826Error::modbus("mock bus offline"));
+        }
+        Ok(self.devices.lock().unwrap().values().cloned().collect())
+    }
+
+    fn device(&self, addr: u8) -> Result<MockRegisters> {
+        if self.offline.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(Jpf4826Error::modbus("mock bus offline"));
+        }
+        self.devices
+            .lock()
+            .unwrap()
+            .get(&addr)
+            .cloned()
+            .ok_or_else(|| Jpf4826Error::timeout(DEFAULT_TIMEOUT))
+    }
+}
+
+/// Failure injected into a mock request (test-only).
+///
+/// Each produces the error kind the serial backend returns for it, so
+/// checks such as [`Jpf4826Error::is_timeout`] behave alike.
+#[doc(hidden)]
+#[cfg(any(test, feature = "test-mock"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockFailure {
+    /// No answer: a timeout error.
+    Timeout,
+    /// An exception response (Server Device Failure): a Modbus error.
+    Exception,
+    /// A failure on the transport, e.g. a garbled frame: a Modbus error.
+    Transport,
+}
+
+#[cfg(any(test, feature = "test-mock"))]
+impl MockFailure {
+    /// Returns the error of a request at `addr` failing this way.
+    fn error(self, addr: u16, operation: &str) -> Jpf4826Error {
+        match self {
+            Self::Timeout => Jpf4826Error::timeout(DEFAULT_TIMEOUT),
+            Self::Exception => Jpf4826Error::exception(addr, "ServerDeviceFailure"),
+            Self::Transport => Jpf4826Error::modbus(format!(
+                "Failed to {} at 0x{:04X}: mock transport failure",
+                operation, addr
+            )),
+        }
+    }
+}
+
+/// Failure injected into the next `remaining` requests covering `addr`,
+/// or any address if `None`.
+#[cfg(any(test, feature = "test-mock"))]
+#[derive(Debug, Clone, Copy)]
+struct Injection {
+    addr: Option<u16>,
+    remaining: usize,
+    failure: MockFailure,
+}
+
+/// Takes the first injected failure matching a request for `start..end`.
+#[cfg(any(test, feature = "test-mock"))]
+fn take_injection(
+    injections: &std::sync::Mutex<Vec<Injection>>,
+    start: u16,
+    end: u16,
+) -> Option<MockFailure> {
+    let mut injections = injections.lock().unwrap();
+    let index = injections.iter().position(|injection| {
+        injection
+            .addr
+            .is_none_or(|addr| (start..end).contains(&addr))
+    })?;
+    let injection = &mut injections[index];
+    let failure = injection.failure;
+    injection.remaining -= 1;
+    if injection.remaining == 0 {
+        injections.remove(index);
+    }
+    Some(failure)
+}
+
+/// Instrumentation of a mock client (test-only).
+///
+/// Counts register reads and writes and lets tests slow down or fail
+/// requests.
+#[doc(hidden)]
+#[cfg(any(test, feature = "test-mock"))]
+#[derive(Debug, Clone, Default)]
+pub struct MockProbe {
+    reads: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    writes: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    read_delay: std::sync::Arc<std::sync::Mutex<Duration>>,
+    fail_reads: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    response_limit: std::sync::Arc<std::sync::Mutex<Option<usize>>>,
+    write_delay: std::sync::Arc<std::sync::Mutex<Duration>>,
+    write_log: std::sync::Arc<std::sync::Mutex<Vec<(u16, u16)>>>,
+    read_log: std::sync::Arc<std::sync::Mutex<Vec<(u16, usize)>>>,
+    rejected_reads: std::sync::Arc<std::sync::Mutex<Vec<u16>>>,
+    ignored_writes: std::sync::Arc<std::sync::Mutex<Vec<u16>>>,
+    rejected_writes: std::sync::Arc<std::sync::Mutex<Vec<u16>>>,
+    write_allowance: std::sync::Arc<std::sync::Mutex<Option<usize>>>,
+    silent_requests: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    broadcast_log: std::sync::Arc<std::sync::Mutex<Vec<(u16, u16)>>>,
+    read_injections: std::sync::Arc<std::sync::Mutex<Vec<Injection>>>,
+    write_injections: std::sync::Arc<std::sync::Mutex<Vec<Injection>>>,
+}
+
+#[cfg(any(test, feature = "test-mock"))]
+impl MockProbe {
+    /// Returns the number of read transactions so far.
+    pub fn reads(&self) -> usize {
+        self.reads.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Returns the number of write transactions so far.
+    pub fn writes(&self) -> usize {
+        self.writes.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Returns every register written so far as `(address, value)`, in
+    /// order.
+    pub fn write_log(&self) -> Vec<(u16, u16)> {
+        self.write_log.lock().unwrap().clone()
+    }
+
+    /// Returns every read so far as `(start address, register count)`, in
+    /// order.
+    pub fn read_log(&self) -> Vec<(u16, usize)> {
+        self.read_log.lock().unwrap().clone()
+    }
+
+    /// Returns every register written with the broadcast address (0) so
+    /// far as `(address, value)`, in order. These are not in
+    /// [`write_log`](Self::write_log).
+    pub fn broadcast_log(&self) -> Vec<(u16, u16)> {
+        self.broadcast_log.lock().unwrap().clone()
+    }
+
+    /// Delays every following read by `delay`.
+    pub fn set_read_delay(&self, delay: Duration) {
+        *self.read_delay.lock().unwrap() = delay;
+    }
+
+    /// Delays every following write by `delay` before it takes effect, so
+    /// tests can drop a write while it is in flight.
+    pub fn set_write_delay(&self, delay: Duration) {
+        *self.write_delay.lock().unwrap() = delay;
+    }
+
+    /// Makes every following read fail with a Modbus error.
+    pub fn set_fail_reads(&self, fail: bool) {
+        self.fail_reads
+            .store(fail, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Leaves the next `count` reads or writes unanswered, so they fail
+    /// with a timeout, as while the controller restarts.
+    pub fn set_silent_requests(&self, count: usize) {
+        self.silent_requests
+            .store(count, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Takes one silent request if any are left.
+    fn take_silent_request(&self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        self.silent_requests
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    /// Makes every following read covering `addr` fail with a Modbus
+    /// exception, as a controller refusing an unknown address does.
+    pub fn reject_reads_at(&self, addr: u16) {
+        self.rejected_reads.lock().unwrap().push(addr);
+    }
+
+    /// Acknowledges every following write to `addr` without changing the
+    /// register, as firmware silently ignoring it does.
+    pub fn ignore_writes_at(&self, addr: u16) {
+        self.ignored_writes.lock().unwrap().push(addr);
+    }
+
+    /// Makes every following write covering `addr` fail with a Modbus
+    /// exception, leaving the registers unchanged.
+    pub fn reject_writes_at(&self, addr: u16) {
+        self.rejected_writes.lock().unwrap().push(addr);
+    }
+
+    /// Lets the next `count` writes through and makes every write after
+    /// them fail with a Modbus error.
+    pub fn fail_writes_after(&self, count: usize) {
+        *self.write_allowance.lock().unwrap() = Some(count);
+    }
+
+    /// Makes the next read fail with `failure`.
+    ///
+    /// Injected failures are taken in the order they were added.
+    pub fn fail_next_read(&self, failure: MockFailure) {
+        self.inject(&self.read_injections, None, 1, failure);
+    }
+
+    /// Makes the next `count` reads covering `addr` fail with `failure`.
+    pub fn fail_reads_at(&self, addr: u16, count: usize, failure: MockFailure) {
+        self.inject(&self.read_injections, Some(addr), count, failure);
+    }
+
+    /// Makes the next write fail with `failure`, leaving the registers
+    /// unchanged.
+    pub fn fail_next_write(&self, failure: MockFailure) {
+        self.inject(&self.write_injections, None, 1, failure);
+    }
+
+    /// Makes the next `count` writes covering `addr` fail with `failure`,
+    /// leaving the registers unchanged.
+    pub fn fail_writes_at(&self, addr: u16, count: usize, failure: MockFailure) {
+        self.inject(&self.write_injections, Some(addr), count, failure);
+    }
+
+    fn inject(
+        &self,
+        injections: &std::sync::Mutex<Vec<Injection>>,
+        addr: Option<u16>,
+        count: usize,
+        failure: MockFailure,
+    ) {
+        if count > 0 {
+            injections.lock().unwrap().push(Injection {
+                addr,
+                remaining: count,
+                failure,
+            });
+        }
+    }
+
+    /// Truncates every following read response to at most `limit`
+    /// registers, simulating short responses.
+    pub fn set_response_limit(&self, limit: Option<usize>) {
+        *self.response_limit.lock().unwrap() = limit;
+    }
 }
 
 #[cfg(any(test, feature = "test-mock"))]
 impl MockBackend {
-    fn read_registers(&self, start_addr: u16, count: u16) -> Vec<u16> {
-        let registers = self.controller.lock().unwrap();
-        (start_addr..start_addr + count)
-            .map(|addr| registers.get(&addr).copied().unwrap_or(0))
-            .collect()
+    async fn read_registers_into(&mut self, start_addr: u16, buf: &mut [u16]) -> Result<usize> {
+        use std::sync::atomic::Ordering;
+
+        self.probe.reads.fetch_add(1, Ordering::SeqCst);
+        self.probe
+            .read_log
+            .lock()
+            .unwrap()
+            .push((start_addr, buf.len()));
+        let delay = *self.probe.read_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        if self.probe.fail_reads.load(Ordering::SeqCst) {
+            return Err(Jpf4826Error::modbus("mock read failure"));
+        }
+        if self.probe.take_silent_request() {
+            return Err(Jpf4826Error::timeout(DEFAULT_TIMEOUT));
+        }
+        let end = start_addr.saturating_add(buf.len() as u16);
+        if let Some(failure) = take_injection(&self.probe.read_injections, start_addr, end) {
+            return Err(failure.error(start_addr, "read registers"));
+        }
+        if buf.is_empty() || buf.len() > MAX_READ_COUNT {
+            return Err(Jpf4826Error::exception(start_addr, "IllegalDataValue"));
+        }
+        if let Some(addr) = self
+            .probe
+            .rejected_reads
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|addr| (start_addr..end).contains(addr))
+        {
+            return Err(Jpf4826Error::exception(*addr, "IllegalDataAddress"));
+        }
+
+        let len = self
+            .probe
+            .response_limit
+            .lock()
+            .unwrap()
+            .map_or(buf.len(), |limit| limit.min(buf.len()));
+        let controller = self.registers()?;
+        let registers = controller.lock().unwrap();
+        // An inclusive range ends at 0xFFFF without overflowing
+        for (value, addr) in buf[..len].iter_mut().zip(start_addr..=u16::MAX) {
+            *value = registers.get(&addr).copied().unwrap_or(0);
+        }
+        Ok(len)
+    }
+
+    async fn write_registers(&mut self, start_addr: u16, values: &[u16]) -> Result<()> {
+        let delay = *self.probe.write_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        self.probe
+            .writes
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if self.probe.take_silent_request() {
+            return Err(Jpf4826Error::timeout(DEFAULT_TIMEOUT));
+        }
+        let end = start_addr.saturating_add(values.len() as u16);
+        if let Some(failure) = take_injection(&self.probe.write_injections, start_addr, end) {
+            return Err(failure.error(start_addr, "write registers"));
+        }
+        if let Some(allowance) = self.probe.write_allowance.lock().unwrap().as_mut() {
+            if *allowance == 0 {
+                return Err(Jpf4826Error::modbus("mock write failure"));
+            }
+            *allowance -= 1;
+        }
+        if let Some(addr) = self
+            .probe
+            .rejected_writes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|addr| (start_addr..end).contains(addr))
+        {
+            return Err(Jpf4826Error::exception(*addr, "SlaveDeviceFailure"));
+        }
+        let controller = self.registers()?;
+        let mut registers = controller.lock().unwrap();
+        let mut write_log = self.probe.write_log.lock().unwrap();
+        let ignored = self.probe.ignored_writes.lock().unwrap();
+        let mut moved_to = None;
+        for (addr, value) in (start_addr..).zip(values) {
+            write_log.push((addr, *value));
+            if ignored.contains(&addr) {
+                continue;
+            }
+            registers.insert(addr, *value);
+            mirror_thresholds(&mut registers, addr);
+            if addr == RegisterAddress::ModbusAddress.addr() {
+                moved_to = u8::try_from(*value).ok();
+            }
+        }
+        // A controller on a bus answers at its new address from now on
+        if let (Some(bus), Some(new_addr)) = (&self.bus, moved_to) {
+            bus.move_device(self.slave_addr, new_addr);
+        }
+        Ok(())
+    }
+
+    /// Writes a register of every controller, as a broadcast does.
+    async fn write_broadcast(&mut self, addr: u16, value: u16) -> Result<()> {
+        let delay = *self.probe.write_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        self.probe
+            .writes
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let controllers = match &self.bus {
+            Some(bus) => bus.all_devices()?,
+            None => vec![self.controller.clone()],
+        };
+        for controller in controllers {
+            let mut registers = controller.lock().unwrap();
+            registers.insert(addr, value);
+            mirror_thresholds(&mut registers, addr);
+        }
+        self.probe.broadcast_log.lock().unwrap().push((addr, value));
+        Ok(())
+    }
+
+    /// Returns the registers of the controller at the current address.
+    fn registers(&self) -> Result<MockRegisters> {
+        match &self.bus {
+            Some(bus) => bus.device(self.slave_addr),
+            None => Ok(self.controller.clone()),
+        }
     }
 
-    fn set_slave_addr(&self, addr: u8) {
-        self.slave_addr.set(addr);
+    fn set_slave_addr(&mut self, addr: u8) {
+        self.slave_addr = addr;
     }
 
     pub(crate) fn slave_addr(&self) -> u8 {
-        self.slave_addr.get()
+        self.slave_addr
     }
 }
 
-impl Jpf4826Client {
-    /// Creates a new client connected to the specified serial port.
+impl Jpf4826Client {
+    /// Creates a new client connected to the specified serial port.
+    ///
+    /// Uses 9600 8N1 and the default timeout of 10 seconds for all
+    /// operations, without retries. Use [`Jpf4826ClientBuilder`] for other
+    /// settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
+    /// * `slave_addr` - Modbus slave address (1-254)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Serial port cannot be opened
+    /// - Modbus address is out of range (1-254)
+    pub async fn new(port: &str, slave_addr: u8) -> Result<Self> {
+        Jpf4826ClientBuilder::new(port, slave_addr).connect().await
+    }
+
+    /// Creates a new client with a custom timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
+    /// * `slave_addr` - Modbus slave address (1-254)
+    /// * `timeout` - Timeout for each Modbus operation
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// // Create client with 5 second timeout
+    /// let client = Jpf4826Client::with_timeout(
+    ///     "/dev/ttyUSB0",
+    ///     1,
+    ///     Duration::from_secs(5)
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Serial port cannot be opened
+    /// - Modbus address is out of range (1-254)
+    /// - `timeout` is below [`MIN_TIMEOUT`](crate::MIN_TIMEOUT)
+    pub async fn with_timeout(port: &str, slave_addr: u8, timeout: Duration) -> Result<Self> {
+        Self::open(port, slave_addr, BAUD_RATE, timeout).await
+    }
+
+    /// Creates a new client for a bus running at `baud_rate`.
+    ///
+    /// Uses 8N1 and the default timeout, like [`new`](Self::new). Use
+    /// [`Jpf4826ClientBuilder`] for other settings or baud rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
+    /// * `slave_addr` - Modbus slave address (1-254)
+    /// * `baud_rate` - One of [`SUPPORTED_BAUD_RATES`](crate::SUPPORTED_BAUD_RATES)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let client = Jpf4826Client::new_with_baud("/dev/ttyUSB0", 1, 19200).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `baud_rate` is not supported (checked before opening the port)
+    /// - Serial port cannot be opened
+    /// - Modbus address is out of range (1-254)
+    pub async fn new_with_baud(port: &str, slave_addr: u8, baud_rate: u32) -> Result<Self> {
+        if !SUPPORTED_BAUD_RATES.contains(&baud_rate) {
+            return Err(Jpf4826Error::invalid_parameter(format!(
+                "Baud rate {} not supported (expected one of {:?})",
+                baud_rate, SUPPORTED_BAUD_RATES
+            )));
+        }
+        Self::open(port, slave_addr, baud_rate, DEFAULT_TIMEOUT).await
+    }
+
+    /// Creates a new client for a controller behind a Modbus TCP gateway.
+    ///
+    /// For RS485-to-Ethernet gateways that translate Modbus TCP to
+    /// Modbus-RTU. All operations behave as on a serial connection,
+    /// including timeouts (the default of 10 seconds also bounds
+    /// connecting) and [`set_addr`](Self::set_addr) following the
+    /// controller to its new unit ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket_addr` - Address of the gateway, usually port 502
+    /// * `unit_id` - Modbus address of the controller on the RS485 side
+    ///   (1-254)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let gateway = "192.168.1.50:502".parse().unwrap();
+    /// let mut client = Jpf4826Client::new_tcp(gateway, 1).await?;
+    /// let status = client.status().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `unit_id` is out of range (1-254)
+    /// - The gateway refuses the connection (Modbus error) or does not
+    ///   accept it in time (timeout error)
+    pub async fn new_tcp(socket_addr: std::net::SocketAddr, unit_id: u8) -> Result<Self> {
+        if !(1..=254).contains(&unit_id) {
+            return Err(Jpf4826Error::invalid_address(unit_id));
+        }
+        let modbus = crate::modbus::ModbusClient::new_tcp(socket_addr, unit_id).await?;
+        Ok(Self::from_backend(ClientBackend::RealModbus(modbus)))
+    }
+
+    /// Creates a client on a serial port the caller opened.
+    ///
+    /// For applications that open the port themselves, e.g. to handle
+    /// locking or resolve udev symlinks. The stream is used as is; its
+    /// settings must match the controller (9600 8N1 by default).
+    ///
+    /// The client cannot reopen the stream. After a request times out or is
+    /// dropped, input left on the stream is discarded before the next
+    /// request instead. With [`set_flush_input(false)`](Self::set_flush_input)
+    /// such a request leaves the link unusable: every following operation
+    /// fails with a serial error, and a new client must be created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stream = tokio_serial::SerialStream::open(&tokio_serial::new("/dev/ttyUSB0", 9600))?;
+    /// let mut client = Jpf4826Client::with_serial(stream, 1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Modbus address is out of range (1-254).
+    pub fn with_serial(stream: tokio_serial::SerialStream, slave_addr: u8) -> Result<Self> {
+        Self::with_transport(stream, slave_addr)
+    }
+
+    /// Creates a client speaking Modbus-RTU over any byte stream.
+    ///
+    /// Lets tests or unusual setups supply the link, e.g. a pty or an
+    /// in-memory `tokio::io::duplex` pipe. As with
+    /// [`with_serial`](Self::with_serial), the transport is not reopened
+    /// after a timed-out or dropped request; input left on it is discarded
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// let (transport, _controller_side) = tokio::io::duplex(256);
+    /// let client = Jpf4826Client::with_transport(transport, 1)?;
+    /// assert!(client.is_connected());
+    /// # Ok::<(), jpf4826_driver::Jpf4826Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Modbus address is out of range (1-254).
+    pub fn with_transport<T>(transport: T, slave_addr: u8) -> Result<Self>
+    where
+        T: tokio::io::AsyncRead + tokio::io::AsyncWrite + std::fmt::Debug + Unpin + Send + 'static,
+    {
+        if !(1..=254).contains(&slave_addr) {
+            return Err(Jpf4826Error::invalid_address(slave_addr));
+        }
+        let modbus = crate::modbus::ModbusClient::attach(transport, slave_addr);
+        Ok(Self::from_backend(ClientBackend::RealModbus(modbus)))
+    }
+
+    /// Opens the serial port at `baud_rate`.
+    pub(crate) async fn open(
+        port: &str,
+        slave_addr: u8,
+        baud_rate: u32,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let options = ConnectOptions {
+            timeout,
+            baud_rate,
+            ..ConnectOptions::default()
+        };
+        let mut client = Self::new_lazy(port, slave_addr, options)?;
+        client.connect().await?;
+        Ok(client)
+    }
+
+    /// Creates a client that opens the serial port on first use.
+    ///
+    /// Arguments are validated immediately, but the port is only opened by
+    /// the first operation (or [`connect`](Self::connect)). Until it opens,
+    /// every operation tries again and fails with the serial error; once
+    /// open, the connection is reused. Useful when adapters may be plugged
+    /// in after the program starts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ConnectOptions, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// // Succeeds even if the adapter is not plugged in yet
+    /// let mut client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, ConnectOptions::default())?;
+    ///
+    /// // Opens the port
+    /// let temp = client.temperature().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the Modbus address is out of range (1-254) or the
+    /// timeout is below [`MIN_TIMEOUT`](crate::MIN_TIMEOUT).
+    pub fn new_lazy(port: &str, slave_addr: u8, options: ConnectOptions) -> Result<Self> {
+        if !(1..=254).contains(&slave_addr) {
+            return Err(Jpf4826Error::invalid_address(slave_addr));
+        }
+        check_timeout(options.timeout)?;
+
+        let mut pending = PendingConnection {
+            port: port.to_string(),
+            slave_addr,
+            serial: SerialSettings {
+                baud_rate: options.baud_rate,
+                data_bits: options.data_bits,
+                parity: options.parity,
+                stop_bits: options.stop_bits,
+            },
+            timeout_mode: TimeoutMode::default(),
+            reconnect: ReconnectPolicy::disabled(),
+            inter_frame_delay: None,
+            transport_retries: 0,
+            frame_observer: None,
+            flush_input: true,
+            #[cfg(any(test, feature = "test-mock"))]
+            bus: None,
+        };
+        pending.set_timeout_mode(TimeoutMode::Fixed(options.timeout));
+        let mut client = Self::from_backend(ClientBackend::Pending(pending));
+        client.model = options.model;
+        client.retries = options.retries;
+        Ok(client)
+    }
+
+    /// Creates a lazy client that connects to a mock bus (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub fn new_lazy_mock(bus: MockBus, slave_addr: u8) -> Result<Self> {
+        let mut client = Self::new_lazy("mock", slave_addr, ConnectOptions::default())?;
+        if let ClientBackend::Pending(pending) = &mut client.backend {
+            pending.bus = Some(bus);
+        }
+        Ok(client)
+    }
+
+    fn from_backend(backend: ClientBackend) -> Self {
+        Self {
+            backend,
+            mode: OperatingMode::Unknown,
+            link: LinkInfo::default(),
+            stats: StatsCounters::default(),
+            metrics: None,
+            limiter: None,
+            cache: None,
+            model: DeviceModel::default(),
+            retries: 0,
+            strict_mode: false,
+        }
+    }
+
+    /// Opens the serial port of a lazy client now.
+    ///
+    /// Does nothing if the port is already open.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ConnectOptions, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, ConnectOptions::default())?;
+    /// if let Err(e) = client.connect().await {
+    ///     println!("Adapter not ready yet: {}", e);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the serial port cannot be opened.
+    pub async fn connect(&mut self) -> Result<()> {
+        if let ClientBackend::Pending(pending) = &self.backend {
+            self.backend = pending.open().await?;
+            log::debug!("Opened connection of lazy client");
+        }
+        Ok(())
+    }
+
+    /// Closes the serial port or connection and consumes the client.
+    ///
+    /// Dropping the client also closes the port, but only once the drop
+    /// happens; on Windows the handle of a dropped port can linger long
+    /// enough for another process opening it right after to fail. `close`
+    /// returns once the handle is released. A lazy client that never
+    /// connected has nothing to close.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_fan_speed(60).await?;
+    /// client.close().await?;
+    /// // The port is free for the next user
+    /// let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the link fails while disconnecting or does not
+    /// disconnect within the timeout. The port is released either way.
+    pub async fn close(self) -> Result<()> {
+        match self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => Ok(()),
+            ClientBackend::RealModbus(client) => client.close().await,
+            ClientBackend::Pending(_) => Ok(()),
+        }
+    }
+
+    /// Returns true if the serial port is open.
+    ///
+    /// Only clients created with [`new_lazy`](Self::new_lazy) can be
+    /// unconnected.
+    pub fn is_connected(&self) -> bool {
+        !matches!(self.backend, ClientBackend::Pending(_))
+    }
+
+    /// Sets the timeout for Modbus operations.
+    ///
+    /// This affects all subsequent read and write operations and switches
+    /// an adaptive timeout back to a fixed one. Has no effect on mock
+    /// backend.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Change timeout to 5 seconds
+    /// client.set_timeout(Duration::from_secs(5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid parameter error if `timeout` is below
+    /// [`MIN_TIMEOUT`](crate::MIN_TIMEOUT), which would fail every
+    /// operation; the timeout is left unchanged. Distinguish timeouts of
+    /// later operations with [`Jpf4826Error::is_timeout`] and
+    /// [`Jpf4826Error::timeout_duration`].
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        check_timeout(timeout)?;
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {
+                // Mock backend ignores timeout (instant operations)
+            }
+            ClientBackend::RealModbus(modbus) => modbus.set_timeout(timeout)?,
+            ClientBackend::Pending(pending) => {
+                pending.set_timeout_mode(TimeoutMode::Fixed(timeout));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the timeout applied to the next operation.
+    ///
+    /// In adaptive mode this is the current effective timeout, which follows
+    /// observed response times. For mock backend, always returns
+    /// [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT) since mock operations do
+    /// not actually use timeouts.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let timeout = client.timeout();
+    /// println!("Current timeout: {:?}", timeout);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timeout(&self) -> Duration {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => DEFAULT_TIMEOUT,
+            ClientBackend::RealModbus(modbus) => modbus.timeout(),
+            ClientBackend::Pending(pending) => pending.timeout(),
+        }
+    }
+
+    /// Sets the timeout policy.
+    ///
+    /// [`TimeoutMode::Adaptive`] derives the timeout from the baud rate and
+    /// observed response times; [`TimeoutMode::Fixed`] is equivalent to
+    /// [`Jpf4826Client::set_timeout`]. Has no effect on mock backend.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{AdaptiveTimeout, Jpf4826Client, TimeoutMode};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let adaptive = AdaptiveTimeout::new()
+    ///     .with_bounds(Duration::from_millis(300), Duration::from_secs(5));
+    /// client.set_timeout_mode(TimeoutMode::Adaptive(adaptive));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_timeout_mode(&mut self, mode: TimeoutMode) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_timeout_mode(mode),
+            ClientBackend::Pending(pending) => pending.set_timeout_mode(mode),
+        }
+    }
+
+    /// Returns the timeout policy.
+    ///
+    /// For mock backend, returns the default fixed policy.
+    pub fn timeout_mode(&self) -> TimeoutMode {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => TimeoutMode::default(),
+            ClientBackend::RealModbus(modbus) => modbus.timeout_mode(),
+            ClientBackend::Pending(pending) => pending.timeout_mode,
+        }
+    }
+
+    /// Sets how a serial port that disappeared is reopened.
+    ///
+    /// When a request fails because the port is gone, e.g. after a USB
+    /// adapter re-enumerated, the port is reopened under the same path
+    /// according to `policy` and the request is sent once more. If every
+    /// attempt fails, the returned serial error states how many were made.
+    /// Disabled by default; has no effect on TCP gateways, supplied
+    /// streams or the mock backend.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, ReconnectPolicy};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_reconnect_policy(ReconnectPolicy::new(5, Duration::from_millis(500)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_reconnect_policy(policy),
+            ClientBackend::Pending(pending) => pending.reconnect = policy,
+        }
+    }
+
+    /// Returns the reconnect policy.
+    ///
+    /// For mock backend, returns the disabled policy.
+    pub fn reconnect_policy(&self) -> ReconnectPolicy {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => ReconnectPolicy::disabled(),
+            ClientBackend::RealModbus(modbus) => modbus.reconnect_policy(),
+            ClientBackend::Pending(pending) => pending.reconnect,
+        }
+    }
+
+    /// Sets the silence kept on the line between two requests.
+    ///
+    /// Modbus-RTU ends a frame after 3.5 character times of silence, so
+    /// each request waits until that long has passed since the previous
+    /// operation ended; calls further apart are not delayed. The default
+    /// follows the baud rate, about 4 ms at 9600 baud and 1.75 ms above
+    /// 19200 baud. `Duration::ZERO` disables the delay. TCP gateways frame
+    /// requests themselves and default to no delay; the mock backend
+    /// ignores it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // A slow RS485 adapter needs more time between frames
+    /// client.set_inter_frame_delay(Duration::from_millis(10));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_inter_frame_delay(&mut self, delay: Duration) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_inter_frame_delay(delay),
+            ClientBackend::Pending(pending) => pending.inter_frame_delay = Some(delay),
+        }
+    }
+
+    /// Returns the silence kept on the line between two requests.
+    ///
+    /// For mock backend, returns zero.
+    pub fn inter_frame_delay(&self) -> Duration {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => Duration::ZERO,
+            ClientBackend::RealModbus(modbus) => modbus.inter_frame_delay(),
+            ClientBackend::Pending(pending) => pending
+                .inter_frame_delay
+                .unwrap_or_else(|| inter_frame_delay(pending.serial.baud_rate)),
+        }
+    }
+
+    /// Sets whether input left on the link is discarded before each
+    /// request.
+    ///
+    /// A response that arrives after its request timed out stays in the
+    /// receive buffer and would be parsed as the answer to the next
+    /// request. With flushing on, the default, whatever is waiting when a
+    /// request is sent is read and dropped first (and still shown to a
+    /// [frame observer](Self::set_frame_observer)). This also lets a
+    /// [supplied transport](Self::with_transport) recover from a timeout
+    /// without being reopened. The mock backend ignores it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Keep unsolicited input, e.g. to capture it with a frame observer
+    /// client.set_flush_input(false);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_flush_input(&mut self, enabled: bool) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_flush_input(enabled),
+            ClientBackend::Pending(pending) => pending.flush_input = enabled,
+        }
+    }
+
+    /// Returns whether input left on the link is discarded before each
+    /// request.
+    ///
+    /// For mock backend, returns true.
+    pub fn flush_input(&self) -> bool {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => true,
+            ClientBackend::RealModbus(modbus) => modbus.flush_input(),
+            ClientBackend::Pending(pending) => pending.flush_input,
+        }
+    }
+
+    /// Sets how often a request failing on the link is repeated.
+    ///
+    /// Requests that time out or fail on the transport (see
+    /// [`Jpf4826Error::is_link_failure`]) are sent up to `retries` more
+    /// times before the error is returned. Modbus exceptions and malformed
+    /// responses are returned at once. A repeated
+    /// request counts as one transaction in [`link_info`](Self::link_info)
+    /// and one write against the write budget.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Try each request up to three times on a noisy bus
+    /// client.set_retries(2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_retries(&mut self, retries: u8) {
+        self.retries = retries;
+    }
+
+    /// Returns how often a failed request is repeated.
+    pub fn retries(&self) -> u8 {
+        self.retries
+    }
+
+    /// Sets how often the Modbus layer repeats a request that timed out or
+    /// failed on the transport.
+    ///
+    /// Unlike [`set_retries`](Self::set_retries), Modbus exceptions are
+    /// never repeated, since the controller heard the request and refused
+    /// it. The error returned once the retries are used up states the
+    /// number of attempts. Both settings combine: each client-level
+    /// attempt makes up to `retries + 1` transport attempts, and a
+    /// transaction still counts once in [`stats`](Self::stats). Defaults
+    /// to 0; the mock backend ignores it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Collisions on a half-duplex bus lose the odd frame
+    /// client.set_transport_retries(2);
+    /// let temp = client.temperature().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_transport_retries(&mut self, retries: u8) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_retries(retries),
+            ClientBackend::Pending(pending) => pending.transport_retries = retries,
+        }
+    }
+
+    /// Returns how often the Modbus layer repeats a timed-out or failed
+    /// request.
+    ///
+    /// For mock backend, returns 0.
+    pub fn transport_retries(&self) -> u8 {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => 0,
+            ClientBackend::RealModbus(modbus) => modbus.retries(),
+            ClientBackend::Pending(pending) => pending.transport_retries,
+        }
+    }
+
+    /// Makes [`set_fan_speed`](Self::set_fan_speed) refuse to switch the
+    /// controller out of temperature mode.
+    ///
+    /// Writing a speed to register 0x0003 also selects manual mode, which
+    /// silently disables temperature control. In strict mode
+    /// `set_fan_speed` first reads the register and fails with a mode
+    /// conflict error (see [`Jpf4826Error::is_mode_conflict`]) unless this
+    /// client selected manual mode; switch modes explicitly with
+    /// [`set_manual_speed`](Self::set_manual_speed). Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_strict_mode(true);
+    /// client.set_manual_speed(60).await?;
+    /// // Adjusts the speed, staying in manual mode
+    /// client.set_fan_speed(80).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_strict_mode(&mut self, strict: bool) {
+        self.strict_mode = strict;
+    }
+
+    /// Returns whether [`set_fan_speed`](Self::set_fan_speed) refuses to
+    /// leave temperature mode.
+    pub fn strict_mode(&self) -> bool {
+        self.strict_mode
+    }
+
+    /// Returns the communication statistics since the client was created.
+    ///
+    /// Every bus transaction counts once, whether issued by a high-level
+    /// method or the low-level register API.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let link = client.link_info();
+    /// match link.last_success {
+    ///     Some(at) => println!("Last contact {:?} ago", at.elapsed().unwrap_or_default()),
+    ///     None => println!("No contact yet"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn link_info(&self) -> LinkInfo {
+        self.link.clone()
+    }
+
+    /// Returns a snapshot of the operation statistics.
+    ///
+    /// Counts read and write transactions, timeouts and other failures
+    /// since the client was created or [`reset_stats`](Self::reset_stats)
+    /// was last called, and the mean duration of successful transactions.
+    /// See [`stats`](crate::stats) for what is counted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let stats = client.stats();
+    /// println!("{} of {} transactions failed", stats.failures(), stats.reads + stats.writes);
+    /// client.reset_stats();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> ClientStats {
+        self.stats.snapshot()
+    }
+
+    /// Zeroes the operation statistics.
+    ///
+    /// [`link_info`](Self::link_info) is not affected.
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Returns the bus health counters of the open connection.
+    ///
+    /// Counts every request the Modbus layer sent since the connection was
+    /// opened, retries included, by whether it timed out, failed on the
+    /// transport or was answered with a Modbus exception. See
+    /// [`health`](crate::health) for what is counted.
+    ///
+    /// For mock backend and a connection not opened yet, returns zeroed
+    /// counters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let health = client.bus_health();
+    /// if health.timeouts > 0 {
+    ///     println!("{} requests timed out", health.timeouts);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bus_health(&self) -> BusHealth {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => BusHealth::default(),
+            ClientBackend::RealModbus(modbus) => modbus.bus_health(),
+            ClientBackend::Pending(_) => BusHealth::default(),
+        }
+    }
+
+    /// Installs a recorder told about every read and write transaction.
+    ///
+    /// Replaces any recorder installed before. See [`crate::metrics`] for
+    /// what counts as a transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::sync::Arc;
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use jpf4826_driver::metrics::{CountingRecorder, OperationOutcome};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let counts = Arc::new(CountingRecorder::default());
+    /// client.set_metrics_recorder(counts.clone());
+    /// client.temperature().await?;
+    /// assert_eq!(counts.reads(OperationOutcome::Success), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_metrics_recorder(&mut self, recorder: Arc<dyn MetricsRecorder>) {
+        self.metrics = Some(recorder);
+    }
+
+    /// Removes the metrics recorder, if any.
+    pub fn clear_metrics_recorder(&mut self) {
+        self.metrics = None;
+    }
+
+    /// Returns the installed metrics recorder.
+    pub fn metrics_recorder(&self) -> Option<&Arc<dyn MetricsRecorder>> {
+        self.metrics.as_ref()
+    }
+
+    /// Installs an observer of the raw Modbus frames sent and received.
+    ///
+    /// Replaces any observer installed before and takes effect on the open
+    /// port as well as on ports reopened later. See [`crate::frame`] for
+    /// what the observer sees; the mock backend sends no frames.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::sync::Arc;
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use jpf4826_driver::frame::HexDumpObserver;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Logs "Modbus TX: 01 03 00 00 00 01 84 0A" and the answer
+    /// client.set_frame_observer(Arc::new(HexDumpObserver));
+    /// client.temperature().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_frame_observer(&mut self, observer: Arc<dyn FrameObserver>) {
+        self.replace_frame_observer(Some(observer));
+    }
+
+    /// Removes the frame observer, if any.
+    pub fn clear_frame_observer(&mut self) {
+        self.replace_frame_observer(None);
+    }
+
+    /// Returns the installed frame observer.
+    ///
+    /// For mock backend, returns `None`.
+    pub fn frame_observer(&self) -> Option<Arc<dyn FrameObserver>> {
+        match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => None,
+            ClientBackend::RealModbus(modbus) => modbus.frame_observer(),
+            ClientBackend::Pending(pending) => pending.frame_observer.clone(),
+        }
+    }
+
+    fn replace_frame_observer(&mut self, observer: Option<Arc<dyn FrameObserver>>) {
+        match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(_) => {}
+            ClientBackend::RealModbus(modbus) => modbus.set_frame_observer(observer),
+            ClientBackend::Pending(pending) => pending.frame_observer = observer,
+        }
+    }
+
+    /// Marks the start of one transaction, returning its start time.
+    fn begin(&self, operation: Operation) -> tokio::time::Instant {
+        if let Some(metrics) = &self.metrics {
+            match operation {
+                Operation::Read => metrics.on_read_start(),
+                Operation::Write => metrics.on_write_start(),
+            }
+        }
+        tokio::time::Instant::now()
+    }
+
+    /// Records the outcome of one transaction started at `started`.
+    fn record<T>(
+        &mut self,
+        operation: Operation,
+        started: tokio::time::Instant,
+        result: &Result<T>,
+    ) {
+        let elapsed = started.elapsed();
+        self.link.record(result);
+        self.stats.record(operation, elapsed, result);
+        if let Some(metrics) = &self.metrics {
+            let outcome = OperationOutcome::of(result);
+            match operation {
+                Operation::Read => metrics.on_read_complete(elapsed, outcome),
+                Operation::Write => metrics.on_write_complete(elapsed, outcome),
+            }
+        }
+    }
+
+    /// Limits how often each configuration register may be written.
+    ///
+    /// Passing `None` removes the limit. Setting a budget starts with an
+    /// empty write history. See [`crate::budget`] for which registers are
+    /// budgeted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use jpf4826_driver::budget::WriteBudget;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // At most 4 writes per register and minute
+    /// client.set_write_budget(Some(WriteBudget::new(4, Duration::from_secs(60))))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `max_writes` or `window` is zero.
+    pub fn set_write_budget(&mut self, budget: Option<WriteBudget>) -> Result<()> {
+        if let Some(budget) = budget {
+            if budget.max_writes == 0 || budget.window.is_zero() {
+                return Err(Jpf4826Error::invalid_parameter(
+                    "Write budget needs at least one write in a non-empty window",
+                ));
+            }
+        }
+        self.limiter = budget.map(WriteLimiter::new);
+        Ok(())
+    }
+
+    /// Returns the write budget being enforced, if any.
+    pub fn write_budget(&self) -> Option<WriteBudget> {
+        self.limiter.as_ref().map(WriteLimiter::budget)
+    }
+
+    /// Serves repeated reads from memory for `ttl` after the response.
+    ///
+    /// A read through [`read`](Self::read), [`read_into`](Self::read_into),
+    /// [`read_array`](Self::read_array) or the typed getters built on them
+    /// is answered from a response younger than `ttl` that covers all of
+    /// its registers, e.g. `temperature()` right after `status()`. Writes
+    /// through this client drop the cached ranges they overlap, and a
+    /// reset or address change drops all of them. Changes made by the
+    /// controller itself, such as the fan speeds, or by another master show
+    /// up only once the TTL expires. [`probe`](Self::probe) and
+    /// [`dump_registers`](Self::dump_registers) always go to the bus, as
+    /// does [`read_chunked`](Self::read_chunked).
+    ///
+    /// A zero `ttl`, the default, disables caching and discards the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_cache_ttl(Duration::from_millis(200));
+    /// let status = client.status().await?;
+    /// // Served from the status response
+    /// let temperature = client.temperature().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_cache_ttl(&mut self, ttl: Duration) {
+        self.cache = (!ttl.is_zero()).then(|| ReadCache::new(ttl));
+    }
+
+    /// Returns how long read responses are served from memory, zero if
+    /// caching is disabled.
+    pub fn cache_ttl(&self) -> Duration {
+        self.cache.as_ref().map_or(Duration::ZERO, ReadCache::ttl)
+    }
+
+    /// Fills `buf` with cached values of the registers from `addr`,
+    /// returning false on a miss.
+    fn read_cached(&mut self, addr: u16, buf: &mut [u16]) -> bool {
+        self.cache
+            .as_mut()
+            .is_some_and(|cache| cache.get_into(addr, buf, tokio::time::Instant::now()))
+    }
+
+    /// Caches the values of a complete read response from `addr`.
+    fn cache_response(&mut self, addr: u16, values: &[u16]) {
+        if let Some(cache) = &mut self.cache {
+            cache.insert(addr, values, tokio::time::Instant::now());
+        }
+    }
+
+    /// Drops cached values of the `count` registers written from `addr`.
+    fn invalidate_cache(&mut self, addr: u16, count: usize) {
+        if let Some(cache) = &mut self.cache {
+            cache.invalidate(addr, count);
+        }
+    }
+
+    fn clear_cache(&mut self) {
+        if let Some(cache) = &mut self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Creates a mock client for testing (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub async fn new_mock(
+        registers: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>,
+        slave_addr: u8,
+    ) -> Self {
+        Self::from_backend(ClientBackend::Mock(MockBackend {
+            controller: registers,
+            bus: None,
+            slave_addr,
+            probe: MockProbe::default(),
+        }))
+    }
+
+    /// Creates a mock client of the given hardware revision (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub async fn new_mock_model(
+        registers: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>,
+        slave_addr: u8,
+        model: DeviceModel,
+    ) -> Self {
+        let mut client = Self::new_mock(registers, slave_addr).await;
+        client.model = model;
+        client
+    }
+
+    /// Returns the hardware revision the client was created for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ConnectOptions, DeviceModel, Jpf4826Client};
+    /// let options = ConnectOptions {
+    ///     model: DeviceModel::Jpf4826Rev2,
+    ///     ..ConnectOptions::default()
+    /// };
+    /// let client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, options)?;
+    /// assert!(client.model().has_second_sensor());
+    /// # Ok::<(), jpf4826_driver::Jpf4826Error>(())
+    /// ```
+    pub fn model(&self) -> DeviceModel {
+        self.model
+    }
+
+    /// Rejects access to a register the model lacks.
+    fn check_register(&self, register: RegisterAddress) -> Result<()> {
+        if self.model.has_register(register) {
+            Ok(())
+        } else {
+            Err(Jpf4826Error::unsupported(
+                self.model,
+                format!("Register {:#06X}", register.addr()),
+            ))
+        }
+    }
+
+    /// Returns the instrumentation of a mock client (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub fn mock_probe(&self) -> Option<MockProbe> {
+        match &self.backend {
+            ClientBackend::Mock(mock) => Some(mock.probe.clone()),
+            ClientBackend::RealModbus(_) | ClientBackend::Pending(_) => None,
+        }
+    }
+
+    /// Reads holding registers from the controller.
+    ///
+    /// Low-level method for reading raw register values. Most users should
+    /// use the high-level methods like `temperature()` or `status()` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - Starting register address
+    /// * `count` - Number of consecutive registers to read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, registers::RegisterAddress};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Read temperature register
+    /// let values = client.read(RegisterAddress::CurrentTemperature, 1).await?;
+    /// println!("Raw temperature value: {}", values[0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `count` is 0 or greater than 125 (the Modbus limit;
+    /// see [`read_chunked`](Self::read_chunked)), the model lacks
+    /// `register` (see [`Jpf4826Error::is_unsupported`]), the range covers
+    /// a write-only register (see [`Jpf4826Error::register_access`]),
+    /// Modbus communication fails or the response does not hold exactly
+    /// `count` registers (see [`Jpf4826Error::response_length_mismatch`]).
+    pub async fn read(&mut self, register: RegisterAddress, count: u16) -> Result<Vec<u16>> {
+        if count == 0 {
+            return Err(Jpf4826Error::invalid_parameter("Cannot read 0 registers"));
+        }
+        check_read_count(usize::from(count))?;
+        self.check_register(register)?;
+        check_readable(register.addr(), usize::from(count))?;
+        let mut cached = vec![0; usize::from(count)];
+        if self.read_cached(register.addr(), &mut cached) {
+            return Ok(cached);
+        }
+        self.connect().await?;
+        let started = self.begin(Operation::Read);
+        let mut attempt = 0;
+        let result = loop {
+            let result = match &mut self.backend {
+                #[cfg(any(test, feature = "test-mock"))]
+                ClientBackend::Mock(mock) => {
+                    let mut values = vec![0; usize::from(count)];
+                    mock.read_registers_into(register.addr(), &mut values)
+                        .await
+                        .and_then(|len| check_response_length(register.addr(), values.len(), len))
+                        .map(|()| values)
+                }
+                ClientBackend::RealModbus(modbus) => {
+                    modbus.read_holding_registers(register.addr(), count).await
+                }
+                ClientBackend::Pending(_) => unreachable!("connected above"),
+            };
+            match result {
+                Err(e) if self.should_retry(attempt, &e) => attempt += 1,
+                result => break result,
+            }
+        };
+        self.record(Operation::Read, started, &result);
+        if let Ok(values) = &result {
+            self.cache_response(register.addr(), values);
+        }
+        result
+    }
+
+    /// Reads `count` consecutive holding registers from a raw address,
+    /// splitting the range into requests of at most 125 registers.
+    ///
+    /// A Modbus read carries at most 125 registers; this sends as many
+    /// reads as needed and concatenates the results. Like
+    /// [`write_unchecked`](Self::write_unchecked), the range is not checked
+    /// against the model or the register map, since a bulk read spans
+    /// addresses the map does not know. Every request counts as one
+    /// transaction in the statistics; the read cache is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Three requests: 125 + 125 + 50 registers
+    /// let values = client.read_chunked(0x0000, 300).await?;
+    /// assert_eq!(values.len(), 300);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `count` is 0 or the range runs past address 0xFFFF
+    /// - Modbus communication fails or a response does not hold exactly
+    ///   the registers requested; registers read before are discarded
+    pub async fn read_chunked(&mut self, addr: u16, count: usize) -> Result<Vec<u16>> {
+        if count == 0 {
+            return Err(Jpf4826Error::invalid_parameter("Cannot read 0 registers"));
+        }
+        if usize::from(addr) + count > usize::from(u16::MAX) + 1 {
+            return Err(Jpf4826Error::invalid_parameter(format!(
+                "Cannot read {} registers from {:#06X}: range ends past 0xFFFF",
+                count, addr
+            )));
+        }
+        self.connect().await?;
+
+        let mut values = vec![0; count];
+        for (index, chunk) in values.chunks_mut(MAX_READ_COUNT).enumerate() {
+            // Within the address space, checked above
+            let start = addr + (index * MAX_READ_COUNT) as u16;
+            let started = self.begin(Operation::Read);
+            let expected = chunk.len();
+            let result = self
+                .request_raw_into(start, chunk)
+                .await
+                .and_then(|len| check_response_length(start, expected, len));
+            self.record(Operation::Read, started, &result);
+            result?;
+        }
+        Ok(values)
+    }
+
+    /// Reads consecutive holding registers into `buf` without allocating.
+    ///
+    /// Requests `buf.len()` registers starting at `register` and returns the
+    /// number filled. A short response from the controller fills only the
+    /// beginning of `buf`; the rest is left untouched. An empty `buf`
+    /// returns 0 without a bus transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, registers::RegisterAddress};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let mut speeds = [0u16; 4];
+    /// let filled = client.read_into(RegisterAddress::Fan1Speed, &mut speeds).await?;
+    /// println!("{:?}", &speeds[..filled]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `buf` is longer than 125 registers (Modbus limit)
+    /// - The model lacks `register`
+    /// - The range covers a write-only register
+    /// - Modbus communication fails or the response is longer than requested
+    pub async fn read_into(&mut self, register: RegisterAddress, buf: &mut [u16]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        check_read_count(buf.len())?;
+        self.check_register(register)?;
+        check_readable(register.addr(), buf.len())?;
+        if self.read_cached(register.addr(), buf) {
+            return Ok(buf.len());
+        }
+        self.connect().await?;
+
+        let started = self.begin(Operation::Read);
+        let result = self.request_into(register, buf).await;
+        self.record(Operation::Read, started, &result);
+        if result.as_ref().is_ok_and(|len| *len == buf.len()) {
+            self.cache_response(register.addr(), buf);
+        }
+        result
+    }
+
+    /// Sends a read request for `buf.len()` registers, without recording it.
+    ///
+    /// Failed requests are repeated as configured by
+    /// [`set_retries`](Self::set_retries). The caller connects first.
+    async fn request_into(&mut self, register: RegisterAddress, buf: &mut [u16]) -> Result<usize> {
+        self.request_raw_into(register.addr(), buf).await
+    }
+
+    /// Sends a read request starting at a numeric address, which need not
+    /// be a known register, without recording it.
+    async fn request_raw_into(&mut self, addr: u16, buf: &mut [u16]) -> Result<usize> {
+        let mut attempt = 0;
+        loop {
+            let result = match &mut self.backend {
+                #[cfg(any(test, feature = "test-mock"))]
+                ClientBackend::Mock(mock) => mock.read_registers_into(addr, buf).await,
+                ClientBackend::RealModbus(modbus) => {
+                    modbus.read_holding_registers_into(addr, buf).await
+                }
+                ClientBackend::Pending(_) => unreachable!("connected by the caller"),
+            };
+            match result {
+                Err(e) if self.should_retry(attempt, &e) => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends a write request, without recording it.
+    ///
+    /// Uses function 0x10 (Write Multiple Registers) if `multiple` is set,
+    /// else 0x06 with `values[0]`. Failed requests are repeated as
+    /// configured by [`set_retries`](Self::set_retries). The caller
+    /// connects first.
+    async fn request_write(&mut self, addr: u16, values: &[u16], multiple: bool) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            let result = match &mut self.backend {
+                #[cfg(any(test, feature = "test-mock"))]
+                ClientBackend::Mock(mock) => mock.write_registers(addr, values).await,
+                ClientBackend::RealModbus(modbus) if multiple => {
+                    modbus.write_multiple_registers(addr, values).await
+                }
+                ClientBackend::RealModbus(modbus) => {
+                    modbus.write_single_register(addr, values[0]).await
+                }
+                ClientBackend::Pending(_) => unreachable!("connected by the caller"),
+            };
+            match result {
+                Err(e) if self.should_retry(attempt, &e) => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns true if a request that failed with `error` in attempt
+    /// `attempt` (counting from 0) should be sent again.
+    ///
+    /// Only link failures are repeated; invalid requests and responses
+    /// would fail again.
+    fn should_retry(&self, attempt: u8, error: &Jpf4826Error) -> bool {
+        if attempt >= self.retries || !error.is_link_failure() {
+            return false;
+        }
+        log::warn!(
+            "Request failed ({}), retrying ({}/{})",
+            error,
+            attempt + 1,
+            self.retries
+        );
+        true
+    }
+
+    /// Reads exactly `N` consecutive holding registers into an array.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, registers::RegisterAddress};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let [low, high] = client.read_array(RegisterAddress::StartTemperature).await?;
+    /// println!("Raw thresholds: {} {}", low, high);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `N` is 0 or greater than 125
+    /// - The model lacks `register`
+    /// - The range covers a write-only register
+    /// - Modbus communication fails or the response length differs from `N`
+    pub async fn read_array<const N: usize>(
+        &mut self,
+        register: RegisterAddress,
+    ) -> Result<[u16; N]> {
+        if N == 0 {
+            return Err(Jpf4826Error::invalid_parameter(
+                "Cannot read an empty register array",
+            ));
+        }
+
+        check_read_count(N)?;
+        self.check_register(register)?;
+        check_readable(register.addr(), N)?;
+        let mut values = [0; N];
+        if self.read_cached(register.addr(), &mut values) {
+            return Ok(values);
+        }
+        self.connect().await?;
+
+        // A short response is a link failure here, unlike in read_into()
+        let started = self.begin(Operation::Read);
+        let result = self
+            .request_into(register, &mut values)
+            .await
+            .and_then(|len| check_response_length(register.addr(), N, len));
+        self.record(Operation::Read, started, &result);
+        result?;
+        self.cache_response(register.addr(), &values);
+        Ok(values)
+    }
+
+    /// Reads current temperature from the controller.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let temp = client.temperature().await?;
+    /// println!("Current: {}°C", temp.value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails, a sensor fault error
+    /// (see [`Jpf4826Error::is_sensor_fault`]) if the probe is disconnected
+    /// or shorted, or an invalid response error (see
+    /// [`Jpf4826Error::is_invalid_response`]) if the register holds a value
+    /// outside 0x0014-0x00A0, which no sensor state produces.
+    pub async fn temperature(&mut self) -> Result<Temperature> {
+        self.read_temperature(RegisterAddress::CurrentTemperature)
+            .await
+    }
+
+    /// Reads the current temperature converted to `unit`.
+    ///
+    /// The controller reports whole degrees Celsius; other units are
+    /// rounded to the nearest degree (see [`Temperature::to_unit`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, TemperatureUnit};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let temp = client.temperature_in(TemperatureUnit::Fahrenheit).await?;
+    /// println!("Current: {}°F", temp.value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`temperature`](Self::temperature).
+    pub async fn temperature_in(&mut self, unit: TemperatureUnit) -> Result<Temperature> {
+        Ok(self.temperature().await?.to_unit(unit))
+    }
+
+    /// Reads the second temperature input.
+    ///
+    /// Only models with a second input have the register (see
+    /// [`DeviceModel::has_second_sensor`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ConnectOptions, DeviceModel, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let options = ConnectOptions {
+    ///     model: DeviceModel::Jpf4826Rev2,
+    ///     ..ConnectOptions::default()
+    /// };
+    /// let mut client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, options)?;
+    /// let temp = client.second_temperature().await?;
+    /// println!("Second input: {}°C", temp.value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the model has no second input (see
+    /// [`Jpf4826Error::is_unsupported`]), Modbus communication fails, or a
+    /// sensor fault error if the probe is disconnected or shorted, or an
+    /// invalid response error for a value outside 0x0014-0x00A0.
+    pub async fn second_temperature(&mut self) -> Result<Temperature> {
+        if !self.model.has_second_sensor() {
+            return Err(Jpf4826Error::unsupported(
+                self.model,
+                "Second temperature input",
+            ));
+        }
+        self.read_temperature(RegisterAddress::SecondTemperature)
+            .await
+    }
+
+    /// Reads and decodes a temperature input register.
+    async fn read_temperature(&mut self, register: RegisterAddress) -> Result<Temperature> {
+        let [value] = self.read_array(register).await?;
+        decode_temperature(register, value)?;
+        let celsius = checked_register_to_celsius(value).ok_or_else(|| {
+            log::warn!(
+                "Temperature sensor fault, register {:#06X} reads {:#06X}",
+                register.addr(),
+                value
+            );
+            Jpf4826Error::sensor_fault(register.addr(), value)
+        })?;
+
+        Ok(Temperature {
+            value: celsius,
+            unit: TemperatureUnit::Celsius,
+        })
+    }
+
+    /// Reads speed of a specific fan in RPM.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - A [`FanIndex`] or a plain fan number (1-4)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{FanIndex, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let rpm = client.fan_speed(1).await?;
+    /// println!("Fan 1: {} RPM", rpm);
+    ///
+    /// for fan in FanIndex::ALL {
+    ///     println!("Fan {}: {} RPM", fan, client.fan_speed(fan).await?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - A plain fan number is out of range (1-4)
+    /// - Modbus communication fails
+    pub async fn fan_speed<I>(&mut self, index: I) -> Result<u16>
+    where
+        I: TryInto<FanIndex>,
+        I::Error: Into<FanIndexError>,
+    {
+        let index = index
+            .try_into()
+            .map_err(|err| Jpf4826Error::new_invalid_fan_index(err.into().index()))?;
+        let register = RegisterAddress::fan_speed_register(index)
+            .ok_or_else(|| Jpf4826Error::new_invalid_fan_index(index.get()))?;
+
+        let [rpm] = self.read_array(register).await?;
+        Ok(rpm)
+    }
+
+    /// Reads the speeds of all four fans in RPM, in one request.
+    ///
+    /// Registers 0x0007-0x000A are read together, so polling every fan costs
+    /// one Modbus transaction instead of four calls to
+    /// [`fan_speed`](Self::fan_speed).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// for (i, rpm) in client.fan_speeds().await?.iter().enumerate() {
+    ///     println!("Fan {}: {} RPM", i + 1, rpm);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails.
+    pub async fn fan_speeds(&mut self) -> Result<[u16; 4]> {
+        self.read_array(RegisterAddress::Fan1Speed).await
+    }
+
+    /// Reads which fans are running from register 0x0001.
+    ///
+    /// Element 0 is fan 1. A fan that is not running has not necessarily
+    /// failed: in [`WorkMode::Shutdown`] fans stop below the start
+    /// temperature minus 3°C. Compare with [`fan_status`](Self::fan_status)
+    /// to tell a stopped fan from a faulted one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{FanStatus, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let running = client.fan_running().await?;
+    /// for fan in client.fan_status().await? {
+    ///     if !running[fan.index.position()] && fan.status == FanStatus::Normal {
+    ///         println!("Fan {} is stopped", fan.index);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails.
+    pub async fn fan_running(&mut self) -> Result<[bool; 4]> {
+        let [bitmap] = self.read_array(RegisterAddress::FanStatus).await?;
+        Ok(parse_fan_status_bitmap(bitmap))
+    }
+
+    /// Reads the configured number of fans.
+    ///
+    /// Returns 0 if fault detection is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let count = client.fan_count().await?;
+    /// println!("Configured fans: {}", count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value above 4
+    pub async fn fan_count(&mut self) -> Result<u8> {
+        let [count] = self.read_array(RegisterAddress::FanQuantity).await?;
+        decode_fan_count(count)
+    }
+
+    /// Reads the Modbus address stored in the controller.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let addr = client.modbus_address().await?;
+    /// println!("Address: 0x{:02X}", addr);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value outside 1-254
+    pub async fn modbus_address(&mut self) -> Result<u8> {
+        let [value] = self.read_array(RegisterAddress::ModbusAddress).await?;
+        decode_modbus_address(value)
+    }
+
+    /// Reads the ECO/work mode from register 0x0005.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, WorkMode};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// if client.work_mode().await? == WorkMode::Shutdown {
+    ///     println!("Fans stop below the start temperature");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value other than 0 or 1
+    pub async fn work_mode(&mut self) -> Result<WorkMode> {
+        let [value] = self.read_array(RegisterAddress::WorkMode).await?;
+        decoded_or_invalid(
+            RegisterAddress::WorkMode,
+            value,
+            WorkMode::from_register_value(value),
+        )
+    }
+
+    /// Reads the start and full speed temperature thresholds.
+    ///
+    /// Both registers (0x000C-0x000D) are read in one request and returned
+    /// in Celsius as `(low, high)`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let (low, high) = client.temperature_thresholds().await?;
+    /// println!("Fans start at {}°C, full speed at {}°C", low.value, high.value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - A threshold register holds a value outside 0x0014-0x00A0
+    ///   (see [`Jpf4826Error::is_invalid_response`])
+    /// - The controller's high threshold is not greater than its low
+    ///   threshold, which means it is misconfigured
+    pub async fn temperature_thresholds(&mut self) -> Result<(Temperature, Temperature)> {
+        let [low, high] = self.read_array(RegisterAddress::StartTemperature).await?;
+        let low = decode_temperature(RegisterAddress::StartTemperature, low)?;
+        let high = decode_temperature(RegisterAddress::FullSpeedTemperature, high)?;
+
+        if high <= low {
+            log::warn!("Controller holds inverted thresholds {}-{}°C", low, high);
+            return Err(Jpf4826Error::invalid_thresholds(low, high));
+        }
+
+        let celsius = |value| Temperature {
+            value,
+            unit: TemperatureUnit::Celsius,
+        };
+        Ok((celsius(low), celsius(high)))
+    }
+
+    /// Reads the PWM frequency from register 0x000B.
+    ///
+    /// The register is decoded for the client's [`DeviceModel`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, PwmFrequency};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_pwm_frequency(PwmFrequency::Hz10000).await?;
+    /// assert_eq!(client.pwm_frequency().await?, PwmFrequency::Hz10000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value with no frequency on this model; it is
+    ///   never replaced by a default
+    pub async fn pwm_frequency(&mut self) -> Result<PwmFrequency> {
+        let [value] = self.read_array(RegisterAddress::PwmFrequency).await?;
+        decoded_or_invalid(
+            RegisterAddress::PwmFrequency,
+            value,
+            self.model.pwm_from_register_value(value),
+        )
+    }
+
+    /// Reads the speed control mode from register 0x0003.
+    ///
+    /// 0xFFFF is [`OperatingMode::Temperature`]. In temperature mode the
+    /// register otherwise reads back the calculated speed, so a value of
+    /// 0-100 is only attributed to a mode this client selected itself (see
+    /// [`OperatingMode`]); without one it is [`OperatingMode::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, OperatingMode};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_fan_speed(40).await?;
+    /// assert_eq!(client.operating_mode().await?, OperatingMode::Manual);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value other than 0-100 or 0xFFFF
+    pub async fn operating_mode(&mut self) -> Result<OperatingMode> {
+        let [value] = self.read_array(RegisterAddress::ManualSpeedControl).await?;
+        let mode = match OperatingMode::from_written_value(value) {
+            OperatingMode::Temperature => Some(OperatingMode::Temperature),
+            OperatingMode::Manual => Some(self.mode),
+            OperatingMode::Unknown => None,
+        };
+        decoded_or_invalid(RegisterAddress::ManualSpeedControl, value, mode)
+    }
+
+    /// Reads the speed percentage from register 0x0003.
+    ///
+    /// Returns `None` when the register holds 0xFFFF (temperature mode).
+    /// The controller can also report its calculated speed there while in
+    /// temperature mode, so use [`operating_mode`](Self::operating_mode)
+    /// to tell the modes apart.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_fan_speed(40).await?;
+    /// assert_eq!(client.manual_speed().await?, Some(40));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value other than 0-100 or 0xFFFF
+    pub async fn manual_speed(&mut self) -> Result<Option<u8>> {
+        let [value] = self.read_array(RegisterAddress::ManualSpeedControl).await?;
+        let speed = match value {
+            0xFFFF => Some(None),
+            0..=100 => Some(Some(value as u8)),
+            _ => None,
+        };
+        decoded_or_invalid(RegisterAddress::ManualSpeedControl, value, speed)
+    }
+
+    /// Reads the operating mode together with the manual speed.
+    ///
+    /// Combines [`operating_mode`](Self::operating_mode) and
+    /// [`manual_speed`](Self::manual_speed) in one read of register 0x0003:
+    /// a speed is only reported as [`OperatingModeState::Manual`] when this
+    /// client selected manual mode itself.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, OperatingModeState};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_mode_state(OperatingModeState::Manual(40)).await?;
+    /// assert_eq!(client.mode_state().await?, OperatingModeState::Manual(40));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The register holds a value other than 0-100 or 0xFFFF
+    pub async fn mode_state(&mut self) -> Result<OperatingModeState> {
+        let [value] = self.read_array(RegisterAddress::ManualSpeedControl).await?;
+        let state = match OperatingModeState::from_written_value(value) {
+            OperatingModeState::Manual(speed) => Some(match self.mode {
+                OperatingMode::Manual => OperatingModeState::Manual(speed),
+                OperatingMode::Temperature => OperatingModeState::Temperature,
+                OperatingMode::Unknown => OperatingModeState::Unknown,
+            }),
+            OperatingModeState::Unknown => None,
+            state => Some(state),
+        };
+        decoded_or_invalid(RegisterAddress::ManualSpeedControl, value, state)
+    }
+
+    /// Reads status of all fans (running state, faults, speeds).
+    ///
+    /// Returns information for all 4 fan slots regardless of configured
+    /// fan count. Check `fan_count()` to determine how many are active.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let fans = client.fan_status().await?;
+    /// for fan in fans {
+    ///     println!("Fan {}: {:?} @ {} RPM", fan.index, fan.status, fan.rpm);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails.
+    pub async fn fan_status(&mut self) -> Result<Vec<FanInfo>> {
+        log::debug!("Reading fan status and speeds");
+
+        // Read: fan status bitmap (0x0001), fan speeds (0x0007-0x000A), fault bitmap (0x000E)
+        // We need separate reads since registers aren't consecutive
+        log::debug!("Reading fan status bitmap from register 0x0001");
+        let running = self.fan_running().await?;
+        log::debug!("Fans running: {:?}", running);
+
+        log::debug!("Reading fan speeds from registers 0x0007-0x000A");
+        let speeds = self.fan_speeds().await?;
+        log::debug!("Fan speeds: {:?} RPM", speeds);
+
+        log::debug!("Reading fault bitmap from register 0x000E");
+        let [fault_bitmap] = self.read_array(RegisterAddress::FanFaultCode).await?;
+        log::debug!("Fault bitmap: {:#06X}", fault_bitmap);
+
+        let fault_statuses = parse_fan_fault_bitmap(fault_bitmap);
+
+        let mut fans = Vec::with_capacity(4);
+        for (i, index) in FanIndex::ALL.into_iter().enumerate() {
+            fans.push(FanInfo {
+                index,
+                status: fault_statuses[i],
+                rpm: speeds[i],
+                is_running: running[i],
+            });
+        }
+
+        log::debug!("Assembled {} fan info entries", fans.len());
+        Ok(fans)
+    }
+
+    /// Returns the indices (1-4) of faulted fans within the configured fan
+    /// count.
+    ///
+    /// Reads the fan quantity (0x0006) and the fault bitmap (0x000E) in one
+    /// request. Slots beyond the fan count are left out even if flagged,
+    /// since some firmware reports unconnected slots as faulted; with fault
+    /// detection disabled (fan count 0) the list is empty. See
+    /// [`ControllerStatus::faulted_fans`] for an already fetched status.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// for index in client.faulted_fans().await? {
+    ///     eprintln!("Fan {} failed", index);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails or the fan quantity
+    /// register holds a value outside 0-4.
+    pub async fn faulted_fans(&mut self) -> Result<Vec<u8>> {
+        // 0x0006 (fan quantity) through 0x000E (fault bitmap)
+        let values: [u16; 9] = self.read_array(RegisterAddress::FanQuantity).await?;
+        let fan_count = decode_fan_count(values[0])?;
+        let statuses = parse_fan_fault_bitmap(values[8]);
+        Ok((1..=fan_count)
+            .filter(|index| statuses[usize::from(index - 1)] == FanStatus::Fault)
+            .collect())
+    }
+
+    /// Reads complete controller status.
+    ///
+    /// This method performs a bulk read of all status registers and
+    /// assembles them into a comprehensive status structure.
+    ///
+    /// The controller does not report its operating mode, so
+    /// [`ControllerStatus::mode`] is the mode last selected through this
+    /// client (`set_auto_speed()`, `set_fan_speed()` or a raw write to
+    /// register 0x0003), unless the register itself reads 0xFFFF. Otherwise,
+    /// and after `reset()`, it is [`OperatingMode::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let status = client.status().await?;
+    /// println!("Work mode: {:?}", status.work_mode);
+    /// println!("Temperature: {}°C", status.temperature_current.value);
+    /// println!("Fans: {}", status.fan_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - The temperature register holds a value outside 0x0014-0x00A0; a
+    ///   sensor pegged at either limit is reported through
+    ///   [`ControllerStatus::sensor_fault`] instead
+    /// - A threshold register (0x000C, 0x000D) holds a value outside
+    ///   0x0014-0x00A0
+    /// - The address register holds a value outside 1-254
+    /// - The work mode, fan quantity or PWM frequency register holds a value
+    ///   with no documented meaning (see [`Jpf4826Error::is_invalid_response`]);
+    ///   such values are never replaced by a default
+    pub async fn status(&mut self) -> Result<ControllerStatus> {
+        log::debug!("Reading controller status (15 registers starting from 0x0000)");
+
+        // Read all status registers at once (0x0000-0x000E = 15 registers)
+        let values: [u16; ControllerStatus::REGISTER_COUNT as usize] =
+            self.read_array(RegisterAddress::CurrentTemperature).await?;
+        self.decode_status(&values)
+    }
+
+    /// Returns a stream reading the status every `interval`.
+    ///
+    /// The first read happens at once. Every result is yielded, errors
+    /// included; after a failed read the stream keeps going and reads again
+    /// at the next tick. Each read uses the client's timeout and retries, and
+    /// a read taking longer than `interval` postpones the next one rather
+    /// than bunching them. Dropping the stream stops polling.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut watch = client.watch(Duration::from_secs(2))?;
+    /// while let Some(status) = watch.next().await {
+    ///     match status {
+    ///         Ok(status) => println!("{}°C", status.temperature_current.value),
+    ///         Err(e) => eprintln!("Read failed: {}", e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `interval` is zero.
+    #[cfg(feature = "stream")]
+    pub fn watch(&mut self, interval: Duration) -> Result<StatusWatch<'_>> {
+        if interval.is_zero() {
+            return Err(Jpf4826Error::invalid_parameter(
+                "Watch interval must be greater than zero",
+            ));
+        }
+        Ok(StatusWatch::new(self, interval))
+    }
+
+    /// Reads the writable settings of the controller.
+    ///
+    /// Every setting is filled in except the Modbus address, so the
+    /// configuration can be applied to other controllers on the same bus
+    /// with [`apply_config`](Self::apply_config) without an address clash.
+    /// Set [`ControllerConfig::modbus_address`] to change it as well.
+    ///
+    /// The speed register reads the current speed in both modes, so a
+    /// manual speed is only known once set through this client; otherwise
+    /// [`ControllerConfig::speed`] is `None` unless the controller is in
+    /// automatic mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// let mut source = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let mut target = Jpf4826Client::new("/dev/ttyUSB1", 1).await?;
+    ///
+    /// let config = source.get_config().await?;
+    /// let report = target.apply_config(&config).await?;
+    /// println!("Changed: {:?}", report.written);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`status`](Self::status).
+    pub async fn get_config(&mut self) -> Result<ControllerConfig> {
+        let status = self.status().await?;
+
+        let speed = match status.mode {
+            OperatingMode::Temperature => Some(SpeedSetting::Auto),
+            OperatingMode::Manual => status.manual_speed.map(SpeedSetting::Manual),
+            OperatingMode::Unknown => None,
+        };
+        Ok(ControllerConfig {
+            thresholds: Some(Thresholds {
+                low: status.temperature_low_threshold.value,
+                high: status.temperature_high_threshold.value,
+            }),
+            work_mode: Some(status.work_mode),
+            fan_count: Some(status.fan_count),
+            pwm_frequency: Some(status.pwm_frequency),
+            speed,
+            modbus_address: None,
+        })
+    }
+
+    /// Validates and decodes the status register block.
+    fn decode_status(
+        &self,
+        values: &[u16; ControllerStatus::REGISTER_COUNT as usize],
+    ) -> Result<ControllerStatus> {
+        log::debug!("Raw register values: {:04X?}", values);
+        decode_temperature(RegisterAddress::CurrentTemperature, values[0])?;
+        decode_modbus_address(values[2])?;
+        decoded_or_invalid(
+            RegisterAddress::WorkMode,
+            values[5],
+            WorkMode::from_register_value(values[5]),
+        )?;
+        decode_fan_count(values[6])?;
+        let pwm_frequency = decoded_or_invalid(
+            RegisterAddress::PwmFrequency,
+            values[11],
+            self.model.pwm_from_register_value(values[11]),
+        )?;
+        decode_temperature(RegisterAddress::StartTemperature, values[12])?;
+        decode_temperature(RegisterAddress::FullSpeedTemperature, values[13])?;
+
+        let mut status = ControllerStatus::from_registers(values);
+        status.pwm_frequency = pwm_frequency;
+        if status.mode == OperatingMode::Unknown {
+            status.mode = self.mode;
+        }
+        if status.mode == OperatingMode::Manual {
+            status.manual_speed = OperatingModeState::from_written_value(values[3]).speed();
+        }
+        log::debug!(
+            "Parsed values: temp={}, addr={}, fans={}",
+            status.temperature_current.value,
+            status.modbus_address,
+            status.fan_count
+        );
+        log::debug!("Fault bitmap: {:#06X}", values[14]);
+
+        Ok(status)
+    }
+
+    // === Write Operations ===
+
+    /// Writes a single holding register to the controller.
+    ///
+    /// Low-level method for writing raw register values. Most users should
+    /// use the high-level methods like `set_fan_speed()` or `reset()` instead.
     ///
-    /// Uses the default timeout of 10 seconds for all operations.
+    /// The Modbus protocol validates the write by verifying the controller
+    /// echoes back the same register address and value.
     ///
     /// # Arguments
     ///
-    /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
-    /// * `slave_addr` - Modbus slave address (1-254)
+    /// * `register` - Register address to write
+    /// * `value` - 16-bit value to write
+    ///
+    /// Read-only registers such as the fan speeds are refused before
+    /// anything is sent; see [`write_unchecked`](Self::write_unchecked)
+    /// for registers outside the documented map.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The model lacks `register`
+    /// - `register` is read-only (see [`Jpf4826Error::register_access`])
+    /// - Modbus communication fails
+    /// - Controller response is invalid or does not match the written value
+    pub async fn write(&mut self, register: RegisterAddress, value: u16) -> Result<()> {
+        self.check_register(register)?;
+        check_writable(register.addr(), 1)?;
+        self.write_single(register.addr(), value).await
+    }
+
+    /// Writes a single holding register at a raw address, without checking
+    /// that the model has it or that it is writable.
+    ///
+    /// An escape hatch for undocumented registers; writing a read-only
+    /// register may corrupt the state of some controllers. Write budget,
+    /// read cache and statistics apply as for [`write`](Self::write).
     ///
     /// # Examples
     ///
@@ -89,41 +3154,57 @@ impl Jpf4826Client {
     /// # use jpf4826_driver::Jpf4826Client;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
-    /// let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.write_unchecked(0x0010, 0x0001).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// - Serial port cannot be opened
-    /// - Modbus address is out of range (1-254)
-    pub async fn new(port: &str, slave_addr: u8) -> Result<Self> {
-        Self::with_timeout(port, slave_addr, DEFAULT_TIMEOUT).await
+    /// Returns error if Modbus communication fails or the controller
+    /// response is invalid or does not match the written value.
+    pub async fn write_unchecked(&mut self, addr: u16, value: u16) -> Result<()> {
+        self.write_single(addr, value).await
     }
 
-    /// Creates a new client with a custom timeout.
+    /// Writes one register with function 0x06, after the caller's checks.
+    async fn write_single(&mut self, addr: u16, value: u16) -> Result<()> {
+        self.spend_budget(addr, 1).await?;
+        self.connect().await?;
+        let started = self.begin(Operation::Write);
+        let result = self.request_write(addr, &[value], false).await;
+        // A failed write may still have been applied
+        self.invalidate_cache(addr, 1);
+        self.record(Operation::Write, started, &result);
+        result?;
+        self.count_writes(addr, 1);
+        self.track_mode(addr, &[value]);
+        Ok(())
+    }
+
+    /// Writes consecutive holding registers in a single transaction.
+    ///
+    /// Low-level method using Modbus function 0x10 (Write Multiple
+    /// Registers). `values[0]` goes to `register`, `values[1]` to the
+    /// register after it, and so on. Writing several settings in one frame
+    /// saves a round trip per register on the 9600 baud link.
     ///
     /// # Arguments
     ///
-    /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
-    /// * `slave_addr` - Modbus slave address (1-254)
-    /// * `timeout` - Timeout for each Modbus operation
+    /// * `register` - Address of the first register to write
+    /// * `values` - 16-bit values to write (1-123)
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use jpf4826_driver::Jpf4826Client;
-    /// # use std::time::Duration;
+    /// # use jpf4826_driver::registers::RegisterAddress;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
-    /// // Create client with 5 second timeout
-    /// let client = Jpf4826Client::with_timeout(
-    ///     "/dev/ttyUSB0",
-    ///     1,
-    ///     Duration::from_secs(5)
-    /// ).await?;
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Work mode 1 (minimum speed) and 2 fans in one transaction
+    /// client.write_multiple(RegisterAddress::WorkMode, &[0x0001, 0x0002]).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -131,108 +3212,210 @@ impl Jpf4826Client {
     /// # Errors
     ///
     /// Returns error if:
-    /// - Serial port cannot be opened
-    /// - Modbus address is out of range (1-254)
-    pub async fn with_timeout(port: &str, slave_addr: u8, timeout: Duration) -> Result<Self> {
-        if !(1..=254).contains(&slave_addr) {
-            return Err(Jpf4826Error::invalid_address(slave_addr));
+    /// - `values` is empty or longer than 123 registers
+    /// - The model lacks `register`
+    /// - The range covers a read-only register
+    /// - Modbus communication fails
+    /// - Controller response is invalid or does not match the request
+    pub async fn write_multiple(
+        &mut self,
+        register: RegisterAddress,
+        values: &[u16],
+    ) -> Result<()> {
+        if values.is_empty() || values.len() > MAX_WRITE_COUNT {
+            return Err(Jpf4826Error::invalid_parameter(format!(
+                "Register count {} out of range (1-{})",
+                values.len(),
+                MAX_WRITE_COUNT
+            )));
         }
+        self.check_register(register)?;
+        check_writable(register.addr(), values.len())?;
 
-        let mut modbus_client = crate::modbus::ModbusRtuClient::new(port, slave_addr).await?;
-        modbus_client.set_timeout(timeout);
-        Ok(Self {
-            backend: ClientBackend::RealModbus(modbus_client),
-        })
+        self.spend_budget(register.addr(), values.len()).await?;
+        self.connect().await?;
+        let started = self.begin(Operation::Write);
+        let result = self.request_write(register.addr(), values, true).await;
+        self.invalidate_cache(register.addr(), values.len());
+        self.record(Operation::Write, started, &result);
+        result?;
+        self.count_writes(register.addr(), values.len());
+        self.track_mode(register.addr(), values);
+        Ok(())
     }
 
-    /// Sets the timeout for Modbus operations.
+    /// Rejects or delays a write of `count` registers from `addr` that
+    /// would exceed the write budget.
+    async fn spend_budget(&mut self, addr: u16, count: usize) -> Result<()> {
+        let Some(limiter) = &mut self.limiter else {
+            return Ok(());
+        };
+        let Some((register, wait)) = limiter.wait_time(addr, count, tokio::time::Instant::now())
+        else {
+            return Ok(());
+        };
+
+        match limiter.budget().policy {
+            BudgetPolicy::Reject => {
+                log::warn!(
+                    "Write budget of register {:#06X} used up, rejecting write",
+                    register
+                );
+                Err(Jpf4826Error::write_budget_exceeded(register, wait))
+            }
+            BudgetPolicy::Delay => {
+                log::warn!(
+                    "Write budget of register {:#06X} used up, delaying write by {:?}",
+                    register,
+                    wait
+                );
+                tokio::time::sleep(wait).await;
+                Ok(())
+            }
+        }
+    }
+
+    /// Counts a successful write of `count` registers from `addr`.
+    fn count_writes(&mut self, addr: u16, count: usize) {
+        self.link.record_writes(addr, count);
+        if let Some(limiter) = &mut self.limiter {
+            limiter.record(addr, count, tokio::time::Instant::now());
+        }
+    }
+
+    /// Records the mode selected by a successful write starting at `addr`.
+    fn track_mode(&mut self, addr: u16, values: &[u16]) {
+        let mode_register = RegisterAddress::ManualSpeedControl.addr();
+        if let Some(offset) = mode_register.checked_sub(addr) {
+            if let Some(&value) = values.get(usize::from(offset)) {
+                self.mode = OperatingMode::from_written_value(value);
+            }
+        }
+    }
+
+    /// Resets the controller.
     ///
-    /// This affects all subsequent read and write operations.
-    /// Has no effect on mock backend.
+    /// Sends the reset command (0x00AA) to register 0x0020.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use jpf4826_driver::Jpf4826Client;
-    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// // Change timeout to 5 seconds
-    /// client.set_timeout(Duration::from_secs(5));
+    /// client.reset().await?;
+    /// println!("Controller reset");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_timeout(&mut self, timeout: Duration) {
-        match &mut self.backend {
-            #[cfg(any(test, feature = "test-mock"))]
-            ClientBackend::Mock(_) => {
-                // Mock backend ignores timeout (instant operations)
-            }
-            ClientBackend::RealModbus(modbus) => modbus.set_timeout(timeout),
-        }
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails.
+    pub async fn reset(&mut self) -> Result<()> {
+        // The controller restarts, whether the command is acknowledged or not
+        self.clear_cache();
+        self.write(RegisterAddress::ResetController, 0x00AA).await?;
+        self.mode = OperatingMode::Unknown;
+        Ok(())
     }
 
-    /// Returns the current operation timeout.
+    /// Resets the controller and waits until it answers again.
     ///
-    /// For mock backend, returns `DEFAULT_TIMEOUT` since mock operations
-    /// do not actually use timeouts.
+    /// The controller drops off the bus for a second or two while it
+    /// restarts. After sending the reset command, this polls the current
+    /// temperature every [`RESET_POLL_INTERVAL`] until the controller
+    /// answers, and returns the time from sending the command. Each request
+    /// uses a timeout capped at [`PROBE_TIMEOUT`] and no retries. Some
+    /// firmware restarts without acknowledging the command, so a timed-out
+    /// reset write is tolerated and polling proceeds.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
-    /// # let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let timeout = client.timeout();
-    /// println!("Current timeout: {:?}", timeout);
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let took = client.reset_and_wait(Duration::from_secs(5)).await?;
+    /// println!("Back after {:?}", took);
+    /// let status = client.status().await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn timeout(&self) -> Duration {
-        match &self.backend {
-            #[cfg(any(test, feature = "test-mock"))]
-            ClientBackend::Mock(_) => DEFAULT_TIMEOUT,
-            ClientBackend::RealModbus(modbus) => modbus.timeout(),
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `max_wait` is zero
+    /// - The reset command fails other than by timing out
+    /// - The controller does not answer within `max_wait` (a timeout error
+    ///   carrying `max_wait`)
+    /// - The serial port fails
+    pub async fn reset_and_wait(&mut self, max_wait: Duration) -> Result<Duration> {
+        if max_wait.is_zero() {
+            return Err(Jpf4826Error::invalid_parameter(
+                "Maximum wait must be greater than zero",
+            ));
         }
-    }
+        self.connect().await?;
+        self.clear_cache();
 
-    /// Creates a mock client for testing (test-only).
-    #[doc(hidden)]
-    #[cfg(any(test, feature = "test-mock"))]
-    pub async fn new_mock(
-        registers: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u16, u16>>>,
-        slave_addr: u8,
-    ) -> Self {
-        Self {
-            backend: ClientBackend::Mock(MockBackend {
-                controller: registers,
-                slave_addr: std::cell::Cell::new(slave_addr),
-            }),
-        }
+        let started = tokio::time::Instant::now();
+        let deadline = started + max_wait;
+        self.with_short_timeout(async |client| {
+            match client.reset().await {
+                Ok(()) => {}
+                Err(e) if e.is_timeout() => {
+                    log::info!("Reset command not acknowledged, waiting for the controller");
+                    client.mode = OperatingMode::Unknown;
+                }
+                Err(e) => return Err(e),
+            }
+
+            loop {
+                let now = tokio::time::Instant::now();
+                if now >= deadline {
+                    log::warn!(
+                        "Controller did not answer within {:?} after reset",
+                        max_wait
+                    );
+                    return Err(Jpf4826Error::timeout(max_wait));
+                }
+                tokio::time::sleep(RESET_POLL_INTERVAL.min(deadline - now)).await;
+                match client
+                    .read_array::<1>(RegisterAddress::CurrentTemperature)
+                    .await
+                {
+                    Ok(_) => return Ok(started.elapsed()),
+                    Err(e) if e.is_serial() => return Err(e),
+                    Err(e) => log::debug!("Controller not back after reset yet: {}", e),
+                }
+            }
+        })
+        .await
     }
 
-    /// Reads holding registers from the controller.
-    ///
-    /// Low-level method for reading raw register values. Most users should
-    /// use the high-level methods like `temperature()` or `status()` instead.
+    /// Switches to automatic temperature-based speed control.
     ///
-    /// # Arguments
+    /// In temperature mode, fan speed is controlled automatically based on
+    /// temperature sensor readings. The controller adjusts fan speed between
+    /// the configured low (start) and high (full speed) temperature thresholds.
     ///
-    /// * `register` - Starting register address
-    /// * `count` - Number of consecutive registers to read
+    /// To switch to manual mode, use `set_fan_speed()` which automatically
+    /// enables manual mode when setting a speed percentage.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use jpf4826_driver::{Jpf4826Client, registers::RegisterAddress};
+    /// # use jpf4826_driver::Jpf4826Client;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// // Read temperature register
-    /// let values = client.read(RegisterAddress::CurrentTemperature, 1).await?;
-    /// println!("Raw temperature value: {}", values[0]);
+    /// // Switch to automatic temperature control
+    /// client.set_auto_speed().await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -240,27 +3423,24 @@ impl Jpf4826Client {
     /// # Errors
     ///
     /// Returns error if Modbus communication fails.
-    pub async fn read(&mut self, register: RegisterAddress, count: u16) -> Result<Vec<u16>> {
-        match &mut self.backend {
-            #[cfg(any(test, feature = "test-mock"))]
-            ClientBackend::Mock(mock) => Ok(mock.read_registers(register.addr(), count)),
-            ClientBackend::RealModbus(modbus) => {
-                modbus.read_holding_registers(register.addr(), count).await
-            }
-        }
+    pub async fn set_auto_speed(&mut self) -> Result<()> {
+        self.write(RegisterAddress::ManualSpeedControl, AUTO_SPEED)
+            .await
     }
 
-    /// Reads current temperature from the controller.
+    /// Sets the ECO/work mode.
+    ///
+    /// Determines fan behavior when temperature falls below (start_temp - 3°C).
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use jpf4826_driver::{Jpf4826Client, WorkMode};
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let temp = client.temperature().await?;
-    /// println!("Current: {}°C", temp.value);
+    /// // Fans maintain 20% speed when below start temperature
+    /// client.set_eco(WorkMode::MinimumSpeed).await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -268,21 +3448,26 @@ impl Jpf4826Client {
     /// # Errors
     ///
     /// Returns error if Modbus communication fails.
-    pub async fn temperature(&mut self) -> Result<Temperature> {
-        let values = self.read(RegisterAddress::CurrentTemperature, 1).await?;
-        let celsius = register_to_celsius(values[0]);
-
-        Ok(Temperature {
-            value: celsius,
-            unit: TemperatureUnit::Celsius,
-        })
+    pub async fn set_eco(&mut self, mode: WorkMode) -> Result<()> {
+        let value = mode.to_register_value();
+        self.write(RegisterAddress::WorkMode, value).await
     }
 
-    /// Reads speed of a specific fan in RPM.
+    /// Sets manual fan speed percentage.
+    ///
+    /// This method automatically switches the controller to manual mode
+    /// and sets the specified speed percentage. Temperature-based control
+    /// is disabled while in manual mode.
+    ///
+    /// Mode and speed share register 0x0003, so both change in one write:
+    /// there is no separate switch to manual mode that would run the fans
+    /// at 0% until a speed is set.
+    ///
+    /// To return to automatic temperature control, call `set_auto_speed()`.
     ///
     /// # Arguments
     ///
-    /// * `index` - Fan number (1-4)
+    /// * `speed_percent` - Speed percentage (0-100)
     ///
     /// # Examples
     ///
@@ -291,8 +3476,11 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let rpm = client.fan_speed(1).await?;
-    /// println!("Fan 1: {} RPM", rpm);
+    /// // Set fans to 75% speed (automatically enables manual mode)
+    /// client.set_fan_speed(75).await?;
+    ///
+    /// // Return to automatic temperature control
+    /// client.set_auto_speed().await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -300,19 +3488,28 @@ impl Jpf4826Client {
     /// # Errors
     ///
     /// Returns error if:
-    /// - Fan index is out of range (1-4)
+    /// - Speed is greater than 100
+    /// - In [strict mode](Self::set_strict_mode), the controller is not
+    ///   known to be in manual mode
     /// - Modbus communication fails
-    pub async fn fan_speed(&mut self, index: u8) -> Result<u16> {
-        let register = RegisterAddress::fan_speed_register(index)
-            .ok_or_else(|| Jpf4826Error::new_invalid_fan_index(index))?;
-
-        let values = self.read(register, 1).await?;
-        Ok(values[0])
+    pub async fn set_fan_speed(&mut self, speed_percent: u8) -> Result<()> {
+        if speed_percent > 100 {
+            return Err(Jpf4826Error::invalid_speed(speed_percent));
+        }
+        if self.strict_mode {
+            let mode = self.operating_mode().await?;
+            if mode != OperatingMode::Manual {
+                return Err(Jpf4826Error::mode_conflict(mode));
+            }
+        }
+        self.set_manual_speed(speed_percent).await
     }
 
-    /// Reads the configured number of fans.
+    /// Switches to manual mode and sets the fan speed percentage.
     ///
-    /// Returns 0 if fault detection is disabled.
+    /// Like [`set_fan_speed`](Self::set_fan_speed), but states the mode
+    /// change and is never refused by
+    /// [strict mode](Self::set_strict_mode).
     ///
     /// # Examples
     ///
@@ -321,209 +3518,210 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let count = client.fan_count().await?;
-    /// println!("Configured fans: {}", count);
+    /// client.set_manual_speed(75).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn fan_count(&mut self) -> Result<u8> {
-        let values = self.read(RegisterAddress::FanQuantity, 1).await?;
-        Ok(values[0] as u8)
+    /// Returns error if:
+    /// - Speed is greater than 100
+    /// - Modbus communication fails
+    pub async fn set_manual_speed(&mut self, speed_percent: u8) -> Result<()> {
+        if speed_percent > 100 {
+            return Err(Jpf4826Error::invalid_speed(speed_percent));
+        }
+        self.write(RegisterAddress::ManualSpeedControl, speed_percent as u16)
+            .await
     }
 
-    /// Reads status of all fans (running state, faults, speeds).
+    /// Selects temperature mode or a manual speed.
     ///
-    /// Returns information for all 4 fan slots regardless of configured
-    /// fan count. Check `fan_count()` to determine how many are active.
+    /// [`OperatingModeState::Temperature`] calls
+    /// [`set_auto_speed`](Self::set_auto_speed) and
+    /// [`OperatingModeState::Manual`] calls
+    /// [`set_manual_speed`](Self::set_manual_speed), so the call is never
+    /// refused by [strict mode](Self::set_strict_mode).
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use jpf4826_driver::{Jpf4826Client, OperatingModeState};
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let fans = client.fan_status().await?;
-    /// for fan in fans {
-    ///     println!("Fan {}: {:?} @ {} RPM", fan.index, fan.status, fan.rpm);
-    /// }
+    /// // Run at full speed, then restore the previous mode
+    /// let state = client.mode_state().await?;
+    /// client.set_mode_state(OperatingModeState::Manual(100)).await?;
+    /// client.set_mode_state(state).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn fan_status(&mut self) -> Result<Vec<FanInfo>> {
-        log::debug!("Reading fan status and speeds");
-
-        // Read: fan status bitmap (0x0001), fan speeds (0x0007-0x000A), fault bitmap (0x000E)
-        // We need separate reads since registers aren't consecutive
-        log::debug!("Reading fan status bitmap from register 0x0001");
-        let _status_bitmap = self.read(RegisterAddress::FanStatus, 1).await?[0];
-        log::debug!("Status bitmap: {:#06X}", _status_bitmap);
-
-        log::debug!("Reading fan speeds from registers 0x0007-0x000A");
-        let speeds = self.read(RegisterAddress::Fan1Speed, 4).await?;
-        log::debug!("Fan speeds: {:?} RPM", speeds);
-
-        log::debug!("Reading fault bitmap from register 0x000E");
-        let fault_bitmap = self.read(RegisterAddress::FanFaultCode, 1).await?[0];
-        log::debug!("Fault bitmap: {:#06X}", fault_bitmap);
-
-        let fault_statuses = parse_fan_fault_bitmap(fault_bitmap);
-
-        let mut fans = Vec::with_capacity(4);
-        for i in 0..4 {
-            fans.push(FanInfo {
-                index: (i + 1) as u8,
-                status: fault_statuses[i],
-                rpm: speeds[i],
-            });
+    /// Returns error if:
+    /// - `state` is [`OperatingModeState::Unknown`]
+    /// - Speed is greater than 100
+    /// - Modbus communication fails
+    pub async fn set_mode_state(&mut self, state: OperatingModeState) -> Result<()> {
+        match state {
+            OperatingModeState::Temperature => self.set_auto_speed().await,
+            OperatingModeState::Manual(speed) => self.set_manual_speed(speed).await,
+            OperatingModeState::Unknown => Err(Jpf4826Error::invalid_parameter(
+                "Cannot select the unknown operating mode",
+            )),
         }
+    }
 
-        log::debug!("Assembled {} fan info entries", fans.len());
-        Ok(fans)
+    /// Pulses the fans so the controller can be found in a rack.
+    ///
+    /// Alternates between 100% and 20% speed every second for `duration`,
+    /// then restores the previous speed setting. A manual speed set through
+    /// this client is restored as such; otherwise the controller returns to
+    /// temperature mode. The restoration is attempted even if a pulse
+    /// fails. Every pulse is a register write and counts against the write
+    /// budget.
+    ///
+    /// Dropping the returned future skips the restoration and leaves the
+    /// fans at the last pulse speed; use
+    /// [`identify_until`](Self::identify_until) to stop early.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 5).await?;
+    /// // Watch the rack for the unit at address 5
+    /// client.identify(Duration::from_secs(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails. The error of a pulse
+    /// takes precedence over the error of the restoration.
+    pub async fn identify(&mut self, duration: Duration) -> Result<()> {
+        self.identify_until(duration, std::future::pending()).await
     }
 
-    /// Reads complete controller status.
-    ///
-    /// This method performs a bulk read of all status registers and
-    /// assembles them into a comprehensive status structure.
+    /// Pulses the fans like [`identify`](Self::identify) until `duration`
+    /// elapses or `cancel` completes, then restores the speed setting.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
-    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// let status = client.status().await?;
-    /// println!("ECO Mode: {}", status.eco_mode);
-    /// println!("Temperature: {}°C", status.temperature_current.value);
-    /// println!("Fans: {}", status.fan_count);
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 5).await?;
+    /// let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    /// # drop(stop);
+    /// client
+    ///     .identify_until(Duration::from_secs(60), async {
+    ///         let _ = stopped.await;
+    ///     })
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn status(&mut self) -> Result<ControllerStatus> {
-        log::debug!("Reading controller status (15 registers starting from 0x0000)");
-
-        // Read all status registers at once (0x0000-0x000E = 15 registers)
-        let values = self.read(RegisterAddress::CurrentTemperature, 15).await?;
-        log::debug!("Received {} register values", values.len());
-        log::debug!("Raw register values: {:04X?}", values);
-
-        let current_temp = register_to_celsius(values[0]);
-        let modbus_address = values[2] as u8;
-        let fan_count = values[6] as u8;
-        let pwm_freq_raw = values[11];
-        let start_temp = register_to_celsius(values[12]);
-        let full_temp = register_to_celsius(values[13]);
+    /// Returns error if Modbus communication fails. The error of a pulse
+    /// takes precedence over the error of the restoration.
+    pub async fn identify_until(
+        &mut self,
+        duration: Duration,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<()> {
+        let [current] = self.read_array(RegisterAddress::ManualSpeedControl).await?;
+        // Speed values are read in both modes; only our own write proves manual
+        let restore = if self.mode == OperatingMode::Manual && current <= 100 {
+            current
+        } else {
+            AUTO_SPEED
+        };
 
-        log::debug!(
-            "Parsed values: temp={}, addr={}, fans={}",
-            current_temp,
-            modbus_address,
-            fan_count
-        );
+        log::info!("Identifying controller for {:?}", duration);
+        let pulsed = self.pulse(duration, cancel).await;
+        let restored = self
+            .write(RegisterAddress::ManualSpeedControl, restore)
+            .await;
+        if let Err(e) = &restored {
+            log::warn!(
+                "Failed to restore speed setting {:#06X} after identify: {}",
+                restore,
+                e
+            );
+        }
+        pulsed.and(restored)
+    }
 
-        // Parse work mode and determine ECO mode
-        // ECO mode = true means Shutdown (more energy efficient)
-        // ECO mode = false means MinimumSpeed
-        let work_mode = WorkMode::from_register_value(values[5]).unwrap_or(WorkMode::MinimumSpeed);
-        let eco_mode = work_mode == WorkMode::Shutdown;
-
-        // Parse PWM frequency
-        let pwm_frequency =
-            PwmFrequency::from_register_value(pwm_freq_raw).unwrap_or(PwmFrequency::Hz25000);
-
-        // Parse fan data from already-read registers to avoid redundant reads
-        // values[1] = fan status bitmap (not used currently)
-        // values[7-10] = fan speeds (0x0007-0x000A)
-        // values[14] = fault bitmap (0x000E)
-        log::debug!("Parsing fan status from bulk read data");
-        let fault_bitmap = values[14];
-        log::debug!("Fault bitmap: {:#06X}", fault_bitmap);
-        let fault_statuses = parse_fan_fault_bitmap(fault_bitmap);
+    /// Writes the identify pattern until `duration` elapses or `cancel`
+    /// completes.
+    async fn pulse(&mut self, duration: Duration, cancel: impl Future<Output = ()>) -> Result<()> {
+        let mut cancel = std::pin::pin!(cancel);
+        let deadline = tokio::time::Instant::now() + duration;
+        let mut next = tokio::time::Instant::now();
 
-        let mut fans = Vec::with_capacity(4);
-        for i in 0..4 {
-            fans.push(FanInfo {
-                index: (i + 1) as u8,
-                status: fault_statuses[i],
-                rpm: values[7 + i],
-            });
+        for &speed in IDENTIFY_PATTERN.iter().cycle() {
+            if next >= deadline {
+                break;
+            }
+            self.write(RegisterAddress::ManualSpeedControl, speed)
+                .await?;
+            next += IDENTIFY_PERIOD;
+            if tokio::time::timeout_at(next.min(deadline), &mut cancel)
+                .await
+                .is_ok()
+            {
+                log::info!("Identify cancelled");
+                break;
+            }
         }
-        log::debug!("Fan status parsed successfully from bulk read");
-
-        Ok(ControllerStatus {
-            eco_mode,
-            modbus_address,
-            pwm_frequency,
-            fan_count,
-            temperature_current: Temperature {
-                value: current_temp,
-                unit: TemperatureUnit::Celsius,
-            },
-            temperature_low_threshold: Temperature {
-                value: start_temp,
-                unit: TemperatureUnit::Celsius,
-            },
-            temperature_high_threshold: Temperature {
-                value: full_temp,
-                unit: TemperatureUnit::Celsius,
-            },
-            fans,
-        })
+        Ok(())
     }
 
-    // === Write Operations ===
-
-    /// Writes a single holding register to the controller.
-    ///
-    /// Low-level method for writing raw register values. Most users should
-    /// use the high-level methods like `set_fan_speed()` or `reset()` instead.
+    /// Sets the number of fans connected to the controller.
     ///
-    /// The Modbus protocol validates the write by verifying the controller
-    /// echoes back the same register address and value.
+    /// Valid range: 1-4. Set to 0 to disable fault detection.
     ///
     /// # Arguments
     ///
-    /// * `register` - Register address to write
-    /// * `value` - 16-bit value to write
+    /// * `count` - Number of fans (0-4)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_fan_count(3).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Errors
     ///
     /// Returns error if:
+    /// - Count is greater than 4
     /// - Modbus communication fails
-    /// - Controller response is invalid or does not match the written value
-    pub async fn write(&mut self, register: RegisterAddress, value: u16) -> Result<()> {
-        match &mut self.backend {
-            #[cfg(any(test, feature = "test-mock"))]
-            ClientBackend::Mock(mock) => {
-                mock.controller
-                    .lock()
-                    .unwrap()
-                    .insert(register.addr(), value);
-                Ok(())
-            }
-            ClientBackend::RealModbus(modbus) => {
-                modbus.write_single_register(register.addr(), value).await
-            }
-        }
+    pub async fn set_fan_count(&mut self, count: u8) -> Result<()> {
+        check_fan_count(count)?;
+        self.write(RegisterAddress::FanQuantity, count as u16).await
     }
 
-    /// Resets the controller.
+    /// Disables fan fault detection.
     ///
-    /// Sends the reset command (0x00AA) to register 0x0020.
+    /// Equivalent to calling `set_fan_count(0)`.
     ///
     /// # Examples
     ///
@@ -532,8 +3730,7 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// client.reset().await?;
-    /// println!("Controller reset");
+    /// client.disable_fault_detection().await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -541,76 +3738,160 @@ impl Jpf4826Client {
     /// # Errors
     ///
     /// Returns error if Modbus communication fails.
-    pub async fn reset(&mut self) -> Result<()> {
-        self.write(RegisterAddress::ResetController, 0x00AA).await
+    pub async fn disable_fault_detection(&mut self) -> Result<()> {
+        self.set_fan_count(0).await
     }
 
-    /// Switches to automatic temperature-based speed control.
+    /// Finds the controllers answering at addresses in `range`.
     ///
-    /// In temperature mode, fan speed is controlled automatically based on
-    /// temperature sensor readings. The controller adjusts fan speed between
-    /// the configured low (start) and high (full speed) temperature thresholds.
+    /// Probes each address with a read of the current temperature, waiting
+    /// `per_probe_timeout` for an answer, over the client's own connection
+    /// (the port is opened once, not per address). Addresses that time out
+    /// are skipped. Afterwards the client talks to its own address again
+    /// with its previous timeout and retry settings. Probes are not counted
+    /// in [`link_info`](Self::link_info).
     ///
-    /// To switch to manual mode, use `set_fan_speed()` which automatically
-    /// enables manual mode when setting a speed percentage.
+    /// A full scan of 1-254 takes up to 254 times `per_probe_timeout`; see
+    /// [`scan_bus_until`](Self::scan_bus_until) to stop early.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// // Switch to automatic temperature control
-    /// client.set_auto_speed().await?;
+    /// for device in client.scan_bus(1..=32, Duration::from_millis(100)).await? {
+    ///     println!("Controller at {}", device.addr);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn set_auto_speed(&mut self) -> Result<()> {
-        self.write(RegisterAddress::ManualSpeedControl, 0xFFFF)
+    /// Returns error if `range` is empty or outside 1-254,
+    /// `per_probe_timeout` is zero, or the port fails (serial error), which
+    /// aborts the scan.
+    pub async fn scan_bus(
+        &mut self,
+        range: RangeInclusive<u8>,
+        per_probe_timeout: Duration,
+    ) -> Result<Vec<DiscoveredDevice>> {
+        self.scan_bus_until(range, per_probe_timeout, std::future::pending())
             .await
     }
 
-    /// Sets the ECO/work mode.
+    /// Like [`scan_bus`](Self::scan_bus), stopping once `cancel` completes.
     ///
-    /// Determines fan behavior when temperature falls below (start_temp - 3°C).
+    /// `cancel` is checked before each probe; the controllers found so far
+    /// are returned.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use jpf4826_driver::{Jpf4826Client, WorkMode};
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// // Fans maintain 20% speed when below start temperature
-    /// client.set_eco(WorkMode::MinimumSpeed).await?;
+    /// // Give up after 10 seconds
+    /// let deadline = tokio::time::sleep(Duration::from_secs(10));
+    /// let found = client
+    ///     .scan_bus_until(1..=254, Duration::from_millis(100), deadline)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn set_eco(&mut self, mode: WorkMode) -> Result<()> {
-        let value = mode.to_register_value();
-        self.write(RegisterAddress::WorkMode, value).await
+    /// Same as [`scan_bus`](Self::scan_bus).
+    pub async fn scan_bus_until(
+        &mut self,
+        range: RangeInclusive<u8>,
+        per_probe_timeout: Duration,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Vec<DiscoveredDevice>> {
+        if range.is_empty() || *range.start() == 0 || *range.end() == 255 {
+            return Err(Jpf4826Error::invalid_parameter(format!(
+                "Scan range {}-{} must be within 1-254",
+                range.start(),
+                range.end()
+            )));
+        }
+        if per_probe_timeout.is_zero() {
+            return Err(Jpf4826Error::invalid_parameter(
+                "Probe timeout must be greater than zero",
+            ));
+        }
+        self.connect().await?;
+
+        let slave_addr = self.current_slave_addr();
+        let timeout_mode = self.timeout_mode();
+        let retries = self.retries;
+        self.set_timeout_mode(TimeoutMode::Fixed(per_probe_timeout));
+        self.retries = 0;
+
+        let found = self.probe_addresses(range, cancel).await;
+
+        self.set_slave(slave_addr);
+        self.set_timeout_mode(timeout_mode);
+        self.retries = retries;
+        found
     }
 
-    /// Sets manual fan speed percentage.
-    ///
-    /// This method automatically switches the controller to manual mode
-    /// and sets the specified speed percentage. Temperature-based control
-    /// is disabled while in manual mode.
-    ///
-    /// To return to automatic temperature control, call `set_auto_speed()`.
-    ///
-    /// # Arguments
+    /// Reads the temperature at every address in `range` until `cancel`
+    /// completes.
+    async fn probe_addresses(
+        &mut self,
+        range: RangeInclusive<u8>,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Vec<DiscoveredDevice>> {
+        let mut cancel = std::pin::pin!(cancel);
+        let mut found = Vec::new();
+
+        for addr in range {
+            if is_completed(cancel.as_mut()).await {
+                log::info!("Bus scan cancelled before address {}", addr);
+                break;
+            }
+            self.set_slave(addr);
+            let mut value = [0];
+            match self
+                .request_into(RegisterAddress::CurrentTemperature, &mut value)
+                .await
+            {
+                Ok(1) => {
+                    log::info!("Found controller at address {}", addr);
+                    found.push(DiscoveredDevice {
+                        addr,
+                        temperature: checked_register_to_celsius(value[0]).map(|celsius| {
+                            Temperature {
+                                value: celsius,
+                                unit: TemperatureUnit::Celsius,
+                            }
+                        }),
+                    });
+                }
+                Ok(_) => log::warn!("Short response at address {}, skipping", addr),
+                Err(e) if e.is_timeout() => log::debug!("No answer at address {}", addr),
+                Err(e) if e.is_serial() => return Err(e),
+                Err(e) => log::warn!("Unexpected answer at address {}, skipping: {}", addr, e),
+            }
+        }
+        Ok(found)
+    }
+
+    /// Checks that the controller answers, without reading the status
+    /// block or changing anything.
     ///
-    /// * `speed_percent` - Speed percentage (0-100)
+    /// Reads the current temperature register alone, with the configured
+    /// timeout capped at [`PROBE_TIMEOUT`] and without retries, and
+    /// measures the round trip. A round trip growing over time hints at
+    /// marginal wiring. The raw value is not decoded, so a controller with
+    /// a faulty sensor still responds.
     ///
     /// # Examples
     ///
@@ -619,35 +3900,73 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// // Set fans to 75% speed (automatically enables manual mode)
-    /// client.set_fan_speed(75).await?;
-    ///
-    /// // Return to automatic temperature control
-    /// client.set_auto_speed().await?;
+    /// match client.probe().await {
+    ///     Ok(probe) => println!("Alive, {:?} round trip", probe.round_trip),
+    ///     Err(e) if e.is_timeout() => println!("No answer"),
+    ///     Err(e) => println!("Bus error: {}", e),
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// - Speed is greater than 100
-    /// - Modbus communication fails
-    pub async fn set_fan_speed(&mut self, speed_percent: u8) -> Result<()> {
-        if speed_percent > 100 {
-            return Err(Jpf4826Error::invalid_speed(speed_percent));
+    /// Returns a timeout error (see [`Jpf4826Error::is_timeout`]) if the
+    /// controller does not answer, or a Modbus or serial error if the bus
+    /// itself fails.
+    pub async fn probe(&mut self) -> Result<ProbeResult> {
+        self.connect().await?;
+
+        let round_trip = self
+            .with_short_timeout(async |client| {
+                let started = tokio::time::Instant::now();
+                client
+                    .read_array::<1>(RegisterAddress::CurrentTemperature)
+                    .await?;
+                Ok(started.elapsed())
+            })
+            .await?;
+        Ok(ProbeResult {
+            responded: true,
+            round_trip,
+        })
+    }
+
+    /// Runs `f` with the timeout capped at [`PROBE_TIMEOUT`], without
+    /// retries and without the read cache, restoring all afterwards.
+    async fn with_short_timeout<T>(&mut self, f: impl AsyncFnOnce(&mut Self) -> T) -> T {
+        let timeout_mode = self.timeout_mode();
+        let retries = self.retries;
+        let cache = self.cache.take();
+        // Keeps an adaptive timeout's observations when it is short enough
+        let capped = self.timeout() > PROBE_TIMEOUT;
+        if capped {
+            self.set_timeout_mode(TimeoutMode::Fixed(PROBE_TIMEOUT));
         }
-        self.write(RegisterAddress::ManualSpeedControl, speed_percent as u16)
-            .await
+        self.retries = 0;
+
+        let output = f(self).await;
+
+        if capped {
+            self.set_timeout_mode(timeout_mode);
+        }
+        self.retries = retries;
+        self.cache = cache;
+        output
     }
 
-    /// Sets the number of fans connected to the controller.
-    ///
-    /// Valid range: 1-4. Set to 0 to disable fault detection.
+    /// Reads the raw register map for a diagnostic snapshot.
     ///
-    /// # Arguments
+    /// Reads 0x0000-0x000E in one request and, one at a time, the second
+    /// temperature input (0x000F) and the addresses around the reset
+    /// command (0x001F-0x0021). Reading never triggers the reset, which
+    /// only a write does. Values are not decoded or validated.
     ///
-    /// * `count` - Number of fans (0-4)
+    /// A register the controller refuses, or does not answer for, is
+    /// recorded with its error instead of failing the dump. If the block
+    /// read fails, its registers are read one at a time. The single reads
+    /// use a timeout capped at [`PROBE_TIMEOUT`] and no retries, as
+    /// unknown addresses may go unanswered.
     ///
     /// # Examples
     ///
@@ -656,29 +3975,109 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// client.set_fan_count(3).await?;
+    /// let dump = client.dump_registers().await?;
+    /// println!("{}", dump);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// - Count is greater than 4
-    /// - Modbus communication fails
-    pub async fn set_fan_count(&mut self, count: u8) -> Result<()> {
-        if count > 4 {
-            return Err(Jpf4826Error::invalid_parameter(format!(
-                "Fan count {} out of range (0-4)",
-                count
-            )));
-        }
-        self.write(RegisterAddress::FanQuantity, count as u16).await
+    /// Returns error if the serial port cannot be opened or fails during
+    /// the dump.
+    pub async fn dump_registers(&mut self) -> Result<RegisterDump> {
+        self.connect().await?;
+        let mut dump = RegisterDump::new(SystemTime::now());
+
+        let mut block = [0; ControllerStatus::REGISTER_COUNT as usize];
+        let started = self.begin(Operation::Read);
+        let result = self
+            .request_raw_into(DUMP_BLOCK_START, &mut block)
+            .await
+            .and_then(|len| check_response_length(DUMP_BLOCK_START, block.len(), len));
+        self.record(Operation::Read, started, &result);
+        let single = match result {
+            Ok(()) => {
+                for (addr, value) in (DUMP_BLOCK_START..).zip(block) {
+                    dump.registers.insert(addr, DumpValue::Value(value));
+                }
+                Vec::new()
+            }
+            Err(e) if e.is_serial() => return Err(e),
+            Err(e) => {
+                log::warn!("Block read failed, reading registers one at a time: {}", e);
+                (DUMP_BLOCK_START..DUMP_BLOCK_START + block.len() as u16).collect()
+            }
+        };
+
+        let addresses = single.into_iter().chain(DUMP_EXTRA_ADDRESSES);
+        self.with_short_timeout(async |client| {
+            for addr in addresses {
+                let mut value = [0];
+                let started = client.begin(Operation::Read);
+                let result = client
+                    .request_raw_into(addr, &mut value)
+                    .await
+                    .and_then(|len| check_response_length(addr, 1, len));
+                client.record(Operation::Read, started, &result);
+                let entry = match result {
+                    Ok(()) => DumpValue::Value(value[0]),
+                    Err(e) if e.is_serial() => return Err(e),
+                    Err(e) => DumpValue::Error(e.to_string()),
+                };
+                dump.registers.insert(addr, entry);
+            }
+            Ok(())
+        })
+        .await?;
+        Ok(dump)
     }
 
-    /// Disables fan fault detection.
+    /// Writes a single holding register on every controller on the bus.
     ///
-    /// Equivalent to calling `set_fan_count(0)`.
+    /// Sent to the Modbus broadcast address 0, which every controller
+    /// accepts whatever its own address. Broadcasts are not acknowledged,
+    /// so the write is not verified: the client waits a fixed turnaround
+    /// delay (200 ms) for the controllers to apply it instead of waiting
+    /// for a response. Read the register back at a known address if it
+    /// matters.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the model lacks `register`, `register` is
+    /// read-only, the write budget is used up or the request cannot be
+    /// sent.
+    pub async fn write_broadcast(&mut self, register: RegisterAddress, value: u16) -> Result<()> {
+        self.check_register(register)?;
+        check_writable(register.addr(), 1)?;
+        self.spend_budget(register.addr(), 1).await?;
+        self.connect().await?;
+        let started = self.begin(Operation::Write);
+        let result = match &mut self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(mock) => mock.write_broadcast(register.addr(), value).await,
+            ClientBackend::RealModbus(modbus) => {
+                modbus.write_broadcast(register.addr(), value).await
+            }
+            ClientBackend::Pending(_) => unreachable!("connected above"),
+        };
+        self.invalidate_cache(register.addr(), 1);
+        self.record(Operation::Write, started, &result);
+        result?;
+        self.count_writes(register.addr(), 1);
+        self.track_mode(register.addr(), &[value]);
+        Ok(())
+    }
+
+    /// Assigns a Modbus address to a controller whose address is unknown.
+    ///
+    /// Broadcasts the new address (see
+    /// [`write_broadcast`](Self::write_broadcast)) and switches the client
+    /// to it, for provisioning a factory-fresh unit.
+    ///
+    /// **Every controller on the bus takes the new address.** Only the
+    /// unit being provisioned may be powered while this runs; power up
+    /// the others afterwards.
     ///
     /// # Examples
     ///
@@ -687,16 +4086,26 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// client.disable_fault_detection().await?;
+    /// // Only the new unit is powered
+    /// client.set_addr_broadcast(7).await?;
+    /// let temp = client.temperature().await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn disable_fault_detection(&mut self) -> Result<()> {
-        self.set_fan_count(0).await
+    /// Returns error if:
+    /// - Address is 0 or greater than 254
+    /// - The request cannot be sent
+    pub async fn set_addr_broadcast(&mut self, addr: u8) -> Result<()> {
+        if !(1..=254).contains(&addr) {
+            return Err(Jpf4826Error::invalid_address(addr));
+        }
+        self.write_broadcast(RegisterAddress::ModbusAddress, addr as u16)
+            .await?;
+        self.set_slave(addr);
+        Ok(())
     }
 
     /// Sets the Modbus device address.
@@ -704,6 +4113,10 @@ impl Jpf4826Client {
     /// Valid range: 1-254. The controller will respond to this address
     /// on subsequent Modbus requests.
     ///
+    /// Not cancellation-safe: if the returned future is dropped while the
+    /// write is in flight, the client keeps the old address, which the
+    /// controller may no longer answer at.
+    ///
     /// # Arguments
     ///
     /// * `addr` - New Modbus address (1-254)
@@ -733,16 +4146,134 @@ impl Jpf4826Client {
             .await?;
 
         // Update the client's internal address to match the controller
+        self.set_slave(addr);
+
+        Ok(())
+    }
+
+    /// Changes the Modbus address and checks the controller answers there.
+    ///
+    /// Writes the address like [`set_addr`](Self::set_addr), then reads the
+    /// current temperature register at the new address. If that read
+    /// fails, the client switches back to the old address and reads it
+    /// there too, so the error tells whether the controller kept its old
+    /// address or has to be found with [`scan_bus`](Self::scan_bus).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// match client.change_address(5).await {
+    ///     Err(e) if e.old_address_responds() == Some(true) => {
+    ///         eprintln!("Address unchanged: {}", e);
+    ///     }
+    ///     result => result?,
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`set_addr`](Self::set_addr), or an address change
+    /// error (see [`Jpf4826Error::is_address_change_failed`]) if the
+    /// controller does not answer at the new address. The client then
+    /// talks to the old address again.
+    pub async fn change_address(&mut self, new_addr: u8) -> Result<()> {
+        let old_addr = self.current_slave_addr();
+        self.set_addr(new_addr).await?;
+
+        // Any answer will do; a sensor fault is not an address problem
+        let Err(e) = self
+            .read_array::<1>(RegisterAddress::CurrentTemperature)
+            .await
+        else {
+            log::info!("Controller moved from address {} to {}", old_addr, new_addr);
+            return Ok(());
+        };
+        log::warn!(
+            "Controller did not answer at new address {}: {}",
+            new_addr,
+            e
+        );
+        self.set_slave(old_addr);
+        let old_responds = self
+            .read_array::<1>(RegisterAddress::CurrentTemperature)
+            .await
+            .is_ok();
+        Err(Jpf4826Error::address_change_failed(
+            old_addr,
+            new_addr,
+            old_responds,
+            e.to_string(),
+        ))
+    }
+
+    /// Directs following requests to the controller at `addr`.
+    ///
+    /// Used by [`Jpf4826Manager`](crate::manager::Jpf4826Manager) and
+    /// [`Jpf4826Bus`](crate::bus::Jpf4826Bus) to share one serial
+    /// connection between several controllers on a bus.
+    pub(crate) fn select_slave(&mut self, addr: u8) {
+        if self.current_slave_addr() != addr {
+            // The tracked mode belongs to the previous controller
+            self.mode = OperatingMode::Unknown;
+            self.set_slave(addr);
+        }
+    }
+
+    fn current_slave_addr(&self) -> u8 {
         match &self.backend {
+            #[cfg(any(test, feature = "test-mock"))]
+            ClientBackend::Mock(mock) => mock.slave_addr(),
+            ClientBackend::RealModbus(modbus) => modbus.slave_addr(),
+            ClientBackend::Pending(pending) => pending.slave_addr,
+        }
+    }
+
+    fn set_slave(&mut self, addr: u8) {
+        // Cached values belong to the previous controller
+        self.clear_cache();
+        match &mut self.backend {
             #[cfg(any(test, feature = "test-mock"))]
             ClientBackend::Mock(mock) => mock.set_slave_addr(addr),
             ClientBackend::RealModbus(modbus) => modbus.set_slave_addr(addr),
+            ClientBackend::Pending(pending) => pending.slave_addr = addr,
         }
+    }
 
-        Ok(())
+    /// Sets the PWM frequency for fan control.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, PwmFrequency};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.set_pwm_frequency(PwmFrequency::Hz25000).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the model does not support `freq` (see
+    /// [`DeviceModel::pwm_frequencies`]) or Modbus communication fails.
+    pub async fn set_pwm_frequency(&mut self, freq: PwmFrequency) -> Result<()> {
+        let value = self.pwm_register_value(freq)?;
+        self.write(RegisterAddress::PwmFrequency, value).await
     }
 
-    /// Sets the PWM frequency for fan control.
+    /// Sets the PWM frequency and reads it back to check it was applied.
+    ///
+    /// Some controller revisions silently ignore the write while a fan runs
+    /// at 100%. This writes register 0x000B like
+    /// [`set_pwm_frequency`](Self::set_pwm_frequency), then reads it again
+    /// and compares the decoded frequencies.
     ///
     /// # Examples
     ///
@@ -751,23 +4282,48 @@ impl Jpf4826Client {
     /// # #[tokio::main]
     /// # async fn main() -> jpf4826_driver::Result<()> {
     /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
-    /// client.set_pwm_frequency(PwmFrequency::Hz25000).await?;
+    /// match client.set_pwm_frequency_verified(PwmFrequency::Hz25000).await {
+    ///     Err(e) if e.is_pwm_frequency_mismatch() => eprintln!("Flag this board: {}", e),
+    ///     result => result?,
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns error if Modbus communication fails.
-    pub async fn set_pwm_frequency(&mut self, freq: PwmFrequency) -> Result<()> {
-        let value = freq.to_register_value();
-        self.write(RegisterAddress::PwmFrequency, value).await
+    /// Returns error as [`set_pwm_frequency`](Self::set_pwm_frequency), an
+    /// invalid response error if the register reads back a value with no
+    /// meaning, or a mismatch error (see
+    /// [`Jpf4826Error::is_pwm_frequency_mismatch`]) holding both
+    /// frequencies if the controller kept another one.
+    pub async fn set_pwm_frequency_verified(&mut self, freq: PwmFrequency) -> Result<()> {
+        self.set_pwm_frequency(freq).await?;
+        let actual = self.pwm_frequency().await?;
+        if actual != freq {
+            log::warn!(
+                "Controller kept PWM frequency {} Hz instead of {} Hz",
+                actual.to_hz(),
+                freq.to_hz()
+            );
+            return Err(Jpf4826Error::pwm_frequency_mismatch(freq, actual));
+        }
+        Ok(())
+    }
+
+    /// Encodes `freq` for the model's PWM frequency register.
+    fn pwm_register_value(&self, freq: PwmFrequency) -> Result<u16> {
+        self.model.pwm_register_value(freq).ok_or_else(|| {
+            Jpf4826Error::unsupported(self.model, format!("PWM frequency {} Hz", freq.to_hz()))
+        })
     }
 
     /// Sets temperature thresholds for automatic fan control.
     ///
     /// Fans start spinning at `low` temperature and reach 100% speed at
     /// `high` temperature. Constraint: `high` must be greater than `low`.
+    /// Both thresholds are written to the combined register 0x0004 in one
+    /// transaction, so the controller never holds only one new threshold.
     ///
     /// # Arguments
     ///
@@ -794,35 +4350,57 @@ impl Jpf4826Client {
     /// - Temperatures are out of range (-20 to 120°C)
     /// - Modbus communication fails
     pub async fn set_temperature_threshold(&mut self, low: i16, high: i16) -> Result<()> {
-        // Validate constraint
-        if high <= low {
-            return Err(Jpf4826Error::invalid_thresholds(low, high));
-        }
-
-        // Validate range
-        if !(-20..=120).contains(&low) {
-            return Err(Jpf4826Error::invalid_parameter(format!(
-                "Low temperature {}°C out of range (-20 to 120)",
-                low
-            )));
-        }
-        if !(-20..=120).contains(&high) {
-            return Err(Jpf4826Error::invalid_parameter(format!(
-                "High temperature {}°C out of range (-20 to 120)",
-                high
-            )));
-        }
-
-        // Write both registers
-        let low_value = celsius_to_register(low);
-        let high_value = celsius_to_register(high);
+        self.set_thresholds(Temperature::celsius(low), Temperature::celsius(high))
+            .await
+    }
 
-        self.write(RegisterAddress::StartTemperature, low_value)
-            .await?;
-        self.write(RegisterAddress::FullSpeedTemperature, high_value)
-            .await?;
+    /// Sets temperature thresholds given in `unit`.
+    ///
+    /// The values are converted to whole degrees Celsius, Fahrenheit
+    /// rounded to the nearest degree (see [`Temperature::to_celsius`]), and
+    /// then validated against the controller's -20 to 120°C range and
+    /// written like
+    /// [`set_temperature_threshold`](Self::set_temperature_threshold).
+    /// Error messages quote the values and range in `unit`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, TemperatureUnit};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// // Start at 86°F (30°C), full speed at 122°F (50°C)
+    /// client
+    ///     .set_temperature_threshold_in(86, 122, TemperatureUnit::Fahrenheit)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the converted thresholds are invalid (see
+    /// [`set_temperature_threshold`](Self::set_temperature_threshold)) or
+    /// Modbus communication fails.
+    pub async fn set_temperature_threshold_in(
+        &mut self,
+        low: i16,
+        high: i16,
+        unit: TemperatureUnit,
+    ) -> Result<()> {
+        self.set_thresholds(
+            Temperature { value: low, unit },
+            Temperature { value: high, unit },
+        )
+        .await
+    }
 
-        Ok(())
+    /// Writes the thresholds together after validating them.
+    async fn set_thresholds(&mut self, low: Temperature, high: Temperature) -> Result<()> {
+        let value = combined_threshold_value(low, high)?;
+        self.write(RegisterAddress::CombinedTemperature, value)
+            .await
     }
 
     /// Sets only the start (low) temperature threshold.
@@ -852,6 +4430,8 @@ impl Jpf4826Client {
     /// Returns error if:
     /// - Temperature is out of range (-20 to 120°C)
     /// - New low temperature is not less than current high temperature
+    /// - The full speed temperature register holds a value outside
+    ///   0x0014-0x00A0
     /// - Modbus communication fails
     pub async fn set_start_temperature(&mut self, low: i16) -> Result<()> {
         // Validate range
@@ -863,8 +4443,10 @@ impl Jpf4826Client {
         }
 
         // Read current high threshold to validate constraint
-        let values = self.read(RegisterAddress::FullSpeedTemperature, 1).await?;
-        let current_high = register_to_celsius(values[0]);
+        let [value] = self
+            .read_array(RegisterAddress::FullSpeedTemperature)
+            .await?;
+        let current_high = decode_temperature(RegisterAddress::FullSpeedTemperature, value)?;
 
         // Validate constraint
         if low >= current_high {
@@ -906,6 +4488,7 @@ impl Jpf4826Client {
     /// Returns error if:
     /// - Temperature is out of range (-20 to 120°C)
     /// - New high temperature is not greater than current low temperature
+    /// - The start temperature register holds a value outside 0x0014-0x00A0
     /// - Modbus communication fails
     pub async fn set_full_speed_temperature(&mut self, high: i16) -> Result<()> {
         // Validate range
@@ -917,8 +4500,8 @@ impl Jpf4826Client {
         }
 
         // Read current low threshold to validate constraint
-        let values = self.read(RegisterAddress::StartTemperature, 1).await?;
-        let current_low = register_to_celsius(values[0]);
+        let [value] = self.read_array(RegisterAddress::StartTemperature).await?;
+        let current_low = decode_temperature(RegisterAddress::StartTemperature, value)?;
 
         // Validate constraint
         if high <= current_low {
@@ -933,6 +4516,463 @@ impl Jpf4826Client {
         Ok(())
     }
 
+    /// Applies several settings with as few writes as possible.
+    ///
+    /// The whole configuration is validated before the bus is touched. The
+    /// registers 0x0002-0x000B are then read, settings that already hold the
+    /// requested value are skipped, and the remaining adjacent persistent
+    /// registers share one Write Multiple Registers frame. The speed is
+    /// written after them, so a switch to automatic mode uses the new
+    /// thresholds, and the address last, since the controller only answers
+    /// at the new address afterwards. A manual speed is always written, as
+    /// the register cannot tell it apart from the speed of automatic mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ControllerConfig, Jpf4826Client};
+    /// # use jpf4826_driver::types::WorkMode;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let config = ControllerConfig {
+    ///     work_mode: Some(WorkMode::Shutdown),
+    ///     fan_count: Some(2),
+    ///     ..ControllerConfig::default()
+    /// };
+    /// let report = client.apply_config(&config).await?;
+    /// println!("{} register(s) written", report.written.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The configuration is invalid (see [`ControllerConfig::validate`])
+    /// - The model does not support the PWM frequency
+    /// - Modbus communication fails; settings written before the failure
+    ///   stay applied
+    pub async fn apply_config(&mut self, config: &ControllerConfig) -> Result<ApplyReport> {
+        self.apply_config_until(config, std::future::pending())
+            .await
+    }
+
+    /// Applies settings like [`apply_config`](Self::apply_config), stopping
+    /// before the next write frame once `cancel` completes.
+    ///
+    /// A frame already on the bus is completed, so every setting is either
+    /// written or listed in [`ApplyReport::cancelled`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, Profile};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let report = client
+    ///     .apply_config_until(&Profile::quiet().config, tokio::time::sleep(Duration::from_secs(1)))
+    ///     .await?;
+    /// if report.is_cancelled() {
+    ///     println!("Not applied: {:?}", report.cancelled);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`apply_config`](Self::apply_config).
+    pub async fn apply_config_until(
+        &mut self,
+        config: &ControllerConfig,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<ApplyReport> {
+        let mut cancel = std::pin::pin!(cancel);
+        config.validate().map_err(|err| match err {
+            ConfigError::InvertedThresholds { low, high } => {
+                Jpf4826Error::invalid_thresholds(low, high)
+            }
+            ConfigError::Speed(speed) => Jpf4826Error::invalid_speed(speed),
+            ConfigError::ModbusAddress(addr) => Jpf4826Error::invalid_address(addr),
+            other => Jpf4826Error::invalid_parameter(other.to_string()),
+        })?;
+        if let Some(freq) = config.pwm_frequency {
+            self.pwm_register_value(freq)?;
+        }
+
+        let first = RegisterAddress::ModbusAddress;
+        let current: [u16; 10] = self.read_array(first).await?;
+
+        let mut report = ApplyReport::default();
+        let mut pending: Vec<(RegisterAddress, u16)> = Vec::new();
+        for (register, value) in config.register_values(self.model) {
+            if current[usize::from(register.addr() - first.addr())] == value {
+                report.unchanged.push(register);
+            } else {
+                pending.push((register, value));
+            }
+        }
+
+        // Write each run of adjacent registers in one frame
+        for run in pending.chunk_by(|a, b| b.0.addr() == a.0.addr() + 1) {
+            if report.is_cancelled() || is_completed(cancel.as_mut()).await {
+                log::info!("Applying configuration cancelled");
+                report
+                    .cancelled
+                    .extend(run.iter().map(|(register, _)| *register));
+                continue;
+            }
+            let register = run[0].0;
+            if let [(_, value)] = run {
+                self.write(register, *value).await?;
+            } else {
+                let values: Vec<u16> = run.iter().map(|(_, value)| *value).collect();
+                self.write_multiple(register, &values).await?;
+            }
+            report
+                .written
+                .extend(run.iter().map(|(register, _)| *register));
+            report.transactions += 1;
+        }
+
+        // Speed after the thresholds it may switch to, address last
+        let mut single = Vec::new();
+        match config.speed {
+            Some(SpeedSetting::Auto) if current[1] == 0xFFFF => {
+                report.unchanged.push(RegisterAddress::ManualSpeedControl);
+            }
+            Some(speed) => single.push((
+                RegisterAddress::ManualSpeedControl,
+                speed.to_register_value(),
+            )),
+            None => {}
+        }
+        match config.modbus_address {
+            Some(addr) if current[0] == u16::from(addr) => {
+                report.unchanged.push(RegisterAddress::ModbusAddress);
+            }
+            Some(addr) => single.push((RegisterAddress::ModbusAddress, u16::from(addr))),
+            None => {}
+        }
+        for (register, value) in single {
+            if report.is_cancelled() || is_completed(cancel.as_mut()).await {
+                log::info!("Applying configuration cancelled");
+                report.cancelled.push(register);
+                continue;
+            }
+            if register == RegisterAddress::ModbusAddress {
+                self.set_addr(value as u8).await?;
+            } else {
+                self.write(register, value).await?;
+            }
+            report.written.push(register);
+            report.transactions += 1;
+        }
+
+        Ok(report)
+    }
+
+    /// Applies several settings in order, undoing them if one fails.
+    ///
+    /// Every operation is validated before anything is sent. The current
+    /// values of registers 0x0002-0x000B are then read in one transaction
+    /// and the operations written one by one. If a write fails, the
+    /// registers changed so far, and the failed one if its write timed out
+    /// and may have taken effect, are written back in reverse order. The
+    /// returned [`SetReport`] holds the error and any registers that could
+    /// not be restored.
+    ///
+    /// A [`SetOperation::Address`] change must come last, so every other
+    /// write, and any rollback, reaches the controller at its old address.
+    /// A failed address change is not rolled back. As in
+    /// [`identify`](Self::identify), a manual speed is restored only if it
+    /// was set through this client; otherwise the controller returns to
+    /// temperature mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, SetOperation};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let report = client
+    ///     .set_many(&[
+    ///         SetOperation::FanCount(2),
+    ///         SetOperation::TemperatureThreshold { low: 35, high: 55 },
+    ///         SetOperation::Address(7),
+    ///     ])
+    ///     .await?;
+    /// if !report.is_success() {
+    ///     eprintln!("Rollback problems: {:?}", report.rollback_errors);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error, without writing anything, if an operation is invalid
+    /// (see the setter it mirrors), an address change is not last, or the
+    /// current values cannot be read. Failed writes are reported in the
+    /// [`SetReport`].
+    pub async fn set_many(&mut self, ops: &[SetOperation]) -> Result<SetReport> {
+        if let Some(position) = ops
+            .iter()
+            .position(|op| matches!(op, SetOperation::Address(_)))
+        {
+            if position + 1 != ops.len() {
+                return Err(Jpf4826Error::invalid_parameter(
+                    "Address change must be the last operation",
+                ));
+            }
+        }
+        let writes = ops
+            .iter()
+            .map(|op| self.set_operation_value(op))
+            .collect::<Result<Vec<_>>>()?;
+        if writes.is_empty() {
+            return Ok(SetReport::default());
+        }
+
+        let first = RegisterAddress::ModbusAddress;
+        let previous: [u16; 10] = self.read_array(first).await?;
+        let mode = self.mode;
+
+        let mut report = SetReport::default();
+        for &(register, value) in &writes {
+            let result = if register == RegisterAddress::ModbusAddress {
+                self.set_addr(value as u8).await
+            } else {
+                self.write(register, value).await
+            };
+            if let Err(e) = result {
+                log::warn!(
+                    "Setting {:?} failed, rolling back {} operations: {}",
+                    register,
+                    report.applied,
+                    e
+                );
+                report.error = Some(e);
+                break;
+            }
+            report.applied += 1;
+        }
+        let Some(error) = &report.error else {
+            return Ok(report);
+        };
+
+        // A timed-out write may have taken effect
+        let touched = report.applied + usize::from(error.is_timeout());
+        let mut pending: Vec<RegisterAddress> = Vec::new();
+        for &(register, _) in writes[..touched].iter().rev() {
+            if register != RegisterAddress::ModbusAddress && !pending.contains(&register) {
+                pending.push(register);
+            }
+        }
+        for register in pending {
+            let mut value = previous[usize::from(register.addr() - first.addr())];
+            // Speed values are read in both modes; only our own write proves manual
+            if register == RegisterAddress::ManualSpeedControl
+                && !(mode == OperatingMode::Manual && value <= 100)
+            {
+                value = AUTO_SPEED;
+            }
+            match self.write(register, value).await {
+                Ok(()) => report.restored.push(register),
+                Err(e) => {
+                    log::warn!("Failed to restore {:?} to {:#06X}: {}", register, value, e);
+                    report.rollback_errors.push((register, e));
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Validates `op` and returns the register value it writes.
+    fn set_operation_value(&self, op: &SetOperation) -> Result<(RegisterAddress, u16)> {
+        let value = match *op {
+            SetOperation::FanCount(count) => {
+                check_fan_count(count)?;
+                u16::from(count)
+            }
+            SetOperation::PwmFrequency(freq) => self.pwm_register_value(freq)?,
+            SetOperation::TemperatureThreshold { low, high } => {
+                combined_threshold_value(Temperature::celsius(low), Temperature::celsius(high))?
+            }
+            SetOperation::WorkMode(mode) => mode.to_register_value(),
+            SetOperation::FanSpeed(speed) => {
+                if speed > 100 {
+                    return Err(Jpf4826Error::invalid_speed(speed));
+                }
+                u16::from(speed)
+            }
+            SetOperation::AutoSpeed => AUTO_SPEED,
+            SetOperation::Address(addr) => {
+                if !(1..=254).contains(&addr) {
+                    return Err(Jpf4826Error::invalid_address(addr));
+                }
+                u16::from(addr)
+            }
+        };
+        Ok((op.register(), value))
+    }
+
+    /// Applies a named configuration profile.
+    ///
+    /// See [`apply_config`](Self::apply_config) for how the settings are
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, Profile};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// client.apply_profile(&Profile::quiet()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the profile's configuration is invalid or Modbus
+    /// communication fails.
+    pub async fn apply_profile(&mut self, profile: &Profile) -> Result<ApplyReport> {
+        log::info!("Applying profile '{}'", profile.name);
+        self.apply_config(&profile.config).await
+    }
+
+    /// Restores a saved configuration, leaving the Modbus address alone.
+    ///
+    /// Applies `config` like [`apply_config`](Self::apply_config) but
+    /// ignores [`ControllerConfig::modbus_address`], so a backup taken from
+    /// one controller can be restored to its replacement at whatever
+    /// address the client talks to. Use
+    /// [`restore_config_with_address`](Self::restore_config_with_address)
+    /// to restore the address too.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ControllerConfig, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let config = ControllerConfig::from_reader(std::fs::File::open("controller.json")?)?;
+    /// let report = client.restore_config(&config).await?;
+    /// println!("Restored: {:?}", report.written);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`apply_config`](Self::apply_config).
+    pub async fn restore_config(&mut self, config: &ControllerConfig) -> Result<ApplyReport> {
+        if let Some(addr) = config.modbus_address {
+            log::info!(
+                "Restoring configuration without its Modbus address {}",
+                addr
+            );
+        }
+        let config = ControllerConfig {
+            modbus_address: None,
+            ..*config
+        };
+        self.apply_config(&config).await
+    }
+
+    /// Restores a saved configuration including its Modbus address.
+    ///
+    /// The address is written last, after which the client talks to the
+    /// controller at the new address.
+    ///
+    /// # Errors
+    ///
+    /// Returns error as [`apply_config`](Self::apply_config).
+    pub async fn restore_config_with_address(
+        &mut self,
+        config: &ControllerConfig,
+    ) -> Result<ApplyReport> {
+        self.apply_config(config).await
+    }
+
+    /// Restores the factory settings and temperature mode.
+    ///
+    /// Writes [`ControllerConfig::factory`] like
+    /// [`apply_config`](Self::apply_config), then returns to automatic speed
+    /// control. The Modbus address is kept, since changing it could clash
+    /// with another controller on the bus. The report lists the speed
+    /// register with the settings, so it names every register written.
+    ///
+    /// If the returned future is dropped before the mode is selected, the
+    /// factory settings may be applied while the fans keep a manual speed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let report = client.restore_factory_defaults().await?;
+    /// println!("{} setting(s) reset", report.written.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails; settings written before
+    /// the failure stay applied.
+    pub async fn restore_factory_defaults(&mut self) -> Result<ApplyReport> {
+        let config = ControllerConfig {
+            speed: Some(SpeedSetting::Auto),
+            ..ControllerConfig::factory()
+        };
+        self.apply_config(&config).await
+    }
+
+    /// Reads the status and compares it against `expected`.
+    ///
+    /// Returns every setting that differs from `expected`; see
+    /// [`ControllerConfig::verify`]. An empty list means the controller is
+    /// configured as expected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{ControllerConfig, Jpf4826Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let expected = ControllerConfig {
+    ///     fan_count: Some(4),
+    ///     ..ControllerConfig::default()
+    /// };
+    /// for mismatch in client.verify_configuration(&expected).await? {
+    ///     println!("{}", mismatch);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the status cannot be read.
+    pub async fn verify_configuration(
+        &mut self,
+        expected: &ControllerConfig,
+    ) -> Result<Vec<ConfigMismatch>> {
+        let status = self.status().await?;
+        Ok(expected.verify(&status))
+    }
+
     /// Returns the current slave address (test-only helper).
     ///
     /// This method is only available when testing and allows verification
@@ -941,10 +4981,132 @@ impl Jpf4826Client {
     #[doc(hidden)]
     #[cfg(any(test, feature = "test-mock"))]
     pub fn slave_addr(&self) -> u8 {
-        match &self.backend {
-            #[cfg(any(test, feature = "test-mock"))]
-            ClientBackend::Mock(mock) => mock.slave_addr(),
-            ClientBackend::RealModbus(modbus) => modbus.slave_addr(),
+        self.current_slave_addr()
+    }
+}
+
+/// Returns whether `future` has completed, without waiting for it.
+///
+/// Must not be called again once it returned `true`.
+async fn is_completed<F: Future<Output = ()>>(mut future: std::pin::Pin<&mut F>) -> bool {
+    std::future::poll_fn(|cx| std::task::Poll::Ready(future.as_mut().poll(cx).is_ready())).await
+}
+
+/// Rejects reads of more registers than one Modbus request can carry.
+fn check_read_count(count: usize) -> Result<()> {
+    if count > MAX_READ_COUNT {
+        return Err(Jpf4826Error::invalid_parameter(format!(
+            "Cannot read {} registers at once (maximum {})",
+            count, MAX_READ_COUNT
+        )));
+    }
+    Ok(())
+}
+
+/// Rejects a fan count above 4.
+fn check_fan_count(count: u8) -> Result<()> {
+    if count > 4 {
+        return Err(Jpf4826Error::invalid_parameter(format!(
+            "Fan count {} out of range (0-4)",
+            count
+        )));
+    }
+    Ok(())
+}
+
+/// Validates the thresholds in Celsius and encodes them for register
+/// 0x0004, reporting errors in the caller's unit.
+fn combined_threshold_value(low: Temperature, high: Temperature) -> Result<u16> {
+    let (low_celsius, high_celsius) = (low.to_celsius(), high.to_celsius());
+    // Rounding may map distinct Fahrenheit values to one Celsius degree
+    if high_celsius <= low_celsius {
+        return Err(Jpf4826Error::invalid_thresholds_in(low, high));
+    }
+    check_threshold_range("Low", low)?;
+    check_threshold_range("High", high)?;
+    Ok(encode_combined_temperature(low_celsius, high_celsius))
+}
+
+/// Rejects a threshold outside -20 to 120°C, quoting the range in the
+/// threshold's unit.
+fn check_threshold_range(name: &str, threshold: Temperature) -> Result<()> {
+    if (-20..=120).contains(&threshold.to_celsius()) {
+        return Ok(());
+    }
+    let min = Temperature::celsius(-20).to_unit(threshold.unit);
+    let max = Temperature::celsius(120).to_unit(threshold.unit);
+    Err(Jpf4826Error::invalid_parameter(format!(
+        "{} temperature {} out of range ({} to {})",
+        name, threshold, min.value, max
+    )))
+}
+
+/// Rejects a read of `count` registers from `addr` covering a write-only
+/// register.
+fn check_readable(addr: u16, count: usize) -> Result<()> {
+    check_access(addr, count, RegisterAccess::is_readable)
+}
+
+/// Rejects a write of `count` registers from `addr` covering a read-only
+/// register.
+fn check_writable(addr: u16, count: usize) -> Result<()> {
+    check_access(addr, count, RegisterAccess::is_writable)
+}
+
+/// Rejects a request covering a known register whose access fails
+/// `allowed`. Registers outside the documented map are not checked.
+fn check_access(addr: u16, count: usize, allowed: fn(RegisterAccess) -> bool) -> Result<()> {
+    let denied = register_range(addr, count)
+        .filter_map(RegisterAddress::from_addr)
+        .find(|register| !allowed(register.access()));
+    match denied {
+        Some(register) => {
+            log::warn!(
+                "Refusing request at {:#06X}: register is {}",
+                register.addr(),
+                register.access()
+            );
+            Err(Jpf4826Error::new_register_access(
+                register.addr(),
+                register.access(),
+            ))
         }
+        None => Ok(()),
     }
 }
+
+/// Decodes the address register, rejecting values outside 1-254.
+fn decode_modbus_address(value: u16) -> Result<u8> {
+    decoded_or_invalid(
+        RegisterAddress::ModbusAddress,
+        value,
+        register_to_modbus_address(value),
+    )
+}
+
+/// Decodes a temperature register, rejecting values beyond the pegged
+/// limits of a faulty sensor.
+fn decode_temperature(register: RegisterAddress, value: u16) -> Result<i16> {
+    decoded_or_invalid(register, value, register_to_celsius_in_range(value))
+}
+
+/// Decodes the fan quantity register, rejecting values above 4.
+fn decode_fan_count(value: u16) -> Result<u8> {
+    let count = u8::try_from(value).ok().filter(|count| *count <= 4);
+    decoded_or_invalid(RegisterAddress::FanQuantity, value, count)
+}
+
+/// Rejects a register value that `decoded` found no meaning for.
+///
+/// Unknown values are reported instead of being replaced by a default, so a
+/// corrupted register or unexpected firmware is not mistaken for a setting.
+fn decoded_or_invalid<T>(register: RegisterAddress, value: u16, decoded: Option<T>) -> Result<T> {
+    decoded.ok_or_else(|| {
+        log::warn!(
+            "Register {:#06X} holds invalid value {:#06X}",
+            register.addr(),
+            value
+        );
+        Jpf4826Error::invalid_response(register.addr(), value)
+    })
+}
@@ -14,6 +14,12 @@ use crate::types::FanStatus;
 /// Temperature range: -20°C to 120°C maps to register values 20 to 160.
 const TEMPERATURE_OFFSET: i16 = 40;
 
+/// Lowest current temperature register value the sensor reports (-20°C).
+pub const TEMPERATURE_REGISTER_MIN: u16 = 20;
+
+/// Highest current temperature register value the sensor reports (120°C).
+pub const TEMPERATURE_REGISTER_MAX: u16 = 160;
+
 /// Converts Celsius temperature to Modbus register value.
 ///
 /// # Examples
@@ -38,11 +44,87 @@ pub fn celsius_to_register(celsius: i16) -> u16 {
 /// assert_eq!(register_to_celsius(40), 0);
 /// assert_eq!(register_to_celsius(160), 120);
 /// ```
+///
+/// Values outside the documented range are converted as they are and
+/// saturate instead of overflowing; use [`register_to_celsius_in_range`]
+/// to reject them.
+///
+/// ```
+/// # use jpf4826_driver::conversions::register_to_celsius;
+/// assert_eq!(register_to_celsius(0xFFFF), -41);
+/// assert_eq!(register_to_celsius(0x8000), i16::MIN);
+/// ```
 pub fn register_to_celsius(register: u16) -> i16 {
-    register as i16 - TEMPERATURE_OFFSET
+    (register as i16).saturating_sub(TEMPERATURE_OFFSET)
+}
+
+/// Decodes the current temperature register, rejecting sensor faults.
+///
+/// A disconnected or shorted NTC probe pegs the register at one end of the
+/// documented range, so values at or outside
+/// [`TEMPERATURE_REGISTER_MIN`]..=[`TEMPERATURE_REGISTER_MAX`] are reported
+/// as `None` instead of as a plausible temperature. Use
+/// [`register_to_celsius_in_range`] to tell a pegged reading from a value
+/// no sensor produces.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::checked_register_to_celsius;
+/// assert_eq!(checked_register_to_celsius(71), Some(31));
+/// assert_eq!(checked_register_to_celsius(21), Some(-19));
+/// assert_eq!(checked_register_to_celsius(20), None); // Pegged at -20°C
+/// assert_eq!(checked_register_to_celsius(160), None); // Pegged at 120°C
+/// assert_eq!(checked_register_to_celsius(0xFFFF), None);
+/// ```
+pub fn checked_register_to_celsius(register: u16) -> Option<i16> {
+    (register > TEMPERATURE_REGISTER_MIN && register < TEMPERATURE_REGISTER_MAX)
+        .then(|| register_to_celsius(register))
+}
+
+/// Decodes a temperature register, rejecting values outside the documented
+/// range [`TEMPERATURE_REGISTER_MIN`]..=[`TEMPERATURE_REGISTER_MAX`].
+///
+/// Unlike [`checked_register_to_celsius`], the pegged limits of a faulty
+/// sensor decode as -20°C and 120°C. A value beyond them, such as 0xFFFF,
+/// is no temperature at all but a corrupted read.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::register_to_celsius_in_range;
+/// assert_eq!(register_to_celsius_in_range(71), Some(31));
+/// assert_eq!(register_to_celsius_in_range(0x0014), Some(-20));
+/// assert_eq!(register_to_celsius_in_range(0x00A0), Some(120));
+/// assert_eq!(register_to_celsius_in_range(0x0000), None);
+/// assert_eq!(register_to_celsius_in_range(0x00A1), None);
+/// assert_eq!(register_to_celsius_in_range(0xFFFF), None);
+/// ```
+pub fn register_to_celsius_in_range(register: u16) -> Option<i16> {
+    (TEMPERATURE_REGISTER_MIN..=TEMPERATURE_REGISTER_MAX)
+        .contains(&register)
+        .then(|| register_to_celsius(register))
 }
 
-/// Converts Celsius to Fahrenheit.
+/// Decodes the Modbus address register, rejecting values outside 1-254.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::register_to_modbus_address;
+/// assert_eq!(register_to_modbus_address(0x0001), Some(1));
+/// assert_eq!(register_to_modbus_address(0x00FE), Some(254));
+/// assert_eq!(register_to_modbus_address(0x0000), None);
+/// assert_eq!(register_to_modbus_address(0x00FF), None);
+/// assert_eq!(register_to_modbus_address(0x0101), None); // Not truncated to 1
+/// ```
+pub fn register_to_modbus_address(register: u16) -> Option<u8> {
+    u8::try_from(register)
+        .ok()
+        .filter(|addr| (1..=254).contains(addr))
+}
+
+/// Converts Celsius to Fahrenheit, rounded to the nearest degree.
 ///
 /// # Examples
 ///
@@ -50,9 +132,92 @@ pub fn register_to_celsius(register: u16) -> i16 {
 /// # use jpf4826_driver::conversions::celsius_to_fahrenheit;
 /// assert_eq!(celsius_to_fahrenheit(0), 32);
 /// assert_eq!(celsius_to_fahrenheit(100), 212);
+/// assert_eq!(celsius_to_fahrenheit(-20), -4);
+/// assert_eq!(celsius_to_fahrenheit(26), 79); // 78.8°F
 /// ```
 pub fn celsius_to_fahrenheit(celsius: i16) -> i16 {
-    (celsius * 9 / 5) + 32
+    let scaled = i32::from(celsius) * 9;
+    let rounding = if scaled < 0 { -2 } else { 2 };
+    ((scaled + rounding) / 5 + 32) as i16
+}
+
+/// Converts Fahrenheit to Celsius, rounded to the nearest degree.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::fahrenheit_to_celsius;
+/// assert_eq!(fahrenheit_to_celsius(32), 0);
+/// assert_eq!(fahrenheit_to_celsius(-4), -20);
+/// assert_eq!(fahrenheit_to_celsius(87), 31); // 30.6°C
+/// ```
+pub fn fahrenheit_to_celsius(fahrenheit: i16) -> i16 {
+    let scaled = (i32::from(fahrenheit) - 32) * 5;
+    let rounding = if scaled < 0 { -4 } else { 4 };
+    ((scaled + rounding) / 9) as i16
+}
+
+/// Offset between Celsius and Kelvin in whole degrees, as used by the `i16`
+/// conversions.
+///
+/// 0°C is 273.15 K; whole-degree temperatures drop the 0.15 K, so
+/// [`celsius_to_kelvin`] and [`kelvin_to_celsius`] are exact inverses.
+pub const KELVIN_OFFSET: i16 = 273;
+
+/// Exact offset between Celsius and Kelvin, as used by the `f64`
+/// conversions.
+pub const KELVIN_OFFSET_EXACT: f64 = 273.15;
+
+/// Converts whole degrees Celsius to whole Kelvin, using [`KELVIN_OFFSET`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::celsius_to_kelvin;
+/// assert_eq!(celsius_to_kelvin(0), 273);
+/// assert_eq!(celsius_to_kelvin(-20), 253);
+/// assert_eq!(celsius_to_kelvin(120), 393);
+/// ```
+pub fn celsius_to_kelvin(celsius: i16) -> i16 {
+    celsius.saturating_add(KELVIN_OFFSET)
+}
+
+/// Converts whole Kelvin to whole degrees Celsius, using [`KELVIN_OFFSET`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::kelvin_to_celsius;
+/// assert_eq!(kelvin_to_celsius(273), 0);
+/// assert_eq!(kelvin_to_celsius(393), 120);
+/// ```
+pub fn kelvin_to_celsius(kelvin: i16) -> i16 {
+    kelvin.saturating_sub(KELVIN_OFFSET)
+}
+
+/// Converts Celsius to Kelvin exactly, using [`KELVIN_OFFSET_EXACT`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::celsius_to_kelvin_f64;
+/// assert_eq!(celsius_to_kelvin_f64(0.0), 273.15);
+/// assert!((celsius_to_kelvin_f64(-20.0) - 253.15).abs() < 1e-9);
+/// ```
+pub fn celsius_to_kelvin_f64(celsius: f64) -> f64 {
+    celsius + KELVIN_OFFSET_EXACT
+}
+
+/// Converts Kelvin to Celsius exactly, using [`KELVIN_OFFSET_EXACT`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::conversions::kelvin_to_celsius_f64;
+/// assert_eq!(kelvin_to_celsius_f64(273.15), 0.0);
+/// ```
+pub fn kelvin_to_celsius_f64(kelvin: f64) -> f64 {
+    kelvin - KELVIN_OFFSET_EXACT
 }
 
 /// Parses fan running status from bitmap register.
@@ -132,25 +297,26 @@ pub fn parse_fan_fault_bitmap(bitmap: u16) -> [FanStatus; 4] {
 /// - High byte: Start temperature (low threshold)
 /// - Low byte: Full speed temperature (high threshold)
 ///
-/// Both values use +40 offset.
+/// Both values use +40 offset. Returns `None` if either byte lies outside
+/// [`TEMPERATURE_REGISTER_MIN`]..=[`TEMPERATURE_REGISTER_MAX`].
 ///
 /// # Examples
 ///
 /// ```
 /// # use jpf4826_driver::conversions::parse_combined_temperature;
 /// // 0x465A: high byte 0x46 (70 = 30°C), low byte 0x5A (90 = 50°C)
-/// let (low, high) = parse_combined_temperature(0x465A);
-/// assert_eq!(low, 30);
-/// assert_eq!(high, 50);
+/// assert_eq!(parse_combined_temperature(0x465A), Some((30, 50)));
+/// assert_eq!(parse_combined_temperature(0x46FF), None);
+/// assert_eq!(parse_combined_temperature(0x005A), None);
 /// ```
-pub fn parse_combined_temperature(combined: u16) -> (i16, i16) {
+pub fn parse_combined_temperature(combined: u16) -> Option<(i16, i16)> {
     let high_byte = (combined >> 8) & 0xFF; // Start temp
     let low_byte = combined & 0xFF; // Full speed temp
 
-    let start_temp = register_to_celsius(high_byte);
-    let full_temp = register_to_celsius(low_byte);
+    let start_temp = register_to_celsius_in_range(high_byte)?;
+    let full_temp = register_to_celsius_in_range(low_byte)?;
 
-    (start_temp, full_temp)
+    Some((start_temp, full_temp))
 }
 
 /// Encodes start and full temperatures into combined register.
@@ -0,0 +1,138 @@
+//! The controller's temperature-to-duty-cycle curve.
+//!
+//! In temperature mode the controller drives the fans from the current
+//! temperature and the start (L) and full speed (H) thresholds:
+//!
+//! | Temperature       | Duty cycle                                 |
+//! | ----------------- | ------------------------------------------ |
+//! | below L-3°C       | 0% ([`WorkMode::Shutdown`]) or 20% ([`WorkMode::MinimumSpeed`]) |
+//! | L-3°C up to L     | 20%                                        |
+//! | L up to H         | linear from 20% at L to 100% at H          |
+//! | H and above       | 100%                                       |
+//!
+//! The 3°C below L are hysteresis: in shutdown mode, fans that run keep
+//! running at the minimum until the temperature falls below L-3°C. Fans
+//! that were stopped start again only once L is reached, so while warming
+//! up from a stop a controller may still output 0% in that band.
+//! [`expected_duty_cycle`] has no history and assumes the fans are running.
+
+// Rust guideline compliant 2026-10-16
+
+use crate::types::WorkMode;
+
+/// Duty cycle in percent at the start temperature, and below it in
+/// [`WorkMode::MinimumSpeed`].
+pub const MINIMUM_DUTY_CYCLE: u8 = 20;
+
+/// Degrees Celsius below the start temperature at which fans stop in
+/// [`WorkMode::Shutdown`].
+pub const SHUTDOWN_HYSTERESIS: i16 = 3;
+
+/// Returns the duty cycle in percent (0-100) the controller outputs in
+/// temperature mode.
+///
+/// All temperatures are in degrees Celsius; the ramp between `low` and
+/// `high` is rounded to the nearest percent. Thresholds with `high` not
+/// above `low`, which the controller rejects, give 100% from `high` up.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::curve::expected_duty_cycle;
+/// # use jpf4826_driver::WorkMode;
+/// assert_eq!(expected_duty_cycle(35, 30, 50, WorkMode::Shutdown), 40);
+/// assert_eq!(expected_duty_cycle(25, 30, 50, WorkMode::Shutdown), 0);
+/// assert_eq!(expected_duty_cycle(25, 30, 50, WorkMode::MinimumSpeed), 20);
+/// ```
+pub fn expected_duty_cycle(current: i16, low: i16, high: i16, work_mode: WorkMode) -> u8 {
+    if current >= high {
+        return 100;
+    }
+    if current < low.saturating_sub(SHUTDOWN_HYSTERESIS) {
+        return match work_mode {
+            WorkMode::Shutdown => 0,
+            WorkMode::MinimumSpeed => MINIMUM_DUTY_CYCLE,
+        };
+    }
+    if current < low {
+        return MINIMUM_DUTY_CYCLE;
+    }
+
+    // low <= current < high, so the span is positive
+    let span = i32::from(high) - i32::from(low);
+    let above = i32::from(current) - i32::from(low);
+    let ramp = i32::from(100 - MINIMUM_DUTY_CYCLE);
+    let duty = i32::from(MINIMUM_DUTY_CYCLE) + (above * ramp + span / 2) / span;
+    duty as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOW: i16 = 30;
+    const HIGH: i16 = 50;
+
+    fn both_modes(current: i16) -> (u8, u8) {
+        (
+            expected_duty_cycle(current, LOW, HIGH, WorkMode::Shutdown),
+            expected_duty_cycle(current, LOW, HIGH, WorkMode::MinimumSpeed),
+        )
+    }
+
+    #[test]
+    fn test_below_hysteresis_depends_on_work_mode() {
+        for current in [-20, 0, LOW - SHUTDOWN_HYSTERESIS - 1] {
+            assert_eq!(both_modes(current), (0, MINIMUM_DUTY_CYCLE), "{}", current);
+        }
+    }
+
+    #[test]
+    fn test_hysteresis_band_runs_at_minimum() {
+        for current in LOW - SHUTDOWN_HYSTERESIS..LOW {
+            assert_eq!(
+                both_modes(current),
+                (MINIMUM_DUTY_CYCLE, MINIMUM_DUTY_CYCLE),
+                "{}",
+                current
+            );
+        }
+    }
+
+    #[test]
+    fn test_ramp_is_linear_between_thresholds() {
+        assert_eq!(both_modes(LOW), (20, 20));
+        assert_eq!(both_modes(35), (40, 40));
+        assert_eq!(both_modes(40), (60, 60));
+        assert_eq!(both_modes(HIGH - 1), (96, 96));
+    }
+
+    #[test]
+    fn test_ramp_rounds_to_nearest_percent() {
+        // 80% over 3°C: 26.7% per degree
+        assert_eq!(expected_duty_cycle(31, 30, 33, WorkMode::Shutdown), 47);
+        assert_eq!(expected_duty_cycle(32, 30, 33, WorkMode::Shutdown), 73);
+        // 80% over 7°C: 11.4% per degree
+        assert_eq!(expected_duty_cycle(-19, -20, -13, WorkMode::Shutdown), 31);
+    }
+
+    #[test]
+    fn test_full_speed_at_and_above_high() {
+        for current in [HIGH, HIGH + 1, 120] {
+            assert_eq!(both_modes(current), (100, 100), "{}", current);
+        }
+    }
+
+    #[test]
+    fn test_adjacent_thresholds() {
+        assert_eq!(expected_duty_cycle(30, 30, 31, WorkMode::Shutdown), 20);
+        assert_eq!(expected_duty_cycle(31, 30, 31, WorkMode::Shutdown), 100);
+    }
+
+    #[test]
+    fn test_inverted_thresholds_do_not_divide_by_zero() {
+        assert_eq!(expected_duty_cycle(40, 40, 40, WorkMode::Shutdown), 100);
+        assert_eq!(expected_duty_cycle(48, 50, 49, WorkMode::Shutdown), 20);
+        assert_eq!(expected_duty_cycle(20, 50, 40, WorkMode::Shutdown), 0);
+    }
+}
@@ -0,0 +1,344 @@
+//! Differences between status snapshots.
+//!
+//! [`ControllerStatus::diff`] compares two snapshots field by field, so
+//! monitors can report what changed between polls instead of repeating the
+//! full status. A [`StatusDiff`] displays as one line per change, e.g.
+//! `fan 3: Normal → Fault`, and serializes with only the changed fields.
+
+// Rust guideline compliant 2026-10-16
+
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::types::{
+    ControllerStatus, FanInfo, FanStatus, OperatingMode, PwmFrequency, Temperature, WorkMode,
+};
+
+/// A value that changed between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Change<T> {
+    /// Value in the older snapshot.
+    pub from: T,
+    /// Value in the newer snapshot.
+    pub to: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    /// Returns the change from `from` to `to`, or `None` if they are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::diff::Change;
+    /// assert_eq!(Change::between(1, 2), Some(Change { from: 1, to: 2 }));
+    /// assert_eq!(Change::between(1, 1), None);
+    /// ```
+    pub fn between(from: T, to: T) -> Option<Self> {
+        (from != to).then_some(Self { from, to })
+    }
+}
+
+/// Changes of one fan between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FanChange {
+    /// Fan index (1-4).
+    pub index: u8,
+    /// Changed operational status.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub status: Option<Change<FanStatus>>,
+    /// Changed rotation speed in RPM.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub rpm: Option<Change<u16>>,
+}
+
+impl FanChange {
+    /// Returns true if the fan went from normal to fault.
+    pub fn fault_raised(&self) -> bool {
+        self.status
+            .is_some_and(|change| change.to == FanStatus::Fault)
+    }
+
+    /// Returns true if the fan went from fault to normal.
+    pub fn fault_cleared(&self) -> bool {
+        self.status
+            .is_some_and(|change| change.to == FanStatus::Normal)
+    }
+
+    /// Returns how much faster the fan turns, in RPM, if its speed changed.
+    pub fn rpm_delta(&self) -> Option<i32> {
+        self.rpm
+            .map(|change| i32::from(change.to) - i32::from(change.from))
+    }
+}
+
+impl fmt::Display for FanChange {
+    /// Formats one line per changed field, e.g. `fan 3: Normal → Fault`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if let Some(Change { from, to }) = self.status {
+            write!(f, "fan {}: {:?} → {:?}", self.index, from, to)?;
+            separator = "\n";
+        }
+        if let (Some(Change { from, to }), Some(delta)) = (self.rpm, self.rpm_delta()) {
+            write!(
+                f,
+                "{}fan {}: {} → {} rpm ({:+})",
+                separator, self.index, from, to, delta
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Fields that differ between two status snapshots.
+///
+/// Unchanged fields are `None` and are left out of the serialized form, so
+/// an empty diff serializes as `{}`.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::types::ControllerStatus;
+/// let mut block = [
+///     71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 5, 70, 90, 0x000F,
+/// ];
+/// let before = ControllerStatus::from_registers(&block);
+/// block[0] = 73; // 33°C
+/// block[8] = 1100; // Fan 2 slows down
+/// let after = ControllerStatus::from_registers(&block);
+///
+/// let diff = before.diff(&after);
+/// assert_eq!(diff.temperature_current.unwrap().to.value, 33);
+/// assert_eq!(diff.fans[0].index, 2);
+/// assert!(before.diff(&before).is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StatusDiff {
+    /// Changed speed control mode.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub mode: Option<Change<OperatingMode>>,
+    /// Changed manual speed percentage.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub manual_speed: Option<Change<Option<u8>>>,
+    /// Changed work mode.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub work_mode: Option<Change<WorkMode>>,
+    /// Changed Modbus address.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub modbus_address: Option<Change<u8>>,
+    /// Changed PWM frequency.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub pwm_frequency: Option<Change<PwmFrequency>>,
+    /// Changed number of configured fans.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub fan_count: Option<Change<u8>>,
+    /// Changed current temperature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub temperature_current: Option<Change<Temperature>>,
+    /// Changed temperature sensor fault state.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub sensor_fault: Option<Change<bool>>,
+    /// Changed start temperature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub temperature_low_threshold: Option<Change<Temperature>>,
+    /// Changed full speed temperature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub temperature_high_threshold: Option<Change<Temperature>>,
+    /// Fans whose status or speed changed, by index.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub fans: Vec<FanChange>,
+}
+
+impl StatusDiff {
+    /// Returns true if no field changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the fans that went from normal to fault.
+    pub fn faults_raised(&self) -> impl Iterator<Item = u8> + '_ {
+        self.fans
+            .iter()
+            .filter(|fan| fan.fault_raised())
+            .map(|fan| fan.index)
+    }
+
+    /// Returns how much the current temperature rose, in the newer
+    /// snapshot's unit, if it changed.
+    pub fn temperature_delta(&self) -> Option<i16> {
+        self.temperature_current
+            .map(|Change { from, to }| to.value - from.to_unit(to.unit).value)
+    }
+}
+
+impl fmt::Display for StatusDiff {
+    /// Formats one line per changed field, in the order of the struct
+    /// fields, without a trailing newline. An empty diff formats as an
+    /// empty string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut line = |f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>| {
+            let result = write!(f, "{}{}", separator, args);
+            separator = "\n";
+            result
+        };
+
+        if let Some(Change { from, to }) = self.mode {
+            line(f, format_args!("mode: {:?} → {:?}", from, to))?;
+        }
+        if let Some(Change { from, to }) = self.manual_speed {
+            line(
+                f,
+                format_args!("manual speed: {} → {}", Speed(from), Speed(to)),
+            )?;
+        }
+        if let Some(Change { from, to }) = self.work_mode {
+            line(f, format_args!("work mode: {:?} → {:?}", from, to))?;
+        }
+        if let Some(Change { from, to }) = self.modbus_address {
+            line(f, format_args!("modbus address: {} → {}", from, to))?;
+        }
+        if let Some(Change { from, to }) = self.pwm_frequency {
+            line(
+                f,
+                format_args!("pwm frequency: {} Hz → {} Hz", from.to_hz(), to.to_hz()),
+            )?;
+        }
+        if let Some(Change { from, to }) = self.fan_count {
+            line(f, format_args!("fan count: {} → {}", from, to))?;
+        }
+        if let (Some(Change { from, to }), Some(delta)) =
+            (self.temperature_current, self.temperature_delta())
+        {
+            line(
+                f,
+                format_args!(
+                    "temperature: {} → {} ({:+}{})",
+                    from,
+                    to,
+                    delta,
+                    to.unit.symbol()
+                ),
+            )?;
+        }
+        if let Some(Change { from, to }) = self.sensor_fault {
+            line(f, format_args!("sensor: {} → {}", Sensor(from), Sensor(to)))?;
+        }
+        if let Some(Change { from, to }) = self.temperature_low_threshold {
+            line(f, format_args!("low threshold: {} → {}", from, to))?;
+        }
+        if let Some(Change { from, to }) = self.temperature_high_threshold {
+            line(f, format_args!("high threshold: {} → {}", from, to))?;
+        }
+        for fan in &self.fans {
+            line(f, format_args!("{}", fan))?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a manual speed, `-` in temperature mode.
+struct Speed(Option<u8>);
+
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(speed) => write!(f, "{}%", speed),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+/// Displays a sensor fault state.
+struct Sensor(bool);
+
+impl fmt::Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0 { "Fault" } else { "Normal" })
+    }
+}
+
+impl ControllerStatus {
+    /// Compares this snapshot with a `newer` one.
+    ///
+    /// Fans are matched by index; a fan present in only one snapshot is
+    /// ignored. Every RPM change is reported; see
+    /// [`diff_with_rpm_threshold`](Self::diff_with_rpm_threshold) to
+    /// ignore jitter.
+    pub fn diff(&self, newer: &ControllerStatus) -> StatusDiff {
+        self.diff_with_rpm_threshold(newer, 0)
+    }
+
+    /// Compares this snapshot with a `newer` one, ignoring fan speed
+    /// changes of at most `rpm_threshold`.
+    ///
+    /// Fan speeds wander by a few RPM between polls even at a fixed duty;
+    /// a threshold keeps that noise out of the diff. Status changes are
+    /// always reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::ControllerStatus;
+    /// let mut block = [
+    ///     71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 5, 70, 90, 0x000F,
+    /// ];
+    /// let before = ControllerStatus::from_registers(&block);
+    /// block[7] = 1420; // Fan 1 jitters
+    /// block[8] = 1100; // Fan 2 slows down
+    /// let after = ControllerStatus::from_registers(&block);
+    ///
+    /// let diff = before.diff_with_rpm_threshold(&after, 50);
+    /// assert_eq!(diff.to_string(), "fan 2: 1400 → 1100 rpm (-300)");
+    /// ```
+    pub fn diff_with_rpm_threshold(
+        &self,
+        newer: &ControllerStatus,
+        rpm_threshold: u16,
+    ) -> StatusDiff {
+        StatusDiff {
+            mode: Change::between(self.mode, newer.mode),
+            manual_speed: Change::between(self.manual_speed, newer.manual_speed),
+            work_mode: Change::between(self.work_mode, newer.work_mode),
+            modbus_address: Change::between(self.modbus_address, newer.modbus_address),
+            pwm_frequency: Change::between(self.pwm_frequency, newer.pwm_frequency),
+            fan_count: Change::between(self.fan_count, newer.fan_count),
+            temperature_current: Change::between(
+                self.temperature_current,
+                newer.temperature_current,
+            ),
+            sensor_fault: Change::between(self.sensor_fault, newer.sensor_fault),
+            temperature_low_threshold: Change::between(
+                self.temperature_low_threshold,
+                newer.temperature_low_threshold,
+            ),
+            temperature_high_threshold: Change::between(
+                self.temperature_high_threshold,
+                newer.temperature_high_threshold,
+            ),
+            fans: newer
+                .fans
+                .iter()
+                .filter_map(|fan| {
+                    let old = self.fans.iter().find(|old| old.index == fan.index)?;
+                    fan_change(old, fan, rpm_threshold)
+                })
+                .collect(),
+        }
+    }
+}
+
+fn fan_change(old: &FanInfo, new: &FanInfo, rpm_threshold: u16) -> Option<FanChange> {
+    let change = FanChange {
+        index: new.index.get(),
+        status: Change::between(old.status, new.status),
+        rpm: Change::between(old.rpm, new.rpm)
+            .filter(|_| old.rpm.abs_diff(new.rpm) > rpm_threshold),
+    };
+    (change.status.is_some() || change.rpm.is_some()).then_some(change)
+}
@@ -0,0 +1,124 @@
+//! Raw register snapshots for bug reports.
+//!
+//! [`Jpf4826Client::dump_registers`](crate::Jpf4826Client::dump_registers)
+//! reads the whole register map without decoding it, so a report shows
+//! exactly what the controller holds, including values the typed API
+//! would reject. Addresses the controller refuses are recorded with their
+//! error instead of failing the dump.
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::registers::RegisterAddress;
+
+/// Outcome of reading one register for a [`RegisterDump`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DumpValue {
+    /// Raw value the controller returned.
+    Value(u16),
+    /// Message of the error the read failed with.
+    Error(String),
+}
+
+/// Raw values of the controller's registers at one point in time.
+///
+/// Displays as one line per register, e.g. for pasting into an issue:
+///
+/// ```text
+/// Register dump at 1760601600 (seconds since the Unix epoch)
+/// 0x0000 CurrentTemperature    0x0047 (71)
+/// 0x0001 FanStatus             0x000F (15)
+/// ...
+/// 0x0021                       error: Modbus exception at 0x0021: IllegalDataAddress
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::{registers::RegisterAddress, Jpf4826Client};
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let dump = client.dump_registers().await?;
+/// println!("{}", dump);
+/// assert!(dump.get(RegisterAddress::FanQuantity).is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegisterDump {
+    /// When the dump was started.
+    pub taken_at: SystemTime,
+    /// Read outcome by register address, known or not.
+    pub registers: BTreeMap<u16, DumpValue>,
+}
+
+impl RegisterDump {
+    pub(crate) fn new(taken_at: SystemTime) -> Self {
+        Self {
+            taken_at,
+            registers: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the raw value of `register`, or `None` if it was not read
+    /// or the read failed.
+    pub fn get(&self, register: RegisterAddress) -> Option<u16> {
+        self.raw(register.addr())
+    }
+
+    /// Returns the raw value at `addr`, or `None` if it was not read or
+    /// the read failed.
+    pub fn raw(&self, addr: u16) -> Option<u16> {
+        match self.registers.get(&addr)? {
+            DumpValue::Value(value) => Some(*value),
+            DumpValue::Error(_) => None,
+        }
+    }
+
+    /// Returns the addresses whose read failed, with the error message.
+    pub fn errors(&self) -> impl Iterator<Item = (u16, &str)> {
+        self.registers
+            .iter()
+            .filter_map(|(addr, value)| match value {
+                DumpValue::Value(_) => None,
+                DumpValue::Error(message) => Some((*addr, message.as_str())),
+            })
+    }
+}
+
+impl fmt::Display for RegisterDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.taken_at.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => writeln!(
+                f,
+                "Register dump at {} (seconds since the Unix epoch)",
+                since_epoch.as_secs()
+            )?,
+            Err(_) => writeln!(f, "Register dump")?,
+        }
+        for (addr, value) in &self.registers {
+            let name = RegisterAddress::from_addr(*addr)
+                .map(|register| format!("{:?}", register))
+                .unwrap_or_default();
+            match value {
+                DumpValue::Value(raw) => {
+                    writeln!(f, "0x{:04X} {:<21} 0x{:04X} ({})", addr, name, raw, raw)?
+                }
+                DumpValue::Error(message) => {
+                    writeln!(f, "0x{:04X} {:<21} error: {}", addr, name, message)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
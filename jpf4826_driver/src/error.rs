@@ -9,6 +9,10 @@ use std::backtrace::Backtrace;
 use std::fmt;
 use std::time::Duration;
 
+use crate::model::DeviceModel;
+use crate::registers::RegisterAccess;
+use crate::types::{OperatingMode, PwmFrequency, Temperature};
+
 /// Result type alias for JPF4826 driver operations.
 pub type Result<T> = std::result::Result<T, Jpf4826Error>;
 
@@ -23,24 +27,74 @@ pub struct Jpf4826Error {
 }
 
 /// Internal error classification.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum ErrorKind {
     /// Modbus protocol communication error.
     Modbus(String),
+    /// Controller answered with a Modbus exception at `register`.
+    Exception { register: u16, exception: String },
     /// Serial port communication error.
     Serial(String),
     /// Invalid parameter provided to API.
     InvalidParameter(String),
-    /// Temperature threshold constraint violation.
-    InvalidThresholds { low: i16, high: i16 },
+    /// Temperature threshold constraint violation, in the caller's unit.
+    InvalidThresholds { low: Temperature, high: Temperature },
     /// Fan index out of valid range (1-4).
     InvalidFanIndex(u8),
     /// Modbus address out of valid range (1-254).
     InvalidAddress(u8),
     /// Manual speed percentage out of valid range (0-100).
     InvalidSpeed(u8),
-    /// Operation timed out.
-    Timeout(Duration),
+    /// Read response register count differs from the request.
+    ResponseLength {
+        register: u16,
+        expected: usize,
+        actual: usize,
+    },
+    /// Controller returned a register value outside its valid range.
+    InvalidResponse { register: u16, value: u16 },
+    /// Temperature register reports a disconnected or shorted sensor.
+    SensorFault { register: u16, value: u16 },
+    /// Operation timed out, after the given number of attempts.
+    Timeout { duration: Duration, attempts: u8 },
+    /// Operation needs a register or value the device model lacks.
+    Unsupported {
+        model: DeviceModel,
+        operation: String,
+    },
+    /// Controller answered a request with the Illegal Function exception.
+    FunctionRejected { function: u8, register: u16 },
+    /// Write refused because the register's write budget is used up.
+    WriteBudgetExceeded {
+        register: u16,
+        retry_after: Duration,
+    },
+    /// PWM frequency read back after a write differs from the one written.
+    PwmFrequencyMismatch {
+        expected: PwmFrequency,
+        actual: PwmFrequency,
+    },
+    /// Controller did not answer at its new address after an address
+    /// change.
+    AddressChangeFailed {
+        old: u8,
+        new: u8,
+        old_responds: bool,
+        reason: String,
+    },
+    /// Strict-mode speed write refused outside manual mode.
+    ModeConflict(OperatingMode),
+    /// Read of a write-only or write of a read-only register.
+    RegisterAccess {
+        register: u16,
+        access: RegisterAccess,
+    },
+    /// Configuration file could not be read, written or parsed.
+    #[cfg(feature = "json")]
+    ConfigFile(String),
+    /// MQTT client error.
+    #[cfg(feature = "mqtt")]
+    Mqtt(String),
 }
 
 impl Jpf4826Error {
@@ -52,6 +106,18 @@ impl Jpf4826Error {
         }
     }
 
+    /// Creates error for a Modbus `exception` the controller answered a
+    /// request at `register` with.
+    pub(crate) fn exception<E: fmt::Display>(register: u16, exception: E) -> Self {
+        Self {
+            kind: ErrorKind::Exception {
+                register,
+                exception: exception.to_string(),
+            },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
     /// Creates error for serial port failure.
     pub(crate) fn serial<E: fmt::Display>(err: E) -> Self {
         Self {
@@ -70,6 +136,11 @@ impl Jpf4826Error {
 
     /// Creates error for invalid temperature thresholds.
     pub(crate) fn invalid_thresholds(low: i16, high: i16) -> Self {
+        Self::invalid_thresholds_in(Temperature::celsius(low), Temperature::celsius(high))
+    }
+
+    /// Creates error for invalid temperature thresholds given in any unit.
+    pub(crate) fn invalid_thresholds_in(low: Temperature, high: Temperature) -> Self {
         Self {
             kind: ErrorKind::InvalidThresholds { low, high },
             backtrace: Backtrace::capture(),
@@ -100,15 +171,153 @@ impl Jpf4826Error {
         }
     }
 
+    /// Creates error for a read response with the wrong number of registers.
+    pub(crate) fn response_length(register: u16, expected: usize, actual: usize) -> Self {
+        Self {
+            kind: ErrorKind::ResponseLength {
+                register,
+                expected,
+                actual,
+            },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for an operation `model` does not support.
+    pub(crate) fn unsupported<S: Into<String>>(model: DeviceModel, operation: S) -> Self {
+        Self {
+            kind: ErrorKind::Unsupported {
+                model,
+                operation: operation.into(),
+            },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for an out-of-range register value read from the controller.
+    pub(crate) fn invalid_response(register: u16, value: u16) -> Self {
+        Self {
+            kind: ErrorKind::InvalidResponse { register, value },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a Modbus `function` the controller refused at
+    /// `register`.
+    pub(crate) fn function_rejected(function: u8, register: u16) -> Self {
+        Self {
+            kind: ErrorKind::FunctionRejected { function, register },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a write beyond the register's write budget.
+    pub(crate) fn write_budget_exceeded(register: u16, retry_after: Duration) -> Self {
+        Self {
+            kind: ErrorKind::WriteBudgetExceeded {
+                register,
+                retry_after,
+            },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a PWM frequency write the controller did not apply.
+    pub(crate) fn pwm_frequency_mismatch(expected: PwmFrequency, actual: PwmFrequency) -> Self {
+        Self {
+            kind: ErrorKind::PwmFrequencyMismatch { expected, actual },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for an address change the controller did not confirm
+    /// at `new`, failing with `reason`.
+    pub(crate) fn address_change_failed(
+        old: u8,
+        new: u8,
+        old_responds: bool,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::AddressChangeFailed {
+                old,
+                new,
+                old_responds,
+                reason: reason.into(),
+            },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a speed write that would leave `mode`.
+    pub(crate) fn mode_conflict(mode: OperatingMode) -> Self {
+        Self {
+            kind: ErrorKind::ModeConflict(mode),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a request the register's access does not allow.
+    pub(crate) fn new_register_access(register: u16, access: RegisterAccess) -> Self {
+        Self {
+            kind: ErrorKind::RegisterAccess { register, access },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a temperature register value signalling a sensor fault.
+    pub(crate) fn sensor_fault(register: u16, value: u16) -> Self {
+        Self {
+            kind: ErrorKind::SensorFault { register, value },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
     /// Creates error for operation timeout.
     pub(crate) fn timeout(duration: Duration) -> Self {
+        Self::timeout_after(duration, 1)
+    }
+
+    /// Creates error for a request that timed out each of `attempts`
+    /// times.
+    pub(crate) fn timeout_after(duration: Duration, attempts: u8) -> Self {
+        Self {
+            kind: ErrorKind::Timeout { duration, attempts },
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for a configuration file that could not be read,
+    /// written or parsed.
+    #[cfg(feature = "json")]
+    pub(crate) fn config_file<E: fmt::Display>(err: E) -> Self {
+        Self {
+            kind: ErrorKind::ConfigFile(err.to_string()),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates error for MQTT client failure.
+    #[cfg(feature = "mqtt")]
+    pub(crate) fn mqtt<E: fmt::Display>(err: E) -> Self {
+        Self {
+            kind: ErrorKind::Mqtt(err.to_string()),
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Creates an error of the same kind for another receiver.
+    ///
+    /// The backtrace is captured anew, as `Backtrace` cannot be cloned.
+    pub(crate) fn duplicate(&self) -> Self {
         Self {
-            kind: ErrorKind::Timeout(duration),
+            kind: self.kind.clone(),
             backtrace: Backtrace::capture(),
         }
     }
 
-    /// Returns true if error is due to Modbus communication.
+    /// Returns true if error is due to Modbus communication, including a
+    /// Modbus exception and a response with the wrong number of registers.
     ///
     /// # Examples
     ///
@@ -126,7 +335,26 @@ impl Jpf4826Error {
     /// # }
     /// ```
     pub fn is_modbus(&self) -> bool {
-        matches!(self.kind, ErrorKind::Modbus(_))
+        matches!(
+            self.kind,
+            ErrorKind::Modbus(_) | ErrorKind::Exception { .. } | ErrorKind::ResponseLength { .. }
+        )
+    }
+
+    /// Returns true if the controller answered with a Modbus exception,
+    /// i.e. it heard the request and refused it.
+    pub fn is_modbus_exception(&self) -> bool {
+        matches!(self.kind, ErrorKind::Exception { .. })
+    }
+
+    /// Returns true if the request got no valid answer: it timed out or
+    /// failed on the transport.
+    ///
+    /// These are the failures worth repeating. A Modbus exception or a
+    /// response with the wrong number of registers is an answer and would
+    /// come back the same.
+    pub fn is_link_failure(&self) -> bool {
+        matches!(self.kind, ErrorKind::Modbus(_) | ErrorKind::Timeout { .. })
     }
 
     /// Returns true if error is due to serial port failure.
@@ -134,9 +362,75 @@ impl Jpf4826Error {
         matches!(self.kind, ErrorKind::Serial(_))
     }
 
-    /// Returns true if error is due to invalid parameter.
+    /// Returns true if error is due to invalid parameter, including an
+    /// out-of-range fan index, Modbus address or speed, an inverted
+    /// threshold pair, and a register whose access does not allow the
+    /// request.
     pub fn is_invalid_parameter(&self) -> bool {
-        matches!(self.kind, ErrorKind::InvalidParameter(_))
+        matches!(
+            self.kind,
+            ErrorKind::InvalidParameter(_)
+                | ErrorKind::InvalidThresholds { .. }
+                | ErrorKind::InvalidFanIndex(_)
+                | ErrorKind::InvalidAddress(_)
+                | ErrorKind::InvalidSpeed(_)
+                | ErrorKind::RegisterAccess { .. }
+        )
+    }
+
+    /// Returns the register address and its access if a read of a
+    /// write-only or a write of a read-only register was refused.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, Result};
+    /// # use jpf4826_driver::registers::RegisterAddress;
+    /// # async fn example() -> Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// if let Err(e) = client.write(RegisterAddress::Fan1Speed, 0).await {
+    ///     if let Some((register, access)) = e.register_access() {
+    ///         println!("Register {:#06X} is {}", register, access);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_access(&self) -> Option<(u16, RegisterAccess)> {
+        if let ErrorKind::RegisterAccess { register, access } = self.kind {
+            Some((register, access))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the controller returned an out-of-range register
+    /// value, including a temperature sensor fault.
+    pub fn is_invalid_response(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::InvalidResponse { .. } | ErrorKind::SensorFault { .. }
+        )
+    }
+
+    /// Returns true if the temperature sensor is disconnected or shorted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, Result};
+    /// # async fn example() -> Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// match client.temperature().await {
+    ///     Err(e) if e.is_sensor_fault() => println!("Check the temperature probe"),
+    ///     Err(e) => println!("Other error: {}", e),
+    ///     Ok(temp) => println!("Temperature: {}°C", temp.value),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_sensor_fault(&self) -> bool {
+        matches!(self.kind, ErrorKind::SensorFault { .. })
     }
 
     /// Returns true if error is due to operation timeout.
@@ -156,12 +450,127 @@ impl Jpf4826Error {
     /// # }
     /// ```
     pub fn is_timeout(&self) -> bool {
-        matches!(self.kind, ErrorKind::Timeout(_))
+        matches!(self.kind, ErrorKind::Timeout { .. })
+    }
+
+    /// Returns true if the operation is not supported on the client's
+    /// [`DeviceModel`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::{Jpf4826Client, PwmFrequency, Result};
+    /// # async fn example() -> Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// match client.set_pwm_frequency(PwmFrequency::Hz500).await {
+    ///     Err(e) if e.is_unsupported() => println!("{}", e),
+    ///     other => other?,
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_unsupported(&self) -> bool {
+        matches!(self.kind, ErrorKind::Unsupported { .. })
+    }
+
+    /// Returns true if the controller refused the Modbus function code of
+    /// the request (Illegal Function exception).
+    ///
+    /// Firmware without Write Multiple Registers (0x10) answers
+    /// [`Jpf4826Client::write_multiple`](crate::Jpf4826Client::write_multiple)
+    /// this way; write the registers one at a time instead. The request is
+    /// not repeated by [`set_retries`](crate::Jpf4826Client::set_retries).
+    pub fn is_function_rejected(&self) -> bool {
+        matches!(self.kind, ErrorKind::FunctionRejected { .. })
+    }
+
+    /// Returns true if a write was refused by the client's write budget
+    /// (see [`crate::budget`]).
+    pub fn is_write_budget_exceeded(&self) -> bool {
+        matches!(self.kind, ErrorKind::WriteBudgetExceeded { .. })
+    }
+
+    /// Returns the register and how long until it may be written again if
+    /// a write was refused by the write budget.
+    pub fn write_budget_retry(&self) -> Option<(u16, Duration)> {
+        if let ErrorKind::WriteBudgetExceeded {
+            register,
+            retry_after,
+        } = self.kind
+        {
+            Some((register, retry_after))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the controller did not apply a verified PWM
+    /// frequency write (see
+    /// [`Jpf4826Client::set_pwm_frequency_verified`](crate::Jpf4826Client::set_pwm_frequency_verified)).
+    pub fn is_pwm_frequency_mismatch(&self) -> bool {
+        matches!(self.kind, ErrorKind::PwmFrequencyMismatch { .. })
+    }
+
+    /// Returns the frequency written and the one read back if the
+    /// controller did not apply a verified PWM frequency write.
+    pub fn pwm_frequency_mismatch_values(&self) -> Option<(PwmFrequency, PwmFrequency)> {
+        if let ErrorKind::PwmFrequencyMismatch { expected, actual } = self.kind {
+            Some((expected, actual))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if the controller did not answer at its new address
+    /// after [`change_address`](crate::Jpf4826Client::change_address).
+    pub fn is_address_change_failed(&self) -> bool {
+        matches!(self.kind, ErrorKind::AddressChangeFailed { .. })
+    }
+
+    /// Returns the old and new address of a failed
+    /// [`change_address`](crate::Jpf4826Client::change_address).
+    pub fn address_change_addresses(&self) -> Option<(u8, u8)> {
+        if let ErrorKind::AddressChangeFailed { old, new, .. } = self.kind {
+            Some((old, new))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the controller still answered at its old address
+    /// after a failed
+    /// [`change_address`](crate::Jpf4826Client::change_address).
+    pub fn old_address_responds(&self) -> Option<bool> {
+        if let ErrorKind::AddressChangeFailed { old_responds, .. } = self.kind {
+            Some(old_responds)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if a strict-mode
+    /// [`set_fan_speed`](crate::Jpf4826Client::set_fan_speed) was refused
+    /// because the controller is not known to be in manual mode.
+    pub fn is_mode_conflict(&self) -> bool {
+        matches!(self.kind, ErrorKind::ModeConflict(_))
+    }
+
+    /// Returns true if a configuration file could not be read, written or
+    /// parsed, including unknown fields and invalid settings in it.
+    #[cfg(feature = "json")]
+    pub fn is_config_file(&self) -> bool {
+        matches!(self.kind, ErrorKind::ConfigFile(_))
+    }
+
+    /// Returns true if error is due to the MQTT client.
+    #[cfg(feature = "mqtt")]
+    pub fn is_mqtt(&self) -> bool {
+        matches!(self.kind, ErrorKind::Mqtt(_))
     }
 
     /// Returns the timeout duration if this was a timeout error.
     pub fn timeout_duration(&self) -> Option<Duration> {
-        if let ErrorKind::Timeout(duration) = self.kind {
+        if let ErrorKind::Timeout { duration, .. } = self.kind {
             Some(duration)
         } else {
             None
@@ -192,6 +601,19 @@ impl Jpf4826Error {
         }
     }
 
+    /// Returns the requested and received register counts if a read
+    /// response had the wrong length.
+    pub fn response_length_mismatch(&self) -> Option<(usize, usize)> {
+        if let ErrorKind::ResponseLength {
+            expected, actual, ..
+        } = self.kind
+        {
+            Some((expected, actual))
+        } else {
+            None
+        }
+    }
+
     /// Returns the backtrace for debugging.
     ///
     /// Set `RUST_BACKTRACE=1` environment variable to capture backtraces.
@@ -204,12 +626,20 @@ impl fmt::Display for Jpf4826Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.kind {
             ErrorKind::Modbus(msg) => write!(f, "Modbus communication error: {}", msg),
+            ErrorKind::Exception {
+                register,
+                exception,
+            } => write!(
+                f,
+                "Modbus communication error: Modbus exception at {:#06X}: {}",
+                register, exception
+            ),
             ErrorKind::Serial(msg) => write!(f, "Serial port error: {}", msg),
             ErrorKind::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
             ErrorKind::InvalidThresholds { low, high } => {
                 write!(
                     f,
-                    "Temperature threshold error: high ({}°C) must be greater than low ({}°C)",
+                    "Temperature threshold error: high ({}) must be greater than low ({})",
                     high, low
                 )
             }
@@ -222,13 +652,112 @@ impl fmt::Display for Jpf4826Error {
             ErrorKind::InvalidSpeed(speed) => {
                 write!(f, "Manual speed {}% out of range (0-100)", speed)
             }
-            ErrorKind::Timeout(duration) => {
+            ErrorKind::ResponseLength {
+                register,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Modbus response at {:#06X} has {} registers, expected {}",
+                    register, actual, expected
+                )
+            }
+            ErrorKind::InvalidResponse { register, value } => {
+                write!(
+                    f,
+                    "Controller returned out-of-range value {:#06X} for register {:#06X}",
+                    value, register
+                )
+            }
+            ErrorKind::SensorFault { register, value } => {
+                write!(
+                    f,
+                    "Temperature sensor fault: register {:#06X} reads {:#06X}",
+                    register, value
+                )
+            }
+            ErrorKind::Timeout { duration, attempts } => {
                 write!(
                     f,
                     "Operation timed out after {:.1}s",
                     duration.as_secs_f64()
+                )?;
+                if *attempts > 1 {
+                    write!(f, " ({} attempts)", attempts)?;
+                }
+                Ok(())
+            }
+            ErrorKind::Unsupported { model, operation } => {
+                write!(
+                    f,
+                    "{} is not supported on this model ({})",
+                    operation, model
+                )
+            }
+            ErrorKind::FunctionRejected { function, register } => {
+                write!(
+                    f,
+                    "Controller does not support Modbus function {:#04X} (request at register {:#06X})",
+                    function, register
+                )
+            }
+            ErrorKind::WriteBudgetExceeded {
+                register,
+                retry_after,
+            } => {
+                write!(
+                    f,
+                    "Write budget exceeded for register {:#06X}, retry in {:.1}s",
+                    register,
+                    retry_after.as_secs_f64()
                 )
             }
+            ErrorKind::PwmFrequencyMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "PWM frequency not applied: wrote {} Hz, controller holds {} Hz",
+                    expected.to_hz(),
+                    actual.to_hz()
+                )
+            }
+            ErrorKind::RegisterAccess { register, access } => {
+                write!(f, "Register {:#06X} is {}", register, access)
+            }
+            ErrorKind::AddressChangeFailed {
+                old,
+                new,
+                old_responds,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "Controller did not answer at new address {} ({}); ",
+                    new, reason
+                )?;
+                if *old_responds {
+                    write!(f, "it still answers at old address {}", old)
+                } else {
+                    write!(f, "old address {} does not answer either", old)
+                }
+            }
+            ErrorKind::ModeConflict(mode) => {
+                let state = if *mode == OperatingMode::Temperature {
+                    "is"
+                } else {
+                    "may be"
+                };
+                write!(
+                    f,
+                    "Controller {} in temperature mode; call set_manual_speed() or \
+                     set_strict_mode(false) to switch to manual speed control",
+                    state
+                )
+            }
+            #[cfg(feature = "json")]
+            ErrorKind::ConfigFile(msg) => write!(f, "Configuration file error: {}", msg),
+            #[cfg(feature = "mqtt")]
+            ErrorKind::Mqtt(msg) => write!(f, "MQTT error: {}", msg),
         }
     }
 }
@@ -269,6 +798,100 @@ mod tests {
         assert_eq!(err.timeout_duration(), None);
     }
 
+    #[test]
+    fn test_argument_errors_are_invalid_parameters() {
+        for err in [
+            Jpf4826Error::invalid_parameter("Bad argument"),
+            Jpf4826Error::invalid_thresholds(50, 30),
+            Jpf4826Error::new_invalid_fan_index(5),
+            Jpf4826Error::invalid_address(0),
+            Jpf4826Error::invalid_speed(150),
+        ] {
+            assert!(err.is_invalid_parameter(), "{}", err);
+            assert!(!err.is_invalid_response(), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_invalid_response_error_display() {
+        let err = Jpf4826Error::invalid_response(0x0002, 0x0100);
+
+        assert!(err.is_invalid_response());
+        assert!(!err.is_modbus());
+        assert_eq!(
+            format!("{err}"),
+            "Controller returned out-of-range value 0x0100 for register 0x0002"
+        );
+    }
+
+    #[test]
+    fn test_sensor_fault_error_display() {
+        let err = Jpf4826Error::sensor_fault(0x0000, 0xFFFF);
+
+        assert!(err.is_sensor_fault());
+        assert!(err.is_invalid_response());
+        assert!(!Jpf4826Error::invalid_response(0x0002, 0).is_sensor_fault());
+        assert_eq!(
+            format!("{err}"),
+            "Temperature sensor fault: register 0x0000 reads 0xFFFF"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_error_display() {
+        let err = Jpf4826Error::unsupported(DeviceModel::Jpf4826, "Second temperature input");
+
+        assert!(err.is_unsupported());
+        assert!(!err.is_invalid_parameter());
+        assert_eq!(
+            format!("{err}"),
+            "Second temperature input is not supported on this model (JPF4826)"
+        );
+    }
+
+    #[test]
+    fn test_function_rejected_error_display() {
+        let err = Jpf4826Error::function_rejected(0x10, 0x0005);
+
+        assert!(err.is_function_rejected());
+        // Not a link failure, so not retried
+        assert!(!err.is_modbus());
+        assert_eq!(
+            format!("{err}"),
+            "Controller does not support Modbus function 0x10 (request at register 0x0005)"
+        );
+    }
+
+    #[test]
+    fn test_response_length_error() {
+        let err = Jpf4826Error::response_length(0x0000, 15, 3);
+
+        assert!(err.is_modbus());
+        assert_eq!(err.response_length_mismatch(), Some((15, 3)));
+        assert_eq!(
+            format!("{err}"),
+            "Modbus response at 0x0000 has 3 registers, expected 15"
+        );
+        assert_eq!(Jpf4826Error::modbus("x").response_length_mismatch(), None);
+    }
+
+    #[test]
+    fn test_exception_error() {
+        let err = Jpf4826Error::exception(0x0003, "IllegalDataValue");
+
+        assert!(err.is_modbus());
+        assert!(err.is_modbus_exception());
+        // The controller answered, so repeating the request would not help
+        assert!(!err.is_link_failure());
+        assert_eq!(
+            format!("{err}"),
+            "Modbus communication error: Modbus exception at 0x0003: IllegalDataValue"
+        );
+        assert!(Jpf4826Error::modbus("x").is_link_failure());
+        assert!(Jpf4826Error::timeout(Duration::from_secs(1)).is_link_failure());
+        assert!(!Jpf4826Error::response_length(0x0000, 15, 3).is_link_failure());
+    }
+
     #[test]
     fn test_timeout_error_display_whole_seconds() {
         let err = Jpf4826Error::timeout(Duration::from_secs(10));
@@ -282,4 +905,95 @@ mod tests {
 
         assert_eq!(format!("{err}"), "Operation timed out after 2.5s");
     }
+
+    #[test]
+    fn test_timeout_error_display_attempts() {
+        let err = Jpf4826Error::timeout_after(Duration::from_millis(200), 3);
+        assert_eq!(
+            format!("{err}"),
+            "Operation timed out after 0.2s (3 attempts)"
+        );
+        assert_eq!(err.timeout_duration(), Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn test_write_budget_error_display() {
+        let err = Jpf4826Error::write_budget_exceeded(0x0005, Duration::from_millis(2500));
+
+        assert!(err.is_write_budget_exceeded());
+        assert!(!err.is_timeout());
+        assert_eq!(
+            err.write_budget_retry(),
+            Some((0x0005, Duration::from_millis(2500)))
+        );
+        assert_eq!(
+            err.to_string(),
+            "Write budget exceeded for register 0x0005, retry in 2.5s"
+        );
+    }
+
+    #[test]
+    fn test_address_change_failed_display() {
+        let err = Jpf4826Error::address_change_failed(1, 7, true, "Operation timed out after 1s");
+
+        assert!(err.is_address_change_failed());
+        assert!(!err.is_timeout());
+        assert_eq!(err.address_change_addresses(), Some((1, 7)));
+        assert_eq!(err.old_address_responds(), Some(true));
+        assert_eq!(
+            err.to_string(),
+            "Controller did not answer at new address 7 (Operation timed out after 1s); \
+             it still answers at old address 1"
+        );
+
+        let err = Jpf4826Error::address_change_failed(1, 7, false, "no answer");
+        assert_eq!(err.old_address_responds(), Some(false));
+        assert!(err
+            .to_string()
+            .ends_with("old address 1 does not answer either"));
+    }
+
+    #[test]
+    fn test_mode_conflict_display() {
+        let err = Jpf4826Error::mode_conflict(OperatingMode::Temperature);
+
+        assert!(err.is_mode_conflict());
+        assert!(!err.is_invalid_parameter());
+        assert_eq!(
+            err.to_string(),
+            "Controller is in temperature mode; call set_manual_speed() or \
+             set_strict_mode(false) to switch to manual speed control"
+        );
+        assert!(Jpf4826Error::mode_conflict(OperatingMode::Unknown)
+            .to_string()
+            .starts_with("Controller may be in temperature mode"));
+    }
+
+    #[test]
+    fn test_pwm_frequency_mismatch_display() {
+        let err = Jpf4826Error::pwm_frequency_mismatch(PwmFrequency::Hz25000, PwmFrequency::Hz1000);
+
+        assert!(err.is_pwm_frequency_mismatch());
+        assert!(!err.is_invalid_response());
+        assert_eq!(
+            err.pwm_frequency_mismatch_values(),
+            Some((PwmFrequency::Hz25000, PwmFrequency::Hz1000))
+        );
+        assert_eq!(
+            err.to_string(),
+            "PWM frequency not applied: wrote 25000 Hz, controller holds 1000 Hz"
+        );
+    }
+
+    #[test]
+    fn test_register_access_display() {
+        let err = Jpf4826Error::new_register_access(0x0007, RegisterAccess::ReadOnly);
+
+        assert!(err.is_invalid_parameter());
+        assert_eq!(
+            err.register_access(),
+            Some((0x0007, RegisterAccess::ReadOnly))
+        );
+        assert_eq!(err.to_string(), "Register 0x0007 is read-only");
+    }
 }
@@ -0,0 +1,247 @@
+//! Safe state on host failure.
+//!
+//! A host that drives the fans in manual mode leaves them at the last
+//! written speed when it stops, which may be far too slow for the current
+//! temperature. A [`FailsafeGuard`] writes a [`SafeState`] when the host
+//! gives up control: explicitly through [`FailsafeGuard::trigger`], when
+//! the guard is dropped (including during a panic), or, with a heartbeat,
+//! when the host stops calling [`FailsafeGuard::heartbeat`] in time.
+//!
+//! # Runtime caveats
+//!
+//! Dropping the guard cannot wait for the bus, so it spawns the write on
+//! the current Tokio runtime. The write is lost if there is no runtime or
+//! the runtime shuts down first, as happens when the guard is dropped at
+//! the end of `#[tokio::main]`. Prefer [`FailsafeGuard::trigger`] and
+//! [`FailsafeGuard::disarm`] wherever the program can await; rely on drop
+//! only for unexpected exits such as errors and panics. A process that is
+//! killed or aborts runs no code at all; only the heartbeat of a separate
+//! process could cover that.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use jpf4826_driver::{Jpf4826Client, SharedClient};
+//! # use jpf4826_driver::failsafe::{FailsafeGuard, SafeState};
+//! # #[tokio::main]
+//! # async fn main() -> jpf4826_driver::Result<()> {
+//! # let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+//! let shared = SharedClient::new(client);
+//! let guard = FailsafeGuard::new(shared.clone(), SafeState::Temperature)?
+//!     .with_heartbeat(Duration::from_secs(5));
+//!
+//! for duty in [15, 30, 45] {
+//!     shared.lock().await.set_fan_speed(duty).await?;
+//!     guard.heartbeat();
+//!     tokio::time::sleep(Duration::from_secs(1)).await;
+//! }
+//!
+//! // Hand control back to the controller
+//! guard.trigger().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use crate::client::Jpf4826Client;
+use crate::error::{Jpf4826Error, Result};
+use crate::shared::SharedClient;
+
+/// Speed setting written by a [`FailsafeGuard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafeState {
+    /// Return to automatic, temperature-based speed control.
+    Temperature,
+    /// Fixed speed percentage (0-100).
+    ManualSpeed(u8),
+}
+
+impl SafeState {
+    /// Writes the state to the controller.
+    async fn apply(self, client: &mut Jpf4826Client) -> Result<()> {
+        match self {
+            SafeState::Temperature => client.set_auto_speed().await,
+            SafeState::ManualSpeed(percent) => client.set_manual_speed(percent).await,
+        }
+    }
+}
+
+/// State shared with the heartbeat task.
+struct Shared {
+    client: SharedClient,
+    safe_state: SafeState,
+    /// Set by whoever decides the outcome first: the heartbeat task about
+    /// to write the safe state, or the guard being released.
+    claimed: AtomicBool,
+    alive: Notify,
+}
+
+impl Shared {
+    /// Returns true if the caller decides the outcome.
+    fn claim(&self) -> bool {
+        !self.claimed.swap(true, Ordering::SeqCst)
+    }
+
+    /// Writes the safe state, logging the reason.
+    async fn write(&self, reason: &str) -> Result<()> {
+        log::warn!("Failsafe: {}, writing {:?}", reason, self.safe_state);
+        let result = self.safe_state.apply(&mut *self.client.lock().await).await;
+        if let Err(e) = &result {
+            log::error!("Failsafe: failed to write {:?}: {}", self.safe_state, e);
+        }
+        result
+    }
+}
+
+/// Writes a safe state when the host gives up control.
+///
+/// See the [module documentation](self) for when the safe state is written
+/// and the caveats of dropping the guard.
+pub struct FailsafeGuard {
+    shared: Arc<Shared>,
+    watchdog: Option<JoinHandle<()>>,
+}
+
+impl FailsafeGuard {
+    /// Arms a guard writing `safe_state` through `client`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a manual safe speed is greater than 100.
+    pub fn new(client: SharedClient, safe_state: SafeState) -> Result<Self> {
+        if let SafeState::ManualSpeed(percent) = safe_state {
+            if percent > 100 {
+                return Err(Jpf4826Error::invalid_speed(percent));
+            }
+        }
+        Ok(Self {
+            shared: Arc::new(Shared {
+                client,
+                safe_state,
+                claimed: AtomicBool::new(false),
+                alive: Notify::new(),
+            }),
+            watchdog: None,
+        })
+    }
+
+    /// Also writes the safe state if [`heartbeat`](Self::heartbeat) is not
+    /// called for `timeout`.
+    ///
+    /// The first period starts now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    pub fn with_heartbeat(mut self, timeout: Duration) -> Self {
+        if let Some(previous) = self.watchdog.take() {
+            previous.abort();
+        }
+        let shared = self.shared.clone();
+        self.watchdog = Some(tokio::spawn(async move {
+            while tokio::time::timeout(timeout, shared.alive.notified())
+                .await
+                .is_ok()
+            {}
+            if shared.claim() {
+                // The error is logged by write()
+                let _ = shared.write("heartbeat missed").await;
+            }
+        }));
+        self
+    }
+
+    /// Signals that the host is alive, restarting the heartbeat timeout.
+    ///
+    /// Does nothing without [`with_heartbeat`](Self::with_heartbeat).
+    pub fn heartbeat(&self) {
+        self.shared.alive.notify_one();
+    }
+
+    /// Returns the state the guard writes.
+    pub fn safe_state(&self) -> SafeState {
+        self.shared.safe_state
+    }
+
+    /// Returns true once a missed heartbeat wrote (or is writing) the safe
+    /// state.
+    pub fn is_tripped(&self) -> bool {
+        self.shared.claimed.load(Ordering::SeqCst)
+    }
+
+    /// Writes the safe state now and releases the guard.
+    ///
+    /// Does not write again if a missed heartbeat already did, but waits
+    /// for that write to finish.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the safe state cannot be written.
+    pub async fn trigger(mut self) -> Result<()> {
+        if self.shared.claim() {
+            self.stop_watchdog();
+            self.shared.write("triggered").await
+        } else {
+            self.join_watchdog().await;
+            Ok(())
+        }
+    }
+
+    /// Releases the guard without writing the safe state.
+    ///
+    /// Waits for a safe state write already started by a missed heartbeat.
+    pub async fn disarm(mut self) {
+        if self.shared.claim() {
+            self.stop_watchdog();
+            log::debug!("Failsafe disarmed");
+        } else {
+            self.join_watchdog().await;
+        }
+    }
+
+    /// Stops the heartbeat task after the guard claimed the outcome.
+    fn stop_watchdog(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            watchdog.abort();
+        }
+    }
+
+    /// Waits for the heartbeat task writing the safe state.
+    async fn join_watchdog(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            let _ = watchdog.await;
+        }
+    }
+}
+
+impl Drop for FailsafeGuard {
+    fn drop(&mut self) {
+        // Released by trigger() or disarm(), or tripped by the heartbeat
+        if !self.shared.claim() {
+            return;
+        }
+        self.stop_watchdog();
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let shared = self.shared.clone();
+                runtime.spawn(async move {
+                    let _ = shared.write("guard dropped").await;
+                });
+            }
+            Err(_) => log::error!(
+                "Failsafe: guard dropped outside a Tokio runtime, cannot write {:?}",
+                self.shared.safe_state
+            ),
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! Raw Modbus frame observation.
+//!
+//! A [`FrameObserver`] installed with
+//! [`Jpf4826Client::set_frame_observer`](crate::Jpf4826Client::set_frame_observer)
+//! sees the bytes of every frame sent to and received from the controller,
+//! for debugging protocol issues without a logic analyzer. Frames are
+//! complete application data units: address, PDU and CRC on a serial line,
+//! MBAP header and PDU behind a TCP gateway. [`HexDumpObserver`] logs them
+//! at trace level.
+//!
+//! The bytes are taken from the stream under tokio-modbus, so the observer
+//! also sees responses the driver discards, such as late answers to
+//! abandoned requests. The mock backend sends no frames.
+//!
+//! The same stream discards input left over from earlier transactions
+//! before each request, unless
+//! [`Jpf4826Client::set_flush_input`](crate::Jpf4826Client::set_flush_input)
+//! turns this off.
+
+// Rust guideline compliant 2026-10-16
+
+use std::fmt::{self, Write as _};
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::task::{ready, Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Longest Modbus-RTU frame; unparseable input is reported in chunks of
+/// this size.
+const MAX_FRAME_LEN: usize = 256;
+
+/// Receives the raw bytes of each Modbus frame.
+///
+/// Called on the task performing the request, from inside the stream's
+/// poll functions, so implementations should return quickly and must not
+/// block.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::{Arc, Mutex};
+/// # use jpf4826_driver::Jpf4826Client;
+/// # use jpf4826_driver::frame::FrameObserver;
+/// /// Keeps every frame for a bug report.
+/// #[derive(Default)]
+/// struct Capture(Mutex<Vec<(bool, Vec<u8>)>>);
+///
+/// impl FrameObserver for Capture {
+///     fn on_request(&self, frame: &[u8]) {
+///         self.0.lock().unwrap().push((true, frame.to_vec()));
+///     }
+///
+///     fn on_response(&self, frame: &[u8]) {
+///         self.0.lock().unwrap().push((false, frame.to_vec()));
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let capture = Arc::new(Capture::default());
+/// client.set_frame_observer(capture.clone());
+/// client.temperature().await?;
+/// println!("{} frames", capture.0.lock().unwrap().len());
+/// # Ok(())
+/// # }
+/// ```
+pub trait FrameObserver: Send + Sync {
+    /// Called with each frame sent.
+    fn on_request(&self, frame: &[u8]);
+
+    /// Called with each frame received.
+    fn on_response(&self, frame: &[u8]);
+}
+
+/// Observer logging each frame as hex at trace level.
+///
+/// Lines read "Modbus TX: 01 03 00 00 00 01 84 0A" for requests and
+/// "Modbus RX: ..." for responses.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use jpf4826_driver::Jpf4826Client;
+/// # use jpf4826_driver::frame::HexDumpObserver;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// client.set_frame_observer(Arc::new(HexDumpObserver));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HexDumpObserver;
+
+impl FrameObserver for HexDumpObserver {
+    fn on_request(&self, frame: &[u8]) {
+        log::trace!("Modbus TX: {}", hex(frame));
+    }
+
+    fn on_response(&self, frame: &[u8]) {
+        log::trace!("Modbus RX: {}", hex(frame));
+    }
+}
+
+/// Formats `bytes` as space-separated hex pairs.
+fn hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 3);
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        // Writing to a String cannot fail
+        let _ = write!(out, "{:02X}", byte);
+    }
+    out
+}
+
+/// Observer shared by a client and the streams it opens, so one installed
+/// later reaches a stream already open.
+#[derive(Clone, Default)]
+pub(crate) struct ObserverSlot(Arc<RwLock<Option<Arc<dyn FrameObserver>>>>);
+
+impl ObserverSlot {
+    pub(crate) fn get(&self) -> Option<Arc<dyn FrameObserver>> {
+        self.0.read().map_or(None, |observer| observer.clone())
+    }
+
+    pub(crate) fn set(&self, observer: Option<Arc<dyn FrameObserver>>) {
+        if let Ok(mut slot) = self.0.write() {
+            *slot = observer;
+        }
+    }
+}
+
+/// Whether a client's streams discard pending input before each request,
+/// shared like [`ObserverSlot`]. On by default.
+#[derive(Clone)]
+pub(crate) struct InputFlush(Arc<AtomicBool>);
+
+impl Default for InputFlush {
+    fn default() -> Self {
+        Self(Arc::new(AtomicBool::new(true)))
+    }
+}
+
+impl InputFlush {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set(&self, enabled: bool) {
+        self.0.store(enabled, Ordering::Relaxed);
+    }
+}
+
+/// How frames are delimited on a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Framing {
+    /// Modbus-RTU: address, PDU, CRC.
+    Rtu,
+    /// Modbus TCP: MBAP header with the length of the rest.
+    Tcp,
+}
+
+impl Framing {
+    /// Returns the length of the received frame at the start of `bytes`,
+    /// or `None` if more bytes are needed to tell.
+    fn response_len(self, bytes: &[u8]) -> Option<usize> {
+        match self {
+            Framing::Rtu => {
+                let function = *bytes.get(1)?;
+                if function & 0x80 != 0 {
+                    // Address, function, exception code, CRC
+                    return Some(5);
+                }
+                match function {
+                    // Address, function, byte count, data, CRC
+                    0x01..=0x04 => Some(5 + usize::from(*bytes.get(2)?)),
+                    // Echo of address, function, two words, CRC
+                    0x05 | 0x06 | 0x0F | 0x10 => Some(8),
+                    // Unknown function, report what arrived
+                    _ => Some(bytes.len()),
+                }
+            }
+            Framing::Tcp => {
+                let length = u16::from_be_bytes([*bytes.get(4)?, *bytes.get(5)?]);
+                Some(6 + usize::from(length))
+            }
+        }
+    }
+}
+
+/// Stream passing every byte through while reporting complete frames to
+/// the observer in its slot.
+///
+/// Requests are reported when flushed, which tokio-modbus does after each
+/// frame. Responses are reported once the length their header announces
+/// has arrived.
+///
+/// With `flush` enabled, bytes already waiting when a request starts are
+/// read and dropped first, so a late answer to an abandoned request is not
+/// parsed as the answer to the next one. They still reach the observer.
+pub(crate) struct InspectedStream<S> {
+    inner: S,
+    observer: ObserverSlot,
+    flush: InputFlush,
+    framing: Framing,
+    sent: Vec<u8>,
+    received: Vec<u8>,
+    /// Set between the first write of a request and its flush.
+    writing: bool,
+}
+
+impl<S> InspectedStream<S> {
+    pub(crate) fn new(
+        inner: S,
+        observer: ObserverSlot,
+        flush: InputFlush,
+        framing: Framing,
+    ) -> Self {
+        Self {
+            inner,
+            observer,
+            flush,
+            framing,
+            sent: Vec::new(),
+            received: Vec::new(),
+            writing: false,
+        }
+    }
+
+    /// Passes received bytes to the observer, if any.
+    fn observe_received(&mut self, bytes: &[u8]) {
+        match self.observer.get() {
+            Some(observer) => {
+                self.received.extend_from_slice(bytes);
+                self.report_received(observer.as_ref());
+            }
+            None => self.received.clear(),
+        }
+    }
+
+    /// Reports the complete frames at the start of the receive buffer.
+    fn report_received(&mut self, observer: &dyn FrameObserver) {
+        loop {
+            let len = match self.framing.response_len(&self.received) {
+                Some(len) => len.max(1),
+                None if self.received.len() >= MAX_FRAME_LEN => MAX_FRAME_LEN,
+                None => return,
+            };
+            if self.received.len() < len {
+                return;
+            }
+            observer.on_response(&self.received[..len]);
+            self.received.drain(..len);
+        }
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for InspectedStream<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectedStream")
+            .field("inner", &self.inner)
+            .field("framing", &self.framing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for InspectedStream<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        self.observe_received(&buf.filled()[before..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: AsyncRead + Unpin> InspectedStream<S> {
+    /// Reads and drops the bytes waiting on the stream, without waiting
+    /// for more.
+    fn discard_pending(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        let mut chunk = [0u8; MAX_FRAME_LEN];
+        let mut discarded = 0;
+        loop {
+            let mut buf = ReadBuf::new(&mut chunk);
+            match Pin::new(&mut self.inner).poll_read(cx, &mut buf) {
+                Poll::Ready(Ok(())) if !buf.filled().is_empty() => {
+                    let len = buf.filled().len();
+                    discarded += len;
+                    self.observe_received(&chunk[..len]);
+                }
+                Poll::Ready(Ok(())) | Poll::Pending => break,
+                Poll::Ready(Err(e)) => return Err(e),
+            }
+        }
+        if discarded > 0 {
+            log::warn!("Discarded {} stale bytes before a request", discarded);
+        }
+        Ok(())
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for InspectedStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if !self.writing {
+            if self.flush.is_enabled() {
+                self.discard_pending(cx)?;
+            }
+            self.writing = true;
+        }
+        let written = ready!(Pin::new(&mut self.inner).poll_write(cx, buf))?;
+        if self.observer.get().is_some() {
+            self.sent.extend_from_slice(&buf[..written]);
+        }
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(Pin::new(&mut self.inner).poll_flush(cx))?;
+        self.writing = false;
+        if !self.sent.is_empty() {
+            if let Some(observer) = self.observer.get() {
+                observer.on_request(&self.sent);
+            }
+            self.sent.clear();
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex(&[0x01, 0x03, 0xAB]), "01 03 AB");
+        assert_eq!(hex(&[]), "");
+    }
+
+    #[test]
+    fn test_rtu_response_len() {
+        assert_eq!(Framing::Rtu.response_len(&[0x01]), None);
+        assert_eq!(Framing::Rtu.response_len(&[0x01, 0x03]), None);
+        assert_eq!(Framing::Rtu.response_len(&[0x01, 0x03, 0x04]), Some(9));
+        assert_eq!(Framing::Rtu.response_len(&[0x01, 0x06]), Some(8));
+        assert_eq!(Framing::Rtu.response_len(&[0x01, 0x83]), Some(5));
+    }
+
+    #[test]
+    fn test_tcp_response_len() {
+        let header = [0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x01];
+        assert_eq!(Framing::Tcp.response_len(&header[..5]), None);
+        assert_eq!(Framing::Tcp.response_len(&header), Some(11));
+    }
+}
@@ -0,0 +1,194 @@
+//! Several controllers treated as one cooling zone.
+//!
+//! A [`ControllerGroup`] is created by [`Jpf4826Manager::group`] from
+//! managed targets. It reads and configures all of them at once, with the
+//! manager's bus handling: controllers on different ports are addressed
+//! concurrently, controllers sharing a port one after another.
+//!
+//! # Partial Failure
+//!
+//! Group operations never fail fast. Every controller is attempted, and the
+//! result is a map from [`Target::name`] to that controller's own
+//! [`Result`]. A failing controller does not stop or undo the writes to the
+//! others, so after a partial failure the zone is inconsistent until the
+//! failed entries are retried; [`failed`] lists them.
+//!
+//! Dropping the future of a group operation stops the controllers not yet
+//! done at their next `.await`; each of them is left as described in the
+//! client's [cancellation guarantees](crate::client#cancellation).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use jpf4826_driver::manager::{Jpf4826Manager, Target};
+//! # use jpf4826_driver::group::failed;
+//! # #[tokio::main]
+//! # async fn main() -> jpf4826_driver::Result<()> {
+//! let manager = Jpf4826Manager::new();
+//! manager.add(Target::new("/dev/ttyUSB0", 1).with_alias("row-a1"))?;
+//! manager.add(Target::new("/dev/ttyUSB0", 2).with_alias("row-a2"))?;
+//! manager.add(Target::new("/dev/ttyUSB1", 1).with_alias("row-a3"))?;
+//! let zone = manager.group(&["row-a1", "row-a2", "row-a3"])?;
+//!
+//! let results = zone.set_temperature_threshold_all(30, 50).await;
+//! for name in failed(&results) {
+//!     eprintln!("{} keeps its old thresholds", name);
+//! }
+//!
+//! let summary = zone.status_all().await.aggregate();
+//! println!(
+//!     "Hottest: {:?}, faulted fans: {}",
+//!     summary.max_temperature, summary.faulted_fans
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Jpf4826Manager::group`]: crate::manager::Jpf4826Manager::group
+//! [`Target::name`]: crate::manager::Target::name
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::error::Result;
+use crate::manager::{fan_out, ControllerHandle, Target};
+use crate::types::{ControllerStatus, FanStatus, Temperature, WorkMode};
+
+/// Per-controller results of a group operation, keyed by
+/// [`Target::name`].
+pub type GroupResults<T> = BTreeMap<String, Result<T>>;
+
+/// Returns the names of the controllers whose operation failed.
+pub fn failed<T>(results: &GroupResults<T>) -> Vec<&str> {
+    results
+        .iter()
+        .filter(|(_, result)| result.is_err())
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+/// Controllers that are read and configured together.
+///
+/// See the [module documentation](self) for partial-failure semantics.
+#[derive(Debug, Clone)]
+pub struct ControllerGroup {
+    handles: Vec<ControllerHandle>,
+}
+
+impl ControllerGroup {
+    pub(crate) fn new(handles: Vec<ControllerHandle>) -> Self {
+        Self { handles }
+    }
+
+    /// Returns the handles of the controllers in the group.
+    pub fn handles(&self) -> &[ControllerHandle] {
+        &self.handles
+    }
+
+    /// Returns the targets of the group, in the order they were named.
+    pub fn targets(&self) -> Vec<Target> {
+        self.handles
+            .iter()
+            .map(|handle| handle.target().clone())
+            .collect()
+    }
+
+    /// Reads the status of every controller.
+    pub async fn status_all(&self) -> GroupStatus {
+        let devices = fan_out(&self.handles, Duration::ZERO, |handle| async move {
+            handle.status().await
+        })
+        .await;
+        GroupStatus { devices }
+    }
+
+    /// Sets the temperature thresholds of every controller.
+    ///
+    /// Invalid thresholds fail every entry without a write. See
+    /// [`Jpf4826Client::set_temperature_threshold`] for the per-controller
+    /// errors.
+    ///
+    /// [`Jpf4826Client::set_temperature_threshold`]: crate::Jpf4826Client::set_temperature_threshold
+    pub async fn set_temperature_threshold_all(&self, low: i16, high: i16) -> GroupResults<()> {
+        fan_out(&self.handles, Duration::ZERO, move |handle| async move {
+            handle
+                .with_client(async |client| client.set_temperature_threshold(low, high).await)
+                .await
+        })
+        .await
+    }
+
+    /// Sets the work mode of every controller.
+    pub async fn set_mode_all(&self, mode: WorkMode) -> GroupResults<()> {
+        fan_out(&self.handles, Duration::ZERO, move |handle| async move {
+            handle
+                .with_client(async |client| client.set_eco(mode).await)
+                .await
+        })
+        .await
+    }
+}
+
+/// Status of every controller of a [`ControllerGroup`].
+#[derive(Debug)]
+pub struct GroupStatus {
+    /// Status or read error per controller.
+    pub devices: GroupResults<ControllerStatus>,
+}
+
+impl GroupStatus {
+    /// Summarizes the controllers that answered.
+    pub fn aggregate(&self) -> GroupSummary {
+        let mut summary = GroupSummary {
+            responding: 0,
+            failed: Vec::new(),
+            max_temperature: None,
+            sensor_faults: 0,
+            faulted_fans: 0,
+        };
+        for (name, result) in &self.devices {
+            let status = match result {
+                Ok(status) => status,
+                Err(_) => {
+                    summary.failed.push(name.clone());
+                    continue;
+                }
+            };
+            summary.responding += 1;
+            summary.faulted_fans += status
+                .fans
+                .iter()
+                .filter(|fan| fan.status == FanStatus::Fault)
+                .count();
+            if status.sensor_fault {
+                summary.sensor_faults += 1;
+            } else if summary
+                .max_temperature
+                .is_none_or(|max| status.temperature_current.value > max.value)
+            {
+                summary.max_temperature = Some(status.temperature_current);
+            }
+        }
+        summary
+    }
+}
+
+/// Aggregate view of a [`GroupStatus`].
+///
+/// Only covers controllers that answered; check
+/// [`failed`](Self::failed) before trusting it for the whole zone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupSummary {
+    /// Number of controllers that answered.
+    pub responding: usize,
+    /// Names of the controllers that could not be read.
+    pub failed: Vec<String>,
+    /// Highest temperature among controllers with a working sensor.
+    pub max_temperature: Option<Temperature>,
+    /// Number of controllers reporting a sensor fault.
+    pub sensor_faults: usize,
+    /// Total number of fans reporting a fault.
+    pub faulted_fans: usize,
+}
@@ -0,0 +1,152 @@
+//! Bus health counters.
+//!
+//! Unlike [`stats`](crate::stats), which counts transactions as the caller
+//! sees them, these counters are kept by the Modbus layer and count every
+//! request sent on the bus: a request retried twice before succeeding
+//! counts two failures and one success. They cover the connection since it
+//! was opened; reopening a lost port or resynchronizing after a timeout
+//! keeps them, [`close`](crate::Jpf4826Client::close) discards them.
+//! Broadcasts, which get no answer, are not counted.
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Marks an unset failure time.
+const UNSET: u64 = u64::MAX;
+
+/// Snapshot of the bus health counters of a connection.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::Jpf4826Client;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let _ = client.status().await;
+/// let health = client.bus_health();
+/// println!(
+///     "{} ok, {} timeouts, {} transport errors, {} exceptions",
+///     health.successes, health.timeouts, health.transport_errors, health.exceptions
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BusHealth {
+    /// Number of requests answered with a valid response.
+    pub successes: u64,
+    /// Number of requests that got no answer in time.
+    pub timeouts: u64,
+    /// Number of requests that failed on the transport, including
+    /// responses with a bad CRC and ports that went away.
+    pub transport_errors: u64,
+    /// Number of requests answered with a Modbus exception.
+    pub exceptions: u64,
+    /// When a request last failed.
+    pub last_failure: Option<SystemTime>,
+}
+
+impl BusHealth {
+    /// Returns the number of failed requests.
+    pub fn failures(&self) -> u64 {
+        self.timeouts + self.transport_errors + self.exceptions
+    }
+}
+
+/// Counters behind [`BusHealth`].
+pub(crate) struct HealthCounters {
+    successes: AtomicU64,
+    timeouts: AtomicU64,
+    transport_errors: AtomicU64,
+    exceptions: AtomicU64,
+    /// Nanoseconds since the Unix epoch, or [`UNSET`].
+    last_failure: AtomicU64,
+}
+
+impl Default for HealthCounters {
+    fn default() -> Self {
+        Self {
+            successes: AtomicU64::new(0),
+            timeouts: AtomicU64::new(0),
+            transport_errors: AtomicU64::new(0),
+            exceptions: AtomicU64::new(0),
+            last_failure: AtomicU64::new(UNSET),
+        }
+    }
+}
+
+impl HealthCounters {
+    /// Records one request that ended with `outcome`, or timed out after
+    /// the returned duration.
+    pub(crate) fn record<T>(&self, outcome: &Result<tokio_modbus::Result<T>, Duration>) {
+        let counter = match outcome {
+            Ok(Ok(Ok(_))) => {
+                self.successes.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+            Ok(Ok(Err(_))) => &self.exceptions,
+            Ok(Err(_)) => &self.transport_errors,
+            Err(_) => &self.timeouts,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| {
+                u64::try_from(since.as_nanos()).map_or(UNSET - 1, |nanos| nanos.min(UNSET - 1))
+            });
+        self.last_failure.store(now, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> BusHealth {
+        let last_failure = self.last_failure.load(Ordering::Relaxed);
+        BusHealth {
+            successes: self.successes.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            transport_errors: self.transport_errors.load(Ordering::Relaxed),
+            exceptions: self.exceptions.load(Ordering::Relaxed),
+            last_failure: (last_failure != UNSET)
+                .then(|| UNIX_EPOCH + Duration::from_nanos(last_failure)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_modbus::Exception;
+
+    #[test]
+    fn test_outcomes_are_counted_by_kind() {
+        let counters = HealthCounters::default();
+        assert_eq!(counters.snapshot(), BusHealth::default());
+
+        counters.record::<()>(&Ok(Ok(Ok(()))));
+        assert_eq!(counters.snapshot().last_failure, None);
+
+        counters.record::<()>(&Ok(Ok(Err(Exception::ServerDeviceFailure))));
+        counters.record::<()>(&Ok(Err(tokio_modbus::Error::Transport(
+            std::io::Error::other("bad CRC"),
+        ))));
+        counters.record::<()>(&Err(Duration::from_secs(1)));
+
+        let health = counters.snapshot();
+        assert_eq!(
+            (
+                health.successes,
+                health.exceptions,
+                health.transport_errors,
+                health.timeouts
+            ),
+            (1, 1, 1, 1)
+        );
+        assert_eq!(health.failures(), 3);
+        assert!(health.last_failure.is_some());
+    }
+}
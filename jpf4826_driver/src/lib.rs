@@ -18,21 +18,153 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Feature Flags
+//!
+//! The register map ([`registers`]) and its per-revision differences
+//! ([`model`]), value conversions ([`conversions`]), the speed [`curve`]
+//! and the plain data types ([`types`]) do not depend on `std` and can be
+//! used on bare-metal targets with `default-features = false`.
+//!
+//! - `std` (default): the serial [`Jpf4826Client`], the cloneable
+//!   [`SharedClient`] handle, the [`Jpf4826Bus`] for several controllers
+//!   on one port, the multi-port [`Jpf4826Manager`] and its
+//!   controller [`group`]s, the [`failsafe`] guard, operation [`stats`],
+//!   bus [`health`] counters, [`metrics`] hooks, raw [`frame`] observation, rollback-protected
+//!   [`batch`] settings and [`Jpf4826Error`].
+//!   Implies `alloc`.
+//! - `alloc`: heap-backed types such as [`ControllerStatus`], its
+//!   [`StatusDiff`] and configuration [`Profile`]s.
+//! - `serde` (default): `Serialize`/`Deserialize` for the data types.
+//! - `json` (default): reading and writing configuration backups as JSON
+//!   ([`ControllerConfig::to_writer`], [`ControllerConfig::from_reader`]).
+//! - `schemars`: `JsonSchema` for the data types, describing the same shapes
+//!   as the serde impls (nested `temperature`, `{value, unit}` PWM frequency),
+//!   and the versioned document [`schema`]s.
+//! - `mqtt`: [`mqtt::MqttPublisher`] for publishing status over MQTT.
+//! - `stream`: [`Jpf4826Client::watch`], a `Stream` of periodic status
+//!   snapshots.
+//! - `blocking`: [`blocking::Jpf4826Client`], a synchronous client for
+//!   programs without an async runtime.
+//! - `test-mock`: in-memory mock backend for tests.
 
 // Rust guideline compliant 2026-01-27
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 #[doc(inline)]
-pub use client::Jpf4826Client;
+pub use batch::{SetOperation, SetReport};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use bus::{Jpf4826Bus, Jpf4826Device};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use client::{
+    ConnectOptions, DataBits, DiscoveredDevice, Jpf4826Client, Jpf4826ClientBuilder, Parity,
+    ProbeResult, StopBits, PROBE_TIMEOUT, RESET_POLL_INTERVAL,
+};
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use diff::{Change, FanChange, StatusDiff};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use dump::{DumpValue, RegisterDump};
+#[cfg(feature = "std")]
 #[doc(inline)]
 pub use error::{Jpf4826Error, Result};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use health::BusHealth;
+#[cfg(feature = "std")]
 #[doc(inline)]
-pub use modbus::DEFAULT_TIMEOUT;
+pub use link::LinkInfo;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use manager::Jpf4826Manager;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use modbus::{DEFAULT_TIMEOUT, MIN_TIMEOUT, SUPPORTED_BAUD_RATES};
+#[doc(inline)]
+pub use model::DeviceModel;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use profile::{
+    ApplyReport, ConfigMismatch, ControllerConfig, Profile, ProfileSet, SpeedSetting,
+};
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use reconnect::ReconnectPolicy;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use shared::SharedClient;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use stats::ClientStats;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use timeout::{AdaptiveTimeout, TimeoutMode};
 #[doc(inline)]
 pub use types::*;
+#[cfg(feature = "stream")]
+#[doc(inline)]
+pub use watch::StatusWatch;
 
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "std")]
+pub mod budget;
+#[cfg(feature = "std")]
+pub mod bus;
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
 pub mod client;
 pub mod conversions;
+pub mod curve;
+#[cfg(feature = "alloc")]
+pub mod diff;
+#[cfg(feature = "std")]
+pub mod dump;
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
+pub mod failsafe;
+#[cfg(feature = "std")]
+pub mod frame;
+#[cfg(feature = "std")]
+pub mod group;
+#[cfg(feature = "std")]
+pub mod health;
+#[cfg(feature = "std")]
+pub mod link;
+#[cfg(feature = "std")]
+pub mod manager;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "std")]
 mod modbus;
+pub mod model;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "alloc")]
+pub mod profile;
+#[cfg(feature = "std")]
+pub mod reconnect;
 pub mod registers;
+#[cfg(feature = "schemars")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod shared;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod timeout;
 pub mod types;
+#[cfg(feature = "stream")]
+pub mod watch;
@@ -0,0 +1,105 @@
+//! Link health statistics.
+//!
+//! A [`Jpf4826Client`](crate::Jpf4826Client) counts its bus transactions
+//! and remembers when the controller last answered, so long-lived callers
+//! can report link health without wrapping every call. Only transactions
+//! that reach the bus are counted: arguments rejected before a request is
+//! sent, and values rejected after a complete response, leave the
+//! statistics untouched.
+//!
+//! Successful writes are also counted per register, so operators can spot
+//! callers that wear out the controller's configuration memory (see
+//! [`budget`](crate::budget)).
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::budget::{is_budgeted, register_range};
+use crate::error::Jpf4826Error;
+use crate::registers::RegisterAddress;
+
+/// Communication statistics of a client since it was created.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::Jpf4826Client;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let _ = client.status().await;
+/// let link = client.link_info();
+/// println!("{} ok, {} failed", link.successes, link.failures);
+/// if let Some(error) = &link.last_error {
+///     println!("Last error: {}", error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LinkInfo {
+    /// When the controller last answered a transaction.
+    pub last_success: Option<SystemTime>,
+    /// When a transaction last failed.
+    pub last_failure: Option<SystemTime>,
+    /// Number of successful transactions.
+    pub successes: u64,
+    /// Number of failed transactions.
+    pub failures: u64,
+    /// Message of the most recent failure.
+    pub last_error: Option<String>,
+    /// Successful writes by register address, excluding read-only
+    /// registers and the reset command.
+    pub register_writes: BTreeMap<u16, u64>,
+    /// Number of successful reset commands.
+    pub resets: u64,
+}
+
+impl LinkInfo {
+    /// Returns true if the last transaction failed.
+    pub fn is_failing(&self) -> bool {
+        match (self.last_failure, self.last_success) {
+            (Some(failure), Some(success)) => failure > success,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Returns the total number of counted register writes.
+    pub fn total_writes(&self) -> u64 {
+        self.register_writes.values().sum()
+    }
+
+    /// Counts a successful write of `count` registers from `addr`.
+    pub(crate) fn record_writes(&mut self, addr: u16, count: usize) {
+        for register in register_range(addr, count) {
+            if register == RegisterAddress::ResetController.addr() {
+                self.resets += 1;
+            } else if is_budgeted(register) {
+                *self.register_writes.entry(register).or_default() += 1;
+            }
+        }
+    }
+
+    /// Records the outcome of one transaction.
+    pub(crate) fn record<T>(&mut self, result: &Result<T, Jpf4826Error>) {
+        let now = SystemTime::now();
+        match result {
+            Ok(_) => {
+                self.successes += 1;
+                self.last_success = Some(now);
+            }
+            Err(err) => {
+                self.failures += 1;
+                self.last_failure = Some(now);
+                self.last_error = Some(err.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,539 @@
+//! Pooled access to many controllers on several serial ports.
+//!
+//! A gateway typically drives a handful of RS485 buses with several
+//! controllers each. [`Jpf4826Manager`] takes a list of [`Target`]s, opens
+//! one connection per port on first use and hands out [`ControllerHandle`]s
+//! that select their slave address for every request. Requests to
+//! controllers on the same port are serialized; different ports run
+//! concurrently.
+//!
+//! # Reconnection
+//!
+//! A port that fails to open is retried by the first request after the
+//! reconnect delay; requests before that fail fast with the open error. A
+//! Modbus error other than a timeout drops the connection, so the next
+//! request reopens the port. A timeout only concerns the addressed
+//! controller and keeps the connection.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use jpf4826_driver::manager::{Jpf4826Manager, Target};
+//! # use std::time::Duration;
+//! # #[tokio::main]
+//! # async fn main() -> jpf4826_driver::Result<()> {
+//! let manager = Jpf4826Manager::new()
+//!     .with_timeout(Duration::from_secs(1))
+//!     .with_stagger(Duration::from_millis(20));
+//! manager.add(Target::new("/dev/ttyUSB0", 1).with_alias("rack-1"))?;
+//! manager.add(Target::new("/dev/ttyUSB0", 2).with_alias("rack-2"))?;
+//! manager.add(Target::new("/dev/ttyUSB1", 1))?;
+//!
+//! for (name, result) in manager.status_all().await {
+//!     match result {
+//!         Ok(status) => println!("{}: {}°C", name, status.temperature_current.value),
+//!         Err(e) => println!("{}: {}", name, e),
+//!     }
+//! }
+//!
+//! let rack = manager.get("rack-1").expect("configured above");
+//! rack.with_client(async |client| client.set_fan_speed(80).await)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+use crate::client::Jpf4826Client;
+use crate::error::{Jpf4826Error, Result};
+use crate::group::ControllerGroup;
+use crate::modbus::{BAUD_RATE, DEFAULT_TIMEOUT};
+use crate::types::ControllerStatus;
+
+/// A controller managed by [`Jpf4826Manager`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::manager::Target;
+/// let target = Target::new("/dev/ttyUSB0", 3).with_alias("rack-3");
+/// assert_eq!(target.name(), "rack-3");
+///
+/// let target = Target::new("/dev/ttyUSB0", 3);
+/// assert_eq!(target.name(), "/dev/ttyUSB0:3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    port: String,
+    address: u8,
+    baud_rate: u32,
+    alias: Option<String>,
+}
+
+impl Target {
+    /// Creates a target for the controller at `address` on `port`, at the
+    /// JPF4826's standard 9600 baud.
+    pub fn new(port: impl Into<String>, address: u8) -> Self {
+        Self {
+            port: port.into(),
+            address,
+            baud_rate: BAUD_RATE,
+            alias: None,
+        }
+    }
+
+    /// Sets the baud rate of the port.
+    ///
+    /// All targets on one port must use the same baud rate.
+    pub fn with_baud_rate(mut self, baud_rate: u32) -> Self {
+        self.baud_rate = baud_rate;
+        self
+    }
+
+    /// Sets a name to look the target up by.
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Returns the serial port path.
+    pub fn port(&self) -> &str {
+        &self.port
+    }
+
+    /// Returns the Modbus address.
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Returns the baud rate.
+    pub fn baud_rate(&self) -> u32 {
+        self.baud_rate
+    }
+
+    /// Returns the alias, if set.
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Returns the alias, or `port:address` without one.
+    pub fn name(&self) -> String {
+        match &self.alias {
+            Some(alias) => alias.clone(),
+            None => self.location(),
+        }
+    }
+
+    fn location(&self) -> String {
+        format!("{}:{}", self.port, self.address)
+    }
+
+    /// Returns whether `name` is the alias or `port:address` of the target.
+    fn matches(&self, name: &str) -> bool {
+        self.alias.as_deref() == Some(name) || self.location() == name
+    }
+}
+
+/// Connection settings shared by all buses of a manager.
+#[derive(Debug, Clone, Copy)]
+struct Settings {
+    timeout: Duration,
+    reconnect_delay: Duration,
+}
+
+/// How buses are opened.
+#[derive(Debug, Clone)]
+enum Source {
+    Serial,
+    #[cfg(any(test, feature = "test-mock"))]
+    Mock(std::collections::HashMap<String, crate::client::MockBus>),
+}
+
+/// One serial port shared by the controllers on it.
+struct Bus {
+    port: String,
+    baud_rate: u32,
+    source: Source,
+    state: tokio::sync::Mutex<BusState>,
+}
+
+#[derive(Default)]
+struct BusState {
+    connection: Option<Jpf4826Client>,
+    /// Error of the last failed open and when to try again.
+    failure: Option<(Jpf4826Error, Instant)>,
+}
+
+impl Bus {
+    /// Runs `f` against the controller at `address`, connecting first if
+    /// needed.
+    async fn run<T>(
+        &self,
+        address: u8,
+        settings: Settings,
+        f: impl AsyncFnOnce(&mut Jpf4826Client) -> Result<T>,
+    ) -> Result<T> {
+        let mut state = self.state.lock().await;
+
+        if state.connection.is_none() {
+            if let Some((error, retry_at)) = &state.failure {
+                if Instant::now() < *retry_at {
+                    return Err(error.duplicate());
+                }
+            }
+            match self.connect(address, settings.timeout).await {
+                Ok(client) => {
+                    log::debug!("Connected to bus {}", self.port);
+                    state.connection = Some(client);
+                    state.failure = None;
+                }
+                Err(e) => {
+                    log::warn!("Failed to open bus {}: {}", self.port, e);
+                    let retry_at = Instant::now() + settings.reconnect_delay;
+                    state.failure = Some((e.duplicate(), retry_at));
+                    return Err(e);
+                }
+            }
+        }
+
+        let client = state.connection.as_mut().expect("connected above");
+        client.select_slave(address);
+        let result = f(client).await;
+
+        if let Err(e) = &result {
+            // The transport may be broken; timeouts only concern one device
+            if e.is_modbus() {
+                log::warn!("Dropping connection to bus {}: {}", self.port, e);
+                state.connection = None;
+            }
+        }
+        result
+    }
+
+    async fn connect(&self, address: u8, timeout: Duration) -> Result<Jpf4826Client> {
+        match &self.source {
+            Source::Serial => {
+                Jpf4826Client::open(&self.port, address, self.baud_rate, timeout).await
+            }
+            #[cfg(any(test, feature = "test-mock"))]
+            Source::Mock(buses) => match buses.get(&self.port) {
+                Some(bus) => bus.connect(address),
+                None => Err(Jpf4826Error::serial(format!(
+                    "No mock bus for {}",
+                    self.port
+                ))),
+            },
+        }
+    }
+}
+
+/// Handle to one managed controller.
+///
+/// Cheap to clone. Requests wait for other requests on the same port.
+#[derive(Clone)]
+pub struct ControllerHandle {
+    target: Target,
+    bus: Arc<Bus>,
+    settings: Settings,
+}
+
+impl std::fmt::Debug for ControllerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ControllerHandle")
+            .field("target", &self.target)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ControllerHandle {
+    /// Returns the target this handle addresses.
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Reads the complete controller status.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the port cannot be opened or the read fails.
+    pub async fn status(&self) -> Result<ControllerStatus> {
+        self.with_client(async |client| client.status().await).await
+    }
+
+    /// Runs `f` with exclusive access to the port, addressed to this
+    /// controller.
+    ///
+    /// Changing the Modbus address through the client is not tracked;
+    /// remove and re-add the target instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the port cannot be opened, or the error of `f`.
+    pub async fn with_client<T>(
+        &self,
+        f: impl AsyncFnOnce(&mut Jpf4826Client) -> Result<T>,
+    ) -> Result<T> {
+        self.bus.run(self.target.address, self.settings, f).await
+    }
+}
+
+/// Manager for controllers spread over several serial ports.
+///
+/// See the [module documentation](self) for connection handling.
+pub struct Jpf4826Manager {
+    targets: Mutex<Vec<ControllerHandle>>,
+    settings: Settings,
+    stagger: Duration,
+    source: Source,
+}
+
+impl Default for Jpf4826Manager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Jpf4826Manager {
+    /// Creates a manager without targets.
+    ///
+    /// Uses [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT), a 5 second
+    /// reconnect delay and no delay between polls on a bus.
+    pub fn new() -> Self {
+        Self::with_source(Source::Serial)
+    }
+
+    /// Creates a manager opening the given mock buses by port (test-only).
+    #[doc(hidden)]
+    #[cfg(any(test, feature = "test-mock"))]
+    pub fn new_mock(buses: std::collections::HashMap<String, crate::client::MockBus>) -> Self {
+        Self::with_source(Source::Mock(buses))
+    }
+
+    fn with_source(source: Source) -> Self {
+        Self {
+            targets: Mutex::new(Vec::new()),
+            settings: Settings {
+                timeout: DEFAULT_TIMEOUT,
+                reconnect_delay: Duration::from_secs(5),
+            },
+            stagger: Duration::ZERO,
+            source,
+        }
+    }
+
+    /// Sets the timeout of each Modbus operation.
+    ///
+    /// Applies to ports opened afterwards.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.settings.timeout = timeout;
+        self
+    }
+
+    /// Sets how long to wait before reopening a port that failed to open.
+    pub fn with_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.settings.reconnect_delay = delay;
+        for handle in self.targets.get_mut().unwrap().iter_mut() {
+            handle.settings = self.settings;
+        }
+        self
+    }
+
+    /// Sets the pause between polls of controllers on the same port in
+    /// [`status_all`](Self::status_all).
+    pub fn with_stagger(mut self, stagger: Duration) -> Self {
+        self.stagger = stagger;
+        self
+    }
+
+    /// Adds a target.
+    ///
+    /// Does not open the port; that happens on the first request.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The address is out of range (1-254)
+    /// - The port and address or the alias are already managed
+    /// - The port is managed with a different baud rate
+    pub fn add(&self, target: Target) -> Result<()> {
+        if !(1..=254).contains(&target.address) {
+            return Err(Jpf4826Error::invalid_address(target.address));
+        }
+
+        let mut targets = self.targets.lock().unwrap();
+        let mut bus = None;
+        for handle in targets.iter() {
+            let existing = &handle.target;
+            if existing.port != target.port {
+                continue;
+            }
+            if existing.address == target.address {
+                return Err(Jpf4826Error::invalid_parameter(format!(
+                    "Controller {} is already managed",
+                    target.location()
+                )));
+            }
+            if existing.baud_rate != target.baud_rate {
+                return Err(Jpf4826Error::invalid_parameter(format!(
+                    "Port {} is managed at {} baud, not {}",
+                    target.port, existing.baud_rate, target.baud_rate
+                )));
+            }
+            bus = Some(handle.bus.clone());
+        }
+        if let Some(alias) = &target.alias {
+            if targets.iter().any(|handle| handle.target.matches(alias)) {
+                return Err(Jpf4826Error::invalid_parameter(format!(
+                    "Alias {} is already in use",
+                    alias
+                )));
+            }
+        }
+
+        let bus = bus.unwrap_or_else(|| {
+            Arc::new(Bus {
+                port: target.port.clone(),
+                baud_rate: target.baud_rate,
+                source: self.source.clone(),
+                state: tokio::sync::Mutex::new(BusState::default()),
+            })
+        });
+        log::debug!("Managing controller {}", target.name());
+        targets.push(ControllerHandle {
+            target,
+            bus,
+            settings: self.settings,
+        });
+        Ok(())
+    }
+
+    /// Removes the target with the given alias or `port:address`.
+    ///
+    /// The port is closed once no target or handle uses it anymore.
+    pub fn remove(&self, name: &str) -> Option<Target> {
+        let mut targets = self.targets.lock().unwrap();
+        let index = targets
+            .iter()
+            .position(|handle| handle.target.matches(name))?;
+        Some(targets.remove(index).target)
+    }
+
+    /// Returns the handle of the target with the given alias or
+    /// `port:address`.
+    pub fn get(&self, name: &str) -> Option<ControllerHandle> {
+        self.targets
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|handle| handle.target.matches(name))
+            .cloned()
+    }
+
+    /// Returns the managed targets in the order they were added.
+    pub fn targets(&self) -> Vec<Target> {
+        self.targets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|handle| handle.target.clone())
+            .collect()
+    }
+
+    /// Reads the status of every target, keyed by [`Target::name`].
+    ///
+    /// Ports are polled concurrently; controllers on the same port one after
+    /// another, with the stagger delay in between. A failing target only
+    /// affects its own entry.
+    pub async fn status_all(&self) -> BTreeMap<String, Result<ControllerStatus>> {
+        let handles = self.targets.lock().unwrap().clone();
+        fan_out(&handles, self.stagger, |handle| async move {
+            handle.status().await
+        })
+        .await
+    }
+
+    /// Returns a group of the targets with the given aliases or
+    /// `port:address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a name is not managed or names a target twice.
+    pub fn group(&self, names: &[&str]) -> Result<ControllerGroup> {
+        let mut handles: Vec<ControllerHandle> = Vec::with_capacity(names.len());
+        for name in names {
+            let handle = self.get(name).ok_or_else(|| {
+                Jpf4826Error::invalid_parameter(format!("Controller {} is not managed", name))
+            })?;
+            if handles.iter().any(|h| h.target == handle.target) {
+                return Err(Jpf4826Error::invalid_parameter(format!(
+                    "Controller {} is named twice",
+                    handle.target.name()
+                )));
+            }
+            handles.push(handle);
+        }
+        Ok(ControllerGroup::new(handles))
+    }
+}
+
+/// Runs `op` for every handle, keyed by [`Target::name`].
+///
+/// Ports run concurrently; controllers on the same port one after another,
+/// with `stagger` in between. An error only affects its own entry.
+pub(crate) async fn fan_out<T, F, Fut>(
+    handles: &[ControllerHandle],
+    stagger: Duration,
+    op: F,
+) -> BTreeMap<String, Result<T>>
+where
+    T: Send + 'static,
+    F: Fn(ControllerHandle) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<T>> + Send,
+{
+    // Group handles by bus, keeping the order they were added in
+    let mut groups: Vec<Vec<ControllerHandle>> = Vec::new();
+    for handle in handles {
+        match groups
+            .iter_mut()
+            .find(|group| Arc::ptr_eq(&group[0].bus, &handle.bus))
+        {
+            Some(group) => group.push(handle.clone()),
+            None => groups.push(vec![handle.clone()]),
+        }
+    }
+
+    let mut tasks = JoinSet::new();
+    for group in groups {
+        let op = op.clone();
+        tasks.spawn(async move {
+            let mut results = Vec::with_capacity(group.len());
+            for (i, handle) in group.into_iter().enumerate() {
+                if i > 0 && !stagger.is_zero() {
+                    tokio::time::sleep(stagger).await;
+                }
+                let name = handle.target.name();
+                results.push((name, op(handle).await));
+            }
+            results
+        });
+    }
+
+    let mut outcomes = BTreeMap::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(results) => outcomes.extend(results),
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => log::warn!("Bus task failed: {}", e),
+        }
+    }
+    outcomes
+}
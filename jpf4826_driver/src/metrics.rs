@@ -0,0 +1,210 @@
+//! Per-operation metrics hooks.
+//!
+//! A [`MetricsRecorder`] installed with
+//! [`Jpf4826Client::set_metrics_recorder`](crate::Jpf4826Client::set_metrics_recorder)
+//! is told when each read and write transaction starts and how it ended, so
+//! counters and latency histograms can be fed to Prometheus or any other
+//! metrics system without wrapping every call. Transactions are the same as
+//! in [`stats`](crate::stats): a retried request is one transaction, served
+//! cache hits and bus scans are none. Mock and serial backends report alike.
+//!
+//! Without a recorder the client skips the hooks entirely.
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::error::Jpf4826Error;
+
+/// How a transaction ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationOutcome {
+    /// The controller answered.
+    Success,
+    /// The controller did not answer in time.
+    Timeout,
+    /// The transaction failed otherwise, e.g. with a Modbus exception, a
+    /// malformed response or a serial error.
+    Error,
+}
+
+impl OperationOutcome {
+    /// Returns the outcome of a transaction that returned `result`.
+    pub fn of<T>(result: &Result<T, Jpf4826Error>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(err) if err.is_timeout() => Self::Timeout,
+            Err(_) => Self::Error,
+        }
+    }
+
+    /// Returns a lowercase label for the outcome, e.g. for a metric label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::metrics::OperationOutcome;
+    /// assert_eq!(OperationOutcome::Timeout.as_str(), "timeout");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Timeout => "timeout",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Receives a call at the start and end of each transaction.
+///
+/// Every method does nothing by default, so an implementation only
+/// overrides the ones it needs. The methods are called on the task
+/// performing the transaction and should return quickly.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # use jpf4826_driver::Jpf4826Client;
+/// # use jpf4826_driver::metrics::{MetricsRecorder, OperationOutcome};
+/// struct LogRecorder;
+///
+/// impl MetricsRecorder for LogRecorder {
+///     fn on_read_complete(&self, duration: Duration, outcome: OperationOutcome) {
+///         println!("read took {:?}: {}", duration, outcome.as_str());
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// client.set_metrics_recorder(Arc::new(LogRecorder));
+/// client.temperature().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Called before a read request is sent.
+    fn on_read_start(&self) {}
+
+    /// Called when a read transaction ends, after `duration`.
+    fn on_read_complete(&self, _duration: Duration, _outcome: OperationOutcome) {}
+
+    /// Called before a write request is sent, broadcasts included.
+    fn on_write_start(&self) {}
+
+    /// Called when a write transaction ends, after `duration`.
+    fn on_write_complete(&self, _duration: Duration, _outcome: OperationOutcome) {}
+}
+
+/// Recorder that ignores every call.
+///
+/// Installing it has the same effect as
+/// [`clear_metrics_recorder`](crate::Jpf4826Client::clear_metrics_recorder).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {}
+
+/// Recorder counting transactions by kind and outcome in atomics.
+///
+/// A minimal example of a [`MetricsRecorder`]; keep an `Arc` to it to
+/// read the counters while the client uses it.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use jpf4826_driver::Jpf4826Client;
+/// # use jpf4826_driver::metrics::{CountingRecorder, OperationOutcome};
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let counts = Arc::new(CountingRecorder::default());
+/// client.set_metrics_recorder(counts.clone());
+/// let _ = client.status().await;
+/// println!(
+///     "{} reads started, {} timed out",
+///     counts.reads_started(),
+///     counts.reads(OperationOutcome::Timeout)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CountingRecorder {
+    reads_started: AtomicU64,
+    writes_started: AtomicU64,
+    /// Completed reads, indexed by [`outcome_index`].
+    reads: [AtomicU64; 3],
+    /// Completed writes, indexed by [`outcome_index`].
+    writes: [AtomicU64; 3],
+    /// Total nanoseconds of completed transactions.
+    busy_nanos: AtomicU64,
+}
+
+impl CountingRecorder {
+    /// Returns the number of read transactions started.
+    pub fn reads_started(&self) -> u64 {
+        self.reads_started.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of write transactions started.
+    pub fn writes_started(&self) -> u64 {
+        self.writes_started.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of read transactions that ended with `outcome`.
+    pub fn reads(&self, outcome: OperationOutcome) -> u64 {
+        self.reads[outcome_index(outcome)].load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of write transactions that ended with `outcome`.
+    pub fn writes(&self, outcome: OperationOutcome) -> u64 {
+        self.writes[outcome_index(outcome)].load(Ordering::Relaxed)
+    }
+
+    /// Returns the summed duration of all completed transactions.
+    pub fn busy_time(&self) -> Duration {
+        Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed))
+    }
+
+    fn complete(&self, counters: &[AtomicU64; 3], duration: Duration, outcome: OperationOutcome) {
+        counters[outcome_index(outcome)].fetch_add(1, Ordering::Relaxed);
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        // The closure always returns Some, so the update cannot fail
+        let _ = self
+            .busy_nanos
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |total| {
+                Some(total.saturating_add(nanos))
+            });
+    }
+}
+
+impl MetricsRecorder for CountingRecorder {
+    fn on_read_start(&self) {
+        self.reads_started.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_read_complete(&self, duration: Duration, outcome: OperationOutcome) {
+        self.complete(&self.reads, duration, outcome);
+    }
+
+    fn on_write_start(&self) {
+        self.writes_started.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_write_complete(&self, duration: Duration, outcome: OperationOutcome) {
+        self.complete(&self.writes, duration, outcome);
+    }
+}
+
+fn outcome_index(outcome: OperationOutcome) -> usize {
+    match outcome {
+        OperationOutcome::Success => 0,
+        OperationOutcome::Timeout => 1,
+        OperationOutcome::Error => 2,
+    }
+}
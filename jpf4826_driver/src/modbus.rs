@@ -1,15 +1,59 @@
-//! Modbus-RTU communication layer.
+//! Modbus communication layer.
 //!
-//! This module provides the low-level Modbus-RTU protocol implementation
-//! over serial port connection.
+//! This module provides the low-level Modbus protocol implementation over
+//! a serial port connection (Modbus-RTU) or a TCP gateway (Modbus TCP).
+//!
+//! # Resynchronization
+//!
+//! A timed-out request is abandoned, but the controller may still answer.
+//! The late frame would be read as the answer to the next request, so after
+//! a timeout the next operation first reopens the port and discards pending
+//! input (or reconnects to the gateway). The same applies to a request
+//! whose future is dropped before the answer arrived, e.g. the losing
+//! branch of a `select!`.
+//!
+//! A transport supplied by the caller cannot be reopened. Its pending input
+//! is discarded instead; only with input flushing disabled is the link left
+//! unusable.
+//!
+//! A serial port that disappears is reopened according to the
+//! [`ReconnectPolicy`], see the [`reconnect`](crate::reconnect) module.
+//!
+//! # Retries
+//!
+//! A request that times out or fails on the transport can be sent again
+//! up to a configured number of times (none by default), independently of
+//! the client-level retries of
+//! [`Jpf4826Client::set_retries`](crate::Jpf4826Client::set_retries).
+//! Modbus exceptions are never repeated here: the controller heard the
+//! request and refused it.
+//!
+//! # Inter-frame Silence
+//!
+//! Modbus-RTU delimits frames by a silence of at least 3.5 character
+//! times. A request sent right after the previous response, as in a write
+//! followed by a read, can be taken for part of that frame, so each RTU
+//! request waits until the line has been quiet for the inter-frame delay
+//! (see [`inter_frame_delay`]). TCP gateways frame requests themselves and
+//! get no delay.
 
 // Rust guideline compliant 2026-01-27
 
+use crate::client::{DataBits, Parity, StopBits};
 use crate::error::{Jpf4826Error, Result};
-use std::time::Duration;
+use crate::frame::{FrameObserver, Framing, InputFlush, InspectedStream, ObserverSlot};
+use crate::health::{BusHealth, HealthCounters};
+use crate::reconnect::{is_port_lost, ReconnectPolicy};
+use crate::timeout::{TimeoutMode, TimeoutState};
+use std::borrow::Cow;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
 use tokio_modbus::client::Context;
 use tokio_modbus::prelude::*;
-use tokio_serial::SerialStream;
+use tokio_serial::{ClearBuffer, SerialPort, SerialStream};
 
 /// Default timeout for Modbus operations (10 seconds).
 ///
@@ -17,83 +61,605 @@ use tokio_serial::SerialStream;
 /// The timeout applies to each individual Modbus read/write operation.
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
-/// Modbus-RTU client for JPF4826 controller.
-pub struct ModbusRtuClient {
-    context: Context,
-    slave_addr: std::cell::Cell<u8>,
-    timeout: Duration,
+/// Shortest timeout accepted for Modbus operations (10 milliseconds).
+///
+/// A request and its answer take a few milliseconds on the line even at
+/// 38400 baud, so shorter timeouts would fail every operation.
+pub const MIN_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Rejects a fixed timeout below [`MIN_TIMEOUT`].
+pub(crate) fn check_timeout(timeout: Duration) -> Result<()> {
+    if timeout < MIN_TIMEOUT {
+        return Err(Jpf4826Error::invalid_parameter(format!(
+            "Timeout must be at least {:?}, got {:?}",
+            MIN_TIMEOUT, timeout
+        )));
+    }
+    Ok(())
+}
+
+/// Serial baud rate of the JPF4826.
+pub(crate) const BAUD_RATE: u32 = 9600;
+
+/// Baud rates accepted by [`Jpf4826Client::new_with_baud`](crate::Jpf4826Client::new_with_baud).
+pub const SUPPORTED_BAUD_RATES: [u32; 4] = [4800, 9600, 19200, 38400];
+
+/// Serial line settings of a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SerialSettings {
+    pub baud_rate: u32,
+    pub data_bits: DataBits,
+    pub parity: Parity,
+    pub stop_bits: StopBits,
+}
+
+impl std::fmt::Display for SerialSettings {
+    /// Formats the settings in the usual notation, e.g. `9600 8N1`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data_bits = match self.data_bits {
+            DataBits::Seven => 7,
+            DataBits::Eight => 8,
+        };
+        let parity = match self.parity {
+            Parity::None => 'N',
+            Parity::Even => 'E',
+            Parity::Odd => 'O',
+        };
+        let stop_bits = match self.stop_bits {
+            StopBits::One => 1,
+            StopBits::Two => 2,
+        };
+        write!(f, "{} {}{}{}", self.baud_rate, data_bits, parity, stop_bits)
+    }
+}
+
+impl Default for SerialSettings {
+    /// The JPF4826's standard 9600 8N1.
+    fn default() -> Self {
+        Self {
+            baud_rate: BAUD_RATE,
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+        }
+    }
+}
+
+/// Time devices are given to apply a broadcast write, the turnaround delay
+/// of the Modbus serial line specification.
+pub(crate) const BROADCAST_TURNAROUND: Duration = Duration::from_millis(200);
+
+/// Maximum number of registers a single Modbus read may request.
+pub(crate) const MAX_READ_COUNT: usize = 125;
+
+/// Maximum number of registers a single Modbus write may carry.
+pub(crate) const MAX_WRITE_COUNT: usize = 123;
+
+/// Function code of Write Multiple Registers.
+const WRITE_MULTIPLE_FUNCTION: u8 = 0x10;
+
+/// Fixed inter-frame delay above 19200 baud, per the Modbus serial line
+/// specification.
+const FAST_INTER_FRAME_DELAY: Duration = Duration::from_micros(1750);
+
+/// Returns the 3.5 character inter-frame silence at `baud_rate`.
+///
+/// A character takes 11 bits on the line. Above 19200 baud the
+/// specification fixes the delay at 1.75 ms.
+pub(crate) fn inter_frame_delay(baud_rate: u32) -> Duration {
+    if baud_rate == 0 || baud_rate > 19200 {
+        return FAST_INTER_FRAME_DELAY;
+    }
+    // 3.5 characters of 11 bits
+    Duration::from_micros(38_500_000 / u64::from(baud_rate))
+}
+
+/// Link to the controller.
+#[derive(Debug, Clone)]
+enum Transport {
+    /// Modbus-RTU on a serial port.
+    Serial {
+        port: String,
+        settings: SerialSettings,
+    },
+    /// Modbus TCP to an RS485-to-Ethernet gateway.
+    Tcp(SocketAddr),
+    /// Modbus-RTU on a stream supplied by the caller, which cannot be
+    /// reopened.
+    Attached,
+}
+
+impl Transport {
+    /// Opens the link, attaching a context addressing `slave_addr` whose
+    /// frames are reported to `observer` and whose stale input is discarded
+    /// according to `flush`.
+    ///
+    /// A TCP connection that is not established within `timeout` fails.
+    async fn open(
+        &self,
+        slave_addr: u8,
+        timeout: Duration,
+        observer: &ObserverSlot,
+        flush: &InputFlush,
+    ) -> Result<Context> {
+        match self {
+            Self::Serial { port, settings } => {
+                open_context(port, slave_addr, *settings, observer.clone(), flush.clone())
+            }
+            Self::Tcp(addr) => {
+                connect_tcp(*addr, slave_addr, timeout, observer.clone(), flush.clone()).await
+            }
+            Self::Attached => {
+                log::error!("Cannot reopen a supplied transport");
+                Err(Jpf4826Error::serial(
+                    "Supplied transport cannot be reopened after an abandoned request",
+                ))
+            }
+        }
+    }
+
+    /// Returns the inter-frame delay the link starts with.
+    fn inter_frame_delay(&self) -> Duration {
+        match self {
+            Self::Tcp(_) => Duration::ZERO,
+            Self::Serial { .. } | Self::Attached => inter_frame_delay(self.baud_rate()),
+        }
+    }
+
+    /// Returns the baud rate the timeout baseline is derived from.
+    ///
+    /// Behind a gateway or a supplied stream this is unknown, and assumed
+    /// to be the JPF4826 default.
+    fn baud_rate(&self) -> u32 {
+        match self {
+            Self::Serial { settings, .. } => settings.baud_rate,
+            Self::Tcp(_) | Self::Attached => BAUD_RATE,
+        }
+    }
+}
+
+impl std::fmt::Display for Transport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serial { port, .. } => write!(f, "{}", port),
+            Self::Tcp(addr) => write!(f, "tcp://{}", addr),
+            Self::Attached => write!(f, "supplied transport"),
+        }
+    }
+}
+
+/// Modbus client for JPF4826 controller, over a serial port (RTU) or a
+/// TCP gateway.
+///
+/// Every operation takes `&mut self` for the whole transaction, from the
+/// request to the end of the response, so only one is ever on the link.
+/// Tasks sharing a client do so through an async mutex
+/// ([`SharedClient`](crate::SharedClient), [`Jpf4826Bus`](crate::Jpf4826Bus)),
+/// which serializes their transactions.
+pub struct ModbusClient {
+    /// `None` while the link is closed after a failed resynchronization.
+    context: Option<Context>,
+    transport: Transport,
+    slave_addr: u8,
+    timeout: TimeoutState,
+    /// Set while a request is in flight and after a timeout, when a late
+    /// response may still arrive.
+    stale: bool,
+    reconnect: ReconnectPolicy,
+    gap: FrameGap,
+    /// How often a timed-out or failed request is sent again.
+    retries: u8,
+    /// Observer of the raw frames, shared with the open stream.
+    observer: ObserverSlot,
+    /// Whether the open stream discards stale input before each request.
+    flush: InputFlush,
+    /// Outcome of every request sent, retries included.
+    health: HealthCounters,
 }
 
-impl ModbusRtuClient {
+impl ModbusClient {
     /// Creates a new Modbus-RTU client connected to the specified serial port.
     ///
     /// # Arguments
     ///
     /// * `port` - Serial port path (e.g., "/dev/ttyUSB0", "COM3")
     /// * `slave_addr` - Modbus slave address (1-254)
+    /// * `serial` - Line settings, 9600 8N1 for the JPF4826 itself
     ///
     /// # Serial Port Configuration
     ///
-    /// - Baud rate: 9600
+    /// - Baud rate, parity and stop bits: `serial`
     /// - Data bits: 8
-    /// - Parity: None
-    /// - Stop bits: 1
+    /// - Flow control: None
     ///
     /// # Errors
     ///
     /// Returns error if:
     /// - Serial port cannot be opened
     /// - Port configuration fails
-    pub async fn new(port: &str, slave_addr: u8) -> Result<Self> {
+    pub(crate) async fn new(port: &str, slave_addr: u8, serial: SerialSettings) -> Result<Self> {
         log::debug!(
             "Initializing Modbus-RTU client: port={}, slave_addr={}",
             port,
             slave_addr
         );
+        let transport = Transport::Serial {
+            port: port.to_string(),
+            settings: serial,
+        };
+        let client = Self::open(transport, slave_addr).await?;
+        log::debug!("Modbus-RTU client initialized successfully");
+        Ok(client)
+    }
 
-        // Configure serial port according to JPF4826 specification
-        log::debug!("Configuring serial port: 9600 8N1, no flow control");
-        let builder = tokio_serial::new(port, 9600)
-            .data_bits(tokio_serial::DataBits::Eight)
-            .parity(tokio_serial::Parity::None)
-            .stop_bits(tokio_serial::StopBits::One)
-            .flow_control(tokio_serial::FlowControl::None);
-
-        // Open serial port
-        log::debug!("Opening serial port: {}", port);
-        let serial = SerialStream::open(&builder).map_err(|e| {
-            log::error!("Failed to open serial port {}: {}", port, e);
-            Jpf4826Error::serial(format!("Failed to open serial port {}: {}", port, e))
-        })?;
-        log::debug!("Serial port opened successfully");
+    /// Creates a new Modbus TCP client connected to a gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Socket address of the gateway
+    /// * `unit_id` - Unit identifier, the Modbus address of the controller
+    ///   on the gateway's RS485 bus (1-254)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the connection cannot be established within
+    /// [`DEFAULT_TIMEOUT`].
+    pub(crate) async fn new_tcp(addr: SocketAddr, unit_id: u8) -> Result<Self> {
+        log::debug!(
+            "Initializing Modbus TCP client: gateway={}, unit_id={}",
+            addr,
+            unit_id
+        );
+        let client = Self::open(Transport::Tcp(addr), unit_id).await?;
+        log::debug!("Modbus TCP client initialized successfully");
+        Ok(client)
+    }
 
-        // Create Modbus-RTU context
-        log::debug!("Attaching Modbus-RTU context to slave {}", slave_addr);
-        let context = rtu::attach_slave(serial, Slave(slave_addr));
+    /// Creates a new Modbus-RTU client on an already opened stream.
+    ///
+    /// Unlike a port opened by path, the stream cannot be reopened to
+    /// resynchronize. After a timed-out or dropped request, pending input
+    /// is discarded before the next request instead; with that turned off
+    /// (see [`set_flush_input`](Self::set_flush_input)), every following
+    /// operation fails with a serial error.
+    pub(crate) fn attach<T>(transport: T, slave_addr: u8) -> Self
+    where
+        T: AsyncRead + AsyncWrite + std::fmt::Debug + Unpin + Send + 'static,
+    {
+        log::debug!(
+            "Attaching Modbus-RTU context to supplied transport, slave_addr={}",
+            slave_addr
+        );
+        let observer = ObserverSlot::default();
+        let flush = InputFlush::default();
+        let transport =
+            InspectedStream::new(transport, observer.clone(), flush.clone(), Framing::Rtu);
+        Self {
+            context: Some(rtu::attach_slave(transport, Slave(slave_addr))),
+            transport: Transport::Attached,
+            slave_addr,
+            timeout: TimeoutState::new(TimeoutMode::default(), BAUD_RATE),
+            stale: false,
+            reconnect: ReconnectPolicy::disabled(),
+            gap: FrameGap::new(inter_frame_delay(BAUD_RATE)),
+            retries: 0,
+            observer,
+            flush,
+            health: HealthCounters::default(),
+        }
+    }
 
-        log::debug!("Modbus-RTU client initialized successfully");
+    async fn open(transport: Transport, slave_addr: u8) -> Result<Self> {
+        let timeout = TimeoutState::new(TimeoutMode::default(), transport.baud_rate());
+        let observer = ObserverSlot::default();
+        let flush = InputFlush::default();
+        let context = transport
+            .open(slave_addr, timeout.current(), &observer, &flush)
+            .await?;
         Ok(Self {
-            context,
-            slave_addr: std::cell::Cell::new(slave_addr),
-            timeout: DEFAULT_TIMEOUT,
+            context: Some(context),
+            gap: FrameGap::new(transport.inter_frame_delay()),
+            transport,
+            slave_addr,
+            timeout,
+            stale: false,
+            reconnect: ReconnectPolicy::disabled(),
+            retries: 0,
+            observer,
+            flush,
+            health: HealthCounters::default(),
         })
     }
 
-    /// Returns the current operation timeout.
+    /// Reopens the link if the last operation timed out or was dropped.
+    ///
+    /// Dropping the context discards bytes already buffered by the codec;
+    /// flushing the reopened port (or opening a new TCP connection)
+    /// discards the rest of a late response. A supplied stream is kept
+    /// open if it discards stale input before each request anyway.
+    async fn resynchronize(&mut self) -> Result<()> {
+        if !self.stale && self.context.is_some() {
+            return Ok(());
+        }
+        if matches!(self.transport, Transport::Attached)
+            && self.context.is_some()
+            && self.flush.is_enabled()
+        {
+            log::warn!(
+                "Discarding pending input of the supplied transport before the next request"
+            );
+            self.stale = false;
+            return Ok(());
+        }
+        log::warn!(
+            "Resynchronizing {} after an abandoned request, discarding pending input",
+            self.transport
+        );
+        // Close the port first, it is opened in exclusive mode
+        self.context = None;
+        self.context = Some(
+            self.transport
+                .open(
+                    self.slave_addr,
+                    self.timeout.current(),
+                    &self.observer,
+                    &self.flush,
+                )
+                .await?,
+        );
+        self.stale = false;
+        Ok(())
+    }
+
+    /// Reopens the serial port if `error` shows it disappeared and the
+    /// reconnect policy allows it.
+    ///
+    /// Returns true if the request should be sent again on the reopened
+    /// port.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serial error stating the number of attempts made if the
+    /// port could not be reopened.
+    async fn recover(&mut self, error: &tokio_modbus::Error) -> Result<bool> {
+        let tokio_modbus::Error::Transport(io_error) = error else {
+            return Ok(false);
+        };
+        if !self.reconnect.is_enabled()
+            || !matches!(self.transport, Transport::Serial { .. })
+            || !is_port_lost(io_error)
+        {
+            return Ok(false);
+        }
+
+        log::warn!("Lost {}: {}, reopening", self.transport, io_error);
+        // Close the port first, it is opened in exclusive mode
+        self.context = None;
+        let (transport, slave_addr, timeout, observer, flush) = (
+            &self.transport,
+            self.slave_addr,
+            self.timeout.current(),
+            &self.observer,
+            &self.flush,
+        );
+        match self
+            .reconnect
+            .reopen(|| transport.open(slave_addr, timeout, observer, flush))
+            .await
+        {
+            Ok(context) => {
+                self.context = Some(context);
+                self.stale = false;
+                Ok(true)
+            }
+            Err((attempts, last)) => {
+                log::error!(
+                    "Giving up on {} after {} reopen attempts",
+                    self.transport,
+                    attempts
+                );
+                Err(Jpf4826Error::serial(format!(
+                    "Lost serial port {} ({}); {} reopen attempts failed, last: {}",
+                    self.transport, io_error, attempts, last
+                )))
+            }
+        }
+    }
+
+    /// Returns the reconnect policy.
+    pub fn reconnect_policy(&self) -> ReconnectPolicy {
+        self.reconnect
+    }
+
+    /// Sets how a lost serial port is reopened.
+    pub fn set_reconnect_policy(&mut self, policy: ReconnectPolicy) {
+        self.reconnect = policy;
+    }
+
+    /// Returns the timeout applied to the next operation.
     pub fn timeout(&self) -> Duration {
-        self.timeout
+        self.timeout.current()
     }
 
-    /// Sets the timeout for Modbus operations.
+    /// Sets a fixed timeout for Modbus operations.
     ///
     /// This affects all subsequent read and write operations.
-    /// Zero-duration timeouts are ignored to prevent immediate timeout errors.
-    pub fn set_timeout(&mut self, timeout: Duration) {
-        if !timeout.is_zero() {
-            self.timeout = timeout;
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid parameter error if `timeout` is below
+    /// [`MIN_TIMEOUT`]; the timeout is left unchanged.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<()> {
+        check_timeout(timeout)?;
+        self.set_timeout_mode(TimeoutMode::Fixed(timeout));
+        Ok(())
+    }
+
+    /// Returns the timeout policy.
+    pub fn timeout_mode(&self) -> TimeoutMode {
+        self.timeout.mode()
+    }
+
+    /// Sets the timeout policy, discarding observed latencies.
+    ///
+    /// Fixed timeouts below [`MIN_TIMEOUT`] are ignored, as `set_timeout`
+    /// rejects them.
+    pub fn set_timeout_mode(&mut self, mode: TimeoutMode) {
+        if is_valid_mode(mode) {
+            self.timeout = TimeoutState::new(mode, self.transport.baud_rate());
+        } else {
+            log::warn!("Ignoring timeout mode {:?} below {:?}", mode, MIN_TIMEOUT);
         }
     }
 
+    /// Returns the observer of the raw frames, if any.
+    pub fn frame_observer(&self) -> Option<Arc<dyn FrameObserver>> {
+        self.observer.get()
+    }
+
+    /// Sets the observer of the raw frames, replacing any earlier one.
+    ///
+    /// Takes effect on the open stream and on streams reopened later.
+    pub fn set_frame_observer(&mut self, observer: Option<Arc<dyn FrameObserver>>) {
+        self.observer.set(observer);
+    }
+
+    /// Returns true if stale input is discarded before each request.
+    pub fn flush_input(&self) -> bool {
+        self.flush.is_enabled()
+    }
+
+    /// Sets whether stale input is discarded before each request.
+    ///
+    /// Takes effect on the open stream and on streams reopened later.
+    pub fn set_flush_input(&mut self, enabled: bool) {
+        self.flush.set(enabled);
+    }
+
+    /// Returns how often a timed-out or failed request is sent again.
+    pub fn retries(&self) -> u8 {
+        self.retries
+    }
+
+    /// Sets how often a request that timed out or failed on the transport
+    /// is sent again before the error is returned.
+    pub fn set_retries(&mut self, retries: u8) {
+        self.retries = retries;
+    }
+
+    /// Returns the outcome counts of the requests sent since the link was
+    /// opened, each retry counted.
+    pub fn bus_health(&self) -> BusHealth {
+        self.health.snapshot()
+    }
+
+    /// Returns the silence kept between the end of one operation and the
+    /// next request.
+    pub fn inter_frame_delay(&self) -> Duration {
+        self.gap.delay
+    }
+
+    /// Sets the silence kept between operations; zero disables it.
+    pub fn set_inter_frame_delay(&mut self, delay: Duration) {
+        self.gap.delay = delay;
+    }
+
+    /// Returns true if a request that ended with `outcome` should be sent
+    /// again, after `retried` earlier retries.
+    ///
+    /// Timeouts and transport failures are repeated up to
+    /// [`retries`](Self::set_retries) times; an answer, including a Modbus
+    /// exception, is not. A supplied stream cannot be resynchronized after
+    /// a timeout, so a timeout on it is not repeated either.
+    fn should_retry<T>(
+        &self,
+        outcome: &std::result::Result<tokio_modbus::Result<T>, Duration>,
+        retried: u8,
+    ) -> bool {
+        let failure = match outcome {
+            Ok(Ok(_)) => return false,
+            Ok(Err(e)) => e.to_string(),
+            Err(timeout) => format!("timed out after {:?}", timeout),
+        };
+        let stuck = self.stale && matches!(self.transport, Transport::Attached);
+        if retried >= self.retries || stuck {
+            return false;
+        }
+        log::warn!(
+            "Request to {} failed ({}), retrying ({}/{})",
+            self.transport,
+            failure,
+            retried + 1,
+            self.retries
+        );
+        true
+    }
+
+    /// Sends `request` and returns the controller's answer.
+    ///
+    /// Resynchronizes the link first, reopens it once after a transport
+    /// failure it can recover from, and repeats timeouts and transport
+    /// failures as set by [`set_retries`](Self::set_retries). `label` names
+    /// the request in logs and `action` in error messages, e.g. `"READ"`
+    /// and `"read registers at 0x0003"`; `addr` is the register reported
+    /// with a Modbus exception.
+    async fn transact(
+        &mut self,
+        label: &str,
+        action: &str,
+        addr: u16,
+        request: Request<'_>,
+    ) -> Result<Response> {
+        let mut attempts: u8 = 0;
+        let mut reopened = false;
+        let outcome = loop {
+            self.resynchronize().await?;
+            attempts = attempts.saturating_add(1);
+            let operation = connected(&mut self.context)?.call(request.clone());
+            let outcome = timed(&mut self.timeout, &mut self.stale, &mut self.gap, operation).await;
+            self.health.record(&outcome);
+            if let Ok(Err(e)) = &outcome {
+                if !reopened && self.recover(e).await? {
+                    reopened = true;
+                    continue;
+                }
+            }
+            if self.should_retry(&outcome, attempts - 1 - u8::from(reopened)) {
+                continue;
+            }
+            break outcome;
+        };
+        outcome
+            .map_err(|timeout| {
+                log::error!(
+                    "Modbus {} timed out at 0x{:04X} after {:?}{}",
+                    label,
+                    addr,
+                    timeout,
+                    after_attempts(attempts)
+                );
+                Jpf4826Error::timeout_after(timeout, attempts)
+            })?
+            .map_err(|e| {
+                log::error!("Modbus {} failed at 0x{:04X}: {}", label, addr, e);
+                Jpf4826Error::modbus(format!(
+                    "Failed to {}{}: {}",
+                    action,
+                    after_attempts(attempts),
+                    e
+                ))
+            })?
+            .map_err(|e| {
+                log::error!("Modbus exception at 0x{:04X}: {:?}", addr, e);
+                let write_multiple = matches!(request, Request::WriteMultipleRegisters(..));
+                if write_multiple && e == Exception::IllegalFunction {
+                    Jpf4826Error::function_rejected(WRITE_MULTIPLE_FUNCTION, addr)
+                } else {
+                    Jpf4826Error::exception(addr, format!("{:?}", e))
+                }
+            })
+    }
+
     /// Reads holding registers from the controller.
     ///
     /// # Arguments
@@ -104,36 +670,38 @@ impl ModbusRtuClient {
     /// # Errors
     ///
     /// Returns error if:
-    /// - Modbus communication fails
+    /// - Modbus communication fails or the response does not hold `count`
+    ///   registers
     /// - Operation times out
     pub async fn read_holding_registers(&mut self, addr: u16, count: u16) -> Result<Vec<u16>> {
+        let values = self.request_registers(addr, count).await?;
+        check_response_length(addr, usize::from(count), values.len())?;
+        Ok(values)
+    }
+
+    /// Sends a read request and returns the registers as received.
+    async fn request_registers(&mut self, addr: u16, count: u16) -> Result<Vec<u16>> {
         log::debug!(
             "Modbus READ: addr=0x{:04X}, count={}, timeout={:?}",
             addr,
             count,
-            self.timeout
+            self.timeout()
         );
 
-        let operation = self.context.read_holding_registers(addr, count);
-
-        let result = tokio::time::timeout(self.timeout, operation)
-            .await
-            .map_err(|_| {
-                log::error!(
-                    "Modbus READ timed out at 0x{:04X} after {:?}",
-                    addr,
-                    self.timeout
-                );
-                Jpf4826Error::timeout(self.timeout)
-            })?
-            .map_err(|e| {
-                log::error!("Modbus READ failed at 0x{:04X}: {}", addr, e);
-                Jpf4826Error::modbus(format!("Failed to read registers at 0x{:04X}: {}", addr, e))
-            })?
-            .map_err(|e| {
-                log::error!("Modbus exception at 0x{:04X}: {:?}", addr, e);
-                Jpf4826Error::modbus(format!("Modbus exception at 0x{:04X}: {:?}", addr, e))
-            })?;
+        // Issue the raw request: `Reader::read_holding_registers` asserts
+        // the response length, which check_response_length reports instead
+        let action = format!("read registers at 0x{:04X}", addr);
+        let result = self
+            .transact(
+                "READ",
+                &action,
+                addr,
+                Request::ReadHoldingRegisters(addr, count),
+            )
+            .await?;
+        let Response::ReadHoldingRegisters(result) = result else {
+            unreachable!("call() rejects mismatching responses");
+        };
 
         log::debug!(
             "Modbus READ success: addr=0x{:04X}, values={:04X?}",
@@ -143,6 +711,28 @@ impl ModbusRtuClient {
         Ok(result)
     }
 
+    /// Reads `buf.len()` holding registers into `buf`.
+    ///
+    /// Returns the number of registers filled, which is less than
+    /// `buf.len()` if the controller answered with a short response.
+    /// tokio-modbus decodes the response into its own buffer, which is
+    /// copied into `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails or the response is longer than requested
+    /// - Operation times out
+    pub async fn read_holding_registers_into(
+        &mut self,
+        addr: u16,
+        buf: &mut [u16],
+    ) -> Result<usize> {
+        // Callers validate the length against MAX_READ_COUNT
+        let values = self.request_registers(addr, buf.len() as u16).await?;
+        fill_registers(addr, &values, buf)
+    }
+
     /// Writes a single holding register to the controller.
     ///
     /// # Arguments
@@ -160,45 +750,403 @@ impl ModbusRtuClient {
             "Modbus WRITE: addr=0x{:04X}, value=0x{:04X}, timeout={:?}",
             addr,
             value,
-            self.timeout
+            self.timeout()
         );
 
-        let operation = self.context.write_single_register(addr, value);
-
-        tokio::time::timeout(self.timeout, operation)
-            .await
-            .map_err(|_| {
-                log::error!(
-                    "Modbus WRITE timed out at 0x{:04X} after {:?}",
-                    addr,
-                    self.timeout
-                );
-                Jpf4826Error::timeout(self.timeout)
-            })?
-            .map_err(|e| {
-                log::error!("Modbus WRITE failed at 0x{:04X}: {}", addr, e);
-                Jpf4826Error::modbus(format!("Failed to write register 0x{:04X}: {}", addr, e))
-            })?
-            .map_err(|e| {
-                log::error!("Modbus exception at 0x{:04X}: {:?}", addr, e);
-                Jpf4826Error::modbus(format!("Modbus exception at 0x{:04X}: {:?}", addr, e))
-            })?;
+        let action = format!("write register 0x{:04X}", addr);
+        self.transact(
+            "WRITE",
+            &action,
+            addr,
+            Request::WriteSingleRegister(addr, value),
+        )
+        .await?;
 
         log::debug!("Modbus WRITE success: addr=0x{:04X}", addr);
         Ok(())
     }
 
+    /// Writes consecutive holding registers in one transaction (function
+    /// 0x10).
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address of the first register
+    /// * `values` - 16-bit values to write, one per register
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Modbus communication fails
+    /// - Operation times out
+    pub async fn write_multiple_registers(&mut self, addr: u16, values: &[u16]) -> Result<()> {
+        log::debug!(
+            "Modbus WRITE MULTIPLE: addr=0x{:04X}, values={:04X?}, timeout={:?}",
+            addr,
+            values,
+            self.timeout()
+        );
+
+        let action = format!("write {} registers at 0x{:04X}", values.len(), addr);
+        let request = Request::WriteMultipleRegisters(addr, Cow::Borrowed(values));
+        self.transact("WRITE MULTIPLE", &action, addr, request)
+            .await?;
+
+        log::debug!(
+            "Modbus WRITE MULTIPLE success: addr=0x{:04X}, count={}",
+            addr,
+            values.len()
+        );
+        Ok(())
+    }
+
+    /// Writes a single holding register on every device on the bus.
+    ///
+    /// Sent to the broadcast address 0. Devices do not answer broadcasts,
+    /// so instead of waiting for a response this waits
+    /// [`BROADCAST_TURNAROUND`] for the devices to apply the write. Any
+    /// frame received meanwhile is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the request cannot be sent.
+    pub async fn write_broadcast(&mut self, addr: u16, value: u16) -> Result<()> {
+        log::debug!(
+            "Modbus BROADCAST WRITE: addr=0x{:04X}, value=0x{:04X}",
+            addr,
+            value
+        );
+
+        self.resynchronize().await?;
+        self.gap.wait().await;
+        let context = connected(&mut self.context)?;
+        context.set_slave(Slave::broadcast());
+        // Reopened with the configured address if dropped before the end
+        self.stale = true;
+        let outcome = tokio::time::timeout(
+            BROADCAST_TURNAROUND,
+            context.write_single_register(addr, value),
+        )
+        .await;
+        context.set_slave(Slave(self.slave_addr));
+        self.stale = false;
+        self.gap.mark();
+
+        if let Ok(Err(e)) = outcome {
+            log::error!("Modbus BROADCAST WRITE failed at 0x{:04X}: {}", addr, e);
+            return Err(Jpf4826Error::modbus(format!(
+                "Failed to broadcast register 0x{:04X}: {}",
+                addr, e
+            )));
+        }
+        log::debug!("Modbus BROADCAST WRITE sent: addr=0x{:04X}", addr);
+        Ok(())
+    }
+
+    /// Closes the link, releasing the port or connection before returning.
+    ///
+    /// A link already closed after a failed resynchronization is left as
+    /// is.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the transport fails while disconnecting or does not
+    /// disconnect within the timeout. The port is released either way.
+    pub async fn close(mut self) -> Result<()> {
+        let Some(mut context) = self.context.take() else {
+            return Ok(());
+        };
+        log::debug!("Closing {}", self.transport);
+        let timeout = self.timeout.current();
+        let outcome = tokio::time::timeout(timeout, context.disconnect()).await;
+        // Dropping the context closes the port handle
+        drop(context);
+        match outcome {
+            Ok(Ok(_)) => {
+                log::debug!("Closed {}", self.transport);
+                Ok(())
+            }
+            Ok(Err(e)) => {
+                log::error!("Failed to close {}: {}", self.transport, e);
+                Err(Jpf4826Error::serial(format!(
+                    "Failed to close {}: {}",
+                    self.transport, e
+                )))
+            }
+            Err(_) => {
+                log::error!("Closing {} timed out after {:?}", self.transport, timeout);
+                Err(Jpf4826Error::timeout(timeout))
+            }
+        }
+    }
+
     /// Returns the configured slave address.
-    #[allow(dead_code)]
     pub fn slave_addr(&self) -> u8 {
-        self.slave_addr.get()
+        self.slave_addr
     }
 
     /// Updates the configured slave address.
     ///
     /// This method should be called after successfully writing a new address
     /// to the controller's Modbus address register to keep the client in sync.
-    pub(crate) fn set_slave_addr(&self, addr: u8) {
-        self.slave_addr.set(addr);
+    /// Following requests are sent to `addr`.
+    pub(crate) fn set_slave_addr(&mut self, addr: u8) {
+        self.slave_addr = addr;
+        if let Some(context) = &mut self.context {
+            context.set_slave(Slave(addr));
+        }
+    }
+}
+
+/// Opens `port` with `serial` settings and attaches a Modbus-RTU context
+/// to it, reporting frames to `observer`.
+///
+/// Input already pending on the port is discarded.
+fn open_context(
+    port: &str,
+    slave_addr: u8,
+    serial: SerialSettings,
+    observer: ObserverSlot,
+    flush: InputFlush,
+) -> Result<Context> {
+    log::debug!(
+        "Configuring serial port: {} baud, data bits {:?}, parity {:?}, stop bits {:?}, no flow control",
+        serial.baud_rate,
+        serial.data_bits,
+        serial.parity,
+        serial.stop_bits
+    );
+    let builder = tokio_serial::new(port, serial.baud_rate)
+        .data_bits(match serial.data_bits {
+            DataBits::Seven => tokio_serial::DataBits::Seven,
+            DataBits::Eight => tokio_serial::DataBits::Eight,
+        })
+        .parity(match serial.parity {
+            Parity::None => tokio_serial::Parity::None,
+            Parity::Even => tokio_serial::Parity::Even,
+            Parity::Odd => tokio_serial::Parity::Odd,
+        })
+        .stop_bits(match serial.stop_bits {
+            StopBits::One => tokio_serial::StopBits::One,
+            StopBits::Two => tokio_serial::StopBits::Two,
+        })
+        .flow_control(tokio_serial::FlowControl::None);
+
+    // Open serial port
+    log::debug!("Opening serial port: {} at {}", port, serial);
+    let serial = SerialStream::open(&builder).map_err(|e| {
+        log::error!("Failed to open serial port {} at {}: {}", port, serial, e);
+        Jpf4826Error::serial(format!(
+            "Failed to open serial port {} at {}: {}",
+            port, serial, e
+        ))
+    })?;
+    serial.clear(ClearBuffer::Input).map_err(|e| {
+        log::error!("Failed to flush serial port {}: {}", port, e);
+        Jpf4826Error::serial(format!("Failed to flush serial port {}: {}", port, e))
+    })?;
+    log::debug!("Serial port opened successfully");
+
+    // Create Modbus-RTU context
+    log::debug!("Attaching Modbus-RTU context to slave {}", slave_addr);
+    let stream = InspectedStream::new(serial, observer, flush, Framing::Rtu);
+    Ok(rtu::attach_slave(stream, Slave(slave_addr)))
+}
+
+/// Opens a Modbus TCP connection to the gateway at `addr`, reporting
+/// frames to `observer`.
+async fn connect_tcp(
+    addr: SocketAddr,
+    unit_id: u8,
+    timeout: Duration,
+    observer: ObserverSlot,
+    flush: InputFlush,
+) -> Result<Context> {
+    log::debug!("Connecting to Modbus TCP gateway {}", addr);
+    let stream = tokio::time::timeout(timeout, TcpStream::connect(addr))
+        .await
+        .map_err(|_| {
+            log::error!("Connecting to {} timed out after {:?}", addr, timeout);
+            Jpf4826Error::timeout(timeout)
+        })?
+        .map_err(|e| {
+            log::error!("Failed to connect to {}: {}", addr, e);
+            Jpf4826Error::modbus(format!("Failed to connect to gateway {}: {}", addr, e))
+        })?;
+    log::debug!("Connected to gateway, addressing unit {}", unit_id);
+    let stream = InspectedStream::new(stream, observer, flush, Framing::Tcp);
+    Ok(tcp::attach_slave(stream, Slave(unit_id)))
+}
+
+/// Returns false for a fixed timeout below [`MIN_TIMEOUT`].
+pub(crate) fn is_valid_mode(mode: TimeoutMode) -> bool {
+    !matches!(mode, TimeoutMode::Fixed(timeout) if timeout < MIN_TIMEOUT)
+}
+
+/// Returns the open context, or an error if reopening the link failed.
+fn connected(context: &mut Option<Context>) -> Result<&mut Context> {
+    context
+        .as_mut()
+        .ok_or_else(|| Jpf4826Error::serial("Connection is closed"))
+}
+
+/// Runs `operation` under the current timeout and records its latency.
+///
+/// The request is not sent before the inter-frame silence of `gap` has
+/// passed, which does not count against the timeout. `stale` stays set
+/// unless the operation completes, so a timeout or a dropped future leads
+/// to a resynchronization before the next request. Returns the applied
+/// timeout if the operation did not complete in time.
+async fn timed<T>(
+    state: &mut TimeoutState,
+    stale: &mut bool,
+    gap: &mut FrameGap,
+    operation: impl std::future::Future<Output = T>,
+) -> std::result::Result<T, Duration> {
+    gap.wait().await;
+    let timeout = state.current();
+    let started = Instant::now();
+    *stale = true;
+    let outcome = tokio::time::timeout(timeout, operation).await;
+    gap.mark();
+    match outcome {
+        Ok(output) => {
+            *stale = false;
+            state.observe(started.elapsed());
+            Ok(output)
+        }
+        Err(_) => {
+            state.observe_timeout();
+            Err(timeout)
+        }
+    }
+}
+
+/// Silence kept on the line between two frames.
+#[derive(Debug, Clone, Copy)]
+struct FrameGap {
+    delay: Duration,
+    /// When the last operation ended.
+    last: Option<tokio::time::Instant>,
+}
+
+impl FrameGap {
+    fn new(delay: Duration) -> Self {
+        Self { delay, last: None }
+    }
+
+    /// Waits until `delay` has passed since the last operation ended.
+    async fn wait(&self) {
+        if let Some(last) = self.last {
+            // Returns at once if the line has been quiet long enough
+            tokio::time::sleep_until(last + self.delay).await;
+        }
+    }
+
+    /// Notes that an operation ended now.
+    fn mark(&mut self) {
+        self.last = Some(tokio::time::Instant::now());
+    }
+}
+
+/// Returns " after N attempts" for error messages if a request was sent
+/// more than once.
+fn after_attempts(attempts: u8) -> String {
+    if attempts > 1 {
+        format!(" after {} attempts", attempts)
+    } else {
+        String::new()
+    }
+}
+
+/// Rejects a read response at `addr` that does not hold `expected` registers.
+pub(crate) fn check_response_length(addr: u16, expected: usize, actual: usize) -> Result<()> {
+    if actual != expected {
+        log::error!(
+            "Modbus READ at 0x{:04X} returned {} registers, expected {}",
+            addr,
+            actual,
+            expected
+        );
+        return Err(Jpf4826Error::response_length(addr, expected, actual));
+    }
+    Ok(())
+}
+
+/// Copies a read response into `buf`.
+///
+/// Returns the number of registers copied. A response longer than `buf`
+/// does not match the request and is rejected.
+pub(crate) fn fill_registers(addr: u16, values: &[u16], buf: &mut [u16]) -> Result<usize> {
+    if values.len() > buf.len() {
+        return Err(Jpf4826Error::response_length(addr, buf.len(), values.len()));
+    }
+    buf[..values.len()].copy_from_slice(values);
+    Ok(values.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serial_settings_display() {
+        assert_eq!(SerialSettings::default().to_string(), "9600 8N1");
+        let settings = SerialSettings {
+            baud_rate: 19200,
+            data_bits: DataBits::Eight,
+            parity: Parity::Even,
+            stop_bits: StopBits::Two,
+        };
+        assert_eq!(settings.to_string(), "19200 8E2");
+        let settings = SerialSettings {
+            data_bits: DataBits::Seven,
+            ..settings
+        };
+        assert_eq!(settings.to_string(), "19200 7E2");
+    }
+
+    #[test]
+    fn test_fill_registers_full() {
+        let mut buf = [0; 3];
+
+        assert_eq!(fill_registers(0, &[1, 2, 3], &mut buf).unwrap(), 3);
+        assert_eq!(buf, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_fill_registers_short_response_fills_prefix() {
+        let mut buf = [9; 4];
+
+        assert_eq!(fill_registers(0, &[1, 2], &mut buf).unwrap(), 2);
+        assert_eq!(buf, [1, 2, 9, 9]);
+    }
+
+    #[test]
+    fn test_fill_registers_rejects_long_response() {
+        let mut buf = [9; 2];
+
+        let err = fill_registers(0x0007, &[1, 2, 3], &mut buf).unwrap_err();
+
+        assert_eq!(err.response_length_mismatch(), Some((2, 3)));
+        assert_eq!(buf, [9, 9]);
+    }
+
+    #[test]
+    fn test_inter_frame_delay() {
+        assert_eq!(inter_frame_delay(9600), Duration::from_micros(4010));
+        assert_eq!(inter_frame_delay(4800), Duration::from_micros(8020));
+        assert_eq!(inter_frame_delay(19200), Duration::from_micros(2005));
+        assert_eq!(inter_frame_delay(38400), FAST_INTER_FRAME_DELAY);
+    }
+
+    #[test]
+    fn test_check_response_length() {
+        assert!(check_response_length(0, 15, 15).is_ok());
+
+        let err = check_response_length(0, 15, 14).unwrap_err();
+        assert_eq!(err.response_length_mismatch(), Some((15, 14)));
+        let err = check_response_length(0, 1, 2).unwrap_err();
+        assert_eq!(err.response_length_mismatch(), Some((1, 2)));
     }
 }
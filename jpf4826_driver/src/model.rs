@@ -0,0 +1,167 @@
+//! Hardware revisions of the controller and their differences.
+//!
+//! The register map in [`registers`](crate::registers) describes the
+//! original JPF4826. Later revisions change parts of it; [`DeviceModel`]
+//! records those differences so the client can refuse operations a model
+//! does not support instead of writing a register with another meaning.
+//!
+//! | | [`Jpf4826`](DeviceModel::Jpf4826) | [`Jpf4826Rev2`](DeviceModel::Jpf4826Rev2) |
+//! |---|---|---|
+//! | PWM frequencies | 500 Hz to 25 kHz, `0x0000`-`0x0005` | 1 kHz to 25 kHz, `0x0000`-`0x0004` |
+//! | Second temperature input (`0x000F`) | no | yes |
+//!
+//! # Examples
+//!
+//! ```
+//! use jpf4826_driver::model::DeviceModel;
+//! use jpf4826_driver::registers::RegisterAddress;
+//! use jpf4826_driver::PwmFrequency;
+//!
+//! let model = DeviceModel::Jpf4826Rev2;
+//! assert!(!model.supports_pwm_frequency(PwmFrequency::Hz500));
+//! assert_eq!(model.pwm_register_value(PwmFrequency::Hz1000), Some(0x0000));
+//! assert!(model.has_register(RegisterAddress::SecondTemperature));
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use core::fmt;
+
+use crate::registers::RegisterAddress;
+use crate::types::PwmFrequency;
+
+/// PWM frequencies of the revision 2 board, by register value.
+const REV2_PWM_FREQUENCIES: [PwmFrequency; 5] = [
+    PwmFrequency::Hz1000,
+    PwmFrequency::Hz2000,
+    PwmFrequency::Hz5000,
+    PwmFrequency::Hz10000,
+    PwmFrequency::Hz25000,
+];
+
+/// PWM frequencies of the original board, by register value.
+const JPF4826_PWM_FREQUENCIES: [PwmFrequency; 6] = [
+    PwmFrequency::Hz500,
+    PwmFrequency::Hz1000,
+    PwmFrequency::Hz2000,
+    PwmFrequency::Hz5000,
+    PwmFrequency::Hz10000,
+    PwmFrequency::Hz25000,
+];
+
+/// Hardware revision of a controller.
+///
+/// Selected when creating a client (see
+/// [`ConnectOptions::model`](crate::ConnectOptions::model)); the controller
+/// does not report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DeviceModel {
+    /// The original JPF4826, as documented in the protocol specification.
+    #[default]
+    Jpf4826,
+    /// Revision 2 board: drops 500 Hz PWM, which shifts the register values
+    /// of the remaining frequencies down by one, and adds a second
+    /// temperature input at `0x000F`.
+    Jpf4826Rev2,
+}
+
+impl DeviceModel {
+    /// Returns the supported PWM frequencies, ordered by register value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::model::DeviceModel;
+    /// assert_eq!(DeviceModel::Jpf4826.pwm_frequencies().len(), 6);
+    /// assert_eq!(DeviceModel::Jpf4826Rev2.pwm_frequencies().len(), 5);
+    /// ```
+    pub fn pwm_frequencies(self) -> &'static [PwmFrequency] {
+        match self {
+            DeviceModel::Jpf4826 => &JPF4826_PWM_FREQUENCIES,
+            DeviceModel::Jpf4826Rev2 => &REV2_PWM_FREQUENCIES,
+        }
+    }
+
+    /// Returns true if the model can run its fans at `freq`.
+    pub fn supports_pwm_frequency(self, freq: PwmFrequency) -> bool {
+        self.pwm_frequencies().contains(&freq)
+    }
+
+    /// Converts `freq` to this model's PWM frequency register value.
+    ///
+    /// Returns `None` if the model does not support `freq`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::model::DeviceModel;
+    /// # use jpf4826_driver::PwmFrequency;
+    /// assert_eq!(DeviceModel::Jpf4826.pwm_register_value(PwmFrequency::Hz25000), Some(0x0005));
+    /// assert_eq!(DeviceModel::Jpf4826Rev2.pwm_register_value(PwmFrequency::Hz25000), Some(0x0004));
+    /// assert_eq!(DeviceModel::Jpf4826Rev2.pwm_register_value(PwmFrequency::Hz500), None);
+    /// ```
+    pub fn pwm_register_value(self, freq: PwmFrequency) -> Option<u16> {
+        self.pwm_frequencies()
+            .iter()
+            .position(|&supported| supported == freq)
+            .map(|index| index as u16)
+    }
+
+    /// Decodes this model's PWM frequency register value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::model::DeviceModel;
+    /// # use jpf4826_driver::PwmFrequency;
+    /// assert_eq!(DeviceModel::Jpf4826Rev2.pwm_from_register_value(0x0000), Some(PwmFrequency::Hz1000));
+    /// assert_eq!(DeviceModel::Jpf4826Rev2.pwm_from_register_value(0x0005), None);
+    /// ```
+    pub fn pwm_from_register_value(self, value: u16) -> Option<PwmFrequency> {
+        self.pwm_frequencies().get(usize::from(value)).copied()
+    }
+
+    /// Returns true if the model has a second temperature input.
+    pub fn has_second_sensor(self) -> bool {
+        matches!(self, DeviceModel::Jpf4826Rev2)
+    }
+
+    /// Returns true if `register` exists on this model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::model::DeviceModel;
+    /// # use jpf4826_driver::registers::RegisterAddress;
+    /// assert!(DeviceModel::Jpf4826.has_register(RegisterAddress::PwmFrequency));
+    /// assert!(!DeviceModel::Jpf4826.has_register(RegisterAddress::SecondTemperature));
+    /// ```
+    pub fn has_register(self, register: RegisterAddress) -> bool {
+        match register {
+            RegisterAddress::SecondTemperature => self.has_second_sensor(),
+            _ => true,
+        }
+    }
+
+    /// Returns the register at numeric address `addr` on this model, if any.
+    ///
+    /// Like [`RegisterAddress::from_addr`], but without registers the model
+    /// lacks.
+    pub fn register(self, addr: u16) -> Option<RegisterAddress> {
+        RegisterAddress::from_addr(addr).filter(|&register| self.has_register(register))
+    }
+
+    /// Returns the model's name.
+    pub fn name(self) -> &'static str {
+        match self {
+            DeviceModel::Jpf4826 => "JPF4826",
+            DeviceModel::Jpf4826Rev2 => "JPF4826 rev. 2",
+        }
+    }
+}
+
+impl fmt::Display for DeviceModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,363 @@
+//! MQTT publishing of controller status and command handling.
+//!
+//! [`MqttPublisher`] maps a [`ControllerStatus`] onto a fixed topic layout
+//! under a configurable base topic, keeps an availability topic up to date
+//! (backed by an MQTT last will), and turns messages received on the command
+//! topics into [`Jpf4826Client`] calls.
+//!
+//! # Topic Layout
+//!
+//! All topics live below `<base>` (default `jpf4826/<modbus address>`). State
+//! topics are published retained.
+//!
+//! | Topic                    | Payload                                             |
+//! |--------------------------|-----------------------------------------------------|
+//! | `<base>/availability`    | `online` or `offline` (last will)                   |
+//! | `<base>/status`          | Status JSON, same shape as `jpf4826ctl status --json` |
+//! | `<base>/temperature`     | Current temperature in °C, e.g. `31`                |
+//! | `<base>/fan/<n>/rpm`     | Fan `n` (1-4) speed in RPM, e.g. `1400`             |
+//! | `<base>/fan/<n>/status`  | `NORMAL` or `FAULT`                                 |
+//!
+//! # Command Topics
+//!
+//! | Topic                    | Payload                                     | Client call                     |
+//! |--------------------------|---------------------------------------------|---------------------------------|
+//! | `<base>/set/speed`       | `0`-`100`, or `auto`                        | `set_fan_speed` / `set_auto_speed` |
+//! | `<base>/set/eco`         | `on` or `off`                               | `set_eco`                       |
+//! | `<base>/set/thresholds`  | `{"low": 30, "high": 50}` (°C)              | `set_temperature_threshold`     |
+//! | `<base>/set/reset`       | anything                                    | `reset`                         |
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use jpf4826_driver::Jpf4826Client;
+//! # use jpf4826_driver::mqtt::{MqttPublisher, PublisherOptions};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+//! let (publisher, mut eventloop) = MqttPublisher::new(PublisherOptions::new("localhost", 1));
+//! tokio::spawn(async move { while eventloop.poll().await.is_ok() {} });
+//!
+//! publisher.announce_online().await?;
+//! publisher.publish_status(&client.status().await?).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use std::future::Future;
+use std::time::Duration;
+
+use rumqttc::{AsyncClient, EventLoop, LastWill, MqttOptions, QoS};
+
+use crate::{
+    error::{Jpf4826Error, Result},
+    types::{ControllerStatus, FanStatus, WorkMode},
+    Jpf4826Client,
+};
+
+/// Payload published to the availability topic while connected.
+pub const AVAILABILITY_ONLINE: &str = "online";
+/// Payload published to the availability topic on disconnect.
+pub const AVAILABILITY_OFFLINE: &str = "offline";
+
+/// Capacity of the request channel between [`AsyncClient`] and [`EventLoop`].
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Minimal MQTT client surface used by [`MqttPublisher`].
+///
+/// Implemented for [`rumqttc::AsyncClient`]. Implement it yourself to plug in
+/// another MQTT client or a test double.
+pub trait MqttTransport {
+    /// Publishes `payload` to `topic`.
+    fn publish(
+        &self,
+        topic: String,
+        payload: Vec<u8>,
+        retain: bool,
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Subscribes to `topic`, which may contain wildcards.
+    fn subscribe(&self, topic: String) -> impl Future<Output = Result<()>> + Send;
+}
+
+impl MqttTransport for AsyncClient {
+    async fn publish(&self, topic: String, payload: Vec<u8>, retain: bool) -> Result<()> {
+        AsyncClient::publish(self, topic, QoS::AtLeastOnce, retain, payload)
+            .await
+            .map_err(Jpf4826Error::mqtt)
+    }
+
+    async fn subscribe(&self, topic: String) -> Result<()> {
+        AsyncClient::subscribe(self, topic, QoS::AtLeastOnce)
+            .await
+            .map_err(Jpf4826Error::mqtt)
+    }
+}
+
+/// Connection and topic settings for [`MqttPublisher`].
+#[derive(Debug, Clone)]
+pub struct PublisherOptions {
+    /// Broker host name or IP address.
+    pub host: String,
+    /// Broker port.
+    pub port: u16,
+    /// MQTT client identifier.
+    pub client_id: String,
+    /// Prefix for every topic, without trailing slash.
+    pub base_topic: String,
+    /// Keep-alive interval.
+    pub keep_alive: Duration,
+}
+
+impl PublisherOptions {
+    /// Creates options for the controller at Modbus address `addr`.
+    ///
+    /// Uses port 1883, client ID `jpf4826-<addr>`, base topic
+    /// `jpf4826/<addr>` and a 30 second keep-alive.
+    pub fn new(host: impl Into<String>, addr: u8) -> Self {
+        Self {
+            host: host.into(),
+            port: 1883,
+            client_id: format!("jpf4826-{}", addr),
+            base_topic: format!("jpf4826/{}", addr),
+            keep_alive: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Command received on one of the command topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MqttCommand {
+    /// Manual fan speed percentage (0-100).
+    Speed(u8),
+    /// Return to temperature-based automatic speed.
+    AutoSpeed,
+    /// ECO mode on (shutdown) or off (minimum speed).
+    Eco(bool),
+    /// Start and full speed temperatures in Celsius.
+    Thresholds {
+        /// Start temperature.
+        low: i16,
+        /// Full speed temperature.
+        high: i16,
+    },
+    /// Controller reset.
+    Reset,
+}
+
+impl MqttCommand {
+    /// Executes the command against `client`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the underlying client call.
+    pub async fn apply(self, client: &mut Jpf4826Client) -> Result<()> {
+        match self {
+            MqttCommand::Speed(percent) => client.set_fan_speed(percent).await,
+            MqttCommand::AutoSpeed => client.set_auto_speed().await,
+            MqttCommand::Eco(true) => client.set_eco(WorkMode::Shutdown).await,
+            MqttCommand::Eco(false) => client.set_eco(WorkMode::MinimumSpeed).await,
+            MqttCommand::Thresholds { low, high } => {
+                client.set_temperature_threshold(low, high).await
+            }
+            MqttCommand::Reset => client.reset().await,
+        }
+    }
+}
+
+/// Publishes controller status to MQTT and dispatches incoming commands.
+pub struct MqttPublisher<T = AsyncClient> {
+    transport: T,
+    base_topic: String,
+}
+
+impl MqttPublisher<AsyncClient> {
+    /// Creates a publisher backed by [`rumqttc`].
+    ///
+    /// The availability topic is registered as the last will, so the broker
+    /// publishes `offline` if the connection drops. The returned
+    /// [`EventLoop`] must be polled for anything to be sent.
+    pub fn new(options: PublisherOptions) -> (Self, EventLoop) {
+        let base_topic = options.base_topic.trim_end_matches('/').to_string();
+        let mut mqtt_options = MqttOptions::new(options.client_id, options.host, options.port);
+        mqtt_options.set_keep_alive(options.keep_alive);
+        mqtt_options.set_last_will(LastWill::new(
+            format!("{}/availability", base_topic),
+            AVAILABILITY_OFFLINE,
+            QoS::AtLeastOnce,
+            true,
+        ));
+
+        let (client, eventloop) = AsyncClient::new(mqtt_options, CHANNEL_CAPACITY);
+        (Self::with_transport(client, base_topic), eventloop)
+    }
+}
+
+impl<T: MqttTransport> MqttPublisher<T> {
+    /// Creates a publisher on top of an existing transport.
+    ///
+    /// The caller is responsible for configuring the last will on the
+    /// transport's connection, if wanted.
+    pub fn with_transport(transport: T, base_topic: impl Into<String>) -> Self {
+        let base_topic = base_topic.into().trim_end_matches('/').to_string();
+        Self {
+            transport,
+            base_topic,
+        }
+    }
+
+    /// Returns the base topic.
+    pub fn base_topic(&self) -> &str {
+        &self.base_topic
+    }
+
+    /// Returns the underlying transport.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns the full topic for `suffix` below the base topic.
+    pub fn topic(&self, suffix: &str) -> String {
+        format!("{}/{}", self.base_topic, suffix)
+    }
+
+    /// Publishes `online` to the availability topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the transport rejects the message.
+    pub async fn announce_online(&self) -> Result<()> {
+        self.publish_retained("availability", AVAILABILITY_ONLINE.into())
+            .await
+    }
+
+    /// Publishes `offline` to the availability topic.
+    ///
+    /// Call before a clean shutdown; the last will only covers dropped
+    /// connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the transport rejects the message.
+    pub async fn announce_offline(&self) -> Result<()> {
+        self.publish_retained("availability", AVAILABILITY_OFFLINE.into())
+            .await
+    }
+
+    /// Publishes a status snapshot to the status, temperature and fan topics.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the transport rejects a message.
+    pub async fn publish_status(&self, status: &ControllerStatus) -> Result<()> {
+        let json = serde_json::to_string(status)
+            .map_err(|e| Jpf4826Error::mqtt(format!("Failed to encode status: {}", e)))?;
+        self.publish_retained("status", json).await?;
+        self.publish_retained("temperature", status.temperature_current.value.to_string())
+            .await?;
+
+        for fan in &status.fans {
+            let state = match fan.status {
+                FanStatus::Normal => "NORMAL",
+                FanStatus::Fault => "FAULT",
+            };
+            self.publish_retained(&format!("fan/{}/rpm", fan.index), fan.rpm.to_string())
+                .await?;
+            self.publish_retained(&format!("fan/{}/status", fan.index), state.into())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Subscribes to all command topics (`<base>/set/#`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the transport rejects the subscription.
+    pub async fn subscribe_commands(&self) -> Result<()> {
+        self.transport.subscribe(self.topic("set/#")).await
+    }
+
+    /// Parses a message into a command.
+    ///
+    /// Returns `Ok(None)` if `topic` is not a command topic of this publisher.
+    ///
+    /// # Errors
+    ///
+    /// Returns invalid parameter error if the payload is malformed.
+    pub fn parse_command(&self, topic: &str, payload: &[u8]) -> Result<Option<MqttCommand>> {
+        let Some(command) = topic
+            .strip_prefix(self.base_topic.as_str())
+            .and_then(|rest| rest.strip_prefix("/set/"))
+        else {
+            return Ok(None);
+        };
+        let payload = std::str::from_utf8(payload)
+            .map_err(|_| Jpf4826Error::invalid_parameter("MQTT payload is not UTF-8"))?
+            .trim();
+
+        let parsed = match command {
+            "speed" if payload.eq_ignore_ascii_case("auto") => MqttCommand::AutoSpeed,
+            "speed" => MqttCommand::Speed(payload.parse().map_err(|_| {
+                Jpf4826Error::invalid_parameter(format!(
+                    "Speed must be 0-100 or \"auto\", got {:?}",
+                    payload
+                ))
+            })?),
+            "eco" => match payload.to_ascii_lowercase().as_str() {
+                "on" | "true" | "1" => MqttCommand::Eco(true),
+                "off" | "false" | "0" => MqttCommand::Eco(false),
+                _ => {
+                    return Err(Jpf4826Error::invalid_parameter(format!(
+                        "ECO mode must be \"on\" or \"off\", got {:?}",
+                        payload
+                    )))
+                }
+            },
+            "thresholds" => {
+                #[derive(serde::Deserialize)]
+                struct Thresholds {
+                    low: i16,
+                    high: i16,
+                }
+                let Thresholds { low, high } = serde_json::from_str(payload).map_err(|e| {
+                    Jpf4826Error::invalid_parameter(format!("Invalid thresholds payload: {}", e))
+                })?;
+                MqttCommand::Thresholds { low, high }
+            }
+            "reset" => MqttCommand::Reset,
+            _ => return Ok(None),
+        };
+        Ok(Some(parsed))
+    }
+
+    /// Parses a message and applies the resulting command to `client`.
+    ///
+    /// Returns the executed command, or `None` if `topic` is not a command
+    /// topic of this publisher.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payload is malformed or the client call fails.
+    pub async fn handle_message(
+        &self,
+        topic: &str,
+        payload: &[u8],
+        client: &mut Jpf4826Client,
+    ) -> Result<Option<MqttCommand>> {
+        let Some(command) = self.parse_command(topic, payload)? else {
+            return Ok(None);
+        };
+        log::debug!("MQTT command on {}: {:?}", topic, command);
+        command.apply(client).await?;
+        Ok(Some(command))
+    }
+
+    async fn publish_retained(&self, suffix: &str, payload: String) -> Result<()> {
+        self.transport
+            .publish(self.topic(suffix), payload.into_bytes(), true)
+            .await
+    }
+}
@@ -0,0 +1,735 @@
+//! Named configuration profiles.
+//!
+//! A [`ControllerConfig`] lists the writable settings to change; settings
+//! left as `None` keep their current value. A [`Profile`] gives a
+//! configuration a name, and a [`ProfileSet`] is a validated collection of
+//! profiles loaded from any serde format:
+//!
+//! ```json
+//! {
+//!   "profiles": [
+//!     {
+//!       "name": "night",
+//!       "config": {
+//!         "thresholds": {"low": 40, "high": 60},
+//!         "work_mode": "Shutdown",
+//!         "pwm_frequency": {"value": 10000, "unit": "Hz"}
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Profiles are applied with
+//! [`Jpf4826Client::apply_profile`](crate::Jpf4826Client::apply_profile).
+
+// Rust guideline compliant 2026-10-16
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use crate::model::DeviceModel;
+use crate::registers::RegisterAddress;
+use crate::types::{ControllerStatus, OperatingMode, PwmFrequency, WorkMode};
+
+/// Lowest threshold temperature accepted by the controller, in Celsius.
+const THRESHOLD_MIN: i16 = -20;
+/// Highest threshold temperature accepted by the controller, in Celsius.
+const THRESHOLD_MAX: i16 = 120;
+
+/// Factory start temperature, in Celsius.
+pub const FACTORY_LOW_THRESHOLD_C: i16 = 30;
+/// Factory full speed temperature, in Celsius.
+pub const FACTORY_HIGH_THRESHOLD_C: i16 = 50;
+/// Factory work mode.
+pub const FACTORY_WORK_MODE: WorkMode = WorkMode::MinimumSpeed;
+/// Factory number of fans.
+pub const FACTORY_FAN_COUNT: u8 = 4;
+/// Factory PWM frequency.
+pub const FACTORY_PWM_FREQUENCY: PwmFrequency = PwmFrequency::Hz25000;
+/// Factory speed control mode.
+pub const FACTORY_MODE: OperatingMode = OperatingMode::Temperature;
+/// Factory Modbus address.
+pub const FACTORY_ADDRESS: u8 = 1;
+
+/// Start and full speed temperatures in Celsius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Thresholds {
+    /// Temperature at which fans start (-20 to 120).
+    pub low: i16,
+    /// Temperature at which fans reach full speed (-20 to 120).
+    pub high: i16,
+}
+
+/// Writable controller settings to apply together.
+///
+/// The [`Default`] configuration sets nothing, so `..ControllerConfig::default()`
+/// fills in "keep the current value". The factory settings are
+/// [`ControllerConfig::factory`], and a controller's current settings are
+/// read with [`Jpf4826Client::get_config`](crate::Jpf4826Client::get_config).
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::profile::{ControllerConfig, Thresholds};
+/// # use jpf4826_driver::types::WorkMode;
+/// let config = ControllerConfig {
+///     thresholds: Some(Thresholds { low: 30, high: 50 }),
+///     work_mode: Some(WorkMode::MinimumSpeed),
+///     ..ControllerConfig::default()
+/// };
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ControllerConfig {
+    /// Temperature thresholds (register 0x0004).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thresholds: Option<Thresholds>,
+    /// ECO/work mode (register 0x0005).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub work_mode: Option<WorkMode>,
+    /// Number of fans, 0 disables fault detection (register 0x0006).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fan_count: Option<u8>,
+    /// PWM frequency (register 0x000B).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub pwm_frequency: Option<PwmFrequency>,
+    /// Automatic or manual speed control (register 0x0003).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub speed: Option<SpeedSetting>,
+    /// Modbus address (register 0x0002), written last.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub modbus_address: Option<u8>,
+}
+
+/// Speed control selected through register 0x0003.
+///
+/// Serialized as `"auto"` or `{"manual": 40}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum SpeedSetting {
+    /// Automatic, temperature-based speed control.
+    Auto,
+    /// Fixed speed percentage (0-100).
+    Manual(u8),
+}
+
+impl SpeedSetting {
+    /// Converts to Modbus register value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::profile::SpeedSetting;
+    /// assert_eq!(SpeedSetting::Auto.to_register_value(), 0xFFFF);
+    /// assert_eq!(SpeedSetting::Manual(40).to_register_value(), 40);
+    /// ```
+    pub fn to_register_value(self) -> u16 {
+        match self {
+            SpeedSetting::Auto => 0xFFFF,
+            SpeedSetting::Manual(speed) => u16::from(speed),
+        }
+    }
+
+    /// Returns the setting a write of `value` to register 0x0003 selects.
+    ///
+    /// The inverse of [`to_register_value`](Self::to_register_value). Like
+    /// [`OperatingMode::from_written_value`](crate::OperatingMode::from_written_value),
+    /// it does not apply to values read back: in temperature mode the
+    /// register reads the calculated speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::profile::SpeedSetting;
+    /// assert_eq!(SpeedSetting::from_written_value(0xFFFF), Some(SpeedSetting::Auto));
+    /// assert_eq!(SpeedSetting::from_written_value(40), Some(SpeedSetting::Manual(40)));
+    /// assert_eq!(SpeedSetting::from_written_value(101), None);
+    /// ```
+    pub fn from_written_value(value: u16) -> Option<Self> {
+        match value {
+            0xFFFF => Some(SpeedSetting::Auto),
+            0..=100 => Some(SpeedSetting::Manual(value as u8)),
+            _ => None,
+        }
+    }
+}
+
+impl ControllerConfig {
+    /// Returns the settings the controller ships with, from the
+    /// `FACTORY_*` constants.
+    ///
+    /// Speed control mode ([`FACTORY_MODE`]) and address
+    /// ([`FACTORY_ADDRESS`]) are not persistent settings and are left
+    /// unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::profile::{ControllerConfig, Thresholds};
+    /// let factory = ControllerConfig::factory();
+    /// assert_eq!(factory.thresholds, Some(Thresholds { low: 30, high: 50 }));
+    /// assert_eq!(factory.fan_count, Some(4));
+    /// ```
+    pub const fn factory() -> Self {
+        Self {
+            thresholds: Some(Thresholds {
+                low: FACTORY_LOW_THRESHOLD_C,
+                high: FACTORY_HIGH_THRESHOLD_C,
+            }),
+            work_mode: Some(FACTORY_WORK_MODE),
+            fan_count: Some(FACTORY_FAN_COUNT),
+            pwm_frequency: Some(FACTORY_PWM_FREQUENCY),
+            speed: None,
+            modbus_address: None,
+        }
+    }
+
+    /// Writes the configuration as pretty-printed JSON, e.g. to back up a
+    /// controller's settings.
+    ///
+    /// Settings left as `None` are omitted. Requires the `json` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let config = client.get_config().await?;
+    /// config.to_writer(std::fs::File::create("controller.json")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration file error (see
+    /// [`Jpf4826Error::is_config_file`](crate::Jpf4826Error::is_config_file))
+    /// if writing fails.
+    #[cfg(feature = "json")]
+    pub fn to_writer(&self, mut writer: impl std::io::Write) -> crate::Result<()> {
+        serde_json::to_writer_pretty(&mut writer, self)
+            .map_err(crate::Jpf4826Error::config_file)?;
+        writeln!(writer).map_err(crate::Jpf4826Error::config_file)
+    }
+
+    /// Reads a configuration written by [`to_writer`](Self::to_writer) or
+    /// by hand, and validates it.
+    ///
+    /// Unknown fields are rejected rather than ignored, so a misspelt
+    /// setting is not silently left out of a restore. Requires the `json`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::profile::ControllerConfig;
+    /// let config = ControllerConfig::from_reader(r#"{"fan_count": 2}"#.as_bytes()).unwrap();
+    /// assert_eq!(config.fan_count, Some(2));
+    ///
+    /// let err = ControllerConfig::from_reader(r#"{"fans": 2}"#.as_bytes()).unwrap_err();
+    /// assert!(err.to_string().contains("unknown field `fans`"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration file error (see
+    /// [`Jpf4826Error::is_config_file`](crate::Jpf4826Error::is_config_file))
+    /// if reading fails, the JSON is malformed or has unknown fields, or a
+    /// setting is out of range (see [`validate`](Self::validate)).
+    #[cfg(feature = "json")]
+    pub fn from_reader(reader: impl std::io::Read) -> crate::Result<Self> {
+        let config: Self =
+            serde_json::from_reader(reader).map_err(crate::Jpf4826Error::config_file)?;
+        config
+            .validate()
+            .map_err(crate::Jpf4826Error::config_file)?;
+        Ok(config)
+    }
+
+    /// Checks the settings against the ranges the controller accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a threshold is out of range, `high` is not above
+    /// `low`, the fan count is above 4, the manual speed is above 100 or the
+    /// address is outside 1-254.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(Thresholds { low, high }) = self.thresholds {
+            for value in [low, high] {
+                if !(THRESHOLD_MIN..=THRESHOLD_MAX).contains(&value) {
+                    return Err(ConfigError::ThresholdOutOfRange(value));
+                }
+            }
+            if high <= low {
+                return Err(ConfigError::InvertedThresholds { low, high });
+            }
+        }
+        if let Some(count) = self.fan_count.filter(|&count| count > 4) {
+            return Err(ConfigError::FanCount(count));
+        }
+        if let Some(SpeedSetting::Manual(speed)) = self.speed {
+            if speed > 100 {
+                return Err(ConfigError::Speed(speed));
+            }
+        }
+        match self.modbus_address {
+            Some(addr) if !(1..=254).contains(&addr) => Err(ConfigError::ModbusAddress(addr)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Compares the settings against a status read by
+    /// [`Jpf4826Client::status`](crate::Jpf4826Client::status).
+    ///
+    /// Returns every setting that differs, in register order. Settings left
+    /// as `None` are not checked, and neither is the speed, which the status
+    /// does not include. Thresholds are compared in Celsius, so
+    /// `status` must not have been converted to another unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::profile::{ConfigMismatch, ControllerConfig};
+    /// # use jpf4826_driver::types::ControllerStatus;
+    /// # let mut registers = [0u16; 15];
+    /// # registers[6] = 2;
+    /// # let status = ControllerStatus::from_registers(&registers);
+    /// let expected = ControllerConfig {
+    ///     fan_count: Some(4),
+    ///     ..ControllerConfig::default()
+    /// };
+    /// assert_eq!(
+    ///     expected.verify(&status),
+    ///     vec![ConfigMismatch::FanCount { expected: 4, actual: 2 }]
+    /// );
+    /// ```
+    pub fn verify(&self, status: &ControllerStatus) -> Vec<ConfigMismatch> {
+        let mut mismatches = Vec::new();
+        if let Some(expected) = self.modbus_address {
+            if status.modbus_address != expected {
+                mismatches.push(ConfigMismatch::ModbusAddress {
+                    expected,
+                    actual: status.modbus_address,
+                });
+            }
+        }
+        if let Some(expected) = self.thresholds {
+            let actual = Thresholds {
+                low: status.temperature_low_threshold.value,
+                high: status.temperature_high_threshold.value,
+            };
+            if actual != expected {
+                mismatches.push(ConfigMismatch::Thresholds { expected, actual });
+            }
+        }
+        if let Some(expected) = self.work_mode {
+            if status.work_mode != expected {
+                mismatches.push(ConfigMismatch::WorkMode {
+                    expected,
+                    actual: status.work_mode,
+                });
+            }
+        }
+        if let Some(expected) = self.fan_count {
+            if status.fan_count != expected {
+                mismatches.push(ConfigMismatch::FanCount {
+                    expected,
+                    actual: status.fan_count,
+                });
+            }
+        }
+        if let Some(expected) = self.pwm_frequency {
+            if status.pwm_frequency != expected {
+                mismatches.push(ConfigMismatch::PwmFrequency {
+                    expected,
+                    actual: status.pwm_frequency,
+                });
+            }
+        }
+        mismatches
+    }
+
+    /// Returns the persistent register values this configuration writes on
+    /// `model`, in address order.
+    ///
+    /// Speed and address are left out, as they are written after the rest.
+    /// So is a PWM frequency the model does not support; callers reject it
+    /// first.
+    #[cfg(feature = "std")]
+    pub(crate) fn register_values(&self, model: DeviceModel) -> Vec<(RegisterAddress, u16)> {
+        use crate::conversions::encode_combined_temperature;
+
+        let mut values = Vec::new();
+        if let Some(Thresholds { low, high }) = self.thresholds {
+            values.push((
+                RegisterAddress::CombinedTemperature,
+                encode_combined_temperature(low, high),
+            ));
+        }
+        if let Some(mode) = self.work_mode {
+            values.push((RegisterAddress::WorkMode, mode.to_register_value()));
+        }
+        if let Some(count) = self.fan_count {
+            values.push((RegisterAddress::FanQuantity, u16::from(count)));
+        }
+        if let Some(value) = self
+            .pwm_frequency
+            .and_then(|freq| model.pwm_register_value(freq))
+        {
+            values.push((RegisterAddress::PwmFrequency, value));
+        }
+        values
+    }
+}
+
+/// A setting whose value differs from the expected one, as reported by
+/// [`ControllerConfig::verify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigMismatch {
+    /// The Modbus address differs.
+    ModbusAddress {
+        /// Expected address.
+        expected: u8,
+        /// Address of the controller.
+        actual: u8,
+    },
+    /// Start and full speed temperatures differ.
+    Thresholds {
+        /// Expected thresholds.
+        expected: Thresholds,
+        /// Thresholds of the controller.
+        actual: Thresholds,
+    },
+    /// The work mode differs.
+    WorkMode {
+        /// Expected work mode.
+        expected: WorkMode,
+        /// Work mode of the controller.
+        actual: WorkMode,
+    },
+    /// The number of fans differs.
+    FanCount {
+        /// Expected number of fans.
+        expected: u8,
+        /// Number of fans configured on the controller.
+        actual: u8,
+    },
+    /// The PWM frequency differs.
+    PwmFrequency {
+        /// Expected frequency.
+        expected: PwmFrequency,
+        /// Frequency of the controller.
+        actual: PwmFrequency,
+    },
+}
+
+impl fmt::Display for ConfigMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigMismatch::ModbusAddress { expected, actual } => {
+                write!(f, "Modbus address is {}, expected {}", actual, expected)
+            }
+            ConfigMismatch::Thresholds { expected, actual } => write!(
+                f,
+                "Thresholds are {}-{}°C, expected {}-{}°C",
+                actual.low, actual.high, expected.low, expected.high
+            ),
+            ConfigMismatch::WorkMode { expected, actual } => {
+                write!(f, "Work mode is {:?}, expected {:?}", actual, expected)
+            }
+            ConfigMismatch::FanCount { expected, actual } => {
+                write!(f, "Fan count is {}, expected {}", actual, expected)
+            }
+            ConfigMismatch::PwmFrequency { expected, actual } => write!(
+                f,
+                "PWM frequency is {} Hz, expected {} Hz",
+                actual.to_hz(),
+                expected.to_hz()
+            ),
+        }
+    }
+}
+
+/// Reason a configuration or profile document was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A threshold is outside -20 to 120°C.
+    ThresholdOutOfRange(i16),
+    /// The full speed temperature is not above the start temperature.
+    InvertedThresholds {
+        /// Start temperature.
+        low: i16,
+        /// Full speed temperature.
+        high: i16,
+    },
+    /// The fan count is above 4.
+    FanCount(u8),
+    /// The manual speed is above 100%.
+    Speed(u8),
+    /// The Modbus address is outside 1-254.
+    ModbusAddress(u8),
+    /// A profile has an empty name.
+    EmptyName,
+    /// Two profiles share a name.
+    DuplicateProfile(String),
+    /// A profile has an invalid configuration.
+    InvalidProfile {
+        /// Name of the profile.
+        name: String,
+        /// What is wrong with its configuration.
+        source: Box<ConfigError>,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::ThresholdOutOfRange(value) => write!(
+                f,
+                "Temperature {}°C out of range ({} to {})",
+                value, THRESHOLD_MIN, THRESHOLD_MAX
+            ),
+            ConfigError::InvertedThresholds { low, high } => write!(
+                f,
+                "High temperature {}°C must be greater than low temperature {}°C",
+                high, low
+            ),
+            ConfigError::FanCount(count) => write!(f, "Fan count {} out of range (0-4)", count),
+            ConfigError::Speed(speed) => write!(f, "Manual speed {}% out of range (0-100)", speed),
+            ConfigError::ModbusAddress(addr) => {
+                write!(f, "Modbus address {} out of range (1-254)", addr)
+            }
+            ConfigError::EmptyName => write!(f, "Profile name must not be empty"),
+            ConfigError::DuplicateProfile(name) => {
+                write!(f, "Profile '{}' is defined more than once", name)
+            }
+            ConfigError::InvalidProfile { name, source } => {
+                write!(f, "Profile '{}': {}", name, source)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
+
+/// A named controller configuration.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::profile::Profile;
+/// # use jpf4826_driver::types::WorkMode;
+/// let quiet = Profile::builtin("quiet").unwrap();
+/// assert_eq!(quiet.config.work_mode, Some(WorkMode::Shutdown));
+/// assert!(Profile::builtin("turbo").is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Profile {
+    /// Name used to select the profile.
+    pub name: String,
+    /// Settings applied by the profile.
+    pub config: ControllerConfig,
+}
+
+impl Profile {
+    /// Creates a profile.
+    pub fn new(name: impl Into<String>, config: ControllerConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+        }
+    }
+
+    /// Low noise: fans start late and stop entirely when cool.
+    ///
+    /// Thresholds 35-55°C, shutdown work mode, 10 kHz PWM.
+    pub fn quiet() -> Self {
+        Self::new(
+            "quiet",
+            ControllerConfig {
+                thresholds: Some(Thresholds { low: 35, high: 55 }),
+                work_mode: Some(WorkMode::Shutdown),
+                fan_count: None,
+                pwm_frequency: Some(PwmFrequency::Hz10000),
+                speed: None,
+                modbus_address: None,
+            },
+        )
+    }
+
+    /// Maximum cooling: fans start early and never stop.
+    ///
+    /// Thresholds 25-40°C, minimum speed work mode, 25 kHz PWM.
+    pub fn performance() -> Self {
+        Self::new(
+            "performance",
+            ControllerConfig {
+                thresholds: Some(Thresholds { low: 25, high: 40 }),
+                work_mode: Some(WorkMode::MinimumSpeed),
+                fan_count: None,
+                pwm_frequency: Some(PwmFrequency::Hz25000),
+                speed: None,
+                modbus_address: None,
+            },
+        )
+    }
+
+    /// Returns the built-in profiles.
+    pub fn builtins() -> Vec<Profile> {
+        vec![Self::quiet(), Self::performance()]
+    }
+
+    /// Returns the built-in profile called `name`.
+    pub fn builtin(name: &str) -> Option<Profile> {
+        Self::builtins()
+            .into_iter()
+            .find(|profile| profile.name == name)
+    }
+}
+
+/// Validated collection of user-defined profiles.
+///
+/// Deserializing checks every configuration and rejects empty or duplicate
+/// names, so a loaded set only holds profiles that can be applied.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::profile::ProfileSet;
+/// let set: ProfileSet = serde_json::from_str(
+///     r#"{"profiles": [{"name": "night", "config": {"fan_count": 2}}]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(set.get("night").unwrap().config.fan_count, Some(2));
+/// // Built-in profiles are found unless the document overrides them
+/// assert_eq!(set.resolve("quiet").unwrap().name, "quiet");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ProfileDocument"))]
+pub struct ProfileSet {
+    profiles: Vec<Profile>,
+}
+
+/// Unvalidated shape of a profile document.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileDocument {
+    profiles: Vec<Profile>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ProfileDocument> for ProfileSet {
+    type Error = ConfigError;
+
+    fn try_from(document: ProfileDocument) -> Result<Self, Self::Error> {
+        Self::new(document.profiles)
+    }
+}
+
+impl ProfileSet {
+    /// Creates a set from `profiles`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a name is empty or repeated, or a configuration is
+    /// invalid.
+    pub fn new(profiles: Vec<Profile>) -> Result<Self, ConfigError> {
+        for (i, profile) in profiles.iter().enumerate() {
+            if profile.name.is_empty() {
+                return Err(ConfigError::EmptyName);
+            }
+            if profiles[..i].iter().any(|p| p.name == profile.name) {
+                return Err(ConfigError::DuplicateProfile(profile.name.clone()));
+            }
+            profile
+                .config
+                .validate()
+                .map_err(|err| ConfigError::InvalidProfile {
+                    name: profile.name.clone(),
+                    source: Box::new(err),
+                })?;
+        }
+        Ok(Self { profiles })
+    }
+
+    /// Returns the profile called `name` in this set.
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Returns the profile called `name`, falling back to the built-ins.
+    pub fn resolve(&self, name: &str) -> Option<Profile> {
+        self.get(name).cloned().or_else(|| Profile::builtin(name))
+    }
+
+    /// Returns the profiles in document order.
+    pub fn iter(&self) -> impl Iterator<Item = &Profile> {
+        self.profiles.iter()
+    }
+}
+
+/// Outcome of applying a [`ControllerConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplyReport {
+    /// Registers that were written, in the order written.
+    pub written: Vec<RegisterAddress>,
+    /// Registers that already held the requested value and were skipped.
+    pub unchanged: Vec<RegisterAddress>,
+    /// Number of write transactions used.
+    pub transactions: usize,
+    /// Registers left unwritten because the operation was cancelled (see
+    /// [`Jpf4826Client::apply_config_until`]).
+    ///
+    /// [`Jpf4826Client::apply_config_until`]: crate::Jpf4826Client::apply_config_until
+    pub cancelled: Vec<RegisterAddress>,
+}
+
+impl ApplyReport {
+    /// Returns whether settings were left unwritten by a cancellation.
+    pub fn is_cancelled(&self) -> bool {
+        !self.cancelled.is_empty()
+    }
+}
@@ -0,0 +1,225 @@
+//! Reopening a serial port that disappeared.
+//!
+//! A USB-RS485 adapter that re-enumerates leaves the open port dead: every
+//! request fails with an I/O error although the adapter is back under the
+//! same path moments later. With a [`ReconnectPolicy`] set, a request that
+//! fails this way reopens the port, waiting before each attempt, and is
+//! then sent once more. Only when all attempts fail is the error returned.
+//!
+//! Reconnection is off by default. It applies to ports opened by path;
+//! TCP gateways and supplied streams are not reopened this way.
+
+// Rust guideline compliant 2026-10-16
+
+use std::future::Future;
+use std::io;
+use std::time::Duration;
+
+use crate::error::{Jpf4826Error, Result};
+
+/// How a lost serial port is reopened.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::{Jpf4826Client, ReconnectPolicy};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// // Up to 5 attempts, 500 ms apart, before a request fails
+/// client.set_reconnect_policy(ReconnectPolicy::new(5, Duration::from_millis(500)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReconnectPolicy {
+    max_attempts: u32,
+    delay: Duration,
+}
+
+impl ReconnectPolicy {
+    /// Creates a policy making up to `max_attempts` attempts to reopen the
+    /// port, each after waiting `delay`.
+    ///
+    /// Zero attempts disables reconnection.
+    pub fn new(max_attempts: u32, delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            delay,
+        }
+    }
+
+    /// Returns the policy that never reopens the port, the default.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if a lost port is reopened.
+    pub fn is_enabled(&self) -> bool {
+        self.max_attempts > 0
+    }
+
+    /// Returns the maximum number of attempts to reopen the port.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the wait before each attempt.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Calls `open` until it succeeds or the attempts are used up, waiting
+    /// the delay before each call.
+    ///
+    /// On failure, returns the number of attempts made and the last error.
+    pub(crate) async fn reopen<T, F, Fut>(
+        &self,
+        mut open: F,
+    ) -> std::result::Result<T, (u32, Jpf4826Error)>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut last = Jpf4826Error::serial("Reconnection is disabled");
+        for attempt in 1..=self.max_attempts {
+            tokio::time::sleep(self.delay).await;
+            match open().await {
+                Ok(opened) => {
+                    log::info!("Reopened the port after {} attempt(s)", attempt);
+                    return Ok(opened);
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Reopen attempt {}/{} failed: {}",
+                        attempt,
+                        self.max_attempts,
+                        e
+                    );
+                    last = e;
+                }
+            }
+        }
+        Err((self.max_attempts, last))
+    }
+}
+
+/// Returns true if `error` means the port itself is gone, as opposed to a
+/// garbled or missing answer.
+pub(crate) fn is_port_lost(error: &io::Error) -> bool {
+    /// EIO, ENXIO and ENODEV, reported by a removed USB serial adapter.
+    #[cfg(unix)]
+    const LOST_DEVICE_ERRNOS: [i32; 3] = [5, 6, 19];
+
+    /// ERROR_BAD_COMMAND, ERROR_GEN_FAILURE, ERROR_OPERATION_ABORTED,
+    /// ERROR_DEVICE_NOT_CONNECTED and ERROR_DEVICE_REMOVED, reported by a
+    /// removed USB serial adapter.
+    #[cfg(windows)]
+    const LOST_DEVICE_ERRNOS: [i32; 5] = [22, 31, 995, 1167, 1617];
+
+    #[cfg(any(unix, windows))]
+    if error
+        .raw_os_error()
+        .is_some_and(|errno| LOST_DEVICE_ERRNOS.contains(&errno))
+    {
+        return true;
+    }
+    matches!(
+        error.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Returns an open function failing `failures` times, counting calls.
+    fn flaky_open(
+        failures: u32,
+        calls: &Cell<u32>,
+    ) -> impl FnMut() -> std::future::Ready<Result<u32>> + '_ {
+        move || {
+            calls.set(calls.get() + 1);
+            std::future::ready(if calls.get() <= failures {
+                Err(Jpf4826Error::serial(format!(
+                    "No such port ({})",
+                    calls.get()
+                )))
+            } else {
+                Ok(calls.get())
+            })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reopen_succeeds_after_failures() {
+        let policy = ReconnectPolicy::new(3, Duration::from_millis(100));
+        let calls = Cell::new(0);
+        let started = tokio::time::Instant::now();
+
+        let opened = policy.reopen(flaky_open(2, &calls)).await.unwrap();
+
+        assert_eq!(opened, 3);
+        // Waited before each of the three attempts
+        assert_eq!(started.elapsed(), Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reopen_gives_up_after_max_attempts() {
+        let policy = ReconnectPolicy::new(2, Duration::from_millis(100));
+        let calls = Cell::new(0);
+
+        let (attempts, last) = policy.reopen(flaky_open(5, &calls)).await.unwrap_err();
+
+        assert_eq!(attempts, 2);
+        assert_eq!(calls.get(), 2);
+        assert!(last.to_string().contains("No such port (2)"), "{}", last);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policy_does_not_open() {
+        let calls = Cell::new(0);
+
+        let (attempts, _) = ReconnectPolicy::disabled()
+            .reopen(flaky_open(0, &calls))
+            .await
+            .unwrap_err();
+
+        assert_eq!(attempts, 0);
+        assert_eq!(calls.get(), 0);
+        assert!(!ReconnectPolicy::default().is_enabled());
+    }
+
+    #[test]
+    fn test_lost_port_errors() {
+        assert!(is_port_lost(&io::Error::from(io::ErrorKind::BrokenPipe)));
+        assert!(is_port_lost(&io::Error::from(io::ErrorKind::UnexpectedEof)));
+        assert!(!is_port_lost(&io::Error::from(io::ErrorKind::TimedOut)));
+        assert!(!is_port_lost(&io::Error::from(io::ErrorKind::InvalidData)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_removed_device_errnos_are_lost_port() {
+        assert!(is_port_lost(&io::Error::from_raw_os_error(5)));
+        assert!(is_port_lost(&io::Error::from_raw_os_error(19)));
+        // EACCES: the port exists
+        assert!(!is_port_lost(&io::Error::from_raw_os_error(13)));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_removed_device_errors_are_lost_port() {
+        assert!(is_port_lost(&io::Error::from_raw_os_error(31)));
+        assert!(is_port_lost(&io::Error::from_raw_os_error(1167)));
+        // ERROR_ACCESS_DENIED: the port is held by another program
+        assert!(!is_port_lost(&io::Error::from_raw_os_error(5)));
+    }
+}
@@ -1,10 +1,15 @@
 //! JPF4826 Modbus register address definitions.
 //!
 //! This module defines type-safe register addresses matching the
-//! controller's Modbus-RTU register map.
+//! controller's Modbus-RTU register map. Registers that only some hardware
+//! revisions have are listed in [`DeviceModel`](crate::model::DeviceModel).
 
 // Rust guideline compliant 2026-01-06
 
+use core::fmt;
+
+use crate::types::FanIndex;
+
 /// Modbus register addresses for JPF4826 controller.
 ///
 /// All register addresses follow the controller's register map
@@ -90,6 +95,12 @@ pub enum RegisterAddress {
     /// 1=normal, 0=fault (inverted logic).
     FanFaultCode = 0x000E,
 
+    /// Second temperature input (INT16, Read-only).
+    ///
+    /// Only on [`DeviceModel::Jpf4826Rev2`](crate::model::DeviceModel::Jpf4826Rev2).
+    /// Stored with +40 offset like [`CurrentTemperature`](Self::CurrentTemperature).
+    SecondTemperature = 0x000F,
+
     /// Reset controller command (UINT16, Write-only).
     ///
     /// Write 0x00AA to reset/restart the controller.
@@ -110,23 +121,142 @@ impl RegisterAddress {
         self as u16
     }
 
+    /// Returns the register at numeric address `addr`, if it is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::registers::RegisterAddress;
+    /// assert_eq!(RegisterAddress::from_addr(0x000B), Some(RegisterAddress::PwmFrequency));
+    /// assert_eq!(RegisterAddress::from_addr(0x0010), None);
+    /// ```
+    pub fn from_addr(addr: u16) -> Option<Self> {
+        use RegisterAddress::*;
+
+        [
+            CurrentTemperature,
+            FanStatus,
+            ModbusAddress,
+            ManualSpeedControl,
+            CombinedTemperature,
+            WorkMode,
+            FanQuantity,
+            Fan1Speed,
+            Fan2Speed,
+            Fan3Speed,
+            Fan4Speed,
+            PwmFrequency,
+            StartTemperature,
+            FullSpeedTemperature,
+            FanFaultCode,
+            SecondTemperature,
+            ResetController,
+        ]
+        .into_iter()
+        .find(|register| register.addr() == addr)
+    }
+
+    /// Returns whether the register can be read, written or both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::registers::{RegisterAccess, RegisterAddress};
+    /// assert_eq!(RegisterAddress::Fan1Speed.access(), RegisterAccess::ReadOnly);
+    /// assert_eq!(RegisterAddress::WorkMode.access(), RegisterAccess::ReadWrite);
+    /// assert_eq!(RegisterAddress::ResetController.access(), RegisterAccess::WriteOnly);
+    /// ```
+    pub fn access(self) -> RegisterAccess {
+        match self {
+            RegisterAddress::CurrentTemperature
+            | RegisterAddress::FanStatus
+            | RegisterAddress::Fan1Speed
+            | RegisterAddress::Fan2Speed
+            | RegisterAddress::Fan3Speed
+            | RegisterAddress::Fan4Speed
+            | RegisterAddress::FanFaultCode
+            | RegisterAddress::SecondTemperature => RegisterAccess::ReadOnly,
+            RegisterAddress::ResetController => RegisterAccess::WriteOnly,
+            RegisterAddress::ModbusAddress
+            | RegisterAddress::ManualSpeedControl
+            | RegisterAddress::CombinedTemperature
+            | RegisterAddress::WorkMode
+            | RegisterAddress::FanQuantity
+            | RegisterAddress::PwmFrequency
+            | RegisterAddress::StartTemperature
+            | RegisterAddress::FullSpeedTemperature => RegisterAccess::ReadWrite,
+        }
+    }
+
+    /// Returns true if the controller does not accept writes to the
+    /// register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::registers::RegisterAddress;
+    /// assert!(RegisterAddress::Fan1Speed.is_read_only());
+    /// assert!(!RegisterAddress::WorkMode.is_read_only());
+    /// ```
+    pub fn is_read_only(self) -> bool {
+        self.access() == RegisterAccess::ReadOnly
+    }
+
     /// Returns the register address for a specific fan's RPM.
     ///
+    /// Accepts a [`FanIndex`] or a plain fan number; the latter yields
+    /// `None` outside 1-4.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use jpf4826_driver::registers::RegisterAddress;
-    /// assert_eq!(RegisterAddress::fan_speed_register(1), Some(RegisterAddress::Fan1Speed));
+    /// # use jpf4826_driver::types::FanIndex;
+    /// assert_eq!(RegisterAddress::fan_speed_register(FanIndex::FAN1), Some(RegisterAddress::Fan1Speed));
     /// assert_eq!(RegisterAddress::fan_speed_register(4), Some(RegisterAddress::Fan4Speed));
     /// assert_eq!(RegisterAddress::fan_speed_register(5), None);
     /// ```
-    pub fn fan_speed_register(fan_index: u8) -> Option<Self> {
-        match fan_index {
-            1 => Some(RegisterAddress::Fan1Speed),
-            2 => Some(RegisterAddress::Fan2Speed),
-            3 => Some(RegisterAddress::Fan3Speed),
-            4 => Some(RegisterAddress::Fan4Speed),
-            _ => None,
-        }
+    pub fn fan_speed_register(fan_index: impl TryInto<FanIndex>) -> Option<Self> {
+        let fan_index = fan_index.try_into().ok()?;
+        Some(match fan_index {
+            FanIndex::FAN1 => RegisterAddress::Fan1Speed,
+            FanIndex::FAN2 => RegisterAddress::Fan2Speed,
+            FanIndex::FAN3 => RegisterAddress::Fan3Speed,
+            _ => RegisterAddress::Fan4Speed,
+        })
+    }
+}
+
+/// Which Modbus operations a register supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterAccess {
+    /// Measurements and status; writes are refused or corrupt state on
+    /// some clones.
+    ReadOnly,
+    /// Configuration, readable and writable.
+    ReadWrite,
+    /// Commands, which read back as nothing meaningful.
+    WriteOnly,
+}
+
+impl RegisterAccess {
+    /// Returns true if the register can be read.
+    pub fn is_readable(self) -> bool {
+        self != RegisterAccess::WriteOnly
+    }
+
+    /// Returns true if the register can be written.
+    pub fn is_writable(self) -> bool {
+        self != RegisterAccess::ReadOnly
+    }
+}
+
+impl fmt::Display for RegisterAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RegisterAccess::ReadOnly => "read-only",
+            RegisterAccess::ReadWrite => "read/write",
+            RegisterAccess::WriteOnly => "write-only",
+        })
     }
 }
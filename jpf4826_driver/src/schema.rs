@@ -0,0 +1,59 @@
+//! JSON schemas of the documents the crate serializes.
+//!
+//! The schemas are generated from the `JsonSchema` impls of this build, so
+//! they always describe the JSON the same version serializes. Each carries
+//! [`SCHEMA_VERSION`] as its `x-schema-version` keyword.
+//!
+//! # Examples
+//!
+//! ```
+//! use jpf4826_driver::schema::{schema, Document, SCHEMA_VERSION};
+//!
+//! let status = schema(Document::Status);
+//! assert_eq!(status.get("title").unwrap(), "ControllerStatus");
+//! assert_eq!(status.get("x-schema-version").unwrap(), SCHEMA_VERSION);
+//! ```
+
+// Rust guideline compliant 2026-10-16
+
+use schemars::{schema_for, Schema};
+
+use crate::profile::ControllerConfig;
+use crate::types::ControllerStatus;
+
+/// Version of the serialized document shapes.
+///
+/// Bumped whenever a change to a document could reject JSON that validated
+/// before, such as a removed field or a narrowed range. Additions that old
+/// parsers can ignore keep the version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Keyword holding [`SCHEMA_VERSION`] in every schema.
+pub const VERSION_KEYWORD: &str = "x-schema-version";
+
+/// Documents with a published schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Document {
+    /// A [`ControllerStatus`], as printed by `jpf4826ctl status --json`.
+    Status,
+    /// A [`ControllerConfig`], as used in profiles.
+    Config,
+}
+
+/// Returns the JSON schema of `document`.
+pub fn schema(document: Document) -> Schema {
+    // The derived descriptions are the rustdoc, examples included
+    let (mut schema, description) = match document {
+        Document::Status => (
+            schema_for!(ControllerStatus),
+            "Status of a JPF4826 fan controller.",
+        ),
+        Document::Config => (
+            schema_for!(ControllerConfig),
+            "JPF4826 controller settings. Absent fields are left unchanged.",
+        ),
+    };
+    schema.insert("description".into(), description.into());
+    schema.insert(VERSION_KEYWORD.into(), SCHEMA_VERSION.into());
+    schema
+}
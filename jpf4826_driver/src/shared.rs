@@ -0,0 +1,181 @@
+//! Cloneable client handle for serving several consumers.
+//!
+//! [`SharedClient`] serializes access to one [`Jpf4826Client`] and
+//! coalesces concurrent [`SharedClient::status`] calls: while a status read
+//! is on the bus, further callers wait for its result instead of queueing
+//! their own 15-register read. Optionally, a snapshot younger than
+//! [`SharedClient::with_max_age`] is returned without touching the bus.
+//!
+//! Every bus transaction runs while the caller holds the client's async
+//! mutex, so transactions from concurrent tasks never interleave on the
+//! half-duplex link. The mutex is fair: tasks are served in the order they
+//! started waiting, and a task holding a [`SharedClient::lock`] guard keeps
+//! the bus until the guard is dropped, so a read-modify-write sequence is
+//! not split by other callers.
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{watch, MutexGuard};
+
+use crate::client::Jpf4826Client;
+use crate::error::Result;
+use crate::types::ControllerStatus;
+
+/// Cloneable handle to a client shared between tasks.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::{Jpf4826Client, SharedClient};
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let shared = SharedClient::new(client);
+///
+/// // Both tasks are served by a single bus transaction
+/// let (a, b) = tokio::join!(shared.status(), shared.status());
+/// assert_eq!(a?, b?);
+///
+/// shared.lock().await.set_fan_speed(60).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SharedClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: tokio::sync::Mutex<Jpf4826Client>,
+    state: Mutex<State>,
+    max_age: Duration,
+}
+
+#[derive(Default)]
+struct State {
+    /// Result channel of the read currently on the bus.
+    in_flight: Option<watch::Receiver<Option<Result<ControllerStatus>>>>,
+    /// Last successful snapshot and when it was read.
+    last: Option<(Instant, ControllerStatus)>,
+}
+
+impl SharedClient {
+    /// Wraps `client`, coalescing only concurrent status reads.
+    pub fn new(client: Jpf4826Client) -> Self {
+        Self::with_max_age(client, Duration::ZERO)
+    }
+
+    /// Wraps `client`, also serving status snapshots younger than `max_age`
+    /// from memory.
+    pub fn with_max_age(client: Jpf4826Client, max_age: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                client: tokio::sync::Mutex::new(client),
+                state: Mutex::new(State::default()),
+                max_age,
+            }),
+        }
+    }
+
+    /// Reads the controller status.
+    ///
+    /// Joins a read already in flight, or returns a fresh enough snapshot,
+    /// before starting a new bus transaction. Errors are delivered to every
+    /// caller waiting on the failed read. If the caller performing the read
+    /// is cancelled, one of the waiters takes over.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if Modbus communication fails.
+    pub async fn status(&self) -> Result<ControllerStatus> {
+        loop {
+            let turn = {
+                let mut state = self.inner.state.lock().unwrap();
+                if let Some((read_at, status)) = &state.last {
+                    if read_at.elapsed() < self.inner.max_age {
+                        return Ok(status.clone());
+                    }
+                }
+                match &state.in_flight {
+                    Some(result) => Turn::Wait(result.clone()),
+                    None => {
+                        let (sender, receiver) = watch::channel(None);
+                        state.in_flight = Some(receiver);
+                        Turn::Read(sender)
+                    }
+                }
+            };
+
+            let mut result = match turn {
+                Turn::Wait(result) => result,
+                Turn::Read(sender) => {
+                    let in_flight = InFlight {
+                        inner: &self.inner,
+                        sender,
+                    };
+                    return self.read_status(in_flight).await;
+                }
+            };
+            let outcome = match result.wait_for(Option::is_some).await {
+                Ok(done) => match done.as_ref().expect("waited for a result") {
+                    Ok(status) => Ok(status.clone()),
+                    Err(e) => Err(e.duplicate()),
+                },
+                // The reading caller was cancelled; retry
+                Err(_) => continue,
+            };
+            return outcome;
+        }
+    }
+
+    /// Locks the client for exclusive use, e.g. to write registers.
+    ///
+    /// Waits behind callers that asked for the client earlier. Discards the
+    /// cached snapshot, as the caller may change the state.
+    pub async fn lock(&self) -> MutexGuard<'_, Jpf4826Client> {
+        let client = self.inner.client.lock().await;
+        self.inner.state.lock().unwrap().last = None;
+        client
+    }
+
+    /// Performs the bus read on behalf of all current waiters.
+    async fn read_status(&self, in_flight: InFlight<'_>) -> Result<ControllerStatus> {
+        let result = self.inner.client.lock().await.status().await;
+        if let Ok(status) = &result {
+            self.inner.state.lock().unwrap().last = Some((Instant::now(), status.clone()));
+        }
+
+        let shared = match &result {
+            Ok(status) => Ok(status.clone()),
+            Err(e) => Err(e.duplicate()),
+        };
+        in_flight.sender.send_replace(Some(shared));
+        result
+    }
+}
+
+/// Role of a caller of [`SharedClient::status`].
+enum Turn {
+    /// Another caller is reading; wait for its result.
+    Wait(watch::Receiver<Option<Result<ControllerStatus>>>),
+    /// No read in flight; perform it and publish the result.
+    Read(watch::Sender<Option<Result<ControllerStatus>>>),
+}
+
+/// Marks a status read as in flight until completed or cancelled.
+///
+/// Dropping it clears the marker before the channel closes, so waiters of a
+/// cancelled read retry with a clean state instead of waiting forever.
+struct InFlight<'a> {
+    inner: &'a Inner,
+    sender: watch::Sender<Option<Result<ControllerStatus>>>,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.inner.state.lock().unwrap().in_flight = None;
+    }
+}
@@ -0,0 +1,231 @@
+//! Operation statistics.
+//!
+//! A [`Jpf4826Client`](crate::Jpf4826Client) counts its read and write
+//! transactions, how many of them failed and how long the successful ones
+//! took, so long-running deployments can tell how flaky the bus is without
+//! scraping logs. The counters are atomics updated once per transaction; a
+//! retried request counts once, with its final outcome, as in
+//! [`LinkInfo`](crate::LinkInfo). Bus scans, which expect timeouts, are not
+//! counted.
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::error::Jpf4826Error;
+
+/// EWMA weight of each new latency sample.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+/// Marks an unset latency average or error time.
+const UNSET: u64 = u64::MAX;
+
+/// Snapshot of a client's operation statistics.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use jpf4826_driver::Jpf4826Client;
+/// # #[tokio::main]
+/// # async fn main() -> jpf4826_driver::Result<()> {
+/// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+/// let _ = client.status().await;
+/// let stats = client.stats();
+/// println!(
+///     "{} reads, {} writes, {} timeouts, mean latency {:?}",
+///     stats.reads, stats.writes, stats.timeouts, stats.mean_latency
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ClientStats {
+    /// Number of read transactions, including failed ones.
+    pub reads: u64,
+    /// Number of write transactions, including failed ones and broadcasts.
+    pub writes: u64,
+    /// Number of transactions that timed out.
+    pub timeouts: u64,
+    /// Number of transactions that failed other than by timing out, e.g.
+    /// with a Modbus exception, a malformed response or a serial error.
+    pub modbus_errors: u64,
+    /// When a transaction last failed.
+    pub last_error_at: Option<SystemTime>,
+    /// Exponentially weighted mean duration of successful transactions,
+    /// each new one weighted 0.2.
+    pub mean_latency: Option<Duration>,
+}
+
+impl ClientStats {
+    /// Returns the number of failed transactions.
+    pub fn failures(&self) -> u64 {
+        self.timeouts + self.modbus_errors
+    }
+}
+
+/// Kind of a counted transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+    Read,
+    Write,
+}
+
+/// Counters behind [`ClientStats`].
+pub(crate) struct StatsCounters {
+    reads: AtomicU64,
+    writes: AtomicU64,
+    timeouts: AtomicU64,
+    modbus_errors: AtomicU64,
+    /// Nanoseconds since the Unix epoch, or [`UNSET`].
+    last_error_at: AtomicU64,
+    /// Nanoseconds, or [`UNSET`].
+    mean_latency: AtomicU64,
+}
+
+impl Default for StatsCounters {
+    fn default() -> Self {
+        Self {
+            reads: AtomicU64::new(0),
+            writes: AtomicU64::new(0),
+            timeouts: AtomicU64::new(0),
+            modbus_errors: AtomicU64::new(0),
+            last_error_at: AtomicU64::new(UNSET),
+            mean_latency: AtomicU64::new(UNSET),
+        }
+    }
+}
+
+impl StatsCounters {
+    /// Records one transaction that took `elapsed`.
+    pub(crate) fn record<T>(
+        &self,
+        operation: Operation,
+        elapsed: Duration,
+        result: &Result<T, Jpf4826Error>,
+    ) {
+        let count = match operation {
+            Operation::Read => &self.reads,
+            Operation::Write => &self.writes,
+        };
+        count.fetch_add(1, Ordering::Relaxed);
+
+        match result {
+            Ok(_) => self.observe_latency(elapsed),
+            Err(err) => {
+                let failures = if err.is_timeout() {
+                    &self.timeouts
+                } else {
+                    &self.modbus_errors
+                };
+                failures.fetch_add(1, Ordering::Relaxed);
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, saturating_nanos);
+                self.last_error_at.store(now, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn observe_latency(&self, elapsed: Duration) {
+        let sample = saturating_nanos(elapsed);
+        // The closure always returns Some, so the update cannot fail
+        let _ = self
+            .mean_latency
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |mean| {
+                Some(if mean == UNSET {
+                    sample
+                } else {
+                    let mean =
+                        mean as f64 * (1.0 - LATENCY_SMOOTHING) + sample as f64 * LATENCY_SMOOTHING;
+                    (mean as u64).min(UNSET - 1)
+                })
+            });
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        let set = |value: u64| (value != UNSET).then_some(value);
+        ClientStats {
+            reads: self.reads.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            modbus_errors: self.modbus_errors.load(Ordering::Relaxed),
+            last_error_at: set(self.last_error_at.load(Ordering::Relaxed))
+                .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos)),
+            mean_latency: set(self.mean_latency.load(Ordering::Relaxed)).map(Duration::from_nanos),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        for counter in [
+            &self.reads,
+            &self.writes,
+            &self.timeouts,
+            &self.modbus_errors,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.last_error_at.store(UNSET, Ordering::Relaxed);
+        self.mean_latency.store(UNSET, Ordering::Relaxed);
+    }
+}
+
+/// Returns `duration` in nanoseconds, short of [`UNSET`].
+fn saturating_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).map_or(UNSET - 1, |nanos| nanos.min(UNSET - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OK: Result<(), Jpf4826Error> = Ok(());
+
+    #[test]
+    fn test_first_sample_sets_mean_latency() {
+        let counters = StatsCounters::default();
+        assert_eq!(counters.snapshot(), ClientStats::default());
+
+        counters.record(Operation::Read, Duration::from_millis(100), &OK);
+
+        assert_eq!(
+            counters.snapshot().mean_latency,
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn test_mean_latency_is_weighted() {
+        let counters = StatsCounters::default();
+        counters.record(Operation::Read, Duration::from_millis(100), &OK);
+
+        counters.record(Operation::Write, Duration::from_millis(600), &OK);
+
+        // 0.8 * 100 ms + 0.2 * 600 ms
+        assert_eq!(
+            counters.snapshot().mean_latency,
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn test_failures_leave_latency_alone() {
+        let counters = StatsCounters::default();
+        counters.record(Operation::Read, Duration::from_millis(100), &OK);
+
+        counters.record::<()>(
+            Operation::Read,
+            Duration::from_secs(1),
+            &Err(Jpf4826Error::timeout(Duration::from_secs(1))),
+        );
+
+        let stats = counters.snapshot();
+        assert_eq!(stats.mean_latency, Some(Duration::from_millis(100)));
+        assert_eq!((stats.reads, stats.timeouts), (2, 1));
+        assert!(stats.last_error_at.is_some());
+    }
+}
@@ -0,0 +1,335 @@
+//! Operation timeout policies.
+//!
+//! By default every Modbus operation uses a fixed timeout
+//! ([`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT)). In adaptive mode the
+//! timeout follows the link instead: a baseline is computed from the baud
+//! rate and the size of the largest frame exchange, an exponentially
+//! weighted moving average (EWMA) tracks observed response times, and the
+//! effective timeout is the larger of the two times a multiplier, bounded
+//! by a minimum and maximum.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use crate::modbus::DEFAULT_TIMEOUT;
+use crate::types::ControllerStatus;
+
+/// Bits per character on the wire (8N1: start, 8 data, stop).
+const BITS_PER_CHAR: u32 = 10;
+
+/// Modbus-RTU request frame for reading holding registers or writing a
+/// single register: address, function, 2x2 data bytes, CRC.
+const REQUEST_FRAME_BYTES: u32 = 8;
+
+/// Response frame overhead for reading holding registers: address,
+/// function, byte count, CRC.
+const READ_RESPONSE_OVERHEAD_BYTES: u32 = 5;
+
+/// Silent interval delimiting RTU frames, in characters.
+const INTER_FRAME_CHARS: f64 = 3.5;
+
+/// Allowance for the controller to process a request before answering.
+const TURNAROUND: Duration = Duration::from_millis(50);
+
+/// Timeout policy for Modbus operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeoutMode {
+    /// The same timeout for every operation.
+    Fixed(Duration),
+    /// A timeout derived from the baud rate and observed latency.
+    Adaptive(AdaptiveTimeout),
+}
+
+impl Default for TimeoutMode {
+    fn default() -> Self {
+        Self::Fixed(DEFAULT_TIMEOUT)
+    }
+}
+
+/// Parameters of the adaptive timeout.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::timeout::AdaptiveTimeout;
+/// # use std::time::Duration;
+/// let adaptive = AdaptiveTimeout::new()
+///     .with_multiplier(4.0)
+///     .with_bounds(Duration::from_millis(500), Duration::from_secs(3));
+///
+/// // About 100 ms are expected at 9600 baud; 4x that is below the minimum
+/// let baseline = adaptive.baseline(9600);
+/// assert_eq!(adaptive.effective(baseline, None), Duration::from_millis(500));
+///
+/// // A slow gateway raises the average and with it the timeout
+/// let average = adaptive.update(None, Duration::from_millis(300));
+/// assert_eq!(adaptive.effective(baseline, Some(average)), Duration::from_millis(1200));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveTimeout {
+    multiplier: f64,
+    min: Duration,
+    max: Duration,
+    smoothing: f64,
+}
+
+impl Default for AdaptiveTimeout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdaptiveTimeout {
+    /// Creates the default policy: 3x multiplier, 200 ms to
+    /// [`DEFAULT_TIMEOUT`](crate::DEFAULT_TIMEOUT), smoothing factor 0.2.
+    pub fn new() -> Self {
+        Self {
+            multiplier: 3.0,
+            min: Duration::from_millis(200),
+            max: DEFAULT_TIMEOUT,
+            smoothing: 0.2,
+        }
+    }
+
+    /// Sets the factor applied to the baseline or average latency.
+    ///
+    /// Values below 1.0 are raised to 1.0.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Sets the bounds of the effective timeout.
+    ///
+    /// `max` is raised to `min` if lower.
+    pub fn with_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.min = min;
+        self.max = max.max(min);
+        self
+    }
+
+    /// Sets the EWMA weight of each new sample (0.0-1.0).
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the minimum effective timeout.
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// Returns the maximum effective timeout.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns the expected duration of the largest exchange the client
+    /// performs (reading the status block) at `baud_rate`.
+    ///
+    /// Covers both frames on the wire, the RTU inter-frame gaps, and a fixed
+    /// turnaround allowance for the controller.
+    pub fn baseline(&self, baud_rate: u32) -> Duration {
+        let response_bytes =
+            READ_RESPONSE_OVERHEAD_BYTES + 2 * u32::from(ControllerStatus::REGISTER_COUNT);
+        let chars = f64::from(REQUEST_FRAME_BYTES + response_bytes) + 2.0 * INTER_FRAME_CHARS;
+        let char_time = f64::from(BITS_PER_CHAR) / f64::from(baud_rate.max(1));
+        Duration::from_secs_f64(chars * char_time) + TURNAROUND
+    }
+
+    /// Returns the EWMA after observing `sample`.
+    pub fn update(&self, average: Option<Duration>, sample: Duration) -> Duration {
+        match average {
+            None => sample,
+            Some(average) => average.mul_f64(1.0 - self.smoothing) + sample.mul_f64(self.smoothing),
+        }
+    }
+
+    /// Returns the effective timeout for `baseline` and the latency average.
+    pub fn effective(&self, baseline: Duration, average: Option<Duration>) -> Duration {
+        let expected = average.map_or(baseline, |average| average.max(baseline));
+        expected.mul_f64(self.multiplier).clamp(self.min, self.max)
+    }
+}
+
+/// Per-client timeout state.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeoutState {
+    mode: TimeoutMode,
+    baseline: Duration,
+    average: Option<Duration>,
+    effective: Duration,
+    /// Effective timeout last reported in the log.
+    logged: Duration,
+}
+
+impl TimeoutState {
+    /// Relative change of the effective timeout that is logged.
+    const LOG_THRESHOLD: f64 = 0.25;
+
+    pub(crate) fn new(mode: TimeoutMode, baud_rate: u32) -> Self {
+        let baseline = match mode {
+            TimeoutMode::Fixed(_) => Duration::ZERO,
+            TimeoutMode::Adaptive(adaptive) => adaptive.baseline(baud_rate),
+        };
+        let effective = match mode {
+            TimeoutMode::Fixed(timeout) => timeout,
+            TimeoutMode::Adaptive(adaptive) => adaptive.effective(baseline, None),
+        };
+        Self {
+            mode,
+            baseline,
+            average: None,
+            effective,
+            logged: effective,
+        }
+    }
+
+    pub(crate) fn mode(&self) -> TimeoutMode {
+        self.mode
+    }
+
+    /// Returns the timeout for the next operation.
+    pub(crate) fn current(&self) -> Duration {
+        self.effective
+    }
+
+    /// Records the response time of a completed operation.
+    pub(crate) fn observe(&mut self, elapsed: Duration) {
+        self.record(elapsed);
+    }
+
+    /// Records an operation that timed out.
+    ///
+    /// The true latency is at least the timeout, so it counts as a sample;
+    /// this lets the timeout grow on a slower link than the baseline assumes.
+    pub(crate) fn observe_timeout(&mut self) {
+        self.record(self.effective);
+    }
+
+    fn record(&mut self, sample: Duration) {
+        let TimeoutMode::Adaptive(adaptive) = self.mode else {
+            return;
+        };
+        let average = adaptive.update(self.average, sample);
+        self.average = Some(average);
+        self.effective = adaptive.effective(self.baseline, self.average);
+
+        let change = self.effective.abs_diff(self.logged).as_secs_f64();
+        if change > self.logged.as_secs_f64() * Self::LOG_THRESHOLD {
+            log::info!(
+                "Adaptive timeout changed from {:?} to {:?} (average response {:?})",
+                self.logged,
+                self.effective,
+                average
+            );
+            self.logged = self.effective;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modbus::BAUD_RATE;
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    fn assert_close(actual: Duration, expected: Duration) {
+        assert!(
+            actual.abs_diff(expected) < Duration::from_micros(1),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_baseline_scales_with_baud_rate() {
+        let adaptive = AdaptiveTimeout::new();
+
+        // 43 frame bytes + 7 gap chars = 50 chars of 10 bits, plus turnaround
+        assert_close(adaptive.baseline(9600), Duration::from_nanos(102_083_333));
+        assert_close(adaptive.baseline(1200), Duration::from_nanos(466_666_667));
+    }
+
+    #[test]
+    fn test_effective_uses_larger_of_baseline_and_average() {
+        let adaptive = AdaptiveTimeout::new().with_bounds(ms(0), ms(10_000));
+
+        assert_eq!(adaptive.effective(ms(100), None), ms(300));
+        assert_eq!(adaptive.effective(ms(100), Some(ms(50))), ms(300));
+        assert_eq!(adaptive.effective(ms(100), Some(ms(400))), ms(1200));
+    }
+
+    #[test]
+    fn test_effective_is_bounded() {
+        let adaptive = AdaptiveTimeout::new().with_bounds(ms(500), ms(2000));
+
+        assert_eq!(adaptive.effective(ms(10), None), ms(500));
+        assert_eq!(adaptive.effective(ms(10), Some(ms(5000))), ms(2000));
+    }
+
+    #[test]
+    fn test_bounds_and_multiplier_are_sanitized() {
+        let adaptive = AdaptiveTimeout::new()
+            .with_bounds(ms(500), ms(100))
+            .with_multiplier(0.5);
+
+        assert_eq!(adaptive.max(), ms(500));
+        assert_eq!(adaptive.effective(ms(200), None), ms(500));
+        assert_eq!(adaptive.effective(ms(600), None), ms(500));
+    }
+
+    #[test]
+    fn test_ewma_update() {
+        let adaptive = AdaptiveTimeout::new().with_smoothing(0.25);
+
+        let average = adaptive.update(None, ms(100));
+        assert_eq!(average, ms(100));
+        let average = adaptive.update(Some(average), ms(500));
+        assert_eq!(average, ms(200));
+        let average = adaptive.update(Some(average), ms(200));
+        assert_eq!(average, ms(200));
+    }
+
+    #[test]
+    fn test_fixed_mode_ignores_observations() {
+        let mut state = TimeoutState::new(TimeoutMode::Fixed(ms(1500)), BAUD_RATE);
+
+        state.observe(ms(5000));
+        state.observe_timeout();
+
+        assert_eq!(state.current(), ms(1500));
+    }
+
+    #[test]
+    fn test_adaptive_state_follows_slow_link() {
+        let adaptive = AdaptiveTimeout::new()
+            .with_bounds(ms(200), ms(5000))
+            .with_smoothing(0.5);
+        let mut state = TimeoutState::new(TimeoutMode::Adaptive(adaptive), BAUD_RATE);
+        let initial = state.current();
+        assert_close(initial, adaptive.baseline(BAUD_RATE).mul_f64(3.0));
+
+        // Timeouts push the average up until responses arrive in time
+        state.observe_timeout();
+        state.observe_timeout();
+        assert!(state.current() > initial);
+
+        for _ in 0..20 {
+            state.observe(ms(800));
+        }
+        let settled = state.current();
+        assert!(settled.abs_diff(ms(2400)) < ms(10), "{:?}", settled);
+
+        // Fast responses fall back to the baseline
+        for _ in 0..40 {
+            state.observe(ms(1));
+        }
+        assert_eq!(state.current(), initial);
+    }
+}
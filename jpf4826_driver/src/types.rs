@@ -5,12 +5,35 @@
 
 // Rust guideline compliant 2026-01-16
 
+use core::fmt;
+
+#[cfg(feature = "schemars")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "schemars")]
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::conversions::{
+    celsius_to_fahrenheit, celsius_to_kelvin, fahrenheit_to_celsius, kelvin_to_celsius,
+};
+#[cfg(feature = "alloc")]
+use crate::conversions::{
+    checked_register_to_celsius, parse_fan_fault_bitmap, parse_fan_status_bitmap,
+    register_to_celsius, register_to_modbus_address, TEMPERATURE_REGISTER_MAX,
+    TEMPERATURE_REGISTER_MIN,
+};
+#[cfg(feature = "alloc")]
+use crate::curve::expected_duty_cycle;
+
 /// Work mode determining fan behavior below start temperature.
 ///
 /// This is also known as ECO mode in the controller documentation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum WorkMode {
     /// Fan stops completely below (low_threshold - 3°C).
     Shutdown,
@@ -54,9 +77,260 @@ impl WorkMode {
     }
 }
 
+/// Speed control mode of the controller.
+///
+/// The controller does not report its mode: in temperature mode register
+/// 0x0003 reads back the calculated speed rather than 0xFFFF. A status read
+/// therefore yields [`OperatingMode::Unknown`] unless the register holds
+/// 0xFFFF or the client set the mode itself (see
+/// `Jpf4826Client::status`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum OperatingMode {
+    /// Automatic, temperature-based speed control.
+    Temperature,
+    /// Fixed speed set through register 0x0003.
+    Manual,
+    /// Mode could not be determined.
+    #[default]
+    Unknown,
+}
+
+impl OperatingMode {
+    /// Derives the mode from a value read from register 0x0003.
+    ///
+    /// Only 0xFFFF is conclusive; speed values are read in both modes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::OperatingMode;
+    /// assert_eq!(OperatingMode::from_register_value(0xFFFF), OperatingMode::Temperature);
+    /// assert_eq!(OperatingMode::from_register_value(40), OperatingMode::Unknown);
+    /// ```
+    pub fn from_register_value(value: u16) -> Self {
+        match value {
+            0xFFFF => OperatingMode::Temperature,
+            _ => OperatingMode::Unknown,
+        }
+    }
+
+    /// Returns the mode a write of `value` to register 0x0003 selects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::OperatingMode;
+    /// assert_eq!(OperatingMode::from_written_value(0xFFFF), OperatingMode::Temperature);
+    /// assert_eq!(OperatingMode::from_written_value(75), OperatingMode::Manual);
+    /// assert_eq!(OperatingMode::from_written_value(200), OperatingMode::Unknown);
+    /// ```
+    pub fn from_written_value(value: u16) -> Self {
+        match value {
+            0xFFFF => OperatingMode::Temperature,
+            0..=100 => OperatingMode::Manual,
+            _ => OperatingMode::Unknown,
+        }
+    }
+}
+
+/// Speed control mode together with the speed of manual mode.
+///
+/// Unlike [`OperatingMode`], the manual variant carries its speed
+/// percentage (0-100), so the mode and the value of register 0x0003 travel
+/// as one. Serializes as `{"mode": "MANUAL", "speed": 40}`, or
+/// `{"mode": "TEMPERATURE"}` and `{"mode": "UNKNOWN"}`, keeping the mode
+/// strings of [`OperatingMode`].
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::types::{OperatingMode, OperatingModeState};
+/// let state = OperatingModeState::from_written_value(40);
+/// assert_eq!(state, OperatingModeState::Manual(40));
+/// assert_eq!(state.mode(), OperatingMode::Manual);
+/// assert_eq!(state.speed(), Some(40));
+/// assert_eq!(state.to_register_value(), Some(40));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "OperatingModeStateRepr", into = "OperatingModeStateRepr")
+)]
+pub enum OperatingModeState {
+    /// Automatic, temperature-based speed control.
+    Temperature,
+    /// Fixed speed percentage (0-100) set through register 0x0003.
+    Manual(u8),
+    /// Mode could not be determined.
+    #[default]
+    Unknown,
+}
+
+impl OperatingModeState {
+    /// Returns the state a write of `value` to register 0x0003 selects.
+    ///
+    /// The inverse of [`to_register_value`](Self::to_register_value). As
+    /// with [`OperatingMode::from_written_value`], a value read back is not
+    /// conclusive: in temperature mode the register reads the calculated
+    /// speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::OperatingModeState;
+    /// assert_eq!(
+    ///     OperatingModeState::from_written_value(0xFFFF),
+    ///     OperatingModeState::Temperature
+    /// );
+    /// assert_eq!(
+    ///     OperatingModeState::from_written_value(75),
+    ///     OperatingModeState::Manual(75)
+    /// );
+    /// assert_eq!(
+    ///     OperatingModeState::from_written_value(200),
+    ///     OperatingModeState::Unknown
+    /// );
+    /// ```
+    pub fn from_written_value(value: u16) -> Self {
+        match value {
+            0xFFFF => OperatingModeState::Temperature,
+            0..=100 => OperatingModeState::Manual(value as u8),
+            _ => OperatingModeState::Unknown,
+        }
+    }
+
+    /// Returns the value to write to register 0x0003 to select this state,
+    /// or `None` for [`Unknown`](Self::Unknown) and speeds above 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::OperatingModeState;
+    /// assert_eq!(OperatingModeState::Temperature.to_register_value(), Some(0xFFFF));
+    /// assert_eq!(OperatingModeState::Manual(60).to_register_value(), Some(60));
+    /// assert_eq!(OperatingModeState::Manual(101).to_register_value(), None);
+    /// assert_eq!(OperatingModeState::Unknown.to_register_value(), None);
+    /// ```
+    pub fn to_register_value(self) -> Option<u16> {
+        match self {
+            OperatingModeState::Temperature => Some(0xFFFF),
+            OperatingModeState::Manual(speed) if speed <= 100 => Some(u16::from(speed)),
+            OperatingModeState::Manual(_) | OperatingModeState::Unknown => None,
+        }
+    }
+
+    /// Returns the mode without its speed.
+    pub fn mode(self) -> OperatingMode {
+        match self {
+            OperatingModeState::Temperature => OperatingMode::Temperature,
+            OperatingModeState::Manual(_) => OperatingMode::Manual,
+            OperatingModeState::Unknown => OperatingMode::Unknown,
+        }
+    }
+
+    /// Returns the manual speed percentage, or `None` in the other modes.
+    pub fn speed(self) -> Option<u8> {
+        match self {
+            OperatingModeState::Manual(speed) => Some(speed),
+            _ => None,
+        }
+    }
+}
+
+/// Serialized shape of [`OperatingModeState`], before the speed is checked.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "UPPERCASE", deny_unknown_fields)]
+enum OperatingModeStateRepr {
+    // Struct variants, so that deny_unknown_fields rejects a stray speed
+    Temperature {},
+    Manual { speed: u8 },
+    Unknown {},
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<OperatingModeStateRepr> for OperatingModeState {
+    type Error = ManualSpeedError;
+
+    fn try_from(repr: OperatingModeStateRepr) -> Result<Self, Self::Error> {
+        Ok(match repr {
+            OperatingModeStateRepr::Temperature {} => OperatingModeState::Temperature,
+            OperatingModeStateRepr::Manual { speed } if speed <= 100 => {
+                OperatingModeState::Manual(speed)
+            }
+            OperatingModeStateRepr::Manual { speed } => return Err(ManualSpeedError(speed)),
+            OperatingModeStateRepr::Unknown {} => OperatingModeState::Unknown,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<OperatingModeState> for OperatingModeStateRepr {
+    fn from(state: OperatingModeState) -> Self {
+        match state {
+            OperatingModeState::Temperature => OperatingModeStateRepr::Temperature {},
+            OperatingModeState::Manual(speed) => OperatingModeStateRepr::Manual { speed },
+            OperatingModeState::Unknown => OperatingModeStateRepr::Unknown {},
+        }
+    }
+}
+
+/// Manual speed above 100% in a serialized [`OperatingModeState`].
+#[cfg(feature = "serde")]
+struct ManualSpeedError(u8);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for ManualSpeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Manual speed {}% out of range (0-100)", self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for OperatingModeState {
+    fn schema_name() -> Cow<'static, str> {
+        "OperatingModeState".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "required": ["mode"],
+                    "properties": {
+                        "mode": { "type": "string", "enum": ["TEMPERATURE", "UNKNOWN"] }
+                    },
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "required": ["mode", "speed"],
+                    "properties": {
+                        "mode": { "type": "string", "const": "MANUAL" },
+                        "speed": {
+                            "type": "integer",
+                            "format": "uint8",
+                            "minimum": 0,
+                            "maximum": 100
+                        }
+                    },
+                    "additionalProperties": false
+                }
+            ]
+        })
+    }
+}
+
 /// Fan operational status from controller diagnostics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum FanStatus {
     /// Fan operating correctly.
     Normal,
@@ -65,13 +339,37 @@ pub enum FanStatus {
 }
 
 /// Temperature unit for display and conversion.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub enum TemperatureUnit {
     /// Celsius temperature scale.
     Celsius,
     /// Fahrenheit temperature scale.
     Fahrenheit,
+    /// Kelvin temperature scale, in whole Kelvin (see
+    /// [`KELVIN_OFFSET`](crate::conversions::KELVIN_OFFSET)).
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    /// Returns the unit symbol, e.g. `°F`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::TemperatureUnit;
+    /// assert_eq!(TemperatureUnit::Fahrenheit.symbol(), "°F");
+    /// assert_eq!(TemperatureUnit::Kelvin.symbol(), "K");
+    /// ```
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
 }
 
 /// PWM frequency for fan control signal.
@@ -101,7 +399,10 @@ pub enum PwmFrequency {
 }
 
 impl PwmFrequency {
-    /// Converts to Modbus register value.
+    /// Converts to Modbus register value of the original JPF4826.
+    ///
+    /// Other hardware revisions encode the register differently; see
+    /// [`DeviceModel::pwm_register_value`](crate::model::DeviceModel::pwm_register_value).
     ///
     /// # Examples
     ///
@@ -121,7 +422,8 @@ impl PwmFrequency {
         }
     }
 
-    /// Creates PwmFrequency from Modbus register value.
+    /// Creates PwmFrequency from Modbus register value of the original
+    /// JPF4826.
     ///
     /// # Examples
     ///
@@ -184,6 +486,7 @@ impl PwmFrequency {
 }
 
 // Custom serde implementations to match JSON schema format
+#[cfg(feature = "serde")]
 impl serde::Serialize for PwmFrequency {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -197,6 +500,7 @@ impl serde::Serialize for PwmFrequency {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for PwmFrequency {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -206,18 +510,46 @@ impl<'de> serde::Deserialize<'de> for PwmFrequency {
         struct PwmFrequencyHelper {
             value: u32,
             #[allow(dead_code)]
-            unit: String,
+            unit: serde::de::IgnoredAny,
         }
 
         let helper = PwmFrequencyHelper::deserialize(deserializer)?;
         PwmFrequency::from_hz(helper.value).ok_or_else(|| {
-            serde::de::Error::custom(format!("Invalid PWM frequency: {}", helper.value))
+            serde::de::Error::custom(format_args!("Invalid PWM frequency: {}", helper.value))
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for PwmFrequency {
+    fn schema_name() -> Cow<'static, str> {
+        "PwmFrequency".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "object",
+            "required": ["value", "unit"],
+            "properties": {
+                "value": {
+                    "type": "integer",
+                    "enum": [500, 1000, 2000, 5000, 10000, 25000],
+                    "description": "PWM frequency value in Hz"
+                },
+                "unit": {
+                    "type": "string",
+                    "const": "Hz",
+                    "description": "Unit of frequency (always Hz)"
+                }
+            },
+            "description": "PWM frequency configuration"
         })
     }
 }
 
 /// Temperature reading with associated unit.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Temperature {
     /// Temperature value.
     pub value: i16,
@@ -225,15 +557,263 @@ pub struct Temperature {
     pub unit: TemperatureUnit,
 }
 
+impl Temperature {
+    /// Creates a temperature in Celsius.
+    pub const fn celsius(value: i16) -> Self {
+        Self {
+            value,
+            unit: TemperatureUnit::Celsius,
+        }
+    }
+
+    /// Returns the temperature in whole degrees Celsius.
+    ///
+    /// Fahrenheit is rounded to the nearest degree.
+    pub fn to_celsius(self) -> i16 {
+        match self.unit {
+            TemperatureUnit::Celsius => self.value,
+            TemperatureUnit::Fahrenheit => fahrenheit_to_celsius(self.value),
+            TemperatureUnit::Kelvin => kelvin_to_celsius(self.value),
+        }
+    }
+
+    /// Returns the temperature in whole degrees Fahrenheit.
+    ///
+    /// Converts by way of whole degrees Celsius, rounding to the nearest
+    /// degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::Temperature;
+    /// assert_eq!(Temperature::celsius(-20).to_fahrenheit(), -4);
+    /// assert_eq!(Temperature::celsius(26).to_fahrenheit(), 79); // 78.8°F
+    /// ```
+    pub fn to_fahrenheit(self) -> i16 {
+        self.to_unit(TemperatureUnit::Fahrenheit).value
+    }
+
+    /// Converts the temperature to `unit`, by way of whole degrees Celsius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::{Temperature, TemperatureUnit};
+    /// let kelvin = Temperature::celsius(31).to_unit(TemperatureUnit::Kelvin);
+    /// assert_eq!(kelvin.value, 304);
+    /// assert_eq!(kelvin.to_unit(TemperatureUnit::Celsius).value, 31);
+    /// ```
+    pub fn to_unit(self, unit: TemperatureUnit) -> Self {
+        if self.unit == unit {
+            return self;
+        }
+        let celsius = self.to_celsius();
+        let value = match unit {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius_to_fahrenheit(celsius),
+            TemperatureUnit::Kelvin => celsius_to_kelvin(celsius),
+        };
+        Self { value, unit }
+    }
+}
+
+// The valid range depends on the unit, which derive cannot express
+#[cfg(feature = "schemars")]
+impl JsonSchema for Temperature {
+    fn schema_name() -> Cow<'static, str> {
+        "Temperature".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let unit = generator.subschema_for::<TemperatureUnit>();
+        json_schema!({
+            "type": "object",
+            "required": ["value", "unit"],
+            "properties": {
+                "value": {
+                    "type": "integer",
+                    "description": "Temperature value (range depends on unit)"
+                },
+                "unit": unit
+            },
+            "if": {
+                "properties": { "unit": { "const": "CELSIUS" } }
+            },
+            "then": {
+                "properties": { "value": { "minimum": -20, "maximum": 120 } }
+            },
+            "else": {
+                "if": {
+                    "properties": { "unit": { "const": "FAHRENHEIT" } }
+                },
+                "then": {
+                    "properties": { "value": { "minimum": -4, "maximum": 248 } }
+                },
+                "else": {
+                    "properties": { "value": { "minimum": 253, "maximum": 393 } }
+                }
+            },
+            "description": "Temperature value with unit-specific range validation"
+        })
+    }
+}
+
+/// Formats the value with its unit symbol, e.g. `86°F`.
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.symbol())
+    }
+}
+
+/// Number of a fan channel, 1-4.
+///
+/// Fan channels are numbered from 1 as on the controller's connector
+/// labels. Constructing a `FanIndex` checks the range once, so methods
+/// taking one cannot fail on a bad index.
+///
+/// # Examples
+///
+/// ```
+/// # use jpf4826_driver::types::FanIndex;
+/// assert_eq!(FanIndex::new(1), Some(FanIndex::FAN1));
+/// assert_eq!(FanIndex::new(0), None);
+/// assert_eq!(FanIndex::try_from(4).unwrap().get(), 4);
+///
+/// // Zero-based positions, e.g. from enumerate()
+/// assert_eq!(FanIndex::from_position(0), Some(FanIndex::FAN1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct FanIndex(u8);
+
+impl FanIndex {
+    /// Fan channel 1.
+    pub const FAN1: Self = Self(1);
+    /// Fan channel 2.
+    pub const FAN2: Self = Self(2);
+    /// Fan channel 3.
+    pub const FAN3: Self = Self(3);
+    /// Fan channel 4.
+    pub const FAN4: Self = Self(4);
+    /// All fan channels in order.
+    pub const ALL: [Self; 4] = [Self::FAN1, Self::FAN2, Self::FAN3, Self::FAN4];
+
+    /// Returns the fan with number `index`, or `None` outside 1-4.
+    pub const fn new(index: u8) -> Option<Self> {
+        match index {
+            1..=4 => Some(Self(index)),
+            _ => None,
+        }
+    }
+
+    /// Returns the fan at zero-based `position`, or `None` beyond the
+    /// fourth.
+    pub fn from_position(position: usize) -> Option<Self> {
+        Self::ALL.get(position).copied()
+    }
+
+    /// Returns the fan number, 1-4.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the zero-based position, 0-3, e.g. into
+    /// [`ControllerStatus::fans`].
+    pub const fn position(self) -> usize {
+        self.0 as usize - 1
+    }
+}
+
+impl TryFrom<u8> for FanIndex {
+    type Error = FanIndexError;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Self::new(index).ok_or(FanIndexError(index))
+    }
+}
+
+impl From<FanIndex> for u8 {
+    fn from(index: FanIndex) -> Self {
+        index.0
+    }
+}
+
+impl PartialEq<u8> for FanIndex {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for FanIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for FanIndex {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "FanIndex".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "format": "uint8",
+            "minimum": 1,
+            "maximum": 4
+        })
+    }
+}
+
+/// Error converting a number outside 1-4 into a [`FanIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FanIndexError(u8);
+
+impl FanIndexError {
+    /// Returns the rejected number.
+    pub fn index(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for FanIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fan index {} out of range (1-4)", self.0)
+    }
+}
+
+impl core::error::Error for FanIndexError {}
+
+// Lets methods taking `TryInto<FanIndex>` accept a `FanIndex` itself
+impl From<core::convert::Infallible> for FanIndexError {
+    fn from(never: core::convert::Infallible) -> Self {
+        match never {}
+    }
+}
+
 /// Individual fan status and speed information.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 pub struct FanInfo {
     /// Fan index (1-4).
-    pub index: u8,
+    pub index: FanIndex,
     /// Operational status.
     pub status: FanStatus,
     /// Rotation speed in RPM.
     pub rpm: u16,
+    /// Fan is running (register 0x0001).
+    ///
+    /// A stopped fan with [`FanStatus::Normal`] was stopped by the
+    /// controller, e.g. below the start temperature in
+    /// [`WorkMode::Shutdown`]; a faulted fan reports [`FanStatus::Fault`].
+    pub is_running: bool,
 }
 
 /// Complete controller status snapshot.
@@ -246,16 +826,37 @@ pub struct FanInfo {
 /// Temperature fields are serialized as a nested object:
 /// ```json
 /// {
+///   "mode": "TEMPERATURE",
 ///   "temperature": {
 ///     "current": {...},
 ///     "low_threshold": {...},
-///     "high_threshold": {...}
+///     "high_threshold": {...},
+///     "sensor_fault": false
 ///   }
 /// }
 /// ```
+///
+/// JSON without `sensor_fault` deserializes with `sensor_fault` false.
+///
+/// JSON without `mode`, as written before the field existed, deserializes
+/// with [`OperatingMode::Unknown`]; JSON without `manual_speed` with `None`.
+///
+/// `eco_mode` is always written as `work_mode == "Shutdown"`. When reading,
+/// `work_mode` takes precedence and `eco_mode` is only used if it is absent.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ControllerStatus {
-    /// ECO mode enabled (true = shutdown mode, false = minimum speed mode).
+    /// Speed control mode.
+    pub mode: OperatingMode,
+    /// Speed percentage (0-100) set in register 0x0003 while `mode` is
+    /// [`OperatingMode::Manual`], otherwise `None`.
+    pub manual_speed: Option<u8>,
+    /// Fan behavior below the start temperature (register 0x0005).
+    pub work_mode: WorkMode,
+    /// ECO mode enabled, i.e. `work_mode == WorkMode::Shutdown`.
+    #[deprecated(note = "use `work_mode`, which names the behavior")]
     pub eco_mode: bool,
     /// Modbus address (1-254).
     pub modbus_address: u8,
@@ -264,7 +865,13 @@ pub struct ControllerStatus {
     /// Number of fans configured (0-4, 0 = fault detection disabled).
     pub fan_count: u8,
     /// Current temperature reading.
+    ///
+    /// Holds the pegged range limit (-20°C or 120°C) when `sensor_fault`
+    /// is set and must not be used for control then.
     pub temperature_current: Temperature,
+    /// Temperature sensor disconnected or shorted (see
+    /// [`checked_register_to_celsius`](crate::conversions::checked_register_to_celsius)).
+    pub sensor_fault: bool,
     /// Temperature threshold where fans start spinning.
     pub temperature_low_threshold: Temperature,
     /// Temperature threshold where fans reach 100% speed.
@@ -273,15 +880,262 @@ pub struct ControllerStatus {
     pub fans: Vec<FanInfo>,
 }
 
+#[cfg(feature = "alloc")]
+impl ControllerStatus {
+    /// Number of consecutive registers (0x0000-0x000E) holding the status.
+    pub const REGISTER_COUNT: u16 = 15;
+
+    /// Decodes a status snapshot from the raw status register block.
+    ///
+    /// `values` holds the registers starting at 0x0000, as returned by a
+    /// single read of [`ControllerStatus::REGISTER_COUNT`] registers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::{ControllerStatus, FanStatus};
+    /// let block = [
+    ///     71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 5, 70, 90, 0x000B,
+    /// ];
+    /// let status = ControllerStatus::from_registers(&block);
+    /// assert_eq!(status.temperature_current.value, 31);
+    /// assert_eq!(status.fans[2].status, FanStatus::Fault);
+    /// ```
+    ///
+    /// The block alone never proves manual mode (see [`OperatingMode`]), so
+    /// `manual_speed` is always `None`; [`Jpf4826Client::status()`] fills it
+    /// in when the client selected manual mode itself.
+    ///
+    /// An address register outside 1-254 decodes as `modbus_address` 0,
+    /// which fails serialization. Threshold registers outside 0x0014-0x00A0
+    /// are clamped to -20°C/120°C. Unknown work mode and PWM frequency
+    /// values decode as [`WorkMode::MinimumSpeed`] and
+    /// [`PwmFrequency::Hz25000`], and the fan quantity is truncated to 8 bits.
+    /// [`Jpf4826Client::status()`] rejects all of these with an error instead.
+    ///
+    /// [`Jpf4826Client::status()`]: crate::Jpf4826Client::status
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` holds fewer than [`ControllerStatus::REGISTER_COUNT`] registers.
+    pub fn from_registers(values: &[u16]) -> Self {
+        let sensor_fault = checked_register_to_celsius(values[0]).is_none();
+        // Keep a faulty reading within the range the schema allows
+        let current_temp = register_to_celsius(
+            values[0].clamp(TEMPERATURE_REGISTER_MIN, TEMPERATURE_REGISTER_MAX),
+        );
+        // Out-of-range values decode as 0 instead of being truncated
+        let modbus_address = register_to_modbus_address(values[2]).unwrap_or(0);
+        let mode = OperatingMode::from_register_value(values[3]);
+        let fan_count = values[6] as u8;
+        let pwm_freq_raw = values[11];
+        let start_temp = register_to_celsius(
+            values[12].clamp(TEMPERATURE_REGISTER_MIN, TEMPERATURE_REGISTER_MAX),
+        );
+        let full_temp = register_to_celsius(
+            values[13].clamp(TEMPERATURE_REGISTER_MIN, TEMPERATURE_REGISTER_MAX),
+        );
+
+        // 0x0000 = Shutdown (ECO), 0x0001 = MinimumSpeed
+        let work_mode = WorkMode::from_register_value(values[5]).unwrap_or(WorkMode::MinimumSpeed);
+
+        // Parse PWM frequency
+        let pwm_frequency =
+            PwmFrequency::from_register_value(pwm_freq_raw).unwrap_or(PwmFrequency::Hz25000);
+
+        // values[1] = fan status bitmap (0x0001)
+        // values[7-10] = fan speeds (0x0007-0x000A)
+        // values[14] = fault bitmap (0x000E)
+        let running = parse_fan_status_bitmap(values[1]);
+        let fault_statuses = parse_fan_fault_bitmap(values[14]);
+
+        let mut fans = Vec::with_capacity(4);
+        for (i, index) in FanIndex::ALL.into_iter().enumerate() {
+            fans.push(FanInfo {
+                index,
+                status: fault_statuses[i],
+                rpm: values[7 + i],
+                is_running: running[i],
+            });
+        }
+
+        #[allow(deprecated)]
+        ControllerStatus {
+            mode,
+            manual_speed: None,
+            work_mode,
+            eco_mode: work_mode == WorkMode::Shutdown,
+            modbus_address,
+            pwm_frequency,
+            fan_count,
+            temperature_current: Temperature {
+                value: current_temp,
+                unit: TemperatureUnit::Celsius,
+            },
+            sensor_fault,
+            temperature_low_threshold: Temperature {
+                value: start_temp,
+                unit: TemperatureUnit::Celsius,
+            },
+            temperature_high_threshold: Temperature {
+                value: full_temp,
+                unit: TemperatureUnit::Celsius,
+            },
+            fans,
+        }
+    }
+
+    /// Returns `mode` and `manual_speed` as one [`OperatingModeState`].
+    ///
+    /// Manual mode without a known speed is reported as
+    /// [`OperatingModeState::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use jpf4826_driver::types::{ControllerStatus, OperatingMode, OperatingModeState};
+    /// let block = [71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 5, 70, 90, 0];
+    /// let mut status = ControllerStatus::from_registers(&block);
+    /// assert_eq!(status.mode_state(), OperatingModeState::Temperature);
+    ///
+    /// status.mode = OperatingMode::Manual;
+    /// status.manual_speed = Some(40);
+    /// assert_eq!(status.mode_state(), OperatingModeState::Manual(40));
+    /// ```
+    pub fn mode_state(&self) -> OperatingModeState {
+        match (self.mode, self.manual_speed) {
+            (OperatingMode::Temperature, _) => OperatingModeState::Temperature,
+            (OperatingMode::Manual, Some(speed)) => OperatingModeState::Manual(speed),
+            (OperatingMode::Manual, None) | (OperatingMode::Unknown, _) => {
+                OperatingModeState::Unknown
+            }
+        }
+    }
+
+    /// Returns the duty cycle in percent the controller should be
+    /// outputting, for checking the fan speeds against.
+    ///
+    /// In [`OperatingMode::Manual`] this is the manual speed. Otherwise the
+    /// controller is taken to be in temperature mode and the duty cycle
+    /// follows [`curve::expected_duty_cycle`](crate::curve::expected_duty_cycle)
+    /// for the current temperature, thresholds and work mode. Returns `None`
+    /// on a sensor fault, when the reading means nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let status = client.status().await?;
+    /// if let Some(duty) = status.expected_duty_cycle() {
+    ///     println!("Expecting {}% duty", duty);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expected_duty_cycle(&self) -> Option<u8> {
+        if self.mode == OperatingMode::Manual {
+            return self.manual_speed;
+        }
+        if self.sensor_fault {
+            return None;
+        }
+        Some(expected_duty_cycle(
+            self.temperature_current.to_celsius(),
+            self.temperature_low_threshold.to_celsius(),
+            self.temperature_high_threshold.to_celsius(),
+            self.work_mode,
+        ))
+    }
+
+    /// Returns the indices (1-4) of fans reported as faulted within
+    /// `fan_count`.
+    ///
+    /// Slots beyond the fan count are left out even if flagged, since some
+    /// firmware reports unconnected slots as faulted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let status = client.status().await?;
+    /// if !status.faulted_fans().is_empty() {
+    ///     eprintln!("Faulted fans: {:?}", status.faulted_fans());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn faulted_fans(&self) -> Vec<u8> {
+        self.fans
+            .iter()
+            .filter(|fan| fan.index.get() <= self.fan_count && fan.status == FanStatus::Fault)
+            .map(|fan| fan.index.get())
+            .collect()
+    }
+
+    /// Returns the indices (1-4) of fans that should be turning but read
+    /// below `min_rpm`.
+    ///
+    /// A fan counts if it is within `fan_count`, reported running by the
+    /// controller (register 0x0001) and not yet flagged as faulted. This
+    /// catches a seized fan before the controller's own fault detection
+    /// does; fans stopped on purpose, e.g. below the start temperature in
+    /// [`WorkMode::Shutdown`], and fans already reported as
+    /// [`FanStatus::Fault`] are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use jpf4826_driver::Jpf4826Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> jpf4826_driver::Result<()> {
+    /// # let mut client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+    /// let status = client.status().await?;
+    /// for index in status.stalled_fans(300) {
+    ///     eprintln!("Fan {} looks stalled", index);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stalled_fans(&self, min_rpm: u16) -> Vec<u8> {
+        self.fans
+            .iter()
+            .filter(|fan| {
+                fan.index.get() <= self.fan_count
+                    && fan.is_running
+                    && fan.status == FanStatus::Normal
+                    && fan.rpm < min_rpm
+            })
+            .map(|fan| fan.index.get())
+            .collect()
+    }
+}
+
 // Custom serde implementations to match JSON schema format
+#[cfg(all(feature = "alloc", feature = "serde"))]
 impl serde::Serialize for ControllerStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("ControllerStatus", 6)?;
-        state.serialize_field("eco_mode", &self.eco_mode)?;
+        use serde::ser::{Error, SerializeStruct};
+        if !(1..=254).contains(&self.modbus_address) {
+            return Err(S::Error::custom(format_args!(
+                "modbus_address {} out of range (1-254)",
+                self.modbus_address
+            )));
+        }
+        let mut state = serializer.serialize_struct("ControllerStatus", 9)?;
+        state.serialize_field("mode", &self.mode)?;
+        state.serialize_field("manual_speed", &self.manual_speed)?;
+        state.serialize_field("work_mode", &self.work_mode)?;
+        // Derived from work_mode so the two never disagree
+        state.serialize_field("eco_mode", &(self.work_mode == WorkMode::Shutdown))?;
         state.serialize_field("modbus_address", &self.modbus_address)?;
         state.serialize_field("pwm_frequency", &self.pwm_frequency)?;
         state.serialize_field("fan_count", &self.fan_count)?;
@@ -292,12 +1146,14 @@ impl serde::Serialize for ControllerStatus {
             current: Temperature,
             low_threshold: Temperature,
             high_threshold: Temperature,
+            sensor_fault: bool,
         }
 
         let temp_nested = TemperatureNested {
             current: self.temperature_current,
             low_threshold: self.temperature_low_threshold,
             high_threshold: self.temperature_high_threshold,
+            sensor_fault: self.sensor_fault,
         };
         state.serialize_field("temperature", &temp_nested)?;
         state.serialize_field("fans", &self.fans)?;
@@ -305,6 +1161,85 @@ impl serde::Serialize for ControllerStatus {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl JsonSchema for ControllerStatus {
+    fn schema_name() -> Cow<'static, str> {
+        "ControllerStatus".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mode = generator.subschema_for::<OperatingMode>();
+        let work_mode = generator.subschema_for::<WorkMode>();
+        let pwm_frequency = generator.subschema_for::<PwmFrequency>();
+        let temperature = generator.subschema_for::<Temperature>();
+        let fan = generator.subschema_for::<FanInfo>();
+        json_schema!({
+            "type": "object",
+            "required": [
+                "mode",
+                "manual_speed",
+                "work_mode",
+                "eco_mode",
+                "modbus_address",
+                "pwm_frequency",
+                "fan_count",
+                "temperature",
+                "fans"
+            ],
+            "properties": {
+                "mode": mode,
+                "manual_speed": {
+                    "type": ["integer", "null"],
+                    "minimum": 0,
+                    "maximum": 100,
+                    "description": "Speed percentage in MANUAL mode, null otherwise"
+                },
+                "work_mode": work_mode,
+                "eco_mode": {
+                    "type": "boolean",
+                    "deprecated": true,
+                    "description": "True when work_mode is Shutdown (deprecated, use work_mode)"
+                },
+                "modbus_address": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "maximum": 254,
+                    "description": "Modbus address of the controller"
+                },
+                "pwm_frequency": pwm_frequency,
+                "fan_count": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 4,
+                    "description": "Number of fans connected (0=fault detection disabled)"
+                },
+                "temperature": {
+                    "type": "object",
+                    "required": ["current", "low_threshold", "high_threshold", "sensor_fault"],
+                    "properties": {
+                        "current": temperature,
+                        "low_threshold": temperature,
+                        "high_threshold": temperature,
+                        "sensor_fault": {
+                            "type": "boolean",
+                            "description": "True when the sensor is disconnected or shorted; current is then not a reading"
+                        }
+                    },
+                    "description": "Temperature readings and thresholds"
+                },
+                "fans": {
+                    "type": "array",
+                    "maxItems": 4,
+                    "items": fan,
+                    "description": "Array of individual fan status information"
+                }
+            },
+            "description": "Complete controller status snapshot"
+        })
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
 impl<'de> serde::Deserialize<'de> for ControllerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -315,11 +1250,18 @@ impl<'de> serde::Deserialize<'de> for ControllerStatus {
             current: Temperature,
             low_threshold: Temperature,
             high_threshold: Temperature,
+            #[serde(default)]
+            sensor_fault: bool,
         }
 
         #[derive(Deserialize)]
         struct ControllerStatusHelper {
-            eco_mode: bool,
+            #[serde(default)]
+            mode: OperatingMode,
+            #[serde(default)]
+            manual_speed: Option<u8>,
+            work_mode: Option<WorkMode>,
+            eco_mode: Option<bool>,
             modbus_address: u8,
             pwm_frequency: PwmFrequency,
             fan_count: u8,
@@ -328,12 +1270,36 @@ impl<'de> serde::Deserialize<'de> for ControllerStatus {
         }
 
         let helper = ControllerStatusHelper::deserialize(deserializer)?;
+        if !(1..=254).contains(&helper.modbus_address) {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(u64::from(helper.modbus_address)),
+                &"a Modbus address in 1-254",
+            ));
+        }
+        if let Some(speed) = helper.manual_speed.filter(|speed| *speed > 100) {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(u64::from(speed)),
+                &"a speed percentage in 0-100",
+            ));
+        }
+        // work_mode wins; documents written before it existed only carry eco_mode
+        let work_mode = match (helper.work_mode, helper.eco_mode) {
+            (Some(work_mode), _) => work_mode,
+            (None, Some(true)) => WorkMode::Shutdown,
+            (None, Some(false)) => WorkMode::MinimumSpeed,
+            (None, None) => return Err(serde::de::Error::missing_field("work_mode")),
+        };
+        #[allow(deprecated)]
         Ok(ControllerStatus {
-            eco_mode: helper.eco_mode,
+            mode: helper.mode,
+            manual_speed: helper.manual_speed,
+            work_mode,
+            eco_mode: work_mode == WorkMode::Shutdown,
             modbus_address: helper.modbus_address,
             pwm_frequency: helper.pwm_frequency,
             fan_count: helper.fan_count,
             temperature_current: helper.temperature.current,
+            sensor_fault: helper.temperature.sensor_fault,
             temperature_low_threshold: helper.temperature.low_threshold,
             temperature_high_threshold: helper.temperature.high_threshold,
             fans: helper.fans,
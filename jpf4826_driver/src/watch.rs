@@ -0,0 +1,84 @@
+//! Periodic status snapshots as a [`Stream`].
+//!
+//! [`Jpf4826Client::watch`](crate::Jpf4826Client::watch) returns a
+//! [`StatusWatch`] that reads the controller status at a fixed interval and
+//! yields every result. A failed read is yielded as an error item and the
+//! next tick reads again, so a dashboard sees transient faults without
+//! losing the stream. Dropping the stream stops polling; a read in flight
+//! is cancelled as described in the [`client`](crate::client) module.
+//!
+//! Requires the `stream` feature.
+
+// Rust guideline compliant 2026-10-16
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::time::{Interval, MissedTickBehavior};
+
+use crate::client::Jpf4826Client;
+use crate::error::Result;
+use crate::types::ControllerStatus;
+
+/// Status read in flight, handing the client back when done.
+type PendingRead<'a> =
+    Pin<Box<dyn Future<Output = (&'a mut Jpf4826Client, Result<ControllerStatus>)> + Send + 'a>>;
+
+/// Stream of controller status snapshots, one per interval.
+///
+/// Created by [`Jpf4826Client::watch`]. Never ends on its own; drop it to
+/// stop polling.
+pub struct StatusWatch<'a> {
+    /// Client between reads; `None` while a read is in flight.
+    client: Option<&'a mut Jpf4826Client>,
+    read: Option<PendingRead<'a>>,
+    interval: Interval,
+}
+
+impl<'a> StatusWatch<'a> {
+    pub(crate) fn new(client: &'a mut Jpf4826Client, period: Duration) -> Self {
+        let mut interval = tokio::time::interval(period);
+        // A slow read postpones the following ones instead of bunching them
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            client: Some(client),
+            read: None,
+            interval,
+        }
+    }
+
+    /// Returns the time between reads.
+    pub fn period(&self) -> Duration {
+        self.interval.period()
+    }
+}
+
+impl Stream for StatusWatch<'_> {
+    type Item = Result<ControllerStatus>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.read.is_none() {
+            if this.interval.poll_tick(cx).is_pending() {
+                return Poll::Pending;
+            }
+            let client = this.client.take().expect("client is present between reads");
+            this.read = Some(Box::pin(async move {
+                let status = client.status().await;
+                (client, status)
+            }));
+        }
+
+        let read = this.read.as_mut().expect("read was just started");
+        let (client, status) = std::task::ready!(read.as_mut().poll(cx));
+        this.read = None;
+        this.client = Some(client);
+        if let Err(e) = &status {
+            log::warn!("Status read failed, retrying next interval: {}", e);
+        }
+        Poll::Ready(Some(status))
+    }
+}
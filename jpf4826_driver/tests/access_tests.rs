@@ -0,0 +1,154 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use jpf4826_driver::registers::{RegisterAccess, RegisterAddress};
+use mock::create_test_client;
+
+#[test]
+fn test_register_access_classes() {
+    for addr in 0x0000..=0x0020 {
+        let Some(register) = RegisterAddress::from_addr(addr) else {
+            continue;
+        };
+        let expected = match addr {
+            0x0000 | 0x0001 | 0x0007..=0x000A | 0x000E | 0x000F => RegisterAccess::ReadOnly,
+            0x0020 => RegisterAccess::WriteOnly,
+            _ => RegisterAccess::ReadWrite,
+        };
+        assert_eq!(register.access(), expected, "register {:#06X}", addr);
+        assert_eq!(
+            register.is_read_only(),
+            expected == RegisterAccess::ReadOnly
+        );
+    }
+
+    assert!(RegisterAccess::ReadOnly.is_readable());
+    assert!(!RegisterAccess::ReadOnly.is_writable());
+    assert!(RegisterAccess::ReadWrite.is_readable());
+    assert!(RegisterAccess::ReadWrite.is_writable());
+    assert!(!RegisterAccess::WriteOnly.is_readable());
+    assert!(RegisterAccess::WriteOnly.is_writable());
+}
+
+#[tokio::test]
+async fn test_write_to_read_only_register_is_refused() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client
+        .write(RegisterAddress::Fan1Speed, 0)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.register_access(),
+        Some((0x0007, RegisterAccess::ReadOnly))
+    );
+    assert_eq!(err.to_string(), "Register 0x0007 is read-only");
+
+    let err = client
+        .write(RegisterAddress::CurrentTemperature, 0)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0000, RegisterAccess::ReadOnly))
+    );
+
+    // A range reaching a read-only register is refused as a whole
+    let err = client
+        .write_multiple(RegisterAddress::FanQuantity, &[4, 0])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0007, RegisterAccess::ReadOnly))
+    );
+
+    let err = client
+        .write_broadcast(RegisterAddress::FanStatus, 0)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0001, RegisterAccess::ReadOnly))
+    );
+
+    assert_eq!(probe.writes(), 0);
+    assert_eq!(client.link_info().failures, 0);
+    assert_eq!(mock.read_register(0x0007), Some(1400));
+
+    // Reading it is fine
+    assert_eq!(
+        client.read(RegisterAddress::Fan1Speed, 1).await.unwrap(),
+        [1400]
+    );
+}
+
+#[tokio::test]
+async fn test_read_write_register_allows_both() {
+    let (mut client, mock) = create_test_client().await;
+
+    client.write(RegisterAddress::WorkMode, 0).await.unwrap();
+    assert_eq!(mock.read_register(0x0005), Some(0));
+    assert_eq!(
+        client.read(RegisterAddress::WorkMode, 1).await.unwrap(),
+        [0]
+    );
+}
+
+#[tokio::test]
+async fn test_read_of_write_only_register_is_refused() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client
+        .read(RegisterAddress::ResetController, 1)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.register_access(),
+        Some((0x0020, RegisterAccess::WriteOnly))
+    );
+    assert_eq!(err.to_string(), "Register 0x0020 is write-only");
+
+    let err = client
+        .read_array::<1>(RegisterAddress::ResetController)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0020, RegisterAccess::WriteOnly))
+    );
+
+    // A range reaching the reset register is refused as well
+    let mut buf = [0; 19];
+    let err = client
+        .read_into(RegisterAddress::FanFaultCode, &mut buf)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0020, RegisterAccess::WriteOnly))
+    );
+    assert_eq!(probe.reads(), 0);
+
+    // Writing it is fine
+    client.reset().await.unwrap();
+    assert_eq!(probe.write_log(), [(0x0020, 0x00AA)]);
+}
+
+#[tokio::test]
+async fn test_write_unchecked_skips_access_checks() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    client.write_unchecked(0x0007, 0).await.unwrap();
+    client.write_unchecked(0x0010, 0x0001).await.unwrap();
+
+    assert_eq!(probe.write_log(), [(0x0007, 0), (0x0010, 0x0001)]);
+    assert_eq!(mock.read_register(0x0010), Some(0x0001));
+    assert_eq!(client.link_info().successes, 2);
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{SetOperation, WorkMode};
+use mock::create_test_client;
+
+const OPS: [SetOperation; 3] = [
+    SetOperation::FanCount(2),
+    SetOperation::WorkMode(WorkMode::Shutdown),
+    SetOperation::TemperatureThreshold { low: 35, high: 55 },
+];
+
+#[tokio::test]
+async fn test_set_many_applies_operations_in_order() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let report = client
+        .set_many(&[OPS[0], OPS[1], OPS[2], SetOperation::FanSpeed(60)])
+        .await
+        .unwrap();
+
+    assert!(report.is_success());
+    assert!(!report.is_rolled_back());
+    assert_eq!(report.applied, 4);
+    assert!(report.restored.is_empty());
+    assert_eq!(probe.read_log(), [(0x0002, 10)]);
+    assert_eq!(
+        probe.write_log(),
+        [(0x0006, 2), (0x0005, 0), (0x0004, 0x4B5F), (0x0003, 60)]
+    );
+    assert_eq!(mock.read_register(0x000C), Some(75));
+}
+
+#[tokio::test]
+async fn test_set_many_rolls_back_when_third_operation_fails() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_writes_at(0x0004);
+
+    let report = client.set_many(&OPS).await.unwrap();
+
+    assert_eq!(report.applied, 2);
+    let error = report.error.as_ref().unwrap();
+    assert!(error.is_modbus());
+    assert!(report.is_rolled_back());
+    assert_eq!(
+        report.restored,
+        [RegisterAddress::WorkMode, RegisterAddress::FanQuantity]
+    );
+    assert!(report.rollback_errors.is_empty());
+    assert_eq!(
+        probe.write_log(),
+        [(0x0006, 2), (0x0005, 0), (0x0005, 1), (0x0006, 4)]
+    );
+    assert_eq!(mock.read_register(0x0004), Some(0x465A));
+    assert_eq!(mock.read_register(0x0005), Some(1));
+    assert_eq!(mock.read_register(0x0006), Some(4));
+}
+
+#[tokio::test]
+async fn test_set_many_reports_rollback_failures() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.fail_writes_after(2);
+
+    let report = client.set_many(&OPS).await.unwrap();
+
+    assert_eq!(report.applied, 2);
+    assert!(report.error.is_some());
+    assert!(!report.is_rolled_back());
+    assert!(report.restored.is_empty());
+    let failed: Vec<_> = report
+        .rollback_errors
+        .iter()
+        .map(|(register, _)| *register)
+        .collect();
+    assert_eq!(
+        failed,
+        [RegisterAddress::WorkMode, RegisterAddress::FanQuantity]
+    );
+    // The controller keeps the first two settings
+    assert_eq!(mock.read_register(0x0005), Some(0));
+    assert_eq!(mock.read_register(0x0006), Some(2));
+}
+
+#[tokio::test]
+async fn test_set_many_returns_to_temperature_mode_on_rollback() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_writes_at(0x0006);
+
+    let report = client
+        .set_many(&[
+            SetOperation::FanSpeed(80),
+            SetOperation::WorkMode(WorkMode::Shutdown),
+            SetOperation::FanCount(2),
+        ])
+        .await
+        .unwrap();
+
+    assert!(report.is_rolled_back());
+    assert_eq!(
+        report.restored,
+        [
+            RegisterAddress::WorkMode,
+            RegisterAddress::ManualSpeedControl
+        ]
+    );
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+}
+
+#[tokio::test]
+async fn test_set_many_validates_before_writing() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client
+        .set_many(&[OPS[0], OPS[1], SetOperation::FanSpeed(101)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("101"));
+
+    let err = client
+        .set_many(&[
+            SetOperation::TemperatureThreshold { low: 50, high: 50 },
+            OPS[0],
+        ])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Temperature threshold error: high (50°C) must be greater than low (50°C)"
+    );
+
+    assert_eq!((probe.reads(), probe.writes()), (0, 0));
+}
+
+#[tokio::test]
+async fn test_set_many_requires_address_change_last() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client
+        .set_many(&[SetOperation::Address(7), OPS[0]])
+        .await
+        .unwrap_err();
+
+    assert!(err.is_invalid_parameter());
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test]
+async fn test_set_many_changes_address_last() {
+    let (mut client, mock) = create_test_client().await;
+
+    let report = client
+        .set_many(&[OPS[0], SetOperation::Address(7)])
+        .await
+        .unwrap();
+
+    assert!(report.is_success());
+    assert_eq!(mock.read_register(0x0002), Some(7));
+    assert_eq!(mock.read_register(0x0006), Some(2));
+}
+
+#[tokio::test]
+async fn test_set_many_does_not_roll_back_failed_address_change() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_writes_at(0x0002);
+
+    let report = client
+        .set_many(&[OPS[1], SetOperation::Address(7)])
+        .await
+        .unwrap();
+
+    assert!(report.is_rolled_back());
+    assert_eq!(report.restored, [RegisterAddress::WorkMode]);
+    assert_eq!(probe.write_log(), [(0x0005, 0), (0x0005, 1)]);
+    assert_eq!(mock.read_register(0x0002), Some(1));
+}
+
+#[tokio::test]
+async fn test_set_many_without_operations_does_nothing() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let report = client.set_many(&[]).await.unwrap();
+
+    assert!(report.is_success());
+    assert_eq!(report.applied, 0);
+    assert_eq!((probe.reads(), probe.writes()), (0, 0));
+}
@@ -0,0 +1,78 @@
+#![cfg(all(feature = "test-mock", feature = "blocking"))]
+
+mod mock;
+
+use jpf4826_driver::blocking::Jpf4826Client;
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{OperatingMode, WorkMode};
+use mock::MockController;
+
+fn create_test_client() -> (Jpf4826Client, MockController) {
+    let mock = MockController::new();
+    let client = Jpf4826Client::new_mock(mock.registers.clone(), 1);
+    (client, mock)
+}
+
+#[test]
+fn test_status_without_async_runtime() {
+    let (mut client, _mock) = create_test_client();
+
+    let status = client.status().unwrap();
+
+    assert_eq!(status.temperature_current.value, 31);
+    assert_eq!(status.fan_count, 4);
+    assert_eq!(client.temperature().unwrap().value, 31);
+    assert_eq!(client.fan_speeds().unwrap(), [1400; 4]);
+}
+
+#[test]
+fn test_writes_reach_controller() {
+    let (mut client, mock) = create_test_client();
+
+    client.set_fan_speed(60).unwrap();
+    client.set_eco(WorkMode::MinimumSpeed).unwrap();
+
+    assert_eq!(
+        mock.read_register(RegisterAddress::ManualSpeedControl.addr()),
+        Some(60)
+    );
+    assert_eq!(client.operating_mode().unwrap(), OperatingMode::Manual);
+    assert_eq!(client.manual_speed().unwrap(), Some(60));
+    assert_eq!(client.work_mode().unwrap(), WorkMode::MinimumSpeed);
+}
+
+#[test]
+fn test_errors_are_shared_with_async_client() {
+    let (mut client, _mock) = create_test_client();
+
+    let err: jpf4826_driver::Jpf4826Error = client.set_fan_speed(101).unwrap_err();
+    assert!(err.to_string().contains("101%"), "{}", err);
+
+    client.mock_probe().unwrap().set_fail_reads(true);
+    assert!(client.temperature().unwrap_err().is_modbus());
+}
+
+#[test]
+fn test_config_round_trip() {
+    let (mut client, _mock) = create_test_client();
+    let mut config = client.get_config().unwrap();
+    config.fan_count = Some(2);
+
+    client.apply_config(&config).unwrap();
+
+    assert_eq!(client.fan_count().unwrap(), 2);
+    assert!(client.verify_configuration(&config).unwrap().is_empty());
+}
+
+#[test]
+fn test_client_moves_to_another_thread() {
+    let (mut client, _mock) = create_test_client();
+    client.set_retries(2);
+
+    let handle = std::thread::spawn(move || {
+        let count = client.fan_count().unwrap();
+        (count, client.retries())
+    });
+
+    assert_eq!(handle.join().unwrap(), (4, 2));
+}
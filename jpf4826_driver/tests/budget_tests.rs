@@ -0,0 +1,144 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use jpf4826_driver::budget::{BudgetPolicy, WriteBudget};
+use jpf4826_driver::registers::RegisterAddress;
+use mock::create_test_client;
+use tokio::time::Instant;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+#[tokio::test]
+async fn test_write_counters() {
+    let (mut client, _mock) = create_test_client().await;
+
+    client.set_fan_count(3).await.unwrap();
+    client.set_fan_count(2).await.unwrap();
+    client
+        .write_multiple(RegisterAddress::WorkMode, &[0x0000, 0x0004])
+        .await
+        .unwrap();
+    client.reset().await.unwrap();
+    // Read-only registers are not counted
+    client.write_unchecked(0x0007, 0).await.unwrap();
+
+    let link = client.link_info();
+    assert_eq!(
+        link.register_writes,
+        BTreeMap::from([(0x0005, 1), (0x0006, 3)])
+    );
+    assert_eq!(link.total_writes(), 4);
+    assert_eq!(link.resets, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_budget_rejects_excess_writes() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client
+        .set_write_budget(Some(WriteBudget::new(2, WINDOW)))
+        .unwrap();
+
+    client.set_fan_count(3).await.unwrap();
+    tokio::time::advance(Duration::from_secs(20)).await;
+    client.set_fan_count(2).await.unwrap();
+
+    let err = client.set_fan_count(1).await.unwrap_err();
+    assert!(err.is_write_budget_exceeded());
+    assert_eq!(
+        err.write_budget_retry(),
+        Some((0x0006, Duration::from_secs(40)))
+    );
+    assert_eq!(mock.read_register(0x0006), Some(2));
+    assert_eq!(probe.writes(), 2);
+
+    // A refused write never reaches the bus
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (2, 0));
+    assert_eq!(link.register_writes[&0x0006], 2);
+
+    // Other registers have their own budget
+    client
+        .set_pwm_frequency(jpf4826_driver::PwmFrequency::Hz1000)
+        .await
+        .unwrap();
+
+    // The first write leaves the window
+    tokio::time::advance(Duration::from_secs(40)).await;
+    client.set_fan_count(1).await.unwrap();
+    assert_eq!(mock.read_register(0x0006), Some(1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_budget_rejects_whole_multi_register_write() {
+    let (mut client, mock) = create_test_client().await;
+    client
+        .set_write_budget(Some(WriteBudget::new(1, WINDOW)))
+        .unwrap();
+
+    client.set_fan_count(3).await.unwrap();
+    let err = client
+        .write_multiple(RegisterAddress::WorkMode, &[0x0000, 0x0002])
+        .await
+        .unwrap_err();
+    assert_eq!(err.write_budget_retry(), Some((0x0006, WINDOW)));
+    // Neither register was written
+    assert_eq!(mock.read_register(0x0005), Some(0x0001));
+    assert_eq!(mock.read_register(0x0006), Some(3));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_budget_delays_excess_writes() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let budget = WriteBudget::new(1, WINDOW).with_policy(BudgetPolicy::Delay);
+    client.set_write_budget(Some(budget)).unwrap();
+    assert_eq!(client.write_budget(), Some(budget));
+
+    let started = Instant::now();
+    client.set_fan_count(3).await.unwrap();
+    assert_eq!(started.elapsed(), Duration::ZERO);
+
+    client.set_fan_count(2).await.unwrap();
+    assert_eq!(started.elapsed(), WINDOW);
+    assert_eq!(mock.read_register(0x0006), Some(2));
+    assert_eq!(probe.writes(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_budget_exempts_reset_and_read_only_registers() {
+    let (mut client, _mock) = create_test_client().await;
+    client
+        .set_write_budget(Some(WriteBudget::new(1, WINDOW)))
+        .unwrap();
+
+    for _ in 0..3 {
+        client.reset().await.unwrap();
+        client.write_unchecked(0x0007, 0).await.unwrap();
+    }
+    assert_eq!(client.link_info().resets, 3);
+}
+
+#[tokio::test]
+async fn test_invalid_budget_is_rejected() {
+    let (mut client, _mock) = create_test_client().await;
+
+    for budget in [
+        WriteBudget::new(0, WINDOW),
+        WriteBudget::new(1, Duration::ZERO),
+    ] {
+        let err = client.set_write_budget(Some(budget)).unwrap_err();
+        assert!(err.is_invalid_parameter());
+    }
+    assert_eq!(client.write_budget(), None);
+
+    client
+        .set_write_budget(Some(WriteBudget::new(1, WINDOW)))
+        .unwrap();
+    client.set_write_budget(None).unwrap();
+    assert_eq!(client.write_budget(), None);
+}
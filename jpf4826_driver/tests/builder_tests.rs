@@ -0,0 +1,268 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::{MockBus, MockFailure};
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{
+    DataBits, DeviceModel, Jpf4826Client, Jpf4826ClientBuilder, Parity, StopBits,
+};
+use mock::MockController;
+
+const MISSING_PORT: &str = "/dev/jpf4826-does-not-exist";
+
+#[test]
+fn test_builder_defaults_match_new() {
+    let builder = Jpf4826ClientBuilder::new("/dev/ttyUSB0", 1);
+    let options = builder.options();
+    assert_eq!(options.baud_rate, 9600);
+    assert_eq!(options.data_bits, DataBits::Eight);
+    assert_eq!(options.parity, Parity::None);
+    assert_eq!(options.stop_bits, StopBits::One);
+    assert_eq!(options.timeout, Duration::from_secs(10));
+    assert_eq!(options.retries, 0);
+}
+
+#[test]
+fn test_builder_keeps_settings() {
+    let client = Jpf4826ClientBuilder::new("/dev/ttyUSB0", 1)
+        .with_baud_rate(19200)
+        .with_parity(Parity::Even)
+        .with_stop_bits(StopBits::Two)
+        .with_timeout(Duration::from_secs(2))
+        .with_retries(3)
+        .with_model(DeviceModel::Jpf4826Rev2)
+        .build()
+        .unwrap();
+
+    assert!(!client.is_connected());
+    assert_eq!(client.timeout(), Duration::from_secs(2));
+    assert_eq!(client.retries(), 3);
+    assert_eq!(client.model(), DeviceModel::Jpf4826Rev2);
+}
+
+#[tokio::test]
+async fn test_open_error_names_serial_settings() {
+    let err = Jpf4826ClientBuilder::new(MISSING_PORT, 1)
+        .with_parity(Parity::Even)
+        .connect()
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.is_serial());
+    assert!(err.to_string().contains("at 9600 8E1"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_open_error_names_data_bits() {
+    let builder = Jpf4826ClientBuilder::new(MISSING_PORT, 1)
+        .with_data_bits(DataBits::Seven)
+        .with_parity(Parity::Odd);
+    assert_eq!(builder.options().data_bits, DataBits::Seven);
+
+    let err = builder.connect().await.err().unwrap();
+
+    assert!(err.is_serial());
+    assert!(err.to_string().contains("at 9600 7O1"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_builder_rejects_zero_baud_rate_before_opening() {
+    let err = Jpf4826ClientBuilder::new(MISSING_PORT, 1)
+        .with_baud_rate(0)
+        .connect()
+        .await
+        .err()
+        .unwrap();
+    // Opening the missing port would be a serial error
+    assert!(err.is_invalid_parameter(), "{}", err);
+}
+
+#[tokio::test]
+async fn test_builder_rejects_zero_timeout_before_opening() {
+    let err = Jpf4826ClientBuilder::new(MISSING_PORT, 1)
+        .with_timeout(Duration::ZERO)
+        .connect()
+        .await
+        .err()
+        .unwrap();
+    assert!(err.is_invalid_parameter(), "{}", err);
+}
+
+#[tokio::test]
+async fn test_builder_rejects_invalid_address() {
+    for addr in [0, 255] {
+        let result = Jpf4826ClientBuilder::new(MISSING_PORT, addr)
+            .connect()
+            .await;
+        assert!(result.is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_builder_reports_missing_port() {
+    let err = Jpf4826ClientBuilder::new(MISSING_PORT, 1)
+        .connect()
+        .await
+        .err()
+        .unwrap();
+    assert!(err.is_serial());
+}
+
+#[tokio::test]
+async fn test_failed_read_is_retried() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(2);
+
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.unwrap_err().is_modbus());
+    assert_eq!(probe.reads(), 3);
+    // Counted as one failed transaction
+    assert_eq!(client.link_info().failures, 1);
+
+    probe.set_fail_reads(false);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert_eq!(probe.reads(), 4);
+}
+
+#[tokio::test]
+async fn test_short_response_is_not_retried() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(2);
+
+    probe.set_response_limit(Some(3));
+    let err = client.fan_speeds().await.unwrap_err();
+    assert_eq!(err.response_length_mismatch(), Some((4, 3)));
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test]
+async fn test_exception_is_not_retried() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(2);
+
+    probe.fail_next_read(MockFailure::Exception);
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_modbus_exception(), "{}", err);
+    assert!(!err.is_link_failure(), "{}", err);
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_write_is_retried() {
+    let bus = MockBus::new();
+    let mock = MockController::new();
+    bus.add_device(1, mock.registers.clone());
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), 1).unwrap();
+    client.connect().await.unwrap();
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(1);
+
+    bus.set_offline(true);
+    let err = client
+        .write(RegisterAddress::FanQuantity, 2)
+        .await
+        .unwrap_err();
+    assert!(err.is_modbus());
+    assert_eq!(probe.writes(), 2);
+}
+
+#[tokio::test]
+async fn test_retry_consumes_injected_failure() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(1);
+
+    probe.fail_next_read(MockFailure::Timeout);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert_eq!(probe.reads(), 2);
+
+    probe.fail_next_write(MockFailure::Transport);
+    client.write(RegisterAddress::FanQuantity, 2).await.unwrap();
+    assert_eq!(probe.writes(), 2);
+    assert_eq!(mock.read_register(0x0006), Some(2));
+}
+
+#[tokio::test]
+async fn test_injected_failures_match_backend_error_kinds() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+
+    probe.fail_next_read(MockFailure::Timeout);
+    probe.fail_next_read(MockFailure::Exception);
+    probe.fail_next_read(MockFailure::Transport);
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_timeout(), "{}", err);
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_modbus(), "{}", err);
+    assert!(err.to_string().contains("ServerDeviceFailure"), "{}", err);
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_modbus() && !err.is_timeout(), "{}", err);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_failures_injected_at_address() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+
+    probe.fail_reads_at(0x0006, 2, MockFailure::Exception);
+    // Reads not covering 0x0006 are served
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert!(client.fan_count().await.is_err());
+    assert!(client.status().await.is_err());
+    assert_eq!(client.fan_count().await.unwrap(), 4);
+
+    probe.fail_writes_at(0x0006, 1, MockFailure::Timeout);
+    let err = client.set_fan_count(2).await.unwrap_err();
+    assert!(err.is_timeout());
+    assert_eq!(mock.read_register(0x0006), Some(4));
+    client.set_fan_count(2).await.unwrap();
+    assert_eq!(mock.read_register(0x0006), Some(2));
+}
+
+#[tokio::test]
+async fn test_requests_are_not_retried_by_default() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.is_err());
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test]
+async fn test_new_with_baud_rejects_unsupported_rates() {
+    for baud_rate in [0, 1200, 57600, 115200] {
+        let err = Jpf4826Client::new_with_baud(MISSING_PORT, 1, baud_rate)
+            .await
+            .err()
+            .unwrap();
+        assert!(err.is_invalid_parameter(), "{}: {}", baud_rate, err);
+    }
+}
+
+#[tokio::test]
+async fn test_new_with_baud_opens_supported_rates() {
+    for baud_rate in jpf4826_driver::SUPPORTED_BAUD_RATES {
+        let err = Jpf4826Client::new_with_baud(MISSING_PORT, 1, baud_rate)
+            .await
+            .err()
+            .unwrap();
+        // Got as far as opening the port
+        assert!(err.is_serial(), "{}: {}", baud_rate, err);
+    }
+}
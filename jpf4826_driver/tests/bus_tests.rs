@@ -0,0 +1,90 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::{Jpf4826Bus, Jpf4826Client, OperatingMode};
+use mock::MockController;
+
+/// Creates a bus with controllers at addresses 3 (31°C) and 7 (45°C).
+fn two_controllers() -> (Jpf4826Bus, MockController, MockController) {
+    let mock_bus = MockBus::new();
+    let first = MockController::new();
+    let second = MockController::new();
+    second.write_register(0x0000, 85);
+    mock_bus.add_device(3, first.registers.clone());
+    mock_bus.add_device(7, second.registers.clone());
+
+    let client = Jpf4826Client::new_lazy_mock(mock_bus, 1).unwrap();
+    (Jpf4826Bus::new(client), first, second)
+}
+
+#[tokio::test]
+async fn test_devices_read_their_own_controller() {
+    let (bus, _first, _second) = two_controllers();
+    let first = bus.device(3).unwrap();
+    let second = bus.device(7).unwrap();
+
+    assert_eq!(first.addr(), 3);
+    assert_eq!(first.status().await.unwrap().temperature_current.value, 31);
+    assert_eq!(second.status().await.unwrap().temperature_current.value, 45);
+}
+
+#[tokio::test]
+async fn test_interleaved_requests_keep_their_address() {
+    let (bus, first_mock, second_mock) = two_controllers();
+    let probe = {
+        let device = bus.device(3).unwrap();
+        let mut client = device.lock().await;
+        client.connect().await.unwrap();
+        client.mock_probe().unwrap()
+    };
+    // Requests overlap in time unless serialized
+    probe.set_read_delay(Duration::from_millis(5));
+    probe.set_write_delay(Duration::from_millis(5));
+
+    let mut tasks = Vec::new();
+    for (addr, speed, temperature) in [(3, 30, 31), (7, 70, 45)] {
+        let device = bus.device(addr).unwrap();
+        tasks.push(tokio::spawn(async move {
+            for _ in 0..5 {
+                device
+                    .with_client(async |client| client.set_fan_speed(speed).await)
+                    .await
+                    .unwrap();
+                let temp = device.lock().await.temperature().await.unwrap();
+                assert_eq!(temp.value, temperature, "address {}", addr);
+            }
+        }));
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    assert_eq!(first_mock.read_register(0x0003), Some(30));
+    assert_eq!(second_mock.read_register(0x0003), Some(70));
+}
+
+#[tokio::test]
+async fn test_write_does_not_reach_other_device() {
+    let (bus, _first, _second) = two_controllers();
+    let first = bus.device(3).unwrap();
+    let second = bus.device(7).unwrap();
+
+    first.lock().await.set_fan_speed(40).await.unwrap();
+    assert_eq!(
+        second.lock().await.operating_mode().await.unwrap(),
+        OperatingMode::Temperature
+    );
+    assert_eq!(first.lock().await.manual_speed().await.unwrap(), Some(40));
+}
+
+#[test]
+fn test_device_rejects_invalid_address() {
+    let (bus, _first, _second) = two_controllers();
+    for addr in [0, 255] {
+        assert!(bus.device(addr).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::Jpf4826Client;
+use mock::MockController;
+
+const TTL: Duration = Duration::from_millis(200);
+
+async fn create_cached_client() -> (Jpf4826Client, MockController) {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    client.set_cache_ttl(TTL);
+    (client, mock)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cache_is_disabled_by_default() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    assert_eq!(client.cache_ttl(), Duration::ZERO);
+
+    client.temperature().await.unwrap();
+    client.temperature().await.unwrap();
+
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_reads_within_ttl_are_served_from_cache() {
+    let (mut client, mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let status = client.status().await.unwrap();
+    // Values changed on the controller are not seen within the TTL
+    mock.write_register(0x0000, 80);
+    let temperature = client.temperature().await.unwrap();
+    let speeds = client.fan_speeds().await.unwrap();
+    let raw = client
+        .read(RegisterAddress::CurrentTemperature, 2)
+        .await
+        .unwrap();
+
+    assert_eq!(probe.reads(), 1);
+    assert_eq!(temperature, status.temperature_current);
+    assert_eq!(speeds, [1400; 4]);
+    assert_eq!(raw, vec![71, mock.read_register(0x0001).unwrap()]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cached_values_expire() {
+    let (mut client, mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    mock.write_register(0x0000, 80);
+
+    tokio::time::advance(TTL).await;
+
+    assert_eq!(client.temperature().await.unwrap().value, 40);
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_write_invalidates_overlapping_ranges() {
+    let (mut client, _mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.status().await.unwrap();
+    client.fan_count().await.unwrap();
+    assert_eq!(probe.reads(), 1);
+
+    client.set_fan_count(2).await.unwrap();
+
+    assert_eq!(client.fan_count().await.unwrap(), 2);
+    assert_eq!(probe.reads(), 2);
+    // The write dropped the whole status range
+    client.temperature().await.unwrap();
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failed_read_is_not_cached() {
+    let (mut client, _mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.is_err());
+
+    probe.set_fail_reads(false);
+
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_probe_bypasses_cache() {
+    let (mut client, _mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.temperature().await.unwrap();
+
+    client.probe().await.unwrap();
+    client.probe().await.unwrap();
+
+    assert_eq!(probe.reads(), 3);
+    // The cache is back in use afterwards
+    client.temperature().await.unwrap();
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_zero_ttl_disables_cache() {
+    let (mut client, _mock) = create_cached_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.temperature().await.unwrap();
+
+    client.set_cache_ttl(Duration::ZERO);
+    client.temperature().await.unwrap();
+
+    assert_eq!(probe.reads(), 2);
+    assert_eq!(client.cache_ttl(), Duration::ZERO);
+}
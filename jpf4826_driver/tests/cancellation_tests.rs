@@ -0,0 +1,166 @@
+#![cfg(feature = "test-mock")]
+
+//! Dropping client futures at injected await points, checked against the
+//! guarantees in the `client` module documentation.
+
+mod mock;
+
+use std::future::Future;
+use std::time::Duration;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{ApplyReport, Profile, PwmFrequency};
+use mock::create_test_client;
+
+/// How long every mock write is in flight.
+const WRITE_DELAY: Duration = Duration::from_millis(10);
+
+/// Runs `operation`, dropping it after `after` if still pending.
+async fn drop_after<T>(after: Duration, operation: impl Future<Output = T>) -> Option<T> {
+    tokio::time::timeout(after, operation).await.ok()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_read_leaves_client_usable() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_read_delay(Duration::from_millis(10));
+
+    assert!(drop_after(Duration::from_millis(5), client.status())
+        .await
+        .is_none());
+
+    probe.set_read_delay(Duration::ZERO);
+    assert_eq!(client.status().await.unwrap().temperature_current.value, 31);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_threshold_write_changes_both_or_neither() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_write_delay(WRITE_DELAY);
+
+    let dropped = drop_after(WRITE_DELAY / 2, client.set_temperature_threshold(25, 45)).await;
+
+    assert!(dropped.is_none());
+    assert_eq!(mock.read_register(0x000C), Some(70));
+    assert_eq!(mock.read_register(0x000D), Some(90));
+
+    client.set_temperature_threshold(25, 45).await.unwrap();
+
+    assert_eq!(probe.write_log(), vec![(0x0004, 0x4155)]);
+    assert_eq!(probe.writes(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_apply_config_keeps_first_frame() {
+    let (mut client, mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_write_delay(WRITE_DELAY);
+
+    // 0x0004-0x0005 land, the 0x000B frame is dropped in flight
+    let quiet = Profile::quiet();
+    let dropped = drop_after(WRITE_DELAY * 3 / 2, client.apply_config(&quiet.config)).await;
+
+    assert!(dropped.is_none());
+    assert_eq!(mock.read_register(0x0005), Some(0x0000));
+    assert_eq!(mock.read_register(0x000B), Some(0x0005));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_apply_config_until_finishes_frame_in_flight() {
+    let (mut client, mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_write_delay(WRITE_DELAY);
+
+    let quiet = Profile::quiet();
+    let report = client
+        .apply_config_until(&quiet.config, tokio::time::sleep(WRITE_DELAY / 2))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        report,
+        ApplyReport {
+            written: vec![
+                RegisterAddress::CombinedTemperature,
+                RegisterAddress::WorkMode,
+            ],
+            unchanged: vec![],
+            transactions: 1,
+            cancelled: vec![RegisterAddress::PwmFrequency],
+        }
+    );
+    assert!(report.is_cancelled());
+    assert_eq!(mock.read_register(0x0005), Some(0x0000));
+    assert_eq!(mock.read_register(0x000B), Some(0x0005));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_apply_config_until_cancelled_before_writing() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let quiet = Profile::quiet();
+    let report = client
+        .apply_config_until(&quiet.config, std::future::ready(()))
+        .await
+        .unwrap();
+
+    assert!(report.written.is_empty());
+    assert_eq!(report.transactions, 0);
+    assert_eq!(
+        report.cancelled,
+        vec![
+            RegisterAddress::CombinedTemperature,
+            RegisterAddress::WorkMode,
+            RegisterAddress::PwmFrequency,
+        ]
+    );
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_apply_config_until_without_cancel_is_complete() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_write_delay(WRITE_DELAY);
+
+    let quiet = Profile::quiet();
+    let report = client
+        .apply_config_until(&quiet.config, tokio::time::sleep(WRITE_DELAY * 3))
+        .await
+        .unwrap();
+
+    assert!(!report.is_cancelled());
+    assert_eq!(report.transactions, 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_factory_restore_keeps_manual_speed() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_fan_speed(40).await.unwrap();
+    client
+        .set_pwm_frequency(PwmFrequency::Hz5000)
+        .await
+        .unwrap();
+    client.mock_probe().unwrap().set_write_delay(WRITE_DELAY);
+
+    // The PWM frame lands, the mode write is dropped in flight
+    let dropped = drop_after(WRITE_DELAY * 3 / 2, client.restore_factory_defaults()).await;
+
+    assert!(dropped.is_none());
+    assert_eq!(mock.read_register(0x000B), Some(0x0005));
+    assert_eq!(mock.read_register(0x0003), Some(40));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_identify_leaves_pulse_speed() {
+    let (mut client, mock) = create_test_client().await;
+
+    let dropped = drop_after(
+        Duration::from_millis(1500),
+        client.identify(Duration::from_secs(60)),
+    )
+    .await;
+
+    assert!(dropped.is_none());
+    assert_eq!(mock.read_register(0x0003), Some(20));
+}
@@ -2,17 +2,8 @@
 
 mod mock;
 
-use jpf4826_driver::{FanStatus, Jpf4826Client, TemperatureUnit};
-use mock::MockController;
-
-// Helper to create a test client
-#[cfg(any(test, feature = "test-mock"))]
-async fn create_test_client() -> (Jpf4826Client, MockController) {
-    let mock = MockController::new();
-    let registers = mock.registers.clone();
-    let client = Jpf4826Client::new_mock(registers, 1).await;
-    (client, mock)
-}
+use jpf4826_driver::{FanIndex, FanStatus, OperatingMode, PwmFrequency, TemperatureUnit, WorkMode};
+use mock::create_test_client;
 
 #[tokio::test]
 async fn test_read_temperature() {
@@ -23,6 +14,76 @@ async fn test_read_temperature() {
     assert_eq!(temp.unit, TemperatureUnit::Celsius);
 }
 
+#[tokio::test]
+async fn test_read_temperature_in_unit() {
+    let (mut client, mock) = create_test_client().await;
+
+    let temp = client
+        .temperature_in(TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap();
+    assert_eq!(temp.value, 88); // 31°C = 87.8°F
+    assert_eq!(temp.unit, TemperatureUnit::Fahrenheit);
+
+    mock.write_register(0x0000, 0x0015); // -19°C
+    let temp = client
+        .temperature_in(TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap();
+    assert_eq!(temp.value, -2); // -2.2°F
+    let temp = client
+        .temperature_in(TemperatureUnit::Kelvin)
+        .await
+        .unwrap();
+    assert_eq!(temp.value, 254);
+}
+
+#[tokio::test]
+async fn test_temperature_sensor_fault() {
+    let (mut client, mock) = create_test_client().await;
+
+    // Pegged at either end of the range
+    for value in [0x0014, 0x00A0] {
+        mock.write_register(0x0000, value);
+
+        let err = client.temperature().await.unwrap_err();
+        assert!(err.is_sensor_fault(), "{:#06X}: {}", value, err);
+        let status = client.status().await.unwrap();
+        assert!(status.sensor_fault, "{:#06X}", value);
+    }
+
+    for (value, celsius) in [(0x0015, -19), (0x009F, 119)] {
+        mock.write_register(0x0000, value);
+
+        assert_eq!(client.temperature().await.unwrap().value, celsius);
+        assert!(!client.status().await.unwrap().sensor_fault);
+    }
+}
+
+#[tokio::test]
+async fn test_out_of_range_temperature_is_invalid_data() {
+    let (mut client, mock) = create_test_client().await;
+
+    // No sensor state produces these; they are corrupted reads
+    for value in [0x0000, 0x0013, 0x00A1, 0xFFFF] {
+        mock.write_register(0x0000, value);
+
+        let err = client.temperature().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+        assert!(!err.is_sensor_fault(), "{:#06X}: {}", value, err);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Controller returned out-of-range value {:#06X} for register 0x0000",
+                value
+            )
+        );
+        let err = client.status().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+        assert!(err.to_string().contains("register 0x0000"), "{}", err);
+    }
+}
+
 #[tokio::test]
 async fn test_read_fan_speed() {
     let (mut client, _mock) = create_test_client().await;
@@ -34,6 +95,50 @@ async fn test_read_fan_speed() {
     }
 }
 
+#[tokio::test]
+async fn test_read_fan_speed_by_fan_index() {
+    let (mut client, mock) = create_test_client().await;
+    for (register, rpm) in (0x0007..=0x000A).zip([1100, 1200, 1300, 1400]) {
+        mock.write_register(register, rpm);
+    }
+
+    for (fan, rpm) in FanIndex::ALL.into_iter().zip([1100, 1200, 1300, 1400]) {
+        assert_eq!(client.fan_speed(fan).await.unwrap(), rpm);
+    }
+}
+
+#[tokio::test]
+async fn test_read_fan_speeds_in_one_request() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    assert_eq!(client.fan_speeds().await.unwrap(), [1400; 4]);
+    assert_eq!(probe.reads(), 1);
+
+    // A response one register short shows that all four were requested
+    probe.set_response_limit(Some(3));
+    let err = client.fan_speeds().await.unwrap_err();
+    assert_eq!(err.response_length_mismatch(), Some((4, 3)));
+}
+
+#[tokio::test]
+async fn test_read_fan_running() {
+    let (mut client, mock) = create_test_client().await;
+
+    assert_eq!(client.fan_running().await.unwrap(), [true; 4]);
+
+    mock.set_fan_running(2, false);
+    mock.set_fan_running(4, false);
+    assert_eq!(
+        client.fan_running().await.unwrap(),
+        [true, false, true, false]
+    );
+
+    // A stopped fan is not a faulted one
+    let fans = client.fan_status().await.unwrap();
+    assert_eq!(fans[1].status, FanStatus::Normal);
+}
+
 #[tokio::test]
 async fn test_read_fan_speed_invalid_index() {
     let (mut client, _mock) = create_test_client().await;
@@ -45,7 +150,11 @@ async fn test_read_fan_speed_invalid_index() {
 
     // Fan index 5 should fail
     let result = client.fan_speed(5).await;
-    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().invalid_fan_index(), Some(5));
+
+    // The boundaries themselves are valid
+    assert!(client.fan_speed(1).await.is_ok());
+    assert!(client.fan_speed(4).await.is_ok());
 }
 
 #[tokio::test]
@@ -56,6 +165,244 @@ async fn test_read_fan_count() {
     assert_eq!(count, 4);
 }
 
+#[tokio::test]
+async fn test_read_modbus_address() {
+    let (mut client, _mock) = create_test_client().await;
+
+    assert_eq!(client.modbus_address().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_address_register_is_rejected() {
+    let (mut client, mock) = create_test_client().await;
+
+    for value in [0x0000, 0x00FF, 0x0100] {
+        mock.write_register(0x0002, value);
+
+        let err = client.modbus_address().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+        let err = client.status().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+    }
+}
+
+#[tokio::test]
+async fn test_read_work_mode() {
+    let (mut client, mock) = create_test_client().await;
+
+    for (value, mode) in [(0, WorkMode::Shutdown), (1, WorkMode::MinimumSpeed)] {
+        mock.write_register(0x0005, value);
+        assert_eq!(client.work_mode().await.unwrap(), mode);
+    }
+
+    mock.write_register(0x0005, 7);
+    let err = client.work_mode().await.unwrap_err();
+    assert!(err.is_invalid_response(), "{}", err);
+    assert_eq!(
+        err.to_string(),
+        "Controller returned out-of-range value 0x0007 for register 0x0005"
+    );
+}
+
+#[tokio::test]
+async fn test_read_operating_mode() {
+    let (mut client, mock) = create_test_client().await;
+
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Temperature
+    );
+
+    client.set_fan_speed(40).await.unwrap();
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Manual
+    );
+
+    // Temperature mode reads back its calculated speed
+    client.set_auto_speed().await.unwrap();
+    mock.write_register(0x0003, 55);
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Temperature
+    );
+}
+
+#[tokio::test]
+async fn test_operating_mode_of_untracked_speed_is_unknown() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x0003, 40);
+
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Unknown
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_mode_register_is_rejected() {
+    let (mut client, mock) = create_test_client().await;
+
+    for value in [0x0065, 0x00FF, 0xFFFE] {
+        mock.write_register(0x0003, value);
+
+        let err = client.operating_mode().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+    }
+    assert_eq!(
+        client.operating_mode().await.unwrap_err().to_string(),
+        "Controller returned out-of-range value 0xFFFE for register 0x0003"
+    );
+}
+
+#[tokio::test]
+async fn test_read_manual_speed() {
+    let (mut client, mock) = create_test_client().await;
+
+    assert_eq!(client.manual_speed().await.unwrap(), None);
+
+    client.set_fan_speed(40).await.unwrap();
+    assert_eq!(client.manual_speed().await.unwrap(), Some(40));
+
+    mock.write_register(0x0003, 0);
+    assert_eq!(client.manual_speed().await.unwrap(), Some(0));
+    mock.write_register(0x0003, 100);
+    assert_eq!(client.manual_speed().await.unwrap(), Some(100));
+}
+
+#[tokio::test]
+async fn test_invalid_manual_speed_is_rejected() {
+    let (mut client, mock) = create_test_client().await;
+
+    for value in [0x0065, 0x00FF, 0xFFFE] {
+        mock.write_register(0x0003, value);
+
+        let err = client.manual_speed().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+    }
+}
+
+#[tokio::test]
+async fn test_read_temperature_thresholds() {
+    let (mut client, _mock) = create_test_client().await;
+
+    let (low, high) = client.temperature_thresholds().await.unwrap();
+    assert_eq!((low.value, high.value), (30, 50));
+    assert_eq!(low.unit, TemperatureUnit::Celsius);
+    assert_eq!(high.unit, TemperatureUnit::Celsius);
+}
+
+#[tokio::test]
+async fn test_inverted_temperature_thresholds_are_rejected() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x000C, 90); // 50°C
+    mock.write_register(0x000D, 70); // 30°C
+
+    let err = client.temperature_thresholds().await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Temperature threshold error: high (30°C) must be greater than low (50°C)"
+    );
+
+    mock.write_register(0x000D, 90);
+    assert!(client.temperature_thresholds().await.is_err());
+}
+
+#[tokio::test]
+async fn test_out_of_range_thresholds_are_rejected() {
+    let (mut client, mock) = create_test_client().await;
+
+    for register in [0x000C, 0x000D] {
+        let original = mock.read_register(register).unwrap();
+        for value in [0x0000, 0x00A1, 0xFFFF] {
+            mock.write_register(register, value);
+
+            let err = client.temperature_thresholds().await.unwrap_err();
+            assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+            let err = client.status().await.unwrap_err();
+            assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+            let err = client.get_config().await.unwrap_err();
+            assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+        }
+        mock.write_register(register, original);
+    }
+    assert!(client.status().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_single_threshold_write_rejects_out_of_range_counterpart() {
+    let (mut client, mock) = create_test_client().await;
+
+    for value in [0x0000, 0x00A1, 0xFFFF] {
+        mock.write_register(0x000D, value);
+        let err = client.set_start_temperature(25).await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+    }
+    mock.write_register(0x000D, 90);
+
+    for value in [0x0000, 0x00A1, 0xFFFF] {
+        mock.write_register(0x000C, value);
+        let err = client.set_full_speed_temperature(45).await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", value, err);
+    }
+    // Nothing was written over the bad values
+    assert_eq!(mock.read_register(0x000C), Some(0xFFFF));
+}
+
+#[tokio::test]
+async fn test_read_pwm_frequency() {
+    let (mut client, mock) = create_test_client().await;
+
+    assert_eq!(client.pwm_frequency().await.unwrap(), PwmFrequency::Hz25000);
+    mock.write_register(0x000B, 0x0000);
+    assert_eq!(client.pwm_frequency().await.unwrap(), PwmFrequency::Hz500);
+}
+
+#[tokio::test]
+async fn test_unknown_pwm_frequency_is_not_25_khz() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x000B, 0x0007);
+
+    let err = client.pwm_frequency().await.unwrap_err();
+    assert!(err.is_invalid_response(), "{}", err);
+    assert_eq!(
+        err.to_string(),
+        "Controller returned out-of-range value 0x0007 for register 0x000B"
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_pwm_frequency_is_rejected() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x000B, 0x0007);
+
+    // Not reported as the 25 kHz default
+    let err = client.status().await.unwrap_err();
+    assert!(err.is_invalid_response(), "{}", err);
+    assert_eq!(
+        err.to_string(),
+        "Controller returned out-of-range value 0x0007 for register 0x000B"
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_status_values_are_rejected() {
+    let (mut client, mock) = create_test_client().await;
+
+    for (register, value) in [(0x0005, 0x0002), (0x0006, 0x0005), (0x0006, 0x0104)] {
+        let original = mock.read_register(register).unwrap();
+        mock.write_register(register, value);
+
+        let err = client.status().await.unwrap_err();
+        assert!(err.is_invalid_response(), "{:#06X}: {}", register, err);
+        mock.write_register(register, original);
+    }
+    assert!(client.status().await.is_ok());
+
+    mock.write_register(0x0006, 0x0104);
+    assert!(client.fan_count().await.unwrap_err().is_invalid_response());
+}
+
 #[tokio::test]
 async fn test_read_fan_status() {
     let (mut client, mock) = create_test_client().await;
@@ -77,6 +424,59 @@ async fn test_read_fan_status() {
     assert_eq!(fans[0].status, FanStatus::Normal);
 }
 
+#[tokio::test]
+async fn test_faulted_fans_within_fan_count() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    assert!(client.faulted_fans().await.unwrap().is_empty());
+
+    // Two fans connected; the empty slot 4 reads as faulted
+    mock.write_register(0x0006, 2);
+    mock.set_fan_fault(4, true);
+    assert!(client.faulted_fans().await.unwrap().is_empty());
+    assert!(client.status().await.unwrap().faulted_fans().is_empty());
+
+    mock.set_fan_fault(2, true);
+    let reads = probe.reads();
+    assert_eq!(client.faulted_fans().await.unwrap(), vec![2]);
+    assert_eq!(probe.reads(), reads + 1);
+    assert_eq!(client.status().await.unwrap().faulted_fans(), vec![2]);
+
+    // Fault detection disabled
+    mock.write_register(0x0006, 0);
+    assert!(client.faulted_fans().await.unwrap().is_empty());
+    assert!(client.status().await.unwrap().faulted_fans().is_empty());
+}
+
+#[tokio::test]
+async fn test_fan_status_tells_stopped_from_faulted() {
+    let (mut client, mock) = create_test_client().await;
+    assert!(client
+        .fan_status()
+        .await
+        .unwrap()
+        .iter()
+        .all(|fan| fan.is_running));
+
+    // Fan 2 stopped by the controller, fan 3 failed
+    mock.set_fan_running(2, false);
+    mock.write_register(0x0008, 0);
+    mock.set_fan_running(3, false);
+    mock.write_register(0x0009, 0);
+    mock.set_fan_fault(3, true);
+
+    let fans = client.fan_status().await.unwrap();
+    assert!(!fans[1].is_running);
+    assert_eq!(fans[1].status, FanStatus::Normal);
+    assert!(!fans[2].is_running);
+    assert_eq!(fans[2].status, FanStatus::Fault);
+    assert_eq!(fans[1].rpm, fans[2].rpm);
+    assert!(fans[0].is_running);
+
+    let status = client.status().await.unwrap();
+    assert_eq!(status.fans, fans);
+}
+
 #[tokio::test]
 async fn test_read_full_status() {
     let (mut client, _mock) = create_test_client().await;
@@ -86,7 +486,7 @@ async fn test_read_full_status() {
     // Verify all fields
     assert_eq!(status.modbus_address, 1);
     assert_eq!(status.fan_count, 4);
-    assert!(!status.eco_mode); // Work mode = 1 = MinimumSpeed, so eco_mode = false
+    assert_eq!(status.work_mode, WorkMode::MinimumSpeed); // Register 0x0005 = 1
     assert_eq!(status.temperature_current.value, 31);
     assert_eq!(status.temperature_low_threshold.value, 30);
     assert_eq!(status.temperature_high_threshold.value, 50);
@@ -117,3 +517,263 @@ async fn test_read_low_level() {
     assert_eq!(values[1], 0x000F); // Fan status
     assert_eq!(values[2], 0x0001); // Modbus addr
 }
+
+#[tokio::test]
+async fn test_read_into_matches_read() {
+    let (mut client, _mock) = create_test_client().await;
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let expected = client.read(RegisterAddress::Fan1Speed, 4).await.unwrap();
+    let mut buf = [0u16; 4];
+    let filled = client
+        .read_into(RegisterAddress::Fan1Speed, &mut buf)
+        .await
+        .unwrap();
+
+    assert_eq!(filled, 4);
+    assert_eq!(buf.as_slice(), expected.as_slice());
+
+    let array: [u16; 4] = client.read_array(RegisterAddress::Fan1Speed).await.unwrap();
+    assert_eq!(array, buf);
+}
+
+#[tokio::test]
+async fn test_read_into_short_response_fills_prefix() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_response_limit(Some(2));
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let mut buf = [0xAAAA; 4];
+    let filled = client
+        .read_into(RegisterAddress::CurrentTemperature, &mut buf)
+        .await
+        .unwrap();
+
+    assert_eq!(filled, 2);
+    assert_eq!(buf, [71, 0x000F, 0xAAAA, 0xAAAA]);
+
+    // The Vec API promises exactly `count` registers
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 4)
+        .await
+        .unwrap_err();
+    assert!(err.is_modbus());
+    assert_eq!(err.response_length_mismatch(), Some((4, 2)));
+}
+
+#[tokio::test]
+async fn test_read_array_rejects_short_response() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_response_limit(Some(3));
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let err = client
+        .read_array::<4>(RegisterAddress::Fan1Speed)
+        .await
+        .unwrap_err();
+    assert!(err.is_modbus());
+    assert_eq!(err.response_length_mismatch(), Some((4, 3)));
+
+    // High-level reads surface the error instead of panicking
+    assert!(client.fan_status().await.unwrap_err().is_modbus());
+    let err = client.status().await.unwrap_err();
+    assert_eq!(err.response_length_mismatch(), Some((15, 3)));
+}
+
+#[tokio::test]
+async fn test_read_into_buffer_length_limits() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let filled = client
+        .read_into(RegisterAddress::CurrentTemperature, &mut [])
+        .await
+        .unwrap();
+    assert_eq!(filled, 0);
+    assert_eq!(probe.reads(), 0);
+
+    let mut too_long = [0u16; 126];
+    let err = client
+        .read_into(RegisterAddress::CurrentTemperature, &mut too_long)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+
+    let err = client
+        .read_array::<0>(RegisterAddress::CurrentTemperature)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(probe.reads(), 0);
+}
+
+#[tokio::test]
+async fn test_read_count_limits() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    use jpf4826_driver::registers::{RegisterAccess, RegisterAddress};
+
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 0)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.to_string(),
+        "Invalid parameter: Cannot read 0 registers"
+    );
+
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 126)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.to_string(),
+        "Invalid parameter: Cannot read 126 registers at once (maximum 125)"
+    );
+
+    // 125 is within the Modbus limit, but any such range reaches the
+    // write-only reset register
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 125)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.register_access(),
+        Some((0x0020, RegisterAccess::WriteOnly))
+    );
+    assert_eq!(probe.reads(), 0);
+
+    assert_eq!(
+        client
+            .read(RegisterAddress::CurrentTemperature, 1)
+            .await
+            .unwrap(),
+        [71]
+    );
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test]
+async fn test_read_chunked_splits_large_reads() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let values = client.read_chunked(0x0000, 300).await.unwrap();
+
+    assert_eq!(values.len(), 300);
+    assert_eq!(values[0x0000], 71);
+    assert_eq!(values[0x0007], 1400);
+    assert_eq!(
+        probe.read_log(),
+        [(0x0000, 125), (0x007D, 125), (0x00FA, 50)]
+    );
+    assert_eq!(client.stats().reads, 3);
+
+    // Up to 125 registers take one request
+    let values = client.read_chunked(0x0000, 125).await.unwrap();
+    assert_eq!(values.len(), 125);
+    assert_eq!(probe.reads(), 4);
+}
+
+#[tokio::test]
+async fn test_read_chunked_rejects_invalid_ranges() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    assert!(client
+        .read_chunked(0x0000, 0)
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(client
+        .read_chunked(0xFF00, 0x101)
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert_eq!(probe.reads(), 0);
+
+    // The last register of the address space is fine
+    assert_eq!(client.read_chunked(0xFF00, 0x100).await.unwrap().len(), 256);
+}
+
+#[tokio::test]
+async fn test_read_chunked_fails_on_any_failed_chunk() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_reads_at(0x0100);
+
+    let err = client.read_chunked(0x0000, 300).await.unwrap_err();
+
+    assert!(err.is_modbus());
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test]
+async fn test_high_level_reads_use_one_transaction_each() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let status = client.status().await.unwrap();
+    assert_eq!(probe.reads(), 1);
+    assert_eq!(status.fans[3].rpm, 1400);
+
+    let fans = client.fan_status().await.unwrap();
+    assert_eq!(probe.reads(), 4);
+    assert_eq!(fans, status.fans);
+}
+
+#[tokio::test]
+async fn test_status_decodes_fans_from_its_register_block() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    mock.set_fan_running(2, false);
+    mock.set_fan_fault(3, true);
+    mock.write_register(0x000A, 1200);
+
+    let status = client.status().await.unwrap();
+    assert_eq!(probe.reads(), 1);
+    assert!(!status.fans[1].is_running);
+    assert_eq!(status.fans[2].status, FanStatus::Fault);
+    assert_eq!(status.fans[3].rpm, 1200);
+
+    // One read of the whole 0x0000-0x000E block
+    probe.set_response_limit(Some(14));
+    let err = client.status().await.unwrap_err();
+    assert_eq!(err.response_length_mismatch(), Some((15, 14)));
+    assert_eq!(probe.reads(), 2);
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_status_json_reports_manual_mode_and_speed() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_fan_speed(60).await.unwrap();
+    assert_eq!(mock.read_register(0x0003), Some(60));
+
+    let json = serde_json::to_value(client.status().await.unwrap()).unwrap();
+
+    assert_eq!(json["mode"], "MANUAL");
+    assert_eq!(json["manual_speed"], 60);
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_status_json_does_not_guess_manual_mode() {
+    let (mut client, mock) = create_test_client().await;
+    // In temperature mode 0x0003 reads back the calculated speed, so a
+    // value set by another master cannot be told apart from it
+    mock.write_register(0x0003, 60);
+
+    let json = serde_json::to_value(client.status().await.unwrap()).unwrap();
+
+    assert_eq!(json["mode"], "UNKNOWN");
+    assert!(json["manual_speed"].is_null());
+}
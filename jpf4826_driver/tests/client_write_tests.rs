@@ -2,16 +2,11 @@
 
 mod mock;
 
-use jpf4826_driver::{Jpf4826Client, PwmFrequency, WorkMode};
-use mock::MockController;
-
-// Helper to create a test client
-async fn create_test_client() -> (Jpf4826Client, MockController) {
-    let mock = MockController::new();
-    let registers = mock.registers.clone();
-    let client = Jpf4826Client::new_mock(registers, 1).await;
-    (client, mock)
-}
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::{
+    Jpf4826Client, OperatingMode, OperatingModeState, PwmFrequency, TemperatureUnit, WorkMode,
+};
+use mock::{create_test_client, MockController};
 
 #[tokio::test]
 async fn test_write_low_level() {
@@ -30,6 +25,48 @@ async fn test_write_low_level() {
     assert_eq!(value, 5);
 }
 
+#[tokio::test]
+async fn test_write_multiple_single_transaction() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    // Manual speed, combined thresholds, work mode and fan quantity
+    client
+        .write_multiple(
+            RegisterAddress::ManualSpeedControl,
+            &[0xFFFF, 0x4B5F, 0x0000, 0x0002],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(probe.writes(), 1);
+    assert_eq!(mock.read_registers(0x0003, 4), vec![0xFFFF, 0x4B5F, 0, 2]);
+}
+
+#[tokio::test]
+async fn test_write_multiple_invalid_count() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let err = client
+        .write_multiple(RegisterAddress::WorkMode, &[])
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+
+    let err = client
+        .write_multiple(RegisterAddress::WorkMode, &[0; 124])
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+
+    assert_eq!(probe.writes(), 0);
+}
+
 #[tokio::test]
 async fn test_reset() {
     let (mut client, mock) = create_test_client().await;
@@ -52,6 +89,36 @@ async fn test_set_auto_speed() {
     assert_eq!(value, 0xFFFF);
 }
 
+#[tokio::test]
+async fn test_status_reports_selected_mode() {
+    let (mut client, mock) = create_test_client().await;
+
+    // A speed left by an earlier session does not tell the mode
+    mock.write_register(0x0003, 40);
+    let status = client.status().await.unwrap();
+    assert_eq!(status.mode, OperatingMode::Unknown);
+    assert_eq!(status.manual_speed, None);
+
+    client.set_fan_speed(60).await.unwrap();
+    let status = client.status().await.unwrap();
+    assert_eq!(status.mode, OperatingMode::Manual);
+    assert_eq!(status.manual_speed, Some(60));
+
+    client.set_auto_speed().await.unwrap();
+    let status = client.status().await.unwrap();
+    assert_eq!(status.mode, OperatingMode::Temperature);
+    assert_eq!(status.manual_speed, None);
+
+    // Temperature mode reads back its calculated speed, which is not manual
+    mock.write_register(0x0003, 55);
+    assert_eq!(client.status().await.unwrap().manual_speed, None);
+
+    // A restart leaves the mode undetermined
+    client.set_fan_speed(60).await.unwrap();
+    client.reset().await.unwrap();
+    assert_eq!(client.status().await.unwrap().mode, OperatingMode::Unknown);
+}
+
 #[tokio::test]
 async fn test_set_eco_shutdown() {
     let (mut client, mock) = create_test_client().await;
@@ -103,6 +170,76 @@ async fn test_set_fan_speed_hundred() {
     assert_eq!(value, 100);
 }
 
+#[tokio::test]
+async fn test_set_fan_speed_switches_mode_in_one_write() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    // From temperature mode, and from one manual speed to another
+    client.set_fan_speed(60).await.unwrap();
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Manual
+    );
+    client.set_fan_speed(80).await.unwrap();
+    client.set_auto_speed().await.unwrap();
+
+    assert_eq!(
+        probe.write_log(),
+        vec![(0x0003, 60), (0x0003, 80), (0x0003, 0xFFFF)]
+    );
+}
+
+#[tokio::test]
+async fn test_mode_state_round_trip() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_strict_mode(true);
+    assert_eq!(
+        client.mode_state().await.unwrap(),
+        OperatingModeState::Temperature
+    );
+
+    // Selecting a state is an explicit mode change, even in strict mode
+    client
+        .set_mode_state(OperatingModeState::Manual(40))
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x0003), Some(40));
+    assert_eq!(
+        client.mode_state().await.unwrap(),
+        OperatingModeState::Manual(40)
+    );
+    assert_eq!(
+        client.status().await.unwrap().mode_state(),
+        OperatingModeState::Manual(40)
+    );
+
+    client
+        .set_mode_state(OperatingModeState::Temperature)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+    // Temperature mode may report the calculated speed
+    mock.write_register(0x0003, 55);
+    assert_eq!(
+        client.mode_state().await.unwrap(),
+        OperatingModeState::Temperature
+    );
+}
+
+#[tokio::test]
+async fn test_set_mode_state_rejects_unknown_and_invalid_speed() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    for state in [OperatingModeState::Unknown, OperatingModeState::Manual(101)] {
+        let err = client.set_mode_state(state).await.unwrap_err();
+        assert!(err.is_invalid_parameter(), "{}", err);
+    }
+    assert_eq!(probe.writes(), 0);
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+}
+
 #[tokio::test]
 async fn test_set_fan_speed_invalid() {
     let (mut client, _mock) = create_test_client().await;
@@ -112,6 +249,85 @@ async fn test_set_fan_speed_invalid() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_set_fan_speed_leaves_temperature_mode_by_default() {
+    let (mut client, mock) = create_test_client().await;
+    assert!(!client.strict_mode());
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+
+    client.set_fan_speed(40).await.unwrap();
+
+    assert_eq!(mock.read_register(0x0003), Some(40));
+}
+
+#[tokio::test]
+async fn test_strict_set_fan_speed_refuses_temperature_mode() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.set_strict_mode(true);
+
+    let err = client.set_fan_speed(40).await.unwrap_err();
+
+    assert!(err.is_mode_conflict());
+    assert!(err
+        .to_string()
+        .starts_with("Controller is in temperature mode; call set_manual_speed()"));
+    assert_eq!(probe.read_log(), [(0x0003, 1)]);
+    assert_eq!(probe.writes(), 0);
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+}
+
+#[tokio::test]
+async fn test_strict_set_fan_speed_refuses_unknown_mode() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_strict_mode(true);
+    // Temperature mode may report the calculated speed
+    mock.write_register(0x0003, 40);
+
+    let err = client.set_fan_speed(60).await.unwrap_err();
+
+    assert!(err.is_mode_conflict());
+    assert!(err
+        .to_string()
+        .starts_with("Controller may be in temperature mode"));
+    assert_eq!(mock.read_register(0x0003), Some(40));
+}
+
+#[tokio::test]
+async fn test_strict_set_fan_speed_adjusts_manual_speed() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_strict_mode(true);
+
+    client.set_manual_speed(60).await.unwrap();
+    client.set_fan_speed(80).await.unwrap();
+
+    assert_eq!(mock.read_register(0x0003), Some(80));
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Manual
+    );
+
+    // Back in temperature mode, the check applies again
+    client.set_auto_speed().await.unwrap();
+    assert!(client
+        .set_fan_speed(50)
+        .await
+        .unwrap_err()
+        .is_mode_conflict());
+}
+
+#[tokio::test]
+async fn test_strict_set_fan_speed_validates_before_reading() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.set_strict_mode(true);
+
+    let err = client.set_fan_speed(101).await.unwrap_err();
+
+    assert!(!err.is_mode_conflict());
+    assert_eq!(probe.reads(), 0);
+}
+
 #[tokio::test]
 async fn test_set_fan_count() {
     let (mut client, mock) = create_test_client().await;
@@ -208,6 +424,123 @@ async fn test_set_addr_updates_client_internal_address() {
     );
 }
 
+async fn create_bus_client(addr: u8) -> (Jpf4826Client, MockController, MockBus) {
+    let bus = MockBus::new();
+    let mock = MockController::new();
+    bus.add_device(addr, mock.registers.clone());
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), addr).unwrap();
+    client.connect().await.unwrap();
+    (client, mock, bus)
+}
+
+#[tokio::test]
+async fn test_change_address_verifies_new_address() {
+    let (mut client, mock, _bus) = create_bus_client(1).await;
+    let probe = client.mock_probe().unwrap();
+
+    client.change_address(7).await.unwrap();
+
+    assert_eq!(client.slave_addr(), 7);
+    assert_eq!(mock.read_register(0x0002), Some(7));
+    assert_eq!(probe.write_log(), vec![(0x0002, 7)]);
+    assert_eq!(probe.read_log(), vec![(0x0000, 1)]);
+}
+
+#[tokio::test]
+async fn test_change_address_recovers_when_write_is_ignored() {
+    let (mut client, mock, _bus) = create_bus_client(1).await;
+    let probe = client.mock_probe().unwrap();
+    probe.ignore_writes_at(0x0002);
+
+    let err = client.change_address(7).await.unwrap_err();
+
+    assert!(err.is_address_change_failed());
+    assert_eq!(err.address_change_addresses(), Some((1, 7)));
+    assert_eq!(err.old_address_responds(), Some(true));
+    assert!(err
+        .to_string()
+        .ends_with("it still answers at old address 1"));
+    assert_eq!(client.slave_addr(), 1);
+    assert_eq!(mock.read_register(0x0002), Some(1));
+    // Still usable at the old address
+    client.temperature().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_change_address_reports_when_old_address_is_dead_too() {
+    let (mut client, _mock, _bus) = create_bus_client(1).await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_fail_reads(true);
+
+    let err = client.change_address(7).await.unwrap_err();
+
+    assert!(err.is_address_change_failed());
+    assert_eq!(err.old_address_responds(), Some(false));
+    assert!(err
+        .to_string()
+        .ends_with("old address 1 does not answer either"));
+    assert_eq!(client.slave_addr(), 1);
+    assert_eq!(probe.read_log(), vec![(0x0000, 1), (0x0000, 1)]);
+}
+
+#[tokio::test]
+async fn test_change_address_keeps_address_when_write_fails() {
+    let (mut client, _mock, _bus) = create_bus_client(1).await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_writes_at(0x0002);
+
+    let err = client.change_address(7).await.unwrap_err();
+
+    assert!(!err.is_address_change_failed());
+    assert!(err.is_modbus());
+    assert_eq!(client.slave_addr(), 1);
+    assert_eq!(probe.reads(), 0);
+    assert!(client.change_address(0).await.is_err());
+}
+
+#[tokio::test]
+async fn test_set_addr_broadcast_uses_broadcast_address() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    client.set_addr_broadcast(7).await.unwrap();
+
+    assert_eq!(probe.broadcast_log(), vec![(0x0002, 7)]);
+    assert!(probe.write_log().is_empty());
+    assert_eq!(mock.read_register(0x0002), Some(7));
+    assert_eq!(client.slave_addr(), 7);
+}
+
+#[tokio::test]
+async fn test_set_addr_broadcast_rejects_invalid_address() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    for addr in [0, 255] {
+        assert!(client.set_addr_broadcast(addr).await.is_err());
+    }
+    assert!(probe.broadcast_log().is_empty());
+    assert_eq!(client.slave_addr(), 1);
+}
+
+#[tokio::test]
+async fn test_broadcast_reaches_every_controller_on_bus() {
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let bus = MockBus::new();
+    let (first, second) = (MockController::new(), MockController::new());
+    bus.add_device(1, first.registers.clone());
+    bus.add_device(3, second.registers.clone());
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 1).unwrap();
+
+    client
+        .write_broadcast(RegisterAddress::FanQuantity, 2)
+        .await
+        .unwrap();
+    assert_eq!(first.read_register(0x0006), Some(2));
+    assert_eq!(second.read_register(0x0006), Some(2));
+}
+
 #[tokio::test]
 async fn test_set_pwm_frequency() {
     let (mut client, mock) = create_test_client().await;
@@ -221,6 +554,59 @@ async fn test_set_pwm_frequency() {
     assert_eq!(value, 0x0003); // Hz5000 = 0x0003
 }
 
+#[tokio::test]
+async fn test_set_pwm_frequency_verified() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    client
+        .set_pwm_frequency_verified(PwmFrequency::Hz5000)
+        .await
+        .unwrap();
+
+    assert_eq!(mock.read_register(0x000B), Some(0x0003));
+    assert_eq!(probe.writes(), 1);
+    assert_eq!(probe.read_log(), vec![(0x000B, 1)]);
+}
+
+#[tokio::test]
+async fn test_set_pwm_frequency_verified_reports_ignored_write() {
+    let (mut client, mock) = create_test_client().await;
+    // Factory setting 25 kHz (0x0005) stays in place
+    client.mock_probe().unwrap().ignore_writes_at(0x000B);
+
+    let err = client
+        .set_pwm_frequency_verified(PwmFrequency::Hz1000)
+        .await
+        .unwrap_err();
+
+    assert!(err.is_pwm_frequency_mismatch(), "{}", err);
+    assert_eq!(
+        err.pwm_frequency_mismatch_values(),
+        Some((PwmFrequency::Hz1000, PwmFrequency::Hz25000))
+    );
+    assert_eq!(
+        err.to_string(),
+        "PWM frequency not applied: wrote 1000 Hz, controller holds 25000 Hz"
+    );
+    assert_eq!(mock.read_register(0x000B), Some(0x0005));
+}
+
+#[tokio::test]
+async fn test_set_pwm_frequency_verified_rejects_unknown_read_back() {
+    let (mut client, mock) = create_test_client().await;
+    client.mock_probe().unwrap().ignore_writes_at(0x000B);
+    mock.write_register(0x000B, 0x0007);
+
+    let err = client
+        .set_pwm_frequency_verified(PwmFrequency::Hz1000)
+        .await
+        .unwrap_err();
+
+    assert!(err.is_invalid_response(), "{}", err);
+    assert!(!err.is_pwm_frequency_mismatch());
+}
+
 #[tokio::test]
 async fn test_set_temperature_threshold_valid() {
     let (mut client, mock) = create_test_client().await;
@@ -237,6 +623,35 @@ async fn test_set_temperature_threshold_valid() {
     assert_eq!(full, 85); // 45 + 40
 }
 
+#[tokio::test]
+async fn test_set_temperature_threshold_writes_combined_register() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.set_temperature_threshold(40, 60).await.unwrap();
+    probe.set_write_delay(std::time::Duration::from_millis(20));
+
+    let write = tokio::spawn(async move {
+        client.set_temperature_threshold(30, 50).await.unwrap();
+        client
+    });
+    // Mid-write, the controller holds the old pair or the new one
+    for _ in 0..5 {
+        let pair = (mock.read_register(0x000C), mock.read_register(0x000D));
+        assert!(
+            pair == (Some(80), Some(100)) || pair == (Some(70), Some(90)),
+            "{:?}",
+            pair
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+    let mut client = write.await.unwrap();
+
+    assert_eq!(mock.read_register(0x0004), Some(0x465A));
+    assert_eq!(probe.write_log(), vec![(0x0004, 0x5064), (0x0004, 0x465A)]);
+    let (low, high) = client.temperature_thresholds().await.unwrap();
+    assert_eq!((low.value, high.value), (30, 50));
+}
+
 #[tokio::test]
 async fn test_set_temperature_threshold_invalid_order() {
     let (mut client, _mock) = create_test_client().await;
@@ -268,6 +683,118 @@ async fn test_set_temperature_threshold_out_of_range() {
     assert!(result2.is_err());
 }
 
+#[tokio::test]
+async fn test_set_temperature_threshold_in_kelvin() {
+    let (mut client, mock) = create_test_client().await;
+
+    // 298 K = 25°C, 318 K = 45°C
+    client
+        .set_temperature_threshold_in(298, 318, TemperatureUnit::Kelvin)
+        .await
+        .unwrap();
+
+    assert_eq!(mock.read_register(0x000C), Some(65));
+    assert_eq!(mock.read_register(0x000D), Some(85));
+}
+
+#[tokio::test]
+async fn test_set_temperature_threshold_in_kelvin_checks_celsius_range() {
+    let (mut client, mock) = create_test_client().await;
+
+    // 253 K and 393 K are the hardware limits, -20°C and 120°C
+    client
+        .set_temperature_threshold_in(253, 393, TemperatureUnit::Kelvin)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x000C), Some(20));
+    assert_eq!(mock.read_register(0x000D), Some(160));
+
+    // 252 K is -21°C; a value mistaken for Celsius is far out of range
+    for (low, high) in [(252, 300), (30, 50)] {
+        let err = client
+            .set_temperature_threshold_in(low, high, TemperatureUnit::Kelvin)
+            .await
+            .unwrap_err();
+        assert!(err.is_invalid_parameter());
+    }
+    assert_eq!(mock.read_register(0x000C), Some(20));
+}
+
+#[tokio::test]
+async fn test_set_temperature_threshold_in_fahrenheit() {
+    let (mut client, mock) = create_test_client().await;
+
+    // 86°F = 30°C, 122°F = 50°C
+    client
+        .set_temperature_threshold_in(86, 122, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x000C), Some(70));
+    assert_eq!(mock.read_register(0x000D), Some(90));
+
+    // 87°F is 30.6°C and 100°F 37.8°C, rounded rather than truncated
+    client
+        .set_temperature_threshold_in(87, 100, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x000C), Some(71));
+    assert_eq!(mock.read_register(0x000D), Some(78));
+}
+
+#[tokio::test]
+async fn test_set_temperature_threshold_in_fahrenheit_edges() {
+    let (mut client, mock) = create_test_client().await;
+
+    // -4°F and 248°F are exactly -20°C and 120°C
+    client
+        .set_temperature_threshold_in(-4, 248, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x000C), Some(20));
+    assert_eq!(mock.read_register(0x000D), Some(160));
+
+    // -5°F rounds to -21°C and 249°F to 121°C; errors quote Fahrenheit
+    let err = client
+        .set_temperature_threshold_in(-5, 100, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.to_string(),
+        "Invalid parameter: Low temperature -5°F out of range (-4 to 248°F)"
+    );
+    let err = client
+        .set_temperature_threshold_in(100, 249, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid parameter: High temperature 249°F out of range (-4 to 248°F)"
+    );
+    assert_eq!(mock.read_register(0x000C), Some(20));
+}
+
+#[tokio::test]
+async fn test_set_temperature_threshold_in_fahrenheit_checks_order_after_rounding() {
+    let (mut client, _mock) = create_test_client().await;
+
+    // 33°F and 34°F both round to 1°C
+    let err = client
+        .set_temperature_threshold_in(33, 34, TemperatureUnit::Fahrenheit)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Temperature threshold error: high (34°F) must be greater than low (33°F)"
+    );
+
+    let err = client.set_temperature_threshold(50, 30).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Temperature threshold error: high (30°C) must be greater than low (50°C)"
+    );
+}
+
 #[tokio::test]
 async fn test_set_start_temperature_valid() {
     let (mut client, mock) = create_test_client().await;
@@ -19,14 +19,54 @@ fn test_register_to_celsius() {
     assert_eq!(register_to_celsius(40), 0);
     assert_eq!(register_to_celsius(20), -20);
     assert_eq!(register_to_celsius(160), 120);
+    // Out-of-range values must not overflow
+    assert_eq!(register_to_celsius(0x0000), -40);
+    assert_eq!(register_to_celsius(0x7FFF), 32727);
+    assert_eq!(register_to_celsius(0x8000), i16::MIN);
+    assert_eq!(register_to_celsius(0xFFFF), -41);
 }
 
 #[test]
 fn test_celsius_to_fahrenheit() {
     assert_eq!(celsius_to_fahrenheit(0), 32);
     assert_eq!(celsius_to_fahrenheit(100), 212);
-    assert_eq!(celsius_to_fahrenheit(31), 87); // 31°C = 87.8°F, rounded to 87 or 88
+    assert_eq!(celsius_to_fahrenheit(31), 88); // 87.8°F
+    assert_eq!(celsius_to_fahrenheit(26), 79); // 78.8°F
     assert_eq!(celsius_to_fahrenheit(-20), -4);
+    assert_eq!(celsius_to_fahrenheit(-18), 0); // -0.4°F
+    assert_eq!(celsius_to_fahrenheit(-19), -2); // -2.2°F
+    assert_eq!(celsius_to_fahrenheit(120), 248);
+}
+
+#[test]
+fn test_fahrenheit_to_celsius() {
+    assert_eq!(fahrenheit_to_celsius(32), 0);
+    assert_eq!(fahrenheit_to_celsius(212), 100);
+    assert_eq!(fahrenheit_to_celsius(-4), -20);
+    assert_eq!(fahrenheit_to_celsius(248), 120);
+    assert_eq!(fahrenheit_to_celsius(87), 31); // 30.6°C
+    assert_eq!(fahrenheit_to_celsius(-13), -25);
+    assert_eq!(fahrenheit_to_celsius(86), 30);
+    // Nearest degree on both sides of the limits
+    assert_eq!(fahrenheit_to_celsius(249), 121); // 120.6°C
+    assert_eq!(fahrenheit_to_celsius(-5), -21); // -20.6°C
+    assert_eq!(fahrenheit_to_celsius(-3), -19); // -19.4°C
+}
+
+#[test]
+fn test_kelvin_conversions() {
+    for (celsius, kelvin) in [(0, 273), (-20, 253), (120, 393)] {
+        assert_eq!(celsius_to_kelvin(celsius), kelvin);
+        assert_eq!(kelvin_to_celsius(kelvin), celsius);
+    }
+}
+
+#[test]
+fn test_kelvin_conversions_f64() {
+    for (celsius, kelvin) in [(0.0, 273.15), (-20.0, 253.15), (120.0, 393.15)] {
+        assert!((celsius_to_kelvin_f64(celsius) - kelvin).abs() < 1e-9);
+        assert!((kelvin_to_celsius_f64(kelvin) - celsius).abs() < 1e-9);
+    }
 }
 
 #[test]
@@ -109,11 +149,24 @@ fn test_parse_fan_fault_bitmap_all_faults() {
 #[test]
 fn test_parse_combined_temperature() {
     // 0x465A = high byte 0x46 (70 = 30°C), low byte 0x5A (90 = 50°C)
-    let (low, high) = parse_combined_temperature(0x465A);
+    let (low, high) = parse_combined_temperature(0x465A).unwrap();
     assert_eq!(low, 30); // 70 - 40
     assert_eq!(high, 50); // 90 - 40
 }
 
+#[test]
+fn test_parse_combined_temperature_rejects_out_of_range_bytes() {
+    assert_eq!(parse_combined_temperature(0x14A0), Some((-20, 120)));
+    for combined in [0x0000, 0x4600, 0x13A0, 0x14A1, 0xA15A, 0xFFFF] {
+        assert_eq!(
+            parse_combined_temperature(combined),
+            None,
+            "{:#06X}",
+            combined
+        );
+    }
+}
+
 #[test]
 fn test_encode_combined_temperature() {
     // Start temp 30°C (70), Full temp 50°C (90)
@@ -126,5 +179,5 @@ fn test_combined_temperature_roundtrip() {
     let original = (30, 50);
     let encoded = encode_combined_temperature(original.0, original.1);
     let decoded = parse_combined_temperature(encoded);
-    assert_eq!(original, decoded);
+    assert_eq!(Some(original), decoded);
 }
@@ -0,0 +1,114 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::DumpValue;
+use mock::create_test_client;
+
+#[tokio::test]
+async fn test_dump_holds_default_registers() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let dump = client.dump_registers().await.unwrap();
+
+    assert_eq!(dump.get(RegisterAddress::CurrentTemperature), Some(71));
+    assert_eq!(dump.get(RegisterAddress::ManualSpeedControl), Some(0xFFFF));
+    assert_eq!(dump.get(RegisterAddress::CombinedTemperature), Some(0x465A));
+    assert_eq!(dump.get(RegisterAddress::Fan4Speed), Some(1400));
+    assert_eq!(dump.get(RegisterAddress::FanFaultCode), Some(0x000F));
+    // Unknown addresses are kept by number
+    assert_eq!(dump.raw(0x001F), Some(0));
+    assert_eq!(dump.registers.len(), 19);
+    assert_eq!(dump.errors().count(), 0);
+
+    // One block read, then the extra addresses one at a time
+    assert_eq!(
+        probe.read_log(),
+        vec![
+            (0x0000, 15),
+            (0x000F, 1),
+            (0x001F, 1),
+            (0x0020, 1),
+            (0x0021, 1)
+        ]
+    );
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test]
+async fn test_dump_records_refused_addresses() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_reads_at(0x0020);
+    probe.reject_reads_at(0x000F);
+
+    let dump = client.dump_registers().await.unwrap();
+
+    assert_eq!(dump.get(RegisterAddress::ResetController), None);
+    assert!(matches!(
+        &dump.registers[&0x0020],
+        DumpValue::Error(message) if message.contains("IllegalDataAddress")
+    ));
+    let failed: Vec<u16> = dump.errors().map(|(addr, _)| addr).collect();
+    assert_eq!(failed, vec![0x000F, 0x0020]);
+    assert_eq!(dump.raw(0x0021), Some(0));
+}
+
+#[tokio::test]
+async fn test_dump_falls_back_to_single_reads() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.reject_reads_at(0x0006);
+
+    let dump = client.dump_registers().await.unwrap();
+
+    // Only the refused register is missing
+    assert_eq!(dump.get(RegisterAddress::FanQuantity), None);
+    assert_eq!(dump.get(RegisterAddress::WorkMode), Some(1));
+    assert_eq!(dump.get(RegisterAddress::Fan1Speed), Some(1400));
+    assert_eq!(dump.errors().count(), 1);
+    assert_eq!(probe.reads(), 1 + 15 + 4);
+}
+
+#[tokio::test]
+async fn test_dump_display_lists_registers() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().reject_reads_at(0x0021);
+
+    let text = client.dump_registers().await.unwrap().to_string();
+
+    assert!(text.starts_with("Register dump at "), "{}", text);
+    assert!(
+        text.contains("0x0000 CurrentTemperature    0x0047 (71)\n"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("0x0006 FanQuantity           0x0004 (4)\n"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("0x0021                       error: "),
+        "{}",
+        text
+    );
+    assert_eq!(text.lines().count(), 1 + 19);
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_dump_serde_round_trip() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().reject_reads_at(0x0020);
+    let dump = client.dump_registers().await.unwrap();
+
+    let json = serde_json::to_value(&dump).unwrap();
+    assert_eq!(json["registers"]["0"], serde_json::json!({ "value": 71 }));
+    assert!(json["registers"]["32"]["error"].is_string());
+
+    let parsed: jpf4826_driver::RegisterDump = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, dump);
+}
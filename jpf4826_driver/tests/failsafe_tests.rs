@@ -0,0 +1,120 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockProbe;
+use jpf4826_driver::failsafe::{FailsafeGuard, SafeState};
+use jpf4826_driver::{Jpf4826Client, SharedClient};
+use mock::MockController;
+
+const SPEED_REGISTER: u16 = 0x0003;
+
+/// Creates a shared client whose controller runs manually at 15%.
+async fn create_manual_client() -> (SharedClient, MockProbe, MockController) {
+    let mock = MockController::new();
+    mock.write_register(SPEED_REGISTER, 15);
+    let client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    (SharedClient::new(client), probe, mock)
+}
+
+/// Lets spawned tasks run.
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+}
+
+#[tokio::test]
+async fn test_drop_writes_safe_state() {
+    let (shared, probe, mock) = create_manual_client().await;
+    let guard = FailsafeGuard::new(shared, SafeState::Temperature).unwrap();
+
+    drop(guard);
+    settle().await;
+
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(0xFFFF));
+    assert_eq!(probe.write_log(), vec![(SPEED_REGISTER, 0xFFFF)]);
+}
+
+#[tokio::test]
+async fn test_drop_during_panic_writes_safe_state() {
+    let (shared, _probe, mock) = create_manual_client().await;
+
+    let task = tokio::spawn(async move {
+        let _guard = FailsafeGuard::new(shared, SafeState::ManualSpeed(100)).unwrap();
+        panic!("control loop failed");
+    });
+    assert!(task.await.unwrap_err().is_panic());
+    settle().await;
+
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(100));
+}
+
+#[tokio::test]
+async fn test_disarm_prevents_safe_write() {
+    let (shared, probe, mock) = create_manual_client().await;
+    let guard = FailsafeGuard::new(shared, SafeState::Temperature).unwrap();
+
+    guard.disarm().await;
+    settle().await;
+
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(15));
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test]
+async fn test_trigger_writes_once() {
+    let (shared, probe, mock) = create_manual_client().await;
+    let guard = FailsafeGuard::new(shared, SafeState::ManualSpeed(100)).unwrap();
+
+    guard.trigger().await.unwrap();
+    settle().await;
+
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(100));
+    assert_eq!(probe.writes(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_safe_speed_is_rejected() {
+    let (shared, _probe, _mock) = create_manual_client().await;
+
+    assert!(FailsafeGuard::new(shared, SafeState::ManualSpeed(101)).is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_missed_heartbeat_writes_safe_state() {
+    let (shared, probe, mock) = create_manual_client().await;
+    let guard = FailsafeGuard::new(shared, SafeState::Temperature)
+        .unwrap()
+        .with_heartbeat(Duration::from_secs(2));
+
+    for _ in 0..5 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        guard.heartbeat();
+    }
+    assert!(!guard.is_tripped());
+    assert_eq!(probe.writes(), 0);
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert!(guard.is_tripped());
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(0xFFFF));
+
+    // Neither releasing nor dropping writes again
+    guard.trigger().await.unwrap();
+    settle().await;
+    assert_eq!(probe.writes(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_disarm_stops_heartbeat() {
+    let (shared, probe, _mock) = create_manual_client().await;
+    let guard = FailsafeGuard::new(shared, SafeState::Temperature)
+        .unwrap()
+        .with_heartbeat(Duration::from_secs(2));
+
+    guard.disarm().await;
+    tokio::time::sleep(Duration::from_secs(10)).await;
+
+    assert_eq!(probe.writes(), 0);
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::collections::HashMap;
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::group::failed;
+use jpf4826_driver::manager::{Jpf4826Manager, Target};
+use jpf4826_driver::WorkMode;
+use mock::MockController;
+
+const PORT_A: &str = "/dev/ttyUSB0";
+const PORT_B: &str = "/dev/ttyUSB1";
+
+/// Creates a manager with `row-1` and `row-2` on port A and `row-3` on
+/// port B, returning the controllers by alias.
+fn zone() -> (
+    Jpf4826Manager,
+    MockBus,
+    MockBus,
+    HashMap<&'static str, MockController>,
+) {
+    let bus_a = MockBus::new();
+    let bus_b = MockBus::new();
+    let manager = Jpf4826Manager::new_mock(HashMap::from([
+        (PORT_A.to_string(), bus_a.clone()),
+        (PORT_B.to_string(), bus_b.clone()),
+    ]));
+    let mut controllers = HashMap::new();
+    for (alias, bus, port, addr) in [
+        ("row-1", &bus_a, PORT_A, 1),
+        ("row-2", &bus_a, PORT_A, 2),
+        ("row-3", &bus_b, PORT_B, 1),
+    ] {
+        let controller = MockController::new();
+        bus.add_device(addr, controller.registers.clone());
+        manager
+            .add(Target::new(port, addr).with_alias(alias))
+            .unwrap();
+        controllers.insert(alias, controller);
+    }
+    (manager, bus_a, bus_b, controllers)
+}
+
+#[tokio::test]
+async fn test_status_all_aggregates_zone() {
+    let (manager, _bus_a, _bus_b, controllers) = zone();
+    // 35°C and 42°C; row-3 has a failed fan 2 and a disconnected sensor
+    controllers["row-1"].write_register(0x0000, 75);
+    controllers["row-2"].write_register(0x0000, 82);
+    controllers["row-3"].write_register(0x0000, 160);
+    controllers["row-3"].write_register(0x000E, 0x000D);
+    let zone = manager.group(&["row-1", "row-2", "row-3"]).unwrap();
+
+    let status = zone.status_all().await;
+    let summary = status.aggregate();
+
+    assert_eq!(status.devices.len(), 3);
+    assert_eq!(summary.responding, 3);
+    assert!(summary.failed.is_empty());
+    assert_eq!(summary.max_temperature.unwrap().value, 42);
+    assert_eq!(summary.sensor_faults, 1);
+    assert_eq!(summary.faulted_fans, 1);
+}
+
+#[tokio::test]
+async fn test_setters_apply_to_every_controller() {
+    let (manager, _bus_a, _bus_b, controllers) = zone();
+    let zone = manager.group(&["row-1", "row-2", "row-3"]).unwrap();
+
+    let thresholds = zone.set_temperature_threshold_all(25, 45).await;
+    let modes = zone.set_mode_all(WorkMode::Shutdown).await;
+
+    assert!(failed(&thresholds).is_empty());
+    assert!(failed(&modes).is_empty());
+    for controller in controllers.values() {
+        assert_eq!(controller.read_register(0x000C), Some(65));
+        assert_eq!(controller.read_register(0x000D), Some(85));
+        assert_eq!(controller.read_register(0x0005), Some(0));
+    }
+}
+
+#[tokio::test]
+async fn test_partial_failure_reports_each_controller() {
+    let (manager, _bus_a, bus_b, controllers) = zone();
+    bus_b.set_offline(true);
+    // No controller answers at address 9
+    manager
+        .add(Target::new(PORT_A, 9).with_alias("row-9"))
+        .unwrap();
+    let zone = manager.group(&["row-1", "row-9", "row-3"]).unwrap();
+
+    let results = zone.set_temperature_threshold_all(25, 45).await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results["row-1"].is_ok());
+    assert!(results["row-9"].is_err());
+    assert!(results["row-3"].as_ref().unwrap_err().is_serial());
+    assert_eq!(failed(&results), vec!["row-3", "row-9"]);
+    // The failures neither stopped nor undid the write to row-1
+    assert_eq!(controllers["row-1"].read_register(0x000C), Some(65));
+    assert_eq!(controllers["row-3"].read_register(0x000C), Some(70));
+
+    let summary = zone.status_all().await.aggregate();
+    assert_eq!(summary.responding, 1);
+    assert_eq!(summary.failed, vec!["row-3", "row-9"]);
+    assert_eq!(summary.max_temperature.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_invalid_thresholds_fail_every_entry() {
+    let (manager, _bus_a, _bus_b, controllers) = zone();
+    let zone = manager.group(&["row-1", "row-3"]).unwrap();
+
+    let results = zone.set_temperature_threshold_all(50, 30).await;
+
+    assert_eq!(failed(&results), vec!["row-1", "row-3"]);
+    assert_eq!(controllers["row-1"].read_register(0x000C), Some(70));
+    assert_eq!(controllers["row-3"].read_register(0x000C), Some(70));
+}
+
+#[test]
+fn test_group_rejects_unknown_and_repeated_names() {
+    let (manager, _bus_a, _bus_b, _controllers) = zone();
+
+    assert!(manager
+        .group(&["row-1", "row-4"])
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(manager
+        .group(&["row-1", "/dev/ttyUSB0:1"])
+        .unwrap_err()
+        .is_invalid_parameter());
+    let zone = manager.group(&["row-3", "row-1"]).unwrap();
+    let names: Vec<_> = zone.targets().iter().map(|t| t.name()).collect();
+    assert_eq!(names, vec!["row-3", "row-1"]);
+}
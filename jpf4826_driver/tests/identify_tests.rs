@@ -0,0 +1,87 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::{Jpf4826Client, OperatingMode};
+use mock::create_test_client;
+
+const SPEED_REGISTER: u16 = 0x0003;
+
+/// Returns the values written to the speed register, in order.
+fn speed_writes(client: &Jpf4826Client) -> Vec<u16> {
+    client
+        .mock_probe()
+        .unwrap()
+        .write_log()
+        .into_iter()
+        .filter(|(addr, _)| *addr == SPEED_REGISTER)
+        .map(|(_, value)| value)
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_pulses_then_returns_to_temperature_mode() {
+    let (mut client, mock) = create_test_client().await;
+
+    client.identify(Duration::from_secs(4)).await.unwrap();
+
+    assert_eq!(speed_writes(&client), vec![100, 20, 100, 20, 0xFFFF]);
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(0xFFFF));
+    assert_eq!(
+        client.status().await.unwrap().mode,
+        OperatingMode::Temperature
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_restores_manual_speed() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_fan_speed(60).await.unwrap();
+
+    client.identify(Duration::from_millis(2500)).await.unwrap();
+
+    assert_eq!(speed_writes(&client), vec![60, 100, 20, 100, 60]);
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(60));
+    assert_eq!(client.status().await.unwrap().mode, OperatingMode::Manual);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_takes_requested_duration() {
+    let (mut client, _mock) = create_test_client().await;
+    let start = tokio::time::Instant::now();
+
+    client.identify(Duration::from_secs(10)).await.unwrap();
+
+    assert_eq!(start.elapsed(), Duration::from_secs(10));
+    assert_eq!(speed_writes(&client).len(), 11);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_until_restores_on_early_cancellation() {
+    let (mut client, mock) = create_test_client().await;
+    client.set_fan_speed(45).await.unwrap();
+    let start = tokio::time::Instant::now();
+
+    client
+        .identify_until(
+            Duration::from_secs(60),
+            tokio::time::sleep(Duration::from_millis(1500)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(start.elapsed(), Duration::from_millis(1500));
+    assert_eq!(speed_writes(&client), vec![45, 100, 20, 45]);
+    assert_eq!(mock.read_register(SPEED_REGISTER), Some(45));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_reports_read_failure_without_writing() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_fail_reads(true);
+
+    assert!(client.identify(Duration::from_secs(2)).await.is_err());
+    assert!(speed_writes(&client).is_empty());
+}
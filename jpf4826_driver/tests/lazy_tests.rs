@@ -0,0 +1,104 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::{ConnectOptions, Jpf4826Client};
+use mock::MockController;
+
+/// Creates a bus with one controller at address 1.
+fn bus_with_controller() -> (MockBus, MockController) {
+    let bus = MockBus::new();
+    let controller = MockController::new();
+    bus.add_device(1, controller.registers.clone());
+    (bus, controller)
+}
+
+#[test]
+fn test_new_lazy_rejects_invalid_address() {
+    for addr in [0, 255] {
+        let result = Jpf4826Client::new_lazy("/dev/ttyUSB0", addr, ConnectOptions::default());
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn test_new_lazy_keeps_options_until_connected() {
+    let options = ConnectOptions {
+        timeout: Duration::from_secs(3),
+        ..ConnectOptions::default()
+    };
+    let mut client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, options).unwrap();
+    assert!(!client.is_connected());
+    assert_eq!(client.timeout(), Duration::from_secs(3));
+
+    client.set_timeout(Duration::from_secs(1)).unwrap();
+    assert_eq!(client.timeout(), Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_missing_port_fails_on_first_operation() {
+    let mut client =
+        Jpf4826Client::new_lazy("/dev/jpf4826-does-not-exist", 1, ConnectOptions::default())
+            .unwrap();
+
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_serial());
+    assert!(!client.is_connected());
+    // Failing to open is not a bus transaction
+    assert_eq!(client.link_info().failures, 0);
+}
+
+#[tokio::test]
+async fn test_close_unconnected_client() {
+    let client =
+        Jpf4826Client::new_lazy("/dev/jpf4826-does-not-exist", 1, ConnectOptions::default())
+            .unwrap();
+
+    // Nothing was opened, so there is nothing to fail
+    client.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_lazy_client_opens_once_adapter_appears() {
+    let (bus, _controller) = bus_with_controller();
+    bus.set_offline(true);
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), 1).unwrap();
+    assert_eq!(bus.connects(), 0);
+
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_serial());
+    assert!(client.set_fan_speed(50).await.unwrap_err().is_serial());
+
+    bus.set_offline(false);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert!(client.is_connected());
+    client.status().await.unwrap();
+    client.set_fan_speed(50).await.unwrap();
+    assert_eq!(bus.connects(), 1);
+}
+
+#[tokio::test]
+async fn test_connect_is_idempotent() {
+    let (bus, _controller) = bus_with_controller();
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), 1).unwrap();
+
+    client.connect().await.unwrap();
+    client.connect().await.unwrap();
+    client.temperature().await.unwrap();
+    assert_eq!(bus.connects(), 1);
+}
+
+#[tokio::test]
+async fn test_connect_reports_open_error() {
+    let (bus, _controller) = bus_with_controller();
+    bus.set_offline(true);
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), 1).unwrap();
+
+    assert!(client.connect().await.unwrap_err().is_serial());
+    bus.set_offline(false);
+    client.connect().await.unwrap();
+    assert!(client.is_connected());
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::SystemTime;
+
+use jpf4826_driver::LinkInfo;
+use mock::create_test_client;
+
+#[tokio::test]
+async fn test_new_client_has_no_contact() {
+    let (client, _mock) = create_test_client().await;
+
+    assert_eq!(client.link_info(), LinkInfo::default());
+    assert!(!client.link_info().is_failing());
+}
+
+#[tokio::test]
+async fn test_link_info_tracks_successes_and_failures() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let before = SystemTime::now();
+
+    client.temperature().await.unwrap();
+    client.status().await.unwrap();
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (2, 0));
+    assert!(link.last_success.unwrap() >= before);
+    assert_eq!(link.last_failure, None);
+
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.is_err());
+    assert!(client.fan_status().await.is_err());
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (2, 2));
+    assert!(link.is_failing());
+    assert_eq!(
+        link.last_error.as_deref(),
+        Some("Modbus communication error: mock read failure")
+    );
+
+    // Writes still reach the controller
+    client.set_fan_speed(50).await.unwrap();
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (3, 2));
+    assert!(!link.is_failing());
+    assert!(link.last_error.is_some());
+}
+
+#[tokio::test]
+async fn test_link_info_ignores_rejected_arguments() {
+    let (mut client, mock) = create_test_client().await;
+
+    assert!(client.fan_speed(5).await.is_err());
+    assert!(client.set_fan_speed(101).await.is_err());
+    assert_eq!(client.link_info(), LinkInfo::default());
+
+    // The transaction succeeded even though the value is rejected
+    mock.write_register(0x000B, 0x0007);
+    assert!(client.status().await.unwrap_err().is_invalid_response());
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (1, 0));
+}
+
+#[tokio::test]
+async fn test_short_response_counts_as_failure() {
+    let (mut client, _mock) = create_test_client().await;
+    client.mock_probe().unwrap().set_response_limit(Some(3));
+
+    assert!(client.status().await.is_err());
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (0, 1));
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn test_link_info_serializes() {
+    let (mut client, _mock) = create_test_client().await;
+    client.temperature().await.unwrap();
+
+    let json = serde_json::to_value(client.link_info()).unwrap();
+    assert_eq!(json["successes"], 1);
+    assert_eq!(json["failures"], 0);
+    assert!(json["last_success"].is_object());
+    assert!(json["last_failure"].is_null());
+    assert!(json["last_error"].is_null());
+}
@@ -0,0 +1,251 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::manager::{Jpf4826Manager, Target};
+use mock::MockController;
+
+const PORT_A: &str = "/dev/ttyUSB0";
+const PORT_B: &str = "/dev/ttyUSB1";
+
+/// Creates a bus with one controller per address, each reporting its
+/// address as temperature in °C.
+fn bus_with(addresses: &[u8]) -> (MockBus, HashMap<u8, MockController>) {
+    let bus = MockBus::new();
+    let mut controllers = HashMap::new();
+    for &addr in addresses {
+        let controller = MockController::new();
+        controller.write_register(0x0000, 40 + u16::from(addr));
+        bus.add_device(addr, controller.registers.clone());
+        controllers.insert(addr, controller);
+    }
+    (bus, controllers)
+}
+
+#[tokio::test]
+async fn test_status_all_reports_every_target() {
+    let (bus_a, _) = bus_with(&[1, 2, 3]);
+    let (bus_b, _) = bus_with(&[7, 8]);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([
+        (PORT_A.to_string(), bus_a.clone()),
+        (PORT_B.to_string(), bus_b.clone()),
+    ]));
+    for addr in [1, 2, 3] {
+        manager.add(Target::new(PORT_A, addr)).unwrap();
+    }
+    manager
+        .add(Target::new(PORT_B, 7).with_alias("rack-7"))
+        .unwrap();
+    manager
+        .add(Target::new(PORT_B, 8).with_alias("rack-8"))
+        .unwrap();
+
+    let statuses = manager.status_all().await;
+
+    let temperatures: Vec<_> = statuses
+        .iter()
+        .map(|(name, result)| {
+            let status = result.as_ref().unwrap();
+            (name.as_str(), status.temperature_current.value)
+        })
+        .collect();
+    assert_eq!(
+        temperatures,
+        vec![
+            ("/dev/ttyUSB0:1", 1),
+            ("/dev/ttyUSB0:2", 2),
+            ("/dev/ttyUSB0:3", 3),
+            ("rack-7", 7),
+            ("rack-8", 8),
+        ]
+    );
+    // One connection per port, shared by its controllers
+    assert_eq!(bus_a.connects(), 1);
+    assert_eq!(bus_b.connects(), 1);
+}
+
+#[tokio::test]
+async fn test_failing_target_does_not_affect_others() {
+    let (bus_a, _) = bus_with(&[1, 2]);
+    let (bus_b, _) = bus_with(&[1]);
+    bus_b.set_offline(true);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([
+        (PORT_A.to_string(), bus_a.clone()),
+        (PORT_B.to_string(), bus_b),
+    ]));
+    manager.add(Target::new(PORT_A, 1)).unwrap();
+    manager.add(Target::new(PORT_A, 2)).unwrap();
+    // No controller answers at address 3
+    manager.add(Target::new(PORT_A, 3)).unwrap();
+    manager.add(Target::new(PORT_B, 1)).unwrap();
+
+    let statuses = manager.status_all().await;
+
+    assert!(statuses["/dev/ttyUSB0:1"].is_ok());
+    assert!(statuses["/dev/ttyUSB0:2"].is_ok());
+    assert!(statuses["/dev/ttyUSB0:3"]
+        .as_ref()
+        .unwrap_err()
+        .is_timeout());
+    assert!(statuses["/dev/ttyUSB1:1"].as_ref().unwrap_err().is_serial());
+
+    // The timeout kept the shared connection
+    let handle = manager.get("/dev/ttyUSB0:1").unwrap();
+    assert_eq!(handle.status().await.unwrap().temperature_current.value, 1);
+    assert_eq!(bus_a.connects(), 1);
+}
+
+#[tokio::test]
+async fn test_reconnects_after_bus_failure() {
+    let (bus, _) = bus_with(&[1]);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([(PORT_A.to_string(), bus.clone())]))
+        .with_reconnect_delay(Duration::ZERO);
+    manager.add(Target::new(PORT_A, 1)).unwrap();
+    let handle = manager.get("/dev/ttyUSB0:1").unwrap();
+
+    handle.status().await.unwrap();
+    assert_eq!(bus.connects(), 1);
+
+    // The adapter goes away: the request fails and drops the connection,
+    // the next one fails to reopen the port
+    bus.set_offline(true);
+    assert!(handle.status().await.unwrap_err().is_modbus());
+    assert!(handle.status().await.unwrap_err().is_serial());
+
+    bus.set_offline(false);
+    handle.status().await.unwrap();
+    assert_eq!(bus.connects(), 2);
+}
+
+#[tokio::test]
+async fn test_reconnect_waits_for_delay() {
+    let (bus, _) = bus_with(&[1]);
+    bus.set_offline(true);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([(PORT_A.to_string(), bus.clone())]))
+        .with_reconnect_delay(Duration::from_secs(3600));
+    manager.add(Target::new(PORT_A, 1)).unwrap();
+    let handle = manager.get("/dev/ttyUSB0:1").unwrap();
+
+    assert!(handle.status().await.unwrap_err().is_serial());
+
+    // Back online, but the port is not retried before the delay
+    bus.set_offline(false);
+    assert!(handle.status().await.unwrap_err().is_serial());
+    assert_eq!(bus.connects(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_access_addresses_each_controller() {
+    let (bus_a, controllers_a) = bus_with(&[1, 2, 3, 4]);
+    let (bus_b, controllers_b) = bus_with(&[1, 2]);
+    let manager = Arc::new(Jpf4826Manager::new_mock(HashMap::from([
+        (PORT_A.to_string(), bus_a),
+        (PORT_B.to_string(), bus_b),
+    ])));
+    for addr in 1..=4 {
+        manager.add(Target::new(PORT_A, addr)).unwrap();
+    }
+    for addr in 1..=2 {
+        manager.add(Target::new(PORT_B, addr)).unwrap();
+    }
+
+    let mut tasks = Vec::new();
+    for round in 0..8u8 {
+        for target in manager.targets() {
+            let manager = manager.clone();
+            tasks.push(tokio::spawn(async move {
+                let handle = manager.get(&target.name()).unwrap();
+                let speed = target.address() * 10 + round % 2;
+                handle
+                    .with_client(async |client| client.set_fan_speed(speed).await)
+                    .await
+                    .unwrap();
+                let status = handle.status().await.unwrap();
+                assert_eq!(
+                    status.temperature_current.value,
+                    i16::from(target.address())
+                );
+            }));
+        }
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    // Every write reached the controller it was addressed to
+    for (addr, controller) in controllers_a.iter().chain(&controllers_b) {
+        let speed = controller.read_register(0x0003).unwrap();
+        assert_eq!(speed / 10, u16::from(*addr));
+    }
+}
+
+#[tokio::test]
+async fn test_lookup_semantics() {
+    let (bus, _) = bus_with(&[1, 2]);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([(PORT_A.to_string(), bus)]));
+    manager
+        .add(Target::new(PORT_A, 1).with_alias("inlet"))
+        .unwrap();
+
+    // By alias or by port and address
+    let by_alias = manager.get("inlet").unwrap();
+    let by_location = manager.get("/dev/ttyUSB0:1").unwrap();
+    assert_eq!(by_alias.target(), by_location.target());
+    assert!(manager.get("outlet").is_none());
+    assert!(manager.get("/dev/ttyUSB0:2").is_none());
+
+    // Duplicates and conflicting settings are rejected
+    let err = manager.add(Target::new(PORT_A, 1)).unwrap_err();
+    assert!(err.is_invalid_parameter());
+    let err = manager
+        .add(Target::new(PORT_A, 2).with_alias("inlet"))
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    let err = manager
+        .add(Target::new(PORT_A, 2).with_baud_rate(19200))
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert!(manager.add(Target::new(PORT_A, 0)).is_err());
+
+    // Targets can be added and removed at runtime
+    manager
+        .add(Target::new(PORT_A, 2).with_alias("outlet"))
+        .unwrap();
+    assert_eq!(manager.status_all().await.len(), 2);
+    let removed = manager.remove("inlet").unwrap();
+    assert_eq!(removed.address(), 1);
+    assert!(manager.get("inlet").is_none());
+    assert!(manager.remove("inlet").is_none());
+    let statuses = manager.status_all().await;
+    assert_eq!(statuses.keys().collect::<Vec<_>>(), vec!["outlet"]);
+}
+
+#[tokio::test]
+async fn test_status_all_staggers_polls_on_one_bus() {
+    let (bus_a, _) = bus_with(&[1, 2, 3]);
+    let (bus_b, _) = bus_with(&[1, 2, 3]);
+    let manager = Jpf4826Manager::new_mock(HashMap::from([
+        (PORT_A.to_string(), bus_a),
+        (PORT_B.to_string(), bus_b),
+    ]))
+    .with_stagger(Duration::from_millis(50));
+    for port in [PORT_A, PORT_B] {
+        for addr in 1..=3 {
+            manager.add(Target::new(port, addr)).unwrap();
+        }
+    }
+
+    let started = Instant::now();
+    let statuses = manager.status_all().await;
+    let elapsed = started.elapsed();
+
+    assert_eq!(statuses.len(), 6);
+    // Two pauses per bus, with the buses polled in parallel
+    assert!(elapsed >= Duration::from_millis(100), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_millis(200), "{:?}", elapsed);
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use jpf4826_driver::metrics::{CountingRecorder, MetricsRecorder, NoopRecorder, OperationOutcome};
+use mock::create_test_client;
+
+#[tokio::test]
+async fn test_counting_recorder_counts_operations() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let counts = Arc::new(CountingRecorder::default());
+    client.set_metrics_recorder(counts.clone());
+
+    client.temperature().await.unwrap();
+    client.status().await.unwrap();
+    client.set_fan_speed(50).await.unwrap();
+    probe.set_fail_reads(true);
+    assert!(client.fan_speeds().await.is_err());
+    probe.set_fail_reads(false);
+    probe.set_silent_requests(1);
+    assert!(client.set_auto_speed().await.unwrap_err().is_timeout());
+
+    assert_eq!((counts.reads_started(), counts.writes_started()), (3, 2));
+    assert_eq!(counts.reads(OperationOutcome::Success), 2);
+    assert_eq!(counts.reads(OperationOutcome::Error), 1);
+    assert_eq!(counts.reads(OperationOutcome::Timeout), 0);
+    assert_eq!(counts.writes(OperationOutcome::Success), 1);
+    assert_eq!(counts.writes(OperationOutcome::Timeout), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_recorder_receives_durations() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let counts = Arc::new(CountingRecorder::default());
+    client.set_metrics_recorder(counts.clone());
+
+    probe.set_read_delay(Duration::from_millis(100));
+    probe.set_write_delay(Duration::from_millis(50));
+    client.temperature().await.unwrap();
+    client.set_fan_speed(50).await.unwrap();
+
+    assert_eq!(counts.busy_time(), Duration::from_millis(150));
+}
+
+#[tokio::test]
+async fn test_cache_hits_are_not_transactions() {
+    let (mut client, _mock) = create_test_client().await;
+    let counts = Arc::new(CountingRecorder::default());
+    client.set_metrics_recorder(counts.clone());
+    client.set_cache_ttl(Duration::from_secs(60));
+
+    client.temperature().await.unwrap();
+    client.temperature().await.unwrap();
+
+    assert_eq!(counts.reads_started(), 1);
+}
+
+#[tokio::test]
+async fn test_cleared_recorder_is_not_called() {
+    let (mut client, _mock) = create_test_client().await;
+    let counts = Arc::new(CountingRecorder::default());
+    client.set_metrics_recorder(counts.clone());
+    client.temperature().await.unwrap();
+
+    client.clear_metrics_recorder();
+    assert!(client.metrics_recorder().is_none());
+    client.temperature().await.unwrap();
+
+    client.set_metrics_recorder(Arc::new(NoopRecorder));
+    client.temperature().await.unwrap();
+
+    assert_eq!(counts.reads_started(), 1);
+    assert_eq!(client.stats().reads, 3);
+}
+
+/// Overrides only the write completion hook.
+#[derive(Default)]
+struct WriteFailures(AtomicU64);
+
+impl MetricsRecorder for WriteFailures {
+    fn on_write_complete(&self, _duration: Duration, outcome: OperationOutcome) {
+        if outcome != OperationOutcome::Success {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_recorder_may_implement_some_hooks() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let failures = Arc::new(WriteFailures::default());
+    client.set_metrics_recorder(failures.clone());
+    probe.reject_writes_at(0x0003);
+
+    client.temperature().await.unwrap();
+    assert!(client.set_fan_speed(50).await.is_err());
+    client.set_fan_count(2).await.unwrap();
+
+    assert_eq!(failures.0.load(Ordering::Relaxed), 1);
+}
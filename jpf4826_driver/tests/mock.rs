@@ -8,6 +8,13 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use jpf4826_driver::conversions::{celsius_to_register, encode_combined_temperature};
+use jpf4826_driver::profile::{
+    FACTORY_ADDRESS, FACTORY_FAN_COUNT, FACTORY_HIGH_THRESHOLD_C, FACTORY_LOW_THRESHOLD_C,
+    FACTORY_PWM_FREQUENCY, FACTORY_WORK_MODE,
+};
+use jpf4826_driver::Jpf4826Client;
+
 /// Mock Modbus register storage for testing.
 ///
 /// Simulates a JPF4826 controller's register state in memory.
@@ -32,6 +39,20 @@ impl MockController {
         controller
     }
 
+    /// Creates a mock revision 2 controller with default values.
+    ///
+    /// Differs from [`MockController::new`] in the PWM frequency encoding
+    /// and the second temperature input at 0x000F, reading 25°C.
+    pub fn new_rev2() -> Self {
+        let controller = Self::new();
+        let mut registers = controller.registers.lock().unwrap();
+        // PWM frequency: 25kHz, 0x0004 without the 500Hz option
+        registers.insert(0x000B, 0x0004);
+        registers.insert(0x000F, celsius_to_register(25));
+        drop(registers);
+        controller
+    }
+
     /// Sets realistic default register values.
     fn set_defaults(&self) {
         let mut registers = self.registers.lock().unwrap();
@@ -41,20 +62,23 @@ impl MockController {
         // Fan status: All fans running (0b1111 = 0x000F)
         registers.insert(0x0001, 0x000F);
 
-        // Modbus address: 1
-        registers.insert(0x0002, 0x0001);
+        // Modbus address
+        registers.insert(0x0002, u16::from(FACTORY_ADDRESS));
 
-        // Mode: Temperature mode (0xFFFF)
+        // Mode: Temperature mode (0xFFFF), see FACTORY_MODE
         registers.insert(0x0003, 0xFFFF);
 
         // Combined temperature: Start 30°C (70), Full 50°C (90) = 0x465A
-        registers.insert(0x0004, 0x465A);
+        registers.insert(
+            0x0004,
+            encode_combined_temperature(FACTORY_LOW_THRESHOLD_C, FACTORY_HIGH_THRESHOLD_C),
+        );
 
         // Work mode: Minimum speed (1)
-        registers.insert(0x0005, 0x0001);
+        registers.insert(0x0005, FACTORY_WORK_MODE.to_register_value());
 
         // Fan quantity: 4
-        registers.insert(0x0006, 0x0004);
+        registers.insert(0x0006, u16::from(FACTORY_FAN_COUNT));
 
         // Fan speeds (RPM)
         registers.insert(0x0007, 1400); // Fan 1
@@ -63,13 +87,13 @@ impl MockController {
         registers.insert(0x000A, 1400); // Fan 4
 
         // PWM frequency: 25kHz (0x0005)
-        registers.insert(0x000B, 0x0005);
+        registers.insert(0x000B, FACTORY_PWM_FREQUENCY.to_register_value());
 
         // Start temperature: 30°C (70)
-        registers.insert(0x000C, 70);
+        registers.insert(0x000C, celsius_to_register(FACTORY_LOW_THRESHOLD_C));
 
         // Full speed temperature: 50°C (90)
-        registers.insert(0x000D, 90);
+        registers.insert(0x000D, celsius_to_register(FACTORY_HIGH_THRESHOLD_C));
 
         // Fan fault code: All normal (0b1111 = 0x000F)
         registers.insert(0x000E, 0x000F);
@@ -133,6 +157,14 @@ impl MockController {
     }
 }
 
+/// Creates a client at address 1 on a fresh [`MockController`].
+#[allow(dead_code)] // Not every test file talks to a client
+pub async fn create_test_client() -> (Jpf4826Client, MockController) {
+    let mock = MockController::new();
+    let client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    (client, mock)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,6 +177,14 @@ mod tests {
         assert_eq!(controller.read_register(0x0006), Some(4)); // 4 fans
     }
 
+    #[test]
+    fn test_mock_rev2_defaults() {
+        let controller = MockController::new_rev2();
+        assert_eq!(controller.read_register(0x000B), Some(0x0004)); // 25kHz
+        assert_eq!(controller.read_register(0x000F), Some(65)); // 25°C
+        assert_eq!(MockController::new().read_register(0x000F), None);
+    }
+
     #[test]
     fn test_read_multiple_registers() {
         let controller = MockController::new();
@@ -0,0 +1,197 @@
+#![cfg(feature = "test-mock")]
+
+//! Hardware revision quirks, against model-specific mock register images.
+
+mod mock;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{ControllerConfig, DeviceModel, Jpf4826Client, PwmFrequency};
+use mock::MockController;
+
+async fn create_test_client(model: DeviceModel) -> (Jpf4826Client, MockController) {
+    let mock = match model {
+        DeviceModel::Jpf4826 => MockController::new(),
+        DeviceModel::Jpf4826Rev2 => MockController::new_rev2(),
+    };
+    let client = Jpf4826Client::new_mock_model(mock.registers.clone(), 1, model).await;
+    (client, mock)
+}
+
+#[tokio::test]
+async fn test_default_model_is_jpf4826() {
+    let (client, _mock) = create_test_client(DeviceModel::default()).await;
+
+    assert_eq!(client.model(), DeviceModel::Jpf4826);
+    assert_eq!(
+        jpf4826_driver::ConnectOptions::default().model,
+        DeviceModel::Jpf4826
+    );
+}
+
+#[tokio::test]
+async fn test_pwm_frequency_encoding_per_model() {
+    for (model, register) in [
+        (DeviceModel::Jpf4826, 0x0004),
+        (DeviceModel::Jpf4826Rev2, 0x0003),
+    ] {
+        let (mut client, mock) = create_test_client(model).await;
+
+        client
+            .set_pwm_frequency(PwmFrequency::Hz10000)
+            .await
+            .unwrap();
+
+        assert_eq!(mock.read_register(0x000B), Some(register), "{}", model);
+        assert_eq!(
+            client.status().await.unwrap().pwm_frequency,
+            PwmFrequency::Hz10000,
+            "{}",
+            model
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_status_decodes_model_image() {
+    let (mut client, mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+
+    assert_eq!(
+        client.status().await.unwrap().pwm_frequency,
+        PwmFrequency::Hz25000
+    );
+    assert_eq!(client.pwm_frequency().await.unwrap(), PwmFrequency::Hz25000);
+
+    // 25 kHz on the original board, nothing on rev. 2
+    mock.write_register(0x000B, 0x0005);
+    assert!(client
+        .pwm_frequency()
+        .await
+        .unwrap_err()
+        .is_invalid_response());
+}
+
+#[tokio::test]
+async fn test_unsupported_pwm_frequency_is_not_written() {
+    let (mut client, mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client
+        .set_pwm_frequency(PwmFrequency::Hz500)
+        .await
+        .unwrap_err();
+
+    assert!(err.is_unsupported());
+    assert_eq!(
+        err.to_string(),
+        "PWM frequency 500 Hz is not supported on this model (JPF4826 rev. 2)"
+    );
+    assert_eq!(probe.writes(), 0);
+    assert_eq!(mock.read_register(0x000B), Some(0x0004));
+}
+
+#[tokio::test]
+async fn test_jpf4826_accepts_500_hz() {
+    let (mut client, mock) = create_test_client(DeviceModel::Jpf4826).await;
+
+    client.set_pwm_frequency(PwmFrequency::Hz500).await.unwrap();
+
+    assert_eq!(mock.read_register(0x000B), Some(0x0000));
+}
+
+#[tokio::test]
+async fn test_apply_config_rejects_unsupported_frequency_before_writing() {
+    let (mut client, _mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+    let probe = client.mock_probe().unwrap();
+
+    let config = ControllerConfig {
+        fan_count: Some(2),
+        pwm_frequency: Some(PwmFrequency::Hz500),
+        ..ControllerConfig::default()
+    };
+    let err = client.apply_config(&config).await.unwrap_err();
+
+    assert!(err.is_unsupported());
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test]
+async fn test_apply_config_uses_model_encoding() {
+    let (mut client, mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+
+    // 25 kHz is already set on the rev. 2 image
+    let config = ControllerConfig {
+        pwm_frequency: Some(PwmFrequency::Hz25000),
+        ..ControllerConfig::default()
+    };
+    let report = client.apply_config(&config).await.unwrap();
+    assert_eq!(report.unchanged, vec![RegisterAddress::PwmFrequency]);
+
+    let config = ControllerConfig {
+        pwm_frequency: Some(PwmFrequency::Hz1000),
+        ..ControllerConfig::default()
+    };
+    client.apply_config(&config).await.unwrap();
+    assert_eq!(mock.read_register(0x000B), Some(0x0000));
+}
+
+#[tokio::test]
+async fn test_second_temperature_per_model() {
+    let (mut client, _mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+    assert_eq!(client.second_temperature().await.unwrap().value, 25);
+
+    let (mut client, _mock) = create_test_client(DeviceModel::Jpf4826).await;
+    let probe = client.mock_probe().unwrap();
+    let err = client.second_temperature().await.unwrap_err();
+    assert!(err.is_unsupported());
+    assert_eq!(
+        err.to_string(),
+        "Second temperature input is not supported on this model (JPF4826)"
+    );
+
+    // Raw access to the register is refused as well
+    let err = client
+        .read(RegisterAddress::SecondTemperature, 1)
+        .await
+        .unwrap_err();
+    assert!(err.is_unsupported());
+    assert_eq!(probe.reads(), 0);
+}
+
+#[tokio::test]
+async fn test_second_sensor_fault_names_register() {
+    let (mut client, mock) = create_test_client(DeviceModel::Jpf4826Rev2).await;
+    mock.write_register(0x000F, 0x00A0);
+
+    let err = client.second_temperature().await.unwrap_err();
+
+    assert!(err.is_sensor_fault());
+    assert_eq!(
+        err.to_string(),
+        "Temperature sensor fault: register 0x000F reads 0x00A0"
+    );
+
+    mock.write_register(0x000F, 0xFFFF);
+    let err = client.second_temperature().await.unwrap_err();
+    assert!(!err.is_sensor_fault());
+    assert!(err.is_invalid_response());
+    assert!(err.to_string().contains("register 0x000F"), "{}", err);
+}
+
+#[test]
+fn test_model_register_table() {
+    assert_eq!(
+        DeviceModel::Jpf4826Rev2.register(0x000F),
+        Some(RegisterAddress::SecondTemperature)
+    );
+    assert_eq!(DeviceModel::Jpf4826.register(0x000F), None);
+    assert_eq!(
+        DeviceModel::Jpf4826.register(0x000B),
+        Some(RegisterAddress::PwmFrequency)
+    );
+    for model in [DeviceModel::Jpf4826, DeviceModel::Jpf4826Rev2] {
+        for &freq in model.pwm_frequencies() {
+            let value = model.pwm_register_value(freq).unwrap();
+            assert_eq!(model.pwm_from_register_value(value), Some(freq));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+#![cfg(all(feature = "mqtt", feature = "test-mock"))]
+
+mod mock;
+
+use std::sync::{Arc, Mutex};
+
+use jpf4826_driver::mqtt::{MqttCommand, MqttPublisher, MqttTransport, PublisherOptions};
+use jpf4826_driver::Result;
+use mock::create_test_client;
+
+/// Transport recording every publish and subscription.
+#[derive(Default, Clone)]
+struct RecordingTransport {
+    published: Arc<Mutex<Vec<(String, String, bool)>>>,
+    subscribed: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingTransport {
+    fn payload(&self, topic: &str) -> Option<String> {
+        self.published
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|(t, _, _)| t == topic)
+            .map(|(_, payload, _)| payload.clone())
+    }
+}
+
+impl MqttTransport for RecordingTransport {
+    async fn publish(&self, topic: String, payload: Vec<u8>, retain: bool) -> Result<()> {
+        let payload = String::from_utf8(payload).unwrap();
+        self.published
+            .lock()
+            .unwrap()
+            .push((topic, payload, retain));
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: String) -> Result<()> {
+        self.subscribed.lock().unwrap().push(topic);
+        Ok(())
+    }
+}
+
+fn create_publisher() -> (MqttPublisher<RecordingTransport>, RecordingTransport) {
+    let transport = RecordingTransport::default();
+    let publisher = MqttPublisher::with_transport(transport.clone(), "jpf4826/1");
+    (publisher, transport)
+}
+
+#[test]
+fn test_default_options() {
+    let options = PublisherOptions::new("broker.local", 7);
+
+    assert_eq!(options.port, 1883);
+    assert_eq!(options.client_id, "jpf4826-7");
+    assert_eq!(options.base_topic, "jpf4826/7");
+}
+
+#[tokio::test]
+async fn test_publish_status_topic_layout() {
+    let (mut client, mock) = create_test_client().await;
+    mock.set_fan_fault(3, true);
+    mock.write_register(0x0009, 0);
+    let (publisher, transport) = create_publisher();
+
+    let status = client.status().await.unwrap();
+    publisher.publish_status(&status).await.unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&transport.payload("jpf4826/1/status").unwrap()).unwrap();
+    assert_eq!(json["modbus_address"], 1);
+    assert_eq!(json["temperature"]["current"]["value"], 31);
+    assert_eq!(transport.payload("jpf4826/1/temperature").unwrap(), "31");
+    assert_eq!(transport.payload("jpf4826/1/fan/1/rpm").unwrap(), "1400");
+    assert_eq!(
+        transport.payload("jpf4826/1/fan/1/status").unwrap(),
+        "NORMAL"
+    );
+    assert_eq!(transport.payload("jpf4826/1/fan/3/rpm").unwrap(), "0");
+    assert_eq!(
+        transport.payload("jpf4826/1/fan/3/status").unwrap(),
+        "FAULT"
+    );
+    assert!(transport
+        .published
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|(_, _, retain)| *retain));
+}
+
+#[tokio::test]
+async fn test_availability() {
+    let (publisher, transport) = create_publisher();
+
+    publisher.announce_online().await.unwrap();
+    assert_eq!(
+        transport.payload("jpf4826/1/availability").unwrap(),
+        "online"
+    );
+
+    publisher.announce_offline().await.unwrap();
+    assert_eq!(
+        transport.payload("jpf4826/1/availability").unwrap(),
+        "offline"
+    );
+}
+
+#[tokio::test]
+async fn test_subscribe_commands() {
+    let (publisher, transport) = create_publisher();
+
+    publisher.subscribe_commands().await.unwrap();
+
+    assert_eq!(
+        *transport.subscribed.lock().unwrap(),
+        vec!["jpf4826/1/set/#"]
+    );
+}
+
+#[test]
+fn test_base_topic_trailing_slash_is_trimmed() {
+    let publisher = MqttPublisher::with_transport(RecordingTransport::default(), "home/fans/");
+
+    assert_eq!(publisher.topic("status"), "home/fans/status");
+}
+
+#[test]
+fn test_parse_commands() {
+    let (publisher, _) = create_publisher();
+
+    let cases = [
+        ("jpf4826/1/set/speed", "75", MqttCommand::Speed(75)),
+        ("jpf4826/1/set/speed", "AUTO", MqttCommand::AutoSpeed),
+        ("jpf4826/1/set/eco", "on", MqttCommand::Eco(true)),
+        ("jpf4826/1/set/eco", "off", MqttCommand::Eco(false)),
+        (
+            "jpf4826/1/set/thresholds",
+            r#"{"low": 25, "high": 45}"#,
+            MqttCommand::Thresholds { low: 25, high: 45 },
+        ),
+        ("jpf4826/1/set/reset", "", MqttCommand::Reset),
+    ];
+    for (topic, payload, expected) in cases {
+        let command = publisher.parse_command(topic, payload.as_bytes()).unwrap();
+        assert_eq!(command, Some(expected), "{} {}", topic, payload);
+    }
+}
+
+#[test]
+fn test_parse_ignores_foreign_topics() {
+    let (publisher, _) = create_publisher();
+
+    for topic in [
+        "jpf4826/2/set/speed",
+        "jpf4826/1/status",
+        "jpf4826/1/set/unknown",
+    ] {
+        assert_eq!(publisher.parse_command(topic, b"50").unwrap(), None);
+    }
+}
+
+#[test]
+fn test_parse_rejects_malformed_payloads() {
+    let (publisher, _) = create_publisher();
+
+    for (topic, payload) in [
+        ("jpf4826/1/set/speed", "fast"),
+        ("jpf4826/1/set/eco", "maybe"),
+        ("jpf4826/1/set/thresholds", "30"),
+    ] {
+        let err = publisher
+            .parse_command(topic, payload.as_bytes())
+            .unwrap_err();
+        assert!(err.is_invalid_parameter());
+    }
+}
+
+#[tokio::test]
+async fn test_handle_message_applies_command() {
+    let (mut client, mock) = create_test_client().await;
+    let (publisher, _) = create_publisher();
+
+    let command = publisher
+        .handle_message("jpf4826/1/set/speed", b"60", &mut client)
+        .await
+        .unwrap();
+    assert_eq!(command, Some(MqttCommand::Speed(60)));
+    assert_eq!(mock.read_register(0x0003).unwrap(), 60);
+
+    publisher
+        .handle_message(
+            "jpf4826/1/set/thresholds",
+            br#"{"low": 25, "high": 40}"#,
+            &mut client,
+        )
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x000C).unwrap(), 65);
+    assert_eq!(mock.read_register(0x000D).unwrap(), 80);
+
+    publisher
+        .handle_message("jpf4826/1/set/eco", b"on", &mut client)
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x0005).unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_handle_message_surfaces_client_errors() {
+    let (mut client, _mock) = create_test_client().await;
+    let (publisher, _) = create_publisher();
+
+    let err = publisher
+        .handle_message("jpf4826/1/set/speed", b"150", &mut client)
+        .await
+        .unwrap_err();
+
+    assert!(!err.is_mqtt());
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::Jpf4826Client;
+use mock::MockController;
+
+#[tokio::test]
+async fn test_probe_reads_one_register() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+
+    let result = client.probe().await.unwrap();
+
+    assert!(result.responded);
+    // CurrentTemperature alone
+    assert_eq!(probe.read_log(), vec![(0x0000, 1)]);
+    assert_eq!(probe.writes(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_probe_measures_round_trip() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    client
+        .mock_probe()
+        .unwrap()
+        .set_read_delay(Duration::from_millis(40));
+
+    let result = client.probe().await.unwrap();
+    assert_eq!(result.round_trip, Duration::from_millis(40));
+}
+
+#[tokio::test]
+async fn test_probe_responds_despite_sensor_fault() {
+    let mock = MockController::new();
+    mock.write_register(0x0000, 0xFFFF);
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+
+    assert!(client.probe().await.unwrap().responded);
+}
+
+#[tokio::test]
+async fn test_probe_reports_missing_controller_as_timeout() {
+    let bus = MockBus::new();
+    bus.add_device(3, MockController::new().registers);
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 4).unwrap();
+
+    let err = client.probe().await.unwrap_err();
+    assert!(err.is_timeout(), "{}", err);
+}
+
+#[tokio::test]
+async fn test_probe_reports_bus_error_without_retrying() {
+    let mock = MockController::new();
+    let mut client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(2);
+    probe.set_fail_reads(true);
+
+    let err = client.probe().await.unwrap_err();
+    assert!(err.is_modbus() && !err.is_timeout(), "{}", err);
+    assert_eq!(probe.reads(), 1);
+    // Retries apply to other requests again
+    assert_eq!(client.retries(), 2);
+    assert_eq!(client.link_info().failures, 1);
+}
@@ -0,0 +1,582 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use jpf4826_driver::conversions::encode_combined_temperature;
+use jpf4826_driver::profile::{
+    ConfigError, ConfigMismatch, Thresholds, FACTORY_ADDRESS, FACTORY_MODE,
+};
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{
+    ApplyReport, ControllerConfig, Jpf4826Client, Profile, ProfileSet, PwmFrequency, SpeedSetting,
+    WorkMode,
+};
+use mock::{create_test_client, MockController};
+
+#[test]
+fn test_builtin_presets() {
+    let quiet = Profile::builtin("quiet").unwrap();
+    assert_eq!(
+        quiet.config,
+        ControllerConfig {
+            thresholds: Some(Thresholds { low: 35, high: 55 }),
+            work_mode: Some(WorkMode::Shutdown),
+            fan_count: None,
+            pwm_frequency: Some(PwmFrequency::Hz10000),
+            speed: None,
+            modbus_address: None,
+        }
+    );
+
+    let performance = Profile::builtin("performance").unwrap();
+    assert_eq!(
+        performance.config,
+        ControllerConfig {
+            thresholds: Some(Thresholds { low: 25, high: 40 }),
+            work_mode: Some(WorkMode::MinimumSpeed),
+            fan_count: None,
+            pwm_frequency: Some(PwmFrequency::Hz25000),
+            speed: None,
+            modbus_address: None,
+        }
+    );
+
+    for profile in Profile::builtins() {
+        assert!(profile.config.validate().is_ok(), "{}", profile.name);
+    }
+}
+
+#[tokio::test]
+async fn test_apply_quiet_profile() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let report = client.apply_profile(&Profile::quiet()).await.unwrap();
+
+    // 0x0004-0x0005 share a frame, 0x000B needs its own
+    assert_eq!(
+        report,
+        ApplyReport {
+            written: vec![
+                RegisterAddress::CombinedTemperature,
+                RegisterAddress::WorkMode,
+                RegisterAddress::PwmFrequency,
+            ],
+            unchanged: vec![],
+            transactions: 2,
+            cancelled: vec![],
+        }
+    );
+    assert_eq!((probe.reads(), probe.writes()), (1, 2));
+    assert_eq!(
+        mock.read_register(0x0004),
+        Some(encode_combined_temperature(35, 55))
+    );
+    assert_eq!(mock.read_register(0x0005), Some(0x0000));
+    assert_eq!(mock.read_register(0x000B), Some(0x0004));
+}
+
+#[tokio::test]
+async fn test_apply_skips_unchanged_settings() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    // The mock already runs in minimum speed mode at 25 kHz
+    let report = client.apply_profile(&Profile::performance()).await.unwrap();
+
+    assert_eq!(report.written, vec![RegisterAddress::CombinedTemperature]);
+    assert_eq!(
+        report.unchanged,
+        vec![RegisterAddress::WorkMode, RegisterAddress::PwmFrequency]
+    );
+    assert_eq!(report.transactions, 1);
+    assert_eq!(probe.writes(), 1);
+    assert_eq!(
+        mock.read_register(0x0004),
+        Some(encode_combined_temperature(25, 40))
+    );
+
+    // Applying again writes nothing
+    let report = client.apply_profile(&Profile::performance()).await.unwrap();
+    assert!(report.written.is_empty());
+    assert_eq!(probe.writes(), 1);
+}
+
+#[tokio::test]
+async fn test_apply_rejects_invalid_config() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let inverted = ControllerConfig {
+        thresholds: Some(Thresholds { low: 50, high: 40 }),
+        ..ControllerConfig::default()
+    };
+    let err = client.apply_config(&inverted).await.unwrap_err();
+    assert!(err.to_string().starts_with("Temperature threshold error"));
+
+    let fans = ControllerConfig {
+        fan_count: Some(5),
+        ..ControllerConfig::default()
+    };
+    let err = client.apply_config(&fans).await.unwrap_err();
+    assert!(err.is_invalid_parameter());
+
+    // Nothing reached the bus
+    assert_eq!((probe.reads(), probe.writes()), (0, 0));
+}
+
+#[test]
+fn test_speed_setting_register_round_trip() {
+    let settings = (0..=100)
+        .map(SpeedSetting::Manual)
+        .chain([SpeedSetting::Auto]);
+    for setting in settings {
+        assert_eq!(
+            SpeedSetting::from_written_value(setting.to_register_value()),
+            Some(setting)
+        );
+    }
+    for value in [101, 0x00FF, 0xFFFE] {
+        assert_eq!(SpeedSetting::from_written_value(value), None);
+    }
+}
+
+#[tokio::test]
+async fn test_apply_rejects_invalid_speed_and_address() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let speed = ControllerConfig {
+        thresholds: Some(Thresholds { low: 30, high: 50 }),
+        speed: Some(SpeedSetting::Manual(101)),
+        ..ControllerConfig::default()
+    };
+    let err = client.apply_config(&speed).await.unwrap_err();
+    assert_eq!(err.to_string(), "Manual speed 101% out of range (0-100)");
+
+    let address = ControllerConfig {
+        modbus_address: Some(0),
+        ..ControllerConfig::default()
+    };
+    let err = client.apply_config(&address).await.unwrap_err();
+    assert_eq!(err.to_string(), "Modbus address 0 out of range (1-254)");
+
+    assert_eq!((probe.reads(), probe.writes()), (0, 0));
+}
+
+#[tokio::test]
+async fn test_get_config_reads_writable_settings() {
+    let (mut client, _mock) = create_test_client().await;
+
+    let config = client.get_config().await.unwrap();
+
+    assert_eq!(
+        config,
+        ControllerConfig {
+            speed: Some(SpeedSetting::Auto),
+            ..ControllerConfig::factory()
+        }
+    );
+    assert_eq!(config.modbus_address, None);
+
+    client.set_fan_speed(40).await.unwrap();
+    let config = client.get_config().await.unwrap();
+    assert_eq!(config.speed, Some(SpeedSetting::Manual(40)));
+}
+
+#[tokio::test]
+async fn test_copy_config_between_controllers() {
+    let (mut source, _source_mock) = create_test_client().await;
+    source.set_temperature_threshold(40, 60).await.unwrap();
+    source.set_fan_count(2).await.unwrap();
+    source.set_eco(WorkMode::Shutdown).await.unwrap();
+    source
+        .set_pwm_frequency(PwmFrequency::Hz10000)
+        .await
+        .unwrap();
+    source.set_fan_speed(40).await.unwrap();
+
+    let target_mock = MockController::new();
+    target_mock.write_register(0x0002, 2);
+    let mut target = Jpf4826Client::new_mock(target_mock.registers.clone(), 2).await;
+
+    let config = source.get_config().await.unwrap();
+    let report = target.apply_config(&config).await.unwrap();
+
+    // Thresholds before the speed, the address untouched
+    assert_eq!(
+        report.written,
+        vec![
+            RegisterAddress::CombinedTemperature,
+            RegisterAddress::WorkMode,
+            RegisterAddress::FanQuantity,
+            RegisterAddress::PwmFrequency,
+            RegisterAddress::ManualSpeedControl,
+        ]
+    );
+    assert_eq!(
+        target_mock.read_registers(0x0002, 5),
+        vec![2, 40, encode_combined_temperature(40, 60), 0x0000, 2]
+    );
+    assert_eq!(target_mock.read_register(0x000B), Some(0x0004));
+}
+
+#[tokio::test]
+async fn test_apply_config_writes_address_last() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let config = ControllerConfig {
+        thresholds: Some(Thresholds { low: 35, high: 55 }),
+        speed: Some(SpeedSetting::Auto),
+        modbus_address: Some(7),
+        ..ControllerConfig::default()
+    };
+    let report = client.apply_config(&config).await.unwrap();
+
+    assert_eq!(
+        report.written,
+        vec![
+            RegisterAddress::CombinedTemperature,
+            RegisterAddress::ModbusAddress
+        ]
+    );
+    // Already in automatic mode
+    assert_eq!(report.unchanged, vec![RegisterAddress::ManualSpeedControl]);
+    assert_eq!(
+        probe.write_log(),
+        vec![(0x0004, encode_combined_temperature(35, 55)), (0x0002, 7)]
+    );
+    assert_eq!(mock.read_register(0x0002), Some(7));
+    assert_eq!(client.slave_addr(), 7);
+
+    let status = client.status().await.unwrap();
+    assert_eq!(
+        ControllerConfig {
+            modbus_address: Some(9),
+            ..ControllerConfig::default()
+        }
+        .verify(&status),
+        vec![ConfigMismatch::ModbusAddress {
+            expected: 9,
+            actual: 7
+        }]
+    );
+}
+
+#[test]
+fn test_profile_set_from_document() {
+    let set: ProfileSet = serde_json::from_str(
+        r#"{
+            "profiles": [
+                {
+                    "name": "night",
+                    "config": {
+                        "thresholds": {"low": 40, "high": 60},
+                        "work_mode": "Shutdown",
+                        "fan_count": 2
+                    }
+                },
+                {
+                    "name": "quiet",
+                    "config": {"pwm_frequency": {"value": 5000, "unit": "Hz"}}
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let night = set.get("night").unwrap();
+    assert_eq!(
+        night.config.thresholds,
+        Some(Thresholds { low: 40, high: 60 })
+    );
+    assert_eq!(night.config.work_mode, Some(WorkMode::Shutdown));
+    assert_eq!(night.config.fan_count, Some(2));
+    assert_eq!(night.config.pwm_frequency, None);
+
+    // The document overrides a built-in of the same name
+    let quiet = set.resolve("quiet").unwrap();
+    assert_eq!(quiet.config.pwm_frequency, Some(PwmFrequency::Hz5000));
+    assert_eq!(quiet.config.thresholds, None);
+    assert_eq!(set.resolve("performance"), Some(Profile::performance()));
+    assert_eq!(set.resolve("missing"), None);
+    assert_eq!(set.iter().count(), 2);
+}
+
+#[test]
+fn test_profile_document_round_trip() {
+    let set = ProfileSet::new(Profile::builtins()).unwrap();
+    let json = serde_json::to_string(&set).unwrap();
+    let parsed: ProfileSet = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, set);
+}
+
+#[test]
+fn test_bad_profile_documents_are_rejected() {
+    let cases = [
+        // Inverted thresholds
+        (
+            r#"{"profiles": [{"name": "a", "config": {"thresholds": {"low": 50, "high": 40}}}]}"#,
+            "Profile 'a': High temperature 40°C must be greater than low temperature 50°C",
+        ),
+        // Threshold out of range
+        (
+            r#"{"profiles": [{"name": "a", "config": {"thresholds": {"low": 30, "high": 130}}}]}"#,
+            "Profile 'a': Temperature 130°C out of range (-20 to 120)",
+        ),
+        (
+            r#"{"profiles": [{"name": "a", "config": {"fan_count": 7}}]}"#,
+            "Profile 'a': Fan count 7 out of range (0-4)",
+        ),
+        (
+            r#"{"profiles": [{"name": "", "config": {}}]}"#,
+            "Profile name must not be empty",
+        ),
+        (
+            r#"{"profiles": [{"name": "a", "config": {}}, {"name": "a", "config": {}}]}"#,
+            "Profile 'a' is defined more than once",
+        ),
+    ];
+    for (document, message) in cases {
+        let err = serde_json::from_str::<ProfileSet>(document).unwrap_err();
+        assert!(err.to_string().starts_with(message), "{}", err);
+    }
+
+    // Typos and unsupported values are caught by serde
+    for document in [
+        r#"{"profiles": [{"name": "a", "config": {"fan_cuont": 2}}]}"#,
+        r#"{"profiles": [{"name": "a", "config": {"work_mode": "Turbo"}}]}"#,
+        r#"{"profiles": [{"name": "a", "config": {"pwm_frequency": {"value": 3000, "unit": "Hz"}}}]}"#,
+        r#"{"profiles": [{"name": "a"}]}"#,
+        r#"{"profile": []}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<ProfileSet>(document).is_err(),
+            "{}",
+            document
+        );
+    }
+}
+
+#[test]
+fn test_config_error_display() {
+    let err = ConfigError::InvalidProfile {
+        name: "night".to_string(),
+        source: Box::new(ConfigError::FanCount(9)),
+    };
+    assert_eq!(
+        err.to_string(),
+        "Profile 'night': Fan count 9 out of range (0-4)"
+    );
+}
+
+#[tokio::test]
+async fn test_verify_configuration_matches_defaults() {
+    let (mut client, _mock) = create_test_client().await;
+    let expected = ControllerConfig {
+        thresholds: Some(Thresholds { low: 30, high: 50 }),
+        work_mode: Some(WorkMode::MinimumSpeed),
+        fan_count: Some(4),
+        pwm_frequency: Some(PwmFrequency::Hz25000),
+        speed: None,
+        modbus_address: None,
+    };
+
+    assert!(client
+        .verify_configuration(&expected)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(client
+        .verify_configuration(&ControllerConfig::default())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_verify_configuration_lists_every_mismatch() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x0006, 2);
+    let expected = ControllerConfig {
+        thresholds: Some(Thresholds { low: 35, high: 50 }),
+        work_mode: Some(WorkMode::MinimumSpeed),
+        fan_count: Some(4),
+        pwm_frequency: Some(PwmFrequency::Hz1000),
+        speed: None,
+        modbus_address: None,
+    };
+
+    let mismatches = client.verify_configuration(&expected).await.unwrap();
+
+    assert_eq!(
+        mismatches,
+        vec![
+            ConfigMismatch::Thresholds {
+                expected: Thresholds { low: 35, high: 50 },
+                actual: Thresholds { low: 30, high: 50 },
+            },
+            ConfigMismatch::FanCount {
+                expected: 4,
+                actual: 2
+            },
+            ConfigMismatch::PwmFrequency {
+                expected: PwmFrequency::Hz1000,
+                actual: PwmFrequency::Hz25000,
+            },
+        ]
+    );
+    assert_eq!(mismatches[1].to_string(), "Fan count is 2, expected 4");
+    assert_eq!(
+        mismatches[2].to_string(),
+        "PWM frequency is 25000 Hz, expected 1000 Hz"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_defaults_match_factory_config() {
+    let (mut client, _mock) = create_test_client().await;
+    let factory = ControllerConfig::factory();
+
+    let status = client.status().await.unwrap();
+
+    let thresholds = factory.thresholds.unwrap();
+    assert_eq!(status.temperature_low_threshold.value, thresholds.low);
+    assert_eq!(status.temperature_high_threshold.value, thresholds.high);
+    assert_eq!(Some(status.work_mode), factory.work_mode);
+    assert_eq!(Some(status.fan_count), factory.fan_count);
+    assert_eq!(Some(status.pwm_frequency), factory.pwm_frequency);
+    assert_eq!(status.mode, FACTORY_MODE);
+    assert_eq!(status.modbus_address, FACTORY_ADDRESS);
+    assert!(factory.verify(&status).is_empty());
+}
+
+#[tokio::test]
+async fn test_restore_factory_defaults() {
+    let (mut client, mock) = create_test_client().await;
+    mock.write_register(0x0004, encode_combined_temperature(40, 60));
+    mock.write_register(0x0006, 2);
+    client.set_fan_speed(70).await.unwrap();
+
+    mock.write_register(0x0005, WorkMode::Shutdown.to_register_value());
+    mock.write_register(0x000B, PwmFrequency::Hz2000.to_register_value());
+
+    let report = client.restore_factory_defaults().await.unwrap();
+
+    assert_eq!(
+        report.written,
+        vec![
+            RegisterAddress::CombinedTemperature,
+            RegisterAddress::WorkMode,
+            RegisterAddress::FanQuantity,
+            RegisterAddress::PwmFrequency,
+            RegisterAddress::ManualSpeedControl,
+        ]
+    );
+    assert!(!report.unchanged.contains(&RegisterAddress::ModbusAddress));
+    assert_eq!(mock.read_register(0x0003), Some(0xFFFF));
+    assert_eq!(mock.read_register(0x0004), Some(0x465A));
+    assert_eq!(mock.read_register(0x0005), Some(0x0001));
+    assert_eq!(mock.read_register(0x0006), Some(4));
+    assert_eq!(mock.read_register(0x000B), Some(0x0005));
+    assert_eq!(mock.read_register(0x000C), Some(70));
+    assert_eq!(mock.read_register(0x000D), Some(90));
+    assert_eq!(mock.read_register(0x0002), Some(u16::from(FACTORY_ADDRESS)));
+    let status = client.status().await.unwrap();
+    assert!(ControllerConfig::factory().verify(&status).is_empty());
+    assert_eq!(status.mode, FACTORY_MODE);
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+async fn test_config_backup_round_trip() {
+    let (mut client, mock) = create_test_client().await;
+    let saved = ControllerConfig {
+        thresholds: Some(Thresholds { low: 25, high: 45 }),
+        work_mode: Some(WorkMode::Shutdown),
+        fan_count: Some(2),
+        pwm_frequency: Some(PwmFrequency::Hz10000),
+        ..ControllerConfig::default()
+    };
+    client.apply_config(&saved).await.unwrap();
+
+    let mut file = Vec::new();
+    client
+        .get_config()
+        .await
+        .unwrap()
+        .to_writer(&mut file)
+        .unwrap();
+
+    // Controller swapped for a factory-fresh one
+    client
+        .apply_config(&ControllerConfig::factory())
+        .await
+        .unwrap();
+    assert_eq!(mock.read_register(0x0006), Some(4));
+
+    let restored = ControllerConfig::from_reader(file.as_slice()).unwrap();
+    let report = client.restore_config(&restored).await.unwrap();
+    assert!(report
+        .written
+        .contains(&RegisterAddress::CombinedTemperature));
+
+    assert_eq!(
+        mock.read_register(0x0004),
+        Some(encode_combined_temperature(25, 45))
+    );
+    assert_eq!(mock.read_register(0x0005), Some(0));
+    assert_eq!(mock.read_register(0x0006), Some(2));
+    assert_eq!(mock.read_register(0x000B), Some(0x0004));
+    assert!(client
+        .get_config()
+        .await
+        .unwrap()
+        .verify(&client.status().await.unwrap())
+        .is_empty());
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+async fn test_restore_config_skips_address_unless_asked() {
+    let (mut client, mock) = create_test_client().await;
+    let config = ControllerConfig {
+        fan_count: Some(3),
+        modbus_address: Some(9),
+        ..ControllerConfig::default()
+    };
+
+    let report = client.restore_config(&config).await.unwrap();
+    assert_eq!(mock.read_register(0x0006), Some(3));
+    assert_eq!(mock.read_register(0x0002), Some(u16::from(FACTORY_ADDRESS)));
+    assert!(!report.written.contains(&RegisterAddress::ModbusAddress));
+    // Still answering at the old address
+    assert_eq!(client.fan_count().await.unwrap(), 3);
+
+    let report = client.restore_config_with_address(&config).await.unwrap();
+    assert_eq!(mock.read_register(0x0002), Some(9));
+    assert_eq!(report.written, vec![RegisterAddress::ModbusAddress]);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_config_file_rejects_unknown_and_invalid_settings() {
+    let err =
+        ControllerConfig::from_reader(r#"{"fan_count": 2, "fan_cnt": 3}"#.as_bytes()).unwrap_err();
+    assert!(err.is_config_file());
+    assert!(
+        err.to_string().contains("unknown field `fan_cnt`"),
+        "{}",
+        err
+    );
+
+    let err =
+        ControllerConfig::from_reader(r#"{"thresholds": {"low": 50, "high": 40}}"#.as_bytes())
+            .unwrap_err();
+    assert!(err.is_config_file());
+    assert!(err.to_string().contains("must be greater"), "{}", err);
+
+    let err = ControllerConfig::from_reader(r#"{"fan_count": 2"#.as_bytes()).unwrap_err();
+    assert!(err.is_config_file());
+}
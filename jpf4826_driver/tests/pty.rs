@@ -0,0 +1,122 @@
+#![cfg(all(unix, feature = "std"))]
+
+//! Pseudo-terminal controller for tests over the real serial stack.
+//!
+//! The client opens the slave side of a pty as it would open a USB adapter;
+//! a task on the master side decodes Modbus-RTU read requests and answers
+//! them, optionally after a delay or with fewer registers than requested.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::watch;
+use tokio_serial::{SerialPort, SerialStream};
+
+#[path = "stub.rs"]
+mod stub;
+
+use stub::{crc16, with_crc};
+
+/// Pause before retrying while no client has the slave side open.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Simulated controller behind a pseudo-terminal.
+///
+/// Every register of a read response holds the number of the request
+/// (1, 2, ...), so a response can be matched to the request it answers.
+pub struct PtyController {
+    path: String,
+    delays: Arc<Mutex<VecDeque<Duration>>>,
+    truncations: Arc<Mutex<VecDeque<u16>>>,
+    answered: watch::Receiver<u16>,
+}
+
+impl PtyController {
+    /// Creates the pty pair and starts answering requests.
+    pub fn start() -> Self {
+        let (master, slave) = SerialStream::pair().expect("Failed to create pty pair");
+        let path = slave.name().expect("pty slave without a path");
+        // The client opens its own handle by path
+        drop(slave);
+
+        let delays = Arc::new(Mutex::new(VecDeque::new()));
+        let truncations = Arc::new(Mutex::new(VecDeque::new()));
+        let (sender, answered) = watch::channel(0);
+        tokio::spawn(serve(master, delays.clone(), truncations.clone(), sender));
+        Self {
+            path,
+            delays,
+            truncations,
+            answered,
+        }
+    }
+
+    /// Returns the path to open the controller at.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Delays the answer to the next unanswered request by `delay`.
+    pub fn delay_next(&self, delay: Duration) {
+        self.delays.lock().unwrap().push_back(delay);
+    }
+
+    /// Answers the next unanswered request with at most `count` registers.
+    pub fn truncate_next(&self, count: u16) {
+        self.truncations.lock().unwrap().push_back(count);
+    }
+
+    /// Waits until `count` requests have been answered.
+    pub async fn wait_answered(&self, count: u16) {
+        let mut answered = self.answered.clone();
+        answered.wait_for(|n| *n >= count).await.unwrap();
+    }
+}
+
+async fn serve(
+    mut master: SerialStream,
+    delays: Arc<Mutex<VecDeque<Duration>>>,
+    truncations: Arc<Mutex<VecDeque<u16>>>,
+    answered: watch::Sender<u16>,
+) {
+    let mut sequence = 0u16;
+    loop {
+        // Read Holding Registers request: address, function, start, count, CRC
+        let mut request = [0u8; 8];
+        read_request(&mut master, &mut request).await;
+        if request[1] != 0x03 || crc16(&request[..6]).to_le_bytes() != request[6..] {
+            continue;
+        }
+        sequence += 1;
+
+        let delay = delays.lock().unwrap().pop_front().unwrap_or_default();
+        tokio::time::sleep(delay).await;
+
+        let requested = u16::from_be_bytes([request[4], request[5]]);
+        let count = match truncations.lock().unwrap().pop_front() {
+            Some(limit) => requested.min(limit),
+            None => requested,
+        };
+        let mut response = vec![request[0], 0x03, (count * 2) as u8];
+        for _ in 0..count {
+            response.extend_from_slice(&sequence.to_be_bytes());
+        }
+        let response = with_crc(response);
+        // Succeeds whether or not the client still listens
+        let _ = master.write_all(&response).await;
+        answered.send_replace(sequence);
+    }
+}
+
+async fn read_request(master: &mut SerialStream, buf: &mut [u8]) {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match master.read(&mut buf[filled..]).await {
+            Ok(n) if n > 0 => filled += n,
+            // EIO while no client has the slave side open
+            _ => tokio::time::sleep(POLL_INTERVAL).await,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::{OperatingMode, RESET_POLL_INTERVAL};
+use mock::create_test_client;
+
+#[tokio::test(start_paused = true)]
+async fn test_reset_and_wait_polls_until_controller_answers() {
+    let (mut client, mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.set_fan_speed(60).await.unwrap();
+
+    // Off the bus for 600 ms after the acknowledged reset
+    probe.set_fail_reads(true);
+    let restart = probe.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        restart.set_fail_reads(false);
+    });
+
+    let took = client.reset_and_wait(Duration::from_secs(5)).await.unwrap();
+
+    // Polls at 250 and 500 ms fail, the one at 750 ms is answered
+    assert_eq!(took, 3 * RESET_POLL_INTERVAL);
+    assert_eq!(probe.reads(), 3);
+    assert_eq!(mock.read_register(0x0020), Some(0x00AA));
+    assert_eq!(client.status().await.unwrap().mode, OperatingMode::Unknown);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_reset_and_wait_tolerates_unacknowledged_reset() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    // The reset write and the first two polls go unanswered
+    probe.set_silent_requests(3);
+
+    let took = client.reset_and_wait(Duration::from_secs(5)).await.unwrap();
+
+    assert_eq!(took, 3 * RESET_POLL_INTERVAL);
+    assert_eq!(probe.writes(), 1);
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_reset_and_wait_times_out() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_fail_reads(true);
+    let started = tokio::time::Instant::now();
+
+    let err = client
+        .reset_and_wait(Duration::from_secs(1))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_timeout(), "{}", err);
+    assert_eq!(started.elapsed(), Duration::from_secs(1));
+    assert_eq!(probe.reads(), 4);
+}
+
+#[tokio::test]
+async fn test_reset_and_wait_rejects_zero_wait() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    let err = client.reset_and_wait(Duration::ZERO).await.unwrap_err();
+
+    assert!(err.is_invalid_parameter());
+    assert_eq!(probe.writes(), 0);
+}
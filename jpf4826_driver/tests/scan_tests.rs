@@ -0,0 +1,111 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockBus;
+use jpf4826_driver::Jpf4826Client;
+use mock::MockController;
+
+const PROBE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Creates a bus with controllers at addresses 3 and 17, the one at 17
+/// with a disconnected sensor.
+fn bus_with_responders() -> MockBus {
+    let bus = MockBus::new();
+    bus.add_device(3, MockController::new().registers);
+    let faulty = MockController::new();
+    faulty.write_register(0x0000, 0xFFFF);
+    bus.add_device(17, faulty.registers);
+    bus
+}
+
+#[tokio::test]
+async fn test_scan_finds_responders() {
+    let bus = bus_with_responders();
+    let mut client = Jpf4826Client::new_lazy_mock(bus.clone(), 1).unwrap();
+
+    let found = client.scan_bus(1..=20, PROBE_TIMEOUT).await.unwrap();
+
+    let addrs: Vec<u8> = found.iter().map(|device| device.addr).collect();
+    assert_eq!(addrs, vec![3, 17]);
+    assert_eq!(found[0].temperature.unwrap().value, 31);
+    assert_eq!(found[1].temperature, None);
+    // One connection for the whole scan
+    assert_eq!(bus.connects(), 1);
+}
+
+#[tokio::test]
+async fn test_scan_restores_client_address() {
+    let bus = bus_with_responders();
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 3).unwrap();
+
+    client.scan_bus(1..=254, PROBE_TIMEOUT).await.unwrap();
+
+    assert_eq!(client.slave_addr(), 3);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    // Silent addresses are not link failures
+    assert_eq!(client.link_info().failures, 0);
+}
+
+#[tokio::test]
+async fn test_scan_skips_addresses_outside_range() {
+    let bus = bus_with_responders();
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 1).unwrap();
+
+    let found = client.scan_bus(4..=16, PROBE_TIMEOUT).await.unwrap();
+    assert!(found.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_scan_stops_when_cancelled() {
+    let bus = bus_with_responders();
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 1).unwrap();
+    client.connect().await.unwrap();
+    let probe = client.mock_probe().unwrap();
+    probe.set_read_delay(Duration::from_millis(10));
+
+    // Addresses 1-3 are probed before the cancel signal
+    let cancel = tokio::time::sleep(Duration::from_millis(25));
+    let found = client
+        .scan_bus_until(1..=254, PROBE_TIMEOUT, cancel)
+        .await
+        .unwrap();
+
+    let addrs: Vec<u8> = found.iter().map(|device| device.addr).collect();
+    assert_eq!(addrs, vec![3]);
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test]
+async fn test_scan_aborts_on_serial_error() {
+    let bus = bus_with_responders();
+    bus.set_offline(true);
+    let mut client = Jpf4826Client::new_lazy_mock(bus, 1).unwrap();
+
+    let err = client.scan_bus(1..=20, PROBE_TIMEOUT).await.unwrap_err();
+    assert!(err.is_serial());
+}
+
+#[tokio::test]
+async fn test_scan_rejects_invalid_arguments() {
+    let mut client = Jpf4826Client::new_lazy_mock(bus_with_responders(), 1).unwrap();
+
+    for range in [0..=10, 250..=255] {
+        let err = client.scan_bus(range, PROBE_TIMEOUT).await.unwrap_err();
+        assert!(err.is_invalid_parameter());
+    }
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 10..=5;
+    assert!(client
+        .scan_bus(empty, PROBE_TIMEOUT)
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(client
+        .scan_bus(1..=10, Duration::ZERO)
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+}
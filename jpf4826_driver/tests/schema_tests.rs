@@ -0,0 +1,199 @@
+#![cfg(feature = "schemars")]
+
+use jpf4826_driver::types::*;
+use serde_json::{json, Value};
+
+const HAND_WRITTEN_SCHEMA: &str =
+    include_str!("../../jpf4826ctl/schemas/jpf4826-status-response.schema.json");
+
+fn generated_validator() -> jsonschema::Validator {
+    let schema = serde_json::to_value(schemars::schema_for!(ControllerStatus)).unwrap();
+    jsonschema::validator_for(&schema).expect("Generated schema does not compile")
+}
+
+fn hand_written_validator() -> jsonschema::Validator {
+    let schema: Value = serde_json::from_str(HAND_WRITTEN_SCHEMA).unwrap();
+    jsonschema::validator_for(&schema).expect("Hand-written schema does not compile")
+}
+
+fn sample_status() -> ControllerStatus {
+    ControllerStatus::from_registers(&[
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 0, 1400, 0x0005, 70, 90, 0x000B,
+    ])
+}
+
+fn sample_json() -> Value {
+    serde_json::to_value(sample_status()).unwrap()
+}
+
+#[test]
+fn test_serialized_status_matches_generated_schema() {
+    let validator = generated_validator();
+
+    if let Err(error) = validator.validate(&sample_json()) {
+        panic!(
+            "Serialized status does not match generated schema:\n{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_serialized_fahrenheit_status_matches_generated_schema() {
+    let mut status = sample_status();
+    status.temperature_current = Temperature {
+        value: 240,
+        unit: TemperatureUnit::Fahrenheit,
+    };
+
+    let json = serde_json::to_value(status).unwrap();
+
+    assert!(generated_validator().is_valid(&json));
+}
+
+#[test]
+fn test_serialized_kelvin_status_matches_both_schemas() {
+    let mut status = sample_status();
+    for temp in [
+        &mut status.temperature_current,
+        &mut status.temperature_low_threshold,
+        &mut status.temperature_high_threshold,
+    ] {
+        *temp = temp.to_unit(TemperatureUnit::Kelvin);
+    }
+    let json = serde_json::to_value(&status).unwrap();
+    assert!(generated_validator().is_valid(&json));
+    assert!(hand_written_validator().is_valid(&json));
+
+    // 31 K is not a temperature the controller can report
+    let mut below_range = json.clone();
+    *below_range
+        .pointer_mut("/temperature/current/value")
+        .unwrap() = json!(31);
+    assert!(!generated_validator().is_valid(&below_range));
+    assert!(!hand_written_validator().is_valid(&below_range));
+}
+
+#[test]
+fn test_generated_schema_agrees_with_hand_written_schema() {
+    let generated = generated_validator();
+    let hand_written = hand_written_validator();
+
+    let mut documents = vec![sample_json()];
+    let mutations: [(&str, Value); 14] = [
+        ("/mode", json!("AUTO")),
+        ("/manual_speed", json!(101)),
+        ("/manual_speed", json!("40%")),
+        ("/work_mode", json!("ECO")),
+        ("/pwm_frequency/value", json!(3000)),
+        ("/pwm_frequency/unit", json!("kHz")),
+        ("/modbus_address", json!(0)),
+        ("/fan_count", json!(5)),
+        ("/fans/0/status", json!("BROKEN")),
+        ("/fans/0/index", json!(0)),
+        ("/fans/0/is_running", json!("yes")),
+        ("/temperature/current/value", json!(121)),
+        ("/temperature/low_threshold/unit", json!("RANKINE")),
+        ("/temperature/sensor_fault", json!("yes")),
+    ];
+    for (pointer, value) in mutations {
+        let mut document = sample_json();
+        *document.pointer_mut(pointer).unwrap() = value;
+        documents.push(document);
+    }
+    let mut missing_temperature = sample_json();
+    missing_temperature
+        .as_object_mut()
+        .unwrap()
+        .remove("temperature");
+    documents.push(missing_temperature);
+    let mut missing_mode = sample_json();
+    missing_mode.as_object_mut().unwrap().remove("mode");
+    documents.push(missing_mode);
+
+    for document in &documents {
+        assert_eq!(
+            generated.is_valid(document),
+            hand_written.is_valid(document),
+            "Schemas disagree on {}",
+            document
+        );
+    }
+    // Only the unmodified sample is valid
+    assert!(generated.is_valid(&documents[0]));
+    assert!(documents[1..].iter().all(|doc| !generated.is_valid(doc)));
+}
+
+#[test]
+fn test_pwm_frequency_schema_shape() {
+    let schema = serde_json::to_value(schemars::schema_for!(PwmFrequency)).unwrap();
+
+    assert_eq!(schema["properties"]["unit"]["const"], "Hz");
+    assert_eq!(
+        schema["properties"]["value"]["enum"],
+        json!([500, 1000, 2000, 5000, 10000, 25000])
+    );
+}
+
+#[test]
+fn test_work_mode_schema_matches_serialization() {
+    let schema = serde_json::to_value(schemars::schema_for!(WorkMode)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    for mode in [WorkMode::Shutdown, WorkMode::MinimumSpeed] {
+        assert!(validator.is_valid(&serde_json::to_value(mode).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!("Turbo")));
+}
+
+#[test]
+fn test_operating_mode_schema_matches_serialization() {
+    let schema = serde_json::to_value(schemars::schema_for!(OperatingMode)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    for mode in [
+        OperatingMode::Temperature,
+        OperatingMode::Manual,
+        OperatingMode::Unknown,
+    ] {
+        assert!(validator.is_valid(&serde_json::to_value(mode).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!("Temperature")));
+}
+
+#[test]
+fn test_operating_mode_state_schema_matches_serialization() {
+    let schema = serde_json::to_value(schemars::schema_for!(OperatingModeState)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    for state in [
+        OperatingModeState::Temperature,
+        OperatingModeState::Manual(0),
+        OperatingModeState::Manual(100),
+        OperatingModeState::Unknown,
+    ] {
+        assert!(validator.is_valid(&serde_json::to_value(state).unwrap()));
+    }
+    assert!(!validator.is_valid(&json!({"mode": "MANUAL", "speed": 101})));
+    assert!(!validator.is_valid(&json!({"mode": "MANUAL"})));
+    assert!(!validator.is_valid(&json!({"mode": "TEMPERATURE", "speed": 40})));
+}
+
+#[test]
+fn test_document_schemas_are_versioned() {
+    use jpf4826_driver::schema::{schema, Document, SCHEMA_VERSION, VERSION_KEYWORD};
+    use jpf4826_driver::ControllerConfig;
+
+    for document in [Document::Status, Document::Config] {
+        let schema = serde_json::to_value(schema(document)).unwrap();
+        assert_eq!(schema[VERSION_KEYWORD], SCHEMA_VERSION);
+    }
+
+    let status = jsonschema::validator_for(&schema(Document::Status).to_value()).unwrap();
+    assert!(status.is_valid(&sample_json()));
+    let config = jsonschema::validator_for(&schema(Document::Config).to_value()).unwrap();
+    for value in [ControllerConfig::factory(), ControllerConfig::default()] {
+        assert!(config.is_valid(&serde_json::to_value(value).unwrap()));
+    }
+    assert!(!config.is_valid(&json!({"fan_count": "four"})));
+}
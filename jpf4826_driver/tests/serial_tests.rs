@@ -0,0 +1,129 @@
+#![cfg(all(unix, feature = "std"))]
+
+mod pty;
+
+use std::time::Duration;
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::Jpf4826Client;
+use pty::PtyController;
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+#[tokio::test]
+async fn test_read_over_pty() {
+    let controller = PtyController::start();
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+
+    for expected in 1..=3 {
+        let values = client
+            .read(RegisterAddress::CurrentTemperature, 2)
+            .await
+            .unwrap();
+        assert_eq!(values, vec![expected, expected]);
+    }
+}
+
+#[tokio::test]
+async fn test_late_response_does_not_poison_next_read() {
+    let controller = PtyController::start();
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+
+    // The first answer arrives after the client gave up on it
+    controller.delay_next(TIMEOUT * 2);
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+    controller.wait_answered(1).await;
+    // Let the late frame settle in the input buffer
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // The stale answer to request 1 is discarded; request 2 gets its own
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![2]);
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![3]);
+}
+
+#[tokio::test]
+async fn test_short_response_is_an_error() {
+    let controller = PtyController::start();
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+
+    controller.truncate_next(3);
+    let err = client.status().await.unwrap_err();
+    assert!(err.is_modbus());
+    assert_eq!(err.response_length_mismatch(), Some((15, 3)));
+
+    // A well-formed frame was received, so the link stays usable
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 2)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![2, 2]);
+}
+
+#[tokio::test]
+async fn test_dropped_read_does_not_poison_next_read() {
+    let controller = PtyController::start();
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+
+    // The caller gives up before the client's own timeout
+    controller.delay_next(TIMEOUT / 2);
+    let dropped = tokio::time::timeout(
+        TIMEOUT / 4,
+        client.read(RegisterAddress::CurrentTemperature, 1),
+    )
+    .await;
+    assert!(dropped.is_err());
+    controller.wait_answered(1).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![2]);
+}
+
+#[tokio::test]
+async fn test_close_releases_port_for_reopen() {
+    let controller = PtyController::start();
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![1]);
+
+    client.close().await.unwrap();
+
+    // The port is opened in exclusive mode, so it must be free again
+    let mut client = Jpf4826Client::with_timeout(controller.path(), 1, TIMEOUT)
+        .await
+        .unwrap();
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![2]);
+    client.close().await.unwrap();
+}
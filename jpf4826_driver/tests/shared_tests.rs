@@ -0,0 +1,142 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::Duration;
+
+use jpf4826_driver::client::MockProbe;
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{Jpf4826Client, SharedClient};
+use mock::MockController;
+
+const SLOW_READ: Duration = Duration::from_millis(50);
+
+// Helper to create a shared client whose reads take SLOW_READ
+async fn create_slow_client(max_age: Duration) -> (SharedClient, MockProbe, MockController) {
+    let mock = MockController::new();
+    let client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_read_delay(SLOW_READ);
+    (SharedClient::with_max_age(client, max_age), probe, mock)
+}
+
+fn spawn_status_calls(
+    shared: &SharedClient,
+    count: usize,
+) -> Vec<tokio::task::JoinHandle<jpf4826_driver::Result<jpf4826_driver::ControllerStatus>>> {
+    (0..count)
+        .map(|_| {
+            let shared = shared.clone();
+            tokio::spawn(async move { shared.status().await })
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_status_calls_share_one_read() {
+    let (shared, probe, _mock) = create_slow_client(Duration::ZERO).await;
+
+    let calls = spawn_status_calls(&shared, 32);
+    for call in calls {
+        let status = call.await.unwrap().unwrap();
+        assert_eq!(status.temperature_current.value, 31);
+    }
+
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_errors_reach_every_waiter() {
+    let (shared, probe, _mock) = create_slow_client(Duration::ZERO).await;
+    probe.set_fail_reads(true);
+
+    let calls = spawn_status_calls(&shared, 8);
+    for call in calls {
+        let err = call.await.unwrap().unwrap_err();
+        assert!(err.is_modbus());
+    }
+
+    assert_eq!(probe.reads(), 1);
+}
+
+#[tokio::test]
+async fn test_sequential_calls_read_again_without_max_age() {
+    let (shared, probe, mock) = create_slow_client(Duration::ZERO).await;
+
+    shared.status().await.unwrap();
+    mock.write_register(0x0000, 76); // 36°C
+    let status = shared.status().await.unwrap();
+
+    assert_eq!(status.temperature_current.value, 36);
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test]
+async fn test_fresh_snapshot_is_served_within_max_age() {
+    let (shared, probe, _mock) = create_slow_client(Duration::from_secs(60)).await;
+
+    shared.status().await.unwrap();
+    shared.status().await.unwrap();
+    assert_eq!(probe.reads(), 1);
+
+    // Locking for a write discards the snapshot
+    shared.lock().await.set_fan_speed(50).await.unwrap();
+    shared.status().await.unwrap();
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_cancelled_read_hands_over_to_waiters() {
+    let (shared, probe, _mock) = create_slow_client(Duration::ZERO).await;
+
+    let leader = {
+        let shared = shared.clone();
+        tokio::spawn(async move { shared.status().await })
+    };
+    tokio::time::sleep(SLOW_READ / 5).await;
+    let waiters = spawn_status_calls(&shared, 8);
+    tokio::time::sleep(SLOW_READ / 5).await;
+    leader.abort();
+
+    for waiter in waiters {
+        let result = tokio::time::timeout(SLOW_READ * 10, waiter).await;
+        assert!(result.expect("waiter hung").unwrap().is_ok());
+    }
+    assert!(leader.await.unwrap_err().is_cancelled());
+    assert_eq!(probe.reads(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_operations_do_not_interleave() {
+    let mock = MockController::new();
+    let client = Jpf4826Client::new_mock(mock.registers.clone(), 1).await;
+    let probe = client.mock_probe().unwrap();
+    // Slow writes give other tasks a chance to run in between
+    probe.set_write_delay(Duration::from_millis(5));
+    let shared = SharedClient::new(client);
+
+    let tasks: Vec<_> = (1..=20u16)
+        .map(|speed| {
+            let shared = shared.clone();
+            tokio::spawn(async move {
+                let mut client = shared.lock().await;
+                client
+                    .write(RegisterAddress::ManualSpeedControl, speed)
+                    .await
+                    .unwrap();
+                let value = client
+                    .read(RegisterAddress::ManualSpeedControl, 1)
+                    .await
+                    .unwrap();
+                (speed, value[0])
+            })
+        })
+        .collect();
+
+    for task in tasks {
+        let (written, read_back) = task.await.unwrap();
+        assert_eq!(written, read_back);
+    }
+    assert_eq!(probe.writes(), 20);
+    assert_eq!(probe.reads(), 20);
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+
+use std::time::{Duration, SystemTime};
+
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::ClientStats;
+use mock::create_test_client;
+
+#[tokio::test]
+async fn test_new_client_has_empty_stats() {
+    let (client, _mock) = create_test_client().await;
+
+    assert_eq!(client.stats(), ClientStats::default());
+}
+
+#[tokio::test]
+async fn test_stats_count_reads_and_writes() {
+    let (mut client, _mock) = create_test_client().await;
+
+    client.temperature().await.unwrap();
+    client.status().await.unwrap();
+    client.set_fan_speed(50).await.unwrap();
+    client
+        .write_multiple(RegisterAddress::StartTemperature, &[70, 90])
+        .await
+        .unwrap();
+
+    let stats = client.stats();
+    assert_eq!((stats.reads, stats.writes), (2, 2));
+    assert_eq!(stats.failures(), 0);
+    assert_eq!(stats.last_error_at, None);
+    assert!(stats.mean_latency.is_some());
+}
+
+#[tokio::test]
+async fn test_stats_count_injected_failures() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let before = SystemTime::now();
+
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.is_err());
+    assert!(client.fan_speeds().await.is_err());
+    probe.set_fail_reads(false);
+
+    probe.set_silent_requests(2);
+    assert!(client.temperature().await.unwrap_err().is_timeout());
+    assert!(client.set_fan_speed(50).await.unwrap_err().is_timeout());
+
+    let stats = client.stats();
+    assert_eq!((stats.reads, stats.writes), (3, 1));
+    assert_eq!((stats.timeouts, stats.modbus_errors), (2, 2));
+    assert!(stats.last_error_at.unwrap() >= before);
+    assert_eq!(stats.mean_latency, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_stats_track_mean_latency() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    probe.set_read_delay(Duration::from_millis(100));
+    client.temperature().await.unwrap();
+    assert_eq!(
+        client.stats().mean_latency,
+        Some(Duration::from_millis(100))
+    );
+
+    // Each new sample is weighted 0.2
+    probe.set_read_delay(Duration::from_millis(600));
+    client.temperature().await.unwrap();
+    assert_eq!(
+        client.stats().mean_latency,
+        Some(Duration::from_millis(200))
+    );
+}
+
+#[tokio::test]
+async fn test_stats_ignore_rejected_arguments_and_cache_hits() {
+    let (mut client, _mock) = create_test_client().await;
+    client.set_cache_ttl(Duration::from_secs(60));
+
+    assert!(client.fan_speed(5).await.is_err());
+    assert!(client.set_fan_speed(101).await.is_err());
+    client.temperature().await.unwrap();
+    client.temperature().await.unwrap();
+
+    let stats = client.stats();
+    assert_eq!((stats.reads, stats.writes), (1, 0));
+    assert_eq!(stats.failures(), 0);
+}
+
+#[tokio::test]
+async fn test_reset_stats_keeps_link_info() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.temperature().await.unwrap();
+    probe.set_fail_reads(true);
+    assert!(client.temperature().await.is_err());
+
+    client.reset_stats();
+
+    assert_eq!(client.stats(), ClientStats::default());
+    let link = client.link_info();
+    assert_eq!((link.successes, link.failures), (1, 1));
+
+    probe.set_fail_reads(false);
+    client.temperature().await.unwrap();
+    assert_eq!(client.stats().reads, 1);
+}
@@ -0,0 +1,71 @@
+//! Modbus framing and register map shared by the controller stubs.
+//!
+//! The RTU stubs (`transport_tests.rs`, `pty.rs`) and the TCP gateway stub
+//! (`tcp.rs`) differ in how frames travel, but check CRCs and apply
+//! requests to a register map the same way.
+
+// Each stub uses only part of this module
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+/// Register holding the controller's Modbus address.
+pub const ADDRESS_REGISTER: u16 = 0x0002;
+
+/// Modbus CRC-16 (polynomial 0xA001, initial value 0xFFFF).
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFF;
+    for byte in data {
+        crc ^= u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xA001
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    crc
+}
+
+/// Appends the CRC to `frame`.
+pub fn with_crc(mut frame: Vec<u8>) -> Vec<u8> {
+    frame.extend_from_slice(&crc16(&frame).to_le_bytes());
+    frame
+}
+
+/// Applies a request PDU to `registers` and returns the response PDU.
+///
+/// Handles Read Holding Registers, Write Single Register and Write
+/// Multiple Registers; any other function is answered with the Illegal
+/// Function exception.
+pub fn answer(registers: &mut HashMap<u16, u16>, pdu: &[u8]) -> Vec<u8> {
+    let word = |i: usize| u16::from_be_bytes([pdu[i], pdu[i + 1]]);
+    match pdu[0] {
+        // Read Holding Registers
+        0x03 => {
+            let (start, count) = (word(1), word(3));
+            let mut response = vec![0x03, (count * 2) as u8];
+            for addr in start..start + count {
+                let value = registers.get(&addr).copied().unwrap_or(0);
+                response.extend_from_slice(&value.to_be_bytes());
+            }
+            response
+        }
+        // Write Single Register, answered with an echo
+        0x06 => {
+            registers.insert(word(1), word(3));
+            pdu.to_vec()
+        }
+        // Write Multiple Registers
+        0x10 => {
+            let (start, count) = (word(1), word(3));
+            for (i, addr) in (start..start + count).enumerate() {
+                registers.insert(addr, word(6 + 2 * i));
+            }
+            pdu[..5].to_vec()
+        }
+        // Illegal function
+        function => vec![function | 0x80, 0x01],
+    }
+}
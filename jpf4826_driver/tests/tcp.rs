@@ -0,0 +1,139 @@
+#![cfg(feature = "std")]
+
+//! Modbus TCP gateway stub for tests over the real TCP stack.
+//!
+//! Serves a register map like an RS485-to-Ethernet gateway with one
+//! controller behind it: requests for the unit ID stored in the Modbus
+//! address register (0x0002) are answered, all others are dropped as the
+//! bus would leave them unanswered.
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+#[path = "stub.rs"]
+mod stub;
+
+use stub::{answer, ADDRESS_REGISTER};
+
+/// Simulated gateway listening on a local port.
+pub struct TcpGateway {
+    addr: SocketAddr,
+    delays: Arc<Mutex<VecDeque<Duration>>>,
+    state: Arc<GatewayState>,
+}
+
+/// Request count and injected exceptions, shared by all connections.
+#[derive(Default)]
+struct GatewayState {
+    requests: AtomicUsize,
+    rejections: AtomicUsize,
+}
+
+impl TcpGateway {
+    /// Binds a local port and starts answering requests from `registers`.
+    pub async fn start(registers: Arc<Mutex<HashMap<u16, u16>>>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind gateway stub");
+        let addr = listener.local_addr().unwrap();
+        let delays = Arc::new(Mutex::new(VecDeque::new()));
+        let state = Arc::new(GatewayState::default());
+
+        let connection_delays = delays.clone();
+        let connection_state = state.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(
+                    stream,
+                    registers.clone(),
+                    connection_delays.clone(),
+                    connection_state.clone(),
+                ));
+            }
+        });
+        Self {
+            addr,
+            delays,
+            state,
+        }
+    }
+
+    /// Returns the address to connect to.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Delays the answer to the next request by `delay`.
+    pub fn delay_next(&self, delay: Duration) {
+        self.delays.lock().unwrap().push_back(delay);
+    }
+
+    /// Answers the next `count` requests with the Server Device Failure
+    /// exception.
+    pub fn reject_next(&self, count: usize) {
+        self.state.rejections.store(count, Ordering::SeqCst);
+    }
+
+    /// Returns the number of requests received, answered or not.
+    pub fn requests(&self) -> usize {
+        self.state.requests.load(Ordering::SeqCst)
+    }
+}
+
+async fn serve(
+    mut stream: TcpStream,
+    registers: Arc<Mutex<HashMap<u16, u16>>>,
+    delays: Arc<Mutex<VecDeque<Duration>>>,
+    state: Arc<GatewayState>,
+) {
+    loop {
+        // MBAP header: transaction, protocol, length, unit ID
+        let mut header = [0u8; 7];
+        if stream.read_exact(&mut header).await.is_err() {
+            return;
+        }
+        let length = usize::from(u16::from_be_bytes([header[4], header[5]]));
+        let mut pdu = vec![0u8; length.saturating_sub(1)];
+        if stream.read_exact(&mut pdu).await.is_err() {
+            return;
+        }
+        state.requests.fetch_add(1, Ordering::SeqCst);
+        let rejected = state
+            .rejections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                count.checked_sub(1)
+            })
+            .is_ok();
+
+        let delay = delays.lock().unwrap().pop_front().unwrap_or_default();
+        tokio::time::sleep(delay).await;
+
+        let response = {
+            let mut registers = registers.lock().unwrap();
+            if registers.get(&ADDRESS_REGISTER) != Some(&u16::from(header[6])) {
+                None
+            } else if rejected {
+                // Server device failure
+                Some(vec![pdu[0] | 0x80, 0x04])
+            } else {
+                Some(answer(&mut registers, &pdu))
+            }
+        };
+        let Some(response) = response else {
+            continue;
+        };
+
+        let mut frame = header[..4].to_vec();
+        frame.extend_from_slice(&((response.len() + 1) as u16).to_be_bytes());
+        frame.push(header[6]);
+        frame.extend_from_slice(&response);
+        // Succeeds whether or not the client still listens
+        let _ = stream.write_all(&frame).await;
+    }
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "test-mock")]
+
+mod mock;
+mod tcp;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use jpf4826_driver::frame::FrameObserver;
+use jpf4826_driver::metrics::{CountingRecorder, OperationOutcome};
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{BusHealth, Jpf4826Client, OperatingMode};
+use mock::MockController;
+use tcp::TcpGateway;
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+async fn connect_to_gateway() -> (Jpf4826Client, TcpGateway, MockController) {
+    let controller = MockController::new();
+    let gateway = TcpGateway::start(controller.registers.clone()).await;
+    let client = Jpf4826Client::new_tcp(gateway.addr(), 1).await.unwrap();
+    (client, gateway, controller)
+}
+
+#[tokio::test]
+async fn test_status_over_tcp() {
+    let (mut client, _gateway, _controller) = connect_to_gateway().await;
+
+    let status = client.status().await.unwrap();
+    assert_eq!(status.temperature_current.value, 31);
+    assert_eq!(status.fan_count, 4);
+    assert_eq!(status.fans[0].rpm, 1400);
+}
+
+#[tokio::test]
+async fn test_writes_over_tcp() {
+    let (mut client, _gateway, controller) = connect_to_gateway().await;
+
+    client.set_fan_speed(40).await.unwrap();
+    assert_eq!(controller.read_register(0x0003), Some(40));
+    assert_eq!(
+        client.operating_mode().await.unwrap(),
+        OperatingMode::Manual
+    );
+
+    client
+        .write_multiple(RegisterAddress::WorkMode, &[0x0000, 0x0002])
+        .await
+        .unwrap();
+    assert_eq!(controller.read_registers(0x0005, 2), vec![0x0000, 0x0002]);
+}
+
+#[tokio::test]
+async fn test_set_addr_follows_controller_over_tcp() {
+    let (mut client, _gateway, controller) = connect_to_gateway().await;
+
+    client.set_addr(7).await.unwrap();
+    assert_eq!(controller.read_register(0x0002), Some(7));
+    assert_eq!(client.slave_addr(), 7);
+
+    // The gateway only answers unit ID 7 now
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_timeout_over_tcp_then_recovers() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+
+    gateway.delay_next(TIMEOUT * 3);
+    let err = client.temperature().await.unwrap_err();
+    assert_eq!(err.timeout_duration(), Some(TIMEOUT));
+
+    // Reconnects instead of taking the late answer for the next one
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_new_tcp_rejects_invalid_unit_id() {
+    let gateway = "127.0.0.1:502".parse().unwrap();
+    for unit_id in [0, 255] {
+        let err = Jpf4826Client::new_tcp(gateway, unit_id)
+            .await
+            .err()
+            .unwrap();
+        assert!(!err.is_modbus(), "{}: {}", unit_id, err);
+        assert!(err.to_string().contains("address"), "{}", err);
+    }
+}
+
+#[tokio::test]
+async fn test_new_tcp_reports_refused_connection() {
+    // Bind and release a port so nothing listens on it
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let err = Jpf4826Client::new_tcp(addr, 1).await.err().unwrap();
+    assert!(err.is_modbus(), "{}", err);
+}
+
+#[tokio::test]
+async fn test_gateway_needs_no_inter_frame_delay() {
+    let (client, _gateway, _controller) = connect_to_gateway().await;
+
+    assert_eq!(client.inter_frame_delay(), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_metrics_recorder_over_tcp() {
+    let (mut client, _gateway, _controller) = connect_to_gateway().await;
+    let counts = Arc::new(CountingRecorder::default());
+    client.set_metrics_recorder(counts.clone());
+
+    client.status().await.unwrap();
+    client.set_fan_speed(40).await.unwrap();
+
+    assert_eq!(counts.reads(OperationOutcome::Success), 1);
+    assert_eq!(counts.writes(OperationOutcome::Success), 1);
+    assert!(counts.busy_time() > Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_transport_retries_repeat_timed_out_request() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+    client.set_transport_retries(2);
+    assert_eq!(client.transport_retries(), 2);
+
+    gateway.delay_next(TIMEOUT * 3);
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+
+    assert_eq!(gateway.requests(), 2);
+    // Still one transaction for the client
+    assert_eq!(client.stats().reads, 1);
+    assert_eq!(client.stats().failures(), 0);
+}
+
+#[tokio::test]
+async fn test_transport_retries_report_attempts() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+    client.set_transport_retries(1);
+
+    gateway.delay_next(TIMEOUT * 3);
+    gateway.delay_next(TIMEOUT * 3);
+    let err = client.set_fan_speed(40).await.unwrap_err();
+
+    assert!(err.is_timeout());
+    assert_eq!(
+        err.to_string(),
+        "Operation timed out after 0.2s (2 attempts)"
+    );
+    assert_eq!(gateway.requests(), 2);
+}
+
+#[tokio::test]
+async fn test_transport_retries_skip_modbus_exceptions() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_transport_retries(3);
+
+    gateway.reject_next(1);
+    let err = client.temperature().await.unwrap_err();
+
+    assert!(err.is_modbus());
+    assert!(err.to_string().contains("ServerDeviceFailure"), "{}", err);
+    assert!(!err.to_string().contains("attempts"), "{}", err);
+    assert_eq!(gateway.requests(), 1);
+}
+
+#[tokio::test]
+async fn test_bus_health_counts_each_attempt() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+    client.set_transport_retries(2);
+    assert_eq!(client.bus_health(), BusHealth::default());
+
+    gateway.delay_next(TIMEOUT * 3);
+    client.temperature().await.unwrap();
+    gateway.reject_next(1);
+    client.temperature().await.unwrap_err();
+
+    let health = client.bus_health();
+    assert_eq!(health.timeouts, 1);
+    assert_eq!(health.exceptions, 1);
+    assert_eq!(health.transport_errors, 0);
+    assert_eq!(health.successes, 1);
+    assert!(health.last_failure.is_some());
+    // The caller saw two transactions
+    assert_eq!(client.stats().reads, 2);
+}
+
+#[tokio::test]
+async fn test_bus_health_survives_reconnect() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+
+    gateway.delay_next(TIMEOUT * 3);
+    assert!(client.temperature().await.unwrap_err().is_timeout());
+    // Resynchronizes on a new connection before this request
+    client.temperature().await.unwrap();
+
+    let health = client.bus_health();
+    assert_eq!((health.timeouts, health.successes), (1, 1));
+}
+
+/// Observer keeping the frames received.
+#[derive(Default)]
+struct Responses(Mutex<Vec<Vec<u8>>>);
+
+impl FrameObserver for Responses {
+    fn on_request(&self, _frame: &[u8]) {}
+
+    fn on_response(&self, frame: &[u8]) {
+        self.0.lock().unwrap().push(frame.to_vec());
+    }
+}
+
+#[tokio::test]
+async fn test_frame_observer_over_tcp_survives_reconnect() {
+    let (mut client, gateway, _controller) = connect_to_gateway().await;
+    client.set_timeout(TIMEOUT).unwrap();
+    let responses = Arc::new(Responses::default());
+    client.set_frame_observer(responses.clone());
+
+    gateway.delay_next(TIMEOUT * 3);
+    assert!(client.temperature().await.unwrap_err().is_timeout());
+    // Reconnects with the observer still installed
+    client.temperature().await.unwrap();
+
+    let frames = responses.0.lock().unwrap().clone();
+    let last = frames.last().unwrap();
+    // MBAP header: length 5 covers unit ID, function, byte count and value
+    assert_eq!(last[4..], [0x00, 0x05, 0x01, 0x03, 0x02, 0x00, 0x47]);
+}
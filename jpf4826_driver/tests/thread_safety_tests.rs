@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+//! Compile-time checks that the client and its wrappers can be shared
+//! between tasks.
+
+use jpf4826_driver::{Jpf4826Bus, Jpf4826Client, Jpf4826Device, Jpf4826Manager, SharedClient};
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+// Not Sync: the tokio-modbus context is not, and every request needs
+// `&mut self` anyway. Shared use goes through a mutex, which needs Send.
+#[test]
+fn test_client_is_send() {
+    assert_send::<Jpf4826Client>();
+    assert_send::<std::sync::Arc<tokio::sync::Mutex<Jpf4826Client>>>();
+    assert_sync::<std::sync::Arc<tokio::sync::Mutex<Jpf4826Client>>>();
+}
+
+#[test]
+fn test_shared_handles_are_send_and_sync() {
+    assert_send::<SharedClient>();
+    assert_sync::<SharedClient>();
+    assert_send::<Jpf4826Bus>();
+    assert_sync::<Jpf4826Bus>();
+    assert_send::<Jpf4826Device>();
+    assert_sync::<Jpf4826Device>();
+    assert_send::<Jpf4826Manager>();
+    assert_sync::<Jpf4826Manager>();
+}
+
+#[test]
+fn test_client_futures_are_send() {
+    fn assert_send_future<F: std::future::Future + Send>(_: F) {}
+
+    // Never polled; only the future types are checked
+    let mut client = Jpf4826Client::new_lazy("/dev/null", 1, Default::default()).unwrap();
+    assert_send_future(client.status());
+}
+
+#[cfg(feature = "stream")]
+#[test]
+fn test_status_watch_is_send() {
+    assert_send::<jpf4826_driver::StatusWatch<'static>>();
+}
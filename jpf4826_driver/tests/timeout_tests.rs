@@ -1,18 +1,14 @@
 #![cfg(feature = "test-mock")]
 
 mod mock;
+#[cfg(unix)]
+#[allow(dead_code)] // Only the delay of the pty controller is used here
+mod pty;
 
 use std::time::Duration;
 
-use jpf4826_driver::{Jpf4826Client, DEFAULT_TIMEOUT};
-use mock::MockController;
-
-async fn create_test_client() -> (Jpf4826Client, MockController) {
-    let mock = MockController::new();
-    let registers = mock.registers.clone();
-    let client = Jpf4826Client::new_mock(registers, 1).await;
-    (client, mock)
-}
+use jpf4826_driver::{ConnectOptions, Jpf4826Client, DEFAULT_TIMEOUT, MIN_TIMEOUT};
+use mock::create_test_client;
 
 #[test]
 fn test_default_timeout_is_ten_seconds() {
@@ -31,6 +27,95 @@ async fn test_set_timeout_on_mock_client_is_noop() {
     let (mut client, _mock) = create_test_client().await;
 
     // Mock backend ignores set_timeout; timeout remains DEFAULT_TIMEOUT
-    client.set_timeout(Duration::from_secs(30));
+    client.set_timeout(Duration::from_secs(30)).unwrap();
     assert_eq!(client.timeout(), DEFAULT_TIMEOUT);
 }
+
+#[tokio::test]
+async fn test_set_timeout_rejects_zero() {
+    let (mut client, _mock) = create_test_client().await;
+
+    let err = client.set_timeout(Duration::ZERO).unwrap_err();
+    assert!(err.is_invalid_parameter());
+}
+
+#[tokio::test]
+async fn test_set_timeout_enforces_floor() {
+    let (mut client, _mock) = create_test_client().await;
+
+    let err = client
+        .set_timeout(MIN_TIMEOUT - Duration::from_millis(1))
+        .unwrap_err();
+    assert!(err.is_invalid_parameter());
+    assert_eq!(
+        err.to_string(),
+        "Invalid parameter: Timeout must be at least 10ms, got 9ms"
+    );
+
+    client.set_timeout(MIN_TIMEOUT).unwrap();
+    client.set_timeout(Duration::from_millis(250)).unwrap();
+}
+
+#[test]
+fn test_lazy_client_enforces_timeout_floor() {
+    let options = ConnectOptions {
+        timeout: Duration::from_millis(1),
+        ..ConnectOptions::default()
+    };
+    let err = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, options)
+        .err()
+        .unwrap();
+    assert!(err.is_invalid_parameter(), "{}", err);
+
+    let options = ConnectOptions {
+        timeout: Duration::from_millis(250),
+        ..ConnectOptions::default()
+    };
+    let client = Jpf4826Client::new_lazy("/dev/ttyUSB0", 1, options).unwrap();
+    assert_eq!(client.timeout(), Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_timeout_errors_are_distinguishable() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+
+    probe.set_silent_requests(1);
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_timeout());
+    assert!(!err.is_modbus());
+    assert!(err.timeout_duration().is_some());
+
+    probe.set_fail_reads(true);
+    let err = client.temperature().await.unwrap_err();
+    assert!(!err.is_timeout());
+    assert_eq!(err.timeout_duration(), None);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_set_timeout_reaches_serial_backend() {
+    use jpf4826_driver::registers::RegisterAddress;
+
+    let controller = pty::PtyController::start();
+    let mut client = Jpf4826Client::new(controller.path(), 1).await.unwrap();
+    assert_eq!(client.timeout(), DEFAULT_TIMEOUT);
+
+    let timeout = Duration::from_millis(200);
+    client.set_timeout(timeout).unwrap();
+    assert_eq!(client.timeout(), timeout);
+
+    // Gives up after the new timeout, not the default one
+    controller.delay_next(Duration::from_secs(1));
+    let started = std::time::Instant::now();
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap_err();
+    assert_eq!(err.timeout_duration(), Some(timeout));
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    // A rejected timeout keeps the previous one
+    assert!(client.set_timeout(Duration::ZERO).is_err());
+    assert_eq!(client.timeout(), timeout);
+}
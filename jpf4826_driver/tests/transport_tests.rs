@@ -0,0 +1,451 @@
+#![cfg(feature = "std")]
+
+//! Client over a caller-supplied transport, without the `test-mock` feature.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use jpf4826_driver::frame::{FrameObserver, HexDumpObserver};
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::Jpf4826Client;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+mod stub;
+
+use stub::{answer, crc16, with_crc, ADDRESS_REGISTER};
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Answers Modbus-RTU requests to the address in `registers` (0x0002).
+///
+/// Handles Read Holding Registers, Write Single Register and Write
+/// Multiple Registers, and applies broadcast writes without answering; the
+/// first `ignore` requests are left unanswered.
+async fn respond(stream: DuplexStream, registers: HashMap<u16, u16>, ignore: usize) {
+    serve(stream, registers, ignore, true).await;
+}
+
+/// Like [`respond`], but answers Write Multiple Registers with the Illegal
+/// Function exception, as firmware without it does.
+async fn respond_without_write_multiple(stream: DuplexStream, registers: HashMap<u16, u16>) {
+    serve(stream, registers, 0, false).await;
+}
+
+async fn serve(
+    mut stream: DuplexStream,
+    mut registers: HashMap<u16, u16>,
+    mut ignore: usize,
+    write_multiple: bool,
+) {
+    // Fixed part of every request: address, function, 2 words, CRC
+    let mut request = [0u8; 8];
+    while stream.read_exact(&mut request).await.is_ok() {
+        let mut request = request.to_vec();
+        if request[1] == 0x10 {
+            // Byte count and values follow the register count
+            let count = usize::from(u16::from_be_bytes([request[4], request[5]]));
+            let mut rest = vec![0u8; 1 + 2 * count];
+            stream.read_exact(&mut rest).await.unwrap();
+            request.extend_from_slice(&rest);
+        }
+        let (frame, crc) = request.split_at(request.len() - 2);
+        assert_eq!(crc16(frame).to_le_bytes(), crc);
+        if ignore > 0 {
+            ignore -= 1;
+            continue;
+        }
+
+        let first = u16::from_be_bytes([request[2], request[3]]);
+        let second = u16::from_be_bytes([request[4], request[5]]);
+        if request[0] == 0 {
+            assert_eq!(request[1], 0x06, "Only writes may be broadcast");
+            registers.insert(first, second);
+            continue;
+        }
+        if registers.get(&ADDRESS_REGISTER) != Some(&u16::from(request[0])) {
+            continue;
+        }
+        let mut response = vec![request[0]];
+        if request[1] == 0x10 && !write_multiple {
+            response.extend_from_slice(&[0x90, 0x01]);
+        } else {
+            response.extend(answer(&mut registers, &request[1..request.len() - 2]));
+        }
+        stream.write_all(&with_crc(response)).await.unwrap();
+    }
+}
+
+/// Answers each 8-byte request with the next of `frames`, as given.
+///
+/// Leaves requests unanswered once `frames` is used up.
+async fn replay(mut stream: DuplexStream, frames: Vec<Vec<u8>>) {
+    let mut frames = frames.into_iter();
+    let mut request = [0u8; 8];
+    while stream.read_exact(&mut request).await.is_ok() {
+        if let Some(frame) = frames.next() {
+            stream.write_all(&frame).await.unwrap();
+        }
+    }
+}
+
+fn controller_registers() -> HashMap<u16, u16> {
+    HashMap::from([
+        (0x0000, 71),
+        (ADDRESS_REGISTER, 1),
+        (0x0006, 4),
+        (0x0007, 1400),
+    ])
+}
+
+#[tokio::test]
+async fn test_client_over_duplex_transport() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    assert!(client.is_connected());
+
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+    assert_eq!(client.fan_count().await.unwrap(), 4);
+
+    client.set_fan_count(2).await.unwrap();
+    assert_eq!(client.fan_count().await.unwrap(), 2);
+    assert_eq!(
+        client.read(RegisterAddress::Fan1Speed, 1).await.unwrap(),
+        vec![1400]
+    );
+}
+
+#[test]
+fn test_with_transport_rejects_invalid_address() {
+    for addr in [0, 255] {
+        let (client_side, _controller_side) = tokio::io::duplex(256);
+        assert!(Jpf4826Client::with_transport(client_side, addr).is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_supplied_transport_is_not_reopened_after_timeout() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 1));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+    client.set_flush_input(false);
+
+    assert!(client.temperature().await.unwrap_err().is_timeout());
+    // A late answer could be taken for the next one, so the link is closed
+    assert!(client.temperature().await.unwrap_err().is_serial());
+}
+
+/// Answers every read with the number of the request (1, 2, ...) in each
+/// register, the first answer after `delay`.
+async fn respond_late(mut stream: DuplexStream, delay: Duration) {
+    let mut request = [0u8; 8];
+    let mut sequence = 0u16;
+    while stream.read_exact(&mut request).await.is_ok() {
+        sequence += 1;
+        if sequence == 1 {
+            tokio::time::sleep(delay).await;
+        }
+        let count = u16::from_be_bytes([request[4], request[5]]);
+        let mut response = vec![request[0], 0x03, (count * 2) as u8];
+        for _ in 0..count {
+            response.extend_from_slice(&sequence.to_be_bytes());
+        }
+        stream.write_all(&with_crc(response)).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_late_response_is_flushed_before_next_request() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond_late(controller_side, TIMEOUT * 2));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+    assert!(client.flush_input());
+    let log = Arc::new(FrameLog::default());
+    client.set_frame_observer(log.clone());
+
+    let err = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+    // Let the late answer to the first request arrive
+    tokio::time::sleep(TIMEOUT * 2).await;
+
+    let values = client
+        .read(RegisterAddress::CurrentTemperature, 1)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![2]);
+    // The discarded answer was still observed
+    let responses: Vec<_> = log
+        .frames()
+        .into_iter()
+        .filter(|(request, _)| !request)
+        .collect();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].1[3..5], [0x00, 0x01]);
+}
+
+#[tokio::test]
+async fn test_set_addr_broadcast_does_not_wait_for_answer() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    let mut registers = controller_registers();
+    // Factory-fresh unit at an unknown address
+    registers.insert(ADDRESS_REGISTER, 33);
+    tokio::spawn(respond(controller_side, registers, 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+
+    client.set_addr_broadcast(7).await.unwrap();
+    // Answered at the new address
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_write_multiple_over_rtu() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+
+    client
+        .write_multiple(RegisterAddress::WorkMode, &[0x0000, 0x0002])
+        .await
+        .unwrap();
+    assert_eq!(client.fan_count().await.unwrap(), 2);
+    assert_eq!(
+        client.read(RegisterAddress::WorkMode, 1).await.unwrap(),
+        vec![0x0000]
+    );
+}
+
+#[tokio::test]
+async fn test_rejected_write_multiple_is_reported() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond_without_write_multiple(
+        controller_side,
+        controller_registers(),
+    ));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_retries(2);
+
+    let err = client
+        .write_multiple(RegisterAddress::WorkMode, &[0x0000, 0x0002])
+        .await
+        .unwrap_err();
+    assert!(err.is_function_rejected(), "{}", err);
+    assert!(err.to_string().contains("0x10"), "{}", err);
+    // One failed transaction; the link stays usable
+    assert_eq!(client.link_info().failures, 1);
+    assert_eq!(client.fan_count().await.unwrap(), 4);
+}
+
+#[tokio::test]
+async fn test_close_drops_transport() {
+    let (client_side, mut controller_side) = tokio::io::duplex(256);
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+
+    client.close().await.unwrap();
+
+    // The client side is gone once close returns
+    let mut buf = [0u8; 1];
+    assert_eq!(controller_side.read(&mut buf).await.unwrap(), 0);
+}
+
+/// Asserts that `elapsed` is `expected`, rounded up to the timer's
+/// millisecond resolution.
+fn assert_waited(elapsed: Duration, expected: Duration) {
+    assert!(
+        elapsed >= expected && elapsed < expected + Duration::from_millis(1),
+        "waited {:?}, expected {:?}",
+        elapsed,
+        expected
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_back_to_back_requests_keep_inter_frame_silence() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    // 3.5 characters of 11 bits at 9600 baud
+    assert_eq!(client.inter_frame_delay(), Duration::from_micros(4010));
+
+    let started = tokio::time::Instant::now();
+    client.set_fan_count(2).await.unwrap();
+    assert_eq!(started.elapsed(), Duration::ZERO);
+    client.fan_count().await.unwrap();
+    assert_waited(started.elapsed(), Duration::from_micros(4010));
+
+    // Only the rest of the gap is waited for
+    tokio::time::sleep(Duration::from_millis(3)).await;
+    let started = tokio::time::Instant::now();
+    client.temperature().await.unwrap();
+    assert_waited(started.elapsed(), Duration::from_micros(1010));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_spaced_requests_are_not_delayed() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+
+    client.temperature().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let started = tokio::time::Instant::now();
+    client.temperature().await.unwrap();
+    assert_eq!(started.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_inter_frame_delay_can_be_tuned_and_disabled() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+
+    client.set_inter_frame_delay(Duration::from_millis(10));
+    client.temperature().await.unwrap();
+    let started = tokio::time::Instant::now();
+    client.temperature().await.unwrap();
+    assert_eq!(started.elapsed(), Duration::from_millis(10));
+
+    client.set_inter_frame_delay(Duration::ZERO);
+    let started = tokio::time::Instant::now();
+    client.temperature().await.unwrap();
+    client.set_fan_count(2).await.unwrap();
+    assert_eq!(started.elapsed(), Duration::ZERO);
+}
+
+/// Observer keeping every frame, requests marked `true`.
+#[derive(Default)]
+struct FrameLog(Mutex<Vec<(bool, Vec<u8>)>>);
+
+impl FrameLog {
+    fn frames(&self) -> Vec<(bool, Vec<u8>)> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl FrameObserver for FrameLog {
+    fn on_request(&self, frame: &[u8]) {
+        self.0.lock().unwrap().push((true, frame.to_vec()));
+    }
+
+    fn on_response(&self, frame: &[u8]) {
+        self.0.lock().unwrap().push((false, frame.to_vec()));
+    }
+}
+
+#[tokio::test]
+async fn test_frame_observer_sees_raw_frames() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    let log = Arc::new(FrameLog::default());
+    client.set_frame_observer(log.clone());
+    assert!(client.frame_observer().is_some());
+
+    client.temperature().await.unwrap();
+    client.set_fan_count(2).await.unwrap();
+
+    let frames = log.frames();
+    assert_eq!(frames.len(), 4);
+    assert!(frames.iter().all(|(_, frame)| !frame.is_empty()));
+    let request = [0x01, 0x03, 0x00, 0x00, 0x00, 0x01, 0x84, 0x0A];
+    assert_eq!(frames[0], (true, request.to_vec()));
+    // Address, function, byte count, value 71, CRC
+    assert!(!frames[1].0);
+    assert_eq!(frames[1].1[..5], [0x01, 0x03, 0x02, 0x00, 0x47]);
+    assert_eq!(frames[1].1.len(), 7);
+    // Write Single Register is answered with an echo
+    assert!(frames[2].0);
+    assert_eq!(frames[3], (false, frames[2].1.clone()));
+}
+
+#[tokio::test]
+async fn test_cleared_frame_observer_is_not_called() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    let log = Arc::new(FrameLog::default());
+    client.set_frame_observer(log.clone());
+    client.temperature().await.unwrap();
+
+    client.clear_frame_observer();
+    assert!(client.frame_observer().is_none());
+    client.temperature().await.unwrap();
+
+    assert_eq!(log.frames().len(), 2);
+}
+
+#[tokio::test]
+async fn test_hex_dump_observer_passes_traffic_through() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(respond(controller_side, controller_registers(), 0));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_frame_observer(Arc::new(HexDumpObserver));
+
+    assert_eq!(client.temperature().await.unwrap().value, 31);
+}
+
+#[tokio::test]
+async fn test_read_exception_is_mapped() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    // Illegal Data Address
+    tokio::spawn(replay(
+        controller_side,
+        vec![with_crc(vec![0x01, 0x83, 0x02])],
+    ));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_modbus(), "{}", err);
+    assert!(!err.is_timeout());
+    assert!(err.to_string().contains("IllegalDataAddress"), "{}", err);
+    assert_eq!(client.bus_health().exceptions, 1);
+}
+
+#[tokio::test]
+async fn test_response_with_bad_crc_is_discarded() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    let mut garbled = with_crc(vec![0x01, 0x03, 0x02, 0x00, 0x47]);
+    garbled[6] ^= 0xFF;
+    tokio::spawn(replay(controller_side, vec![garbled]));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+
+    // The garbled frame is dropped and the answer still awaited
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_timeout(), "{}", err);
+    assert_eq!(client.bus_health().successes, 0);
+}
+
+#[tokio::test]
+async fn test_response_from_other_address_is_an_error() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(replay(
+        controller_side,
+        vec![with_crc(vec![0x02, 0x03, 0x02, 0x00, 0x47])],
+    ));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+
+    let err = client.temperature().await.unwrap_err();
+    assert!(err.is_modbus(), "{}", err);
+    assert_eq!(client.bus_health().successes, 0);
+}
+
+#[tokio::test]
+async fn test_unanswered_read_times_out() {
+    let (client_side, controller_side) = tokio::io::duplex(256);
+    tokio::spawn(replay(controller_side, vec![]));
+    let mut client = Jpf4826Client::with_transport(client_side, 1).unwrap();
+    client.set_timeout(TIMEOUT).unwrap();
+
+    let err = client.temperature().await.unwrap_err();
+    assert_eq!(err.timeout_duration(), Some(TIMEOUT));
+    assert_eq!(client.bus_health().timeouts, 1);
+}
@@ -1,3 +1,4 @@
+use jpf4826_driver::diff::Change;
 use jpf4826_driver::types::*;
 
 #[test]
@@ -35,6 +36,7 @@ fn test_fan_status_variants() {
 fn test_temperature_unit_variants() {
     let _celsius = TemperatureUnit::Celsius;
     let _fahrenheit = TemperatureUnit::Fahrenheit;
+    let _kelvin = TemperatureUnit::Kelvin;
 }
 
 #[test]
@@ -107,22 +109,131 @@ fn test_temperature_struct() {
     assert!(matches!(temp.unit, TemperatureUnit::Celsius));
 }
 
+#[test]
+fn test_temperature_to_unit() {
+    for (celsius, fahrenheit, kelvin) in [(0, 32, 273), (-20, -4, 253), (120, 248, 393)] {
+        let temp = Temperature::celsius(celsius);
+        let in_f = temp.to_unit(TemperatureUnit::Fahrenheit);
+        let in_k = temp.to_unit(TemperatureUnit::Kelvin);
+
+        assert_eq!(in_f.value, fahrenheit);
+        assert_eq!(in_k.value, kelvin);
+        assert_eq!(in_k.unit, TemperatureUnit::Kelvin);
+        assert_eq!(in_k.to_celsius(), celsius);
+        assert_eq!(in_f.to_unit(TemperatureUnit::Kelvin), in_k);
+        assert_eq!(in_k.to_unit(TemperatureUnit::Celsius), temp);
+    }
+}
+
+#[test]
+fn test_temperature_to_fahrenheit_rounds_to_nearest() {
+    // 78.8°F, 87.8°F, -0.4°F and -2.2°F
+    for (celsius, fahrenheit) in [(26, 79), (31, 88), (-18, 0), (-19, -2), (-20, -4)] {
+        assert_eq!(Temperature::celsius(celsius).to_fahrenheit(), fahrenheit);
+    }
+    let kelvin = Temperature::celsius(26).to_unit(TemperatureUnit::Kelvin);
+    assert_eq!(kelvin.to_fahrenheit(), 79);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_temperature_unit_json() {
+    assert_eq!(
+        serde_json::to_value(TemperatureUnit::Kelvin).unwrap(),
+        serde_json::json!("KELVIN")
+    );
+}
+
+#[test]
+fn test_temperature_display() {
+    assert_eq!(Temperature::celsius(30).to_string(), "30°C");
+    let fahrenheit = Temperature {
+        value: 86,
+        unit: TemperatureUnit::Fahrenheit,
+    };
+    assert_eq!(fahrenheit.to_string(), "86°F");
+    assert_eq!(
+        Temperature::celsius(30)
+            .to_unit(TemperatureUnit::Kelvin)
+            .to_string(),
+        "303K"
+    );
+}
+
 #[test]
 fn test_fan_info_struct() {
     let fan = FanInfo {
-        index: 1,
+        index: FanIndex::FAN1,
         status: FanStatus::Normal,
         rpm: 1400,
+        is_running: true,
     };
     assert_eq!(fan.index, 1);
     assert!(matches!(fan.status, FanStatus::Normal));
     assert_eq!(fan.rpm, 1400);
+    assert!(fan.is_running);
+}
+
+#[test]
+fn test_fan_index_boundaries() {
+    assert_eq!(FanIndex::new(0), None);
+    assert_eq!(FanIndex::new(1), Some(FanIndex::FAN1));
+    assert_eq!(FanIndex::new(4), Some(FanIndex::FAN4));
+    assert_eq!(FanIndex::new(5), None);
+
+    assert_eq!(FanIndex::try_from(0).unwrap_err().index(), 0);
+    assert_eq!(FanIndex::try_from(1), Ok(FanIndex::FAN1));
+    assert_eq!(FanIndex::try_from(4), Ok(FanIndex::FAN4));
+    assert_eq!(FanIndex::try_from(5).unwrap_err().index(), 5);
+}
+
+#[test]
+fn test_fan_index_positions() {
+    assert_eq!(FanIndex::from_position(0), Some(FanIndex::FAN1));
+    assert_eq!(FanIndex::from_position(3), Some(FanIndex::FAN4));
+    assert_eq!(FanIndex::from_position(4), None);
+    for (position, fan) in FanIndex::ALL.into_iter().enumerate() {
+        assert_eq!(fan.position(), position);
+        assert_eq!(usize::from(fan.get()), position + 1);
+    }
+}
+
+#[test]
+fn test_fan_index_display() {
+    assert_eq!(FanIndex::FAN3.to_string(), "3");
+    assert_eq!(
+        FanIndex::try_from(5).unwrap_err().to_string(),
+        "Fan index 5 out of range (1-4)"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_fan_index_json_is_a_plain_number() {
+    assert_eq!(
+        serde_json::to_value(FanIndex::FAN2).unwrap(),
+        serde_json::json!(2)
+    );
+    assert_eq!(
+        serde_json::from_value::<FanIndex>(serde_json::json!(1)).unwrap(),
+        FanIndex::FAN1
+    );
+    assert_eq!(
+        serde_json::from_value::<FanIndex>(serde_json::json!(4)).unwrap(),
+        FanIndex::FAN4
+    );
+    assert!(serde_json::from_value::<FanIndex>(serde_json::json!(0)).is_err());
+    assert!(serde_json::from_value::<FanIndex>(serde_json::json!(5)).is_err());
 }
 
 #[test]
 fn test_controller_status_struct_creation() {
     // Just test that we can create the struct
+    #[allow(deprecated)]
     let status = ControllerStatus {
+        mode: OperatingMode::Unknown,
+        manual_speed: None,
+        work_mode: WorkMode::Shutdown,
         eco_mode: true,
         modbus_address: 1,
         pwm_frequency: PwmFrequency::Hz25000,
@@ -131,6 +242,7 @@ fn test_controller_status_struct_creation() {
             value: 26,
             unit: TemperatureUnit::Celsius,
         },
+        sensor_fault: false,
         temperature_low_threshold: Temperature {
             value: 27,
             unit: TemperatureUnit::Celsius,
@@ -142,6 +254,526 @@ fn test_controller_status_struct_creation() {
         fans: vec![],
     };
 
-    assert!(status.eco_mode);
+    assert_eq!(status.work_mode, WorkMode::Shutdown);
     assert_eq!(status.fan_count, 4);
 }
+
+#[test]
+fn test_controller_status_from_registers() {
+    let block = [
+        71, 0x000F, 3, 0xFFFF, 0x465A, 0, 4, 1400, 1410, 0, 1430, 0x0002, 65, 80, 0x000B,
+    ];
+
+    let status = ControllerStatus::from_registers(&block);
+
+    assert_eq!(status.mode, OperatingMode::Temperature);
+    assert_eq!(status.work_mode, WorkMode::Shutdown);
+    assert_eq!(status.modbus_address, 3);
+    assert_eq!(status.pwm_frequency, PwmFrequency::Hz2000);
+    assert_eq!(status.fan_count, 4);
+    assert_eq!(status.temperature_current.value, 31);
+    assert_eq!(status.temperature_low_threshold.value, 25);
+    assert_eq!(status.temperature_high_threshold.value, 40);
+    assert_eq!(status.fans.len(), 4);
+    assert_eq!(status.fans[1].rpm, 1410);
+    assert_eq!(status.fans[2].status, FanStatus::Fault);
+    assert!(status.fans.iter().all(|fan| fan.is_running));
+}
+
+#[test]
+fn test_controller_status_from_registers_running_bitmap() {
+    let block = [
+        71, 0x0009, 1, 0xFFFF, 0x465A, 0, 4, 1400, 0, 0, 1400, 0x0005, 70, 90, 0x000B,
+    ];
+
+    let status = ControllerStatus::from_registers(&block);
+
+    let running: Vec<bool> = status.fans.iter().map(|fan| fan.is_running).collect();
+    assert_eq!(running, [true, false, false, true]);
+    // Fan 2 stopped while healthy, fan 3 faulted
+    assert_eq!(status.fans[1].status, FanStatus::Normal);
+    assert_eq!(status.fans[2].status, FanStatus::Fault);
+}
+
+#[test]
+fn test_controller_status_work_mode_register_mapping() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[5] = 0x0000;
+    let status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.work_mode, WorkMode::Shutdown);
+    #[allow(deprecated)]
+    let eco_mode = status.eco_mode;
+    assert!(eco_mode);
+
+    block[5] = 0x0001;
+    let status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.work_mode, WorkMode::MinimumSpeed);
+    #[allow(deprecated)]
+    let eco_mode = status.eco_mode;
+    assert!(!eco_mode);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_controller_status_work_mode_json() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[2] = 0x0001;
+    block[5] = 0x0001;
+    let status = ControllerStatus::from_registers(&block);
+
+    let mut json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["work_mode"], "MinimumSpeed");
+    assert_eq!(json["eco_mode"], false);
+
+    // work_mode wins over a contradicting eco_mode
+    json["eco_mode"] = true.into();
+    let decoded: ControllerStatus = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(decoded.work_mode, WorkMode::MinimumSpeed);
+
+    // Documents without work_mode fall back to eco_mode
+    json.as_object_mut().unwrap().remove("work_mode");
+    let decoded: ControllerStatus = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.work_mode, WorkMode::Shutdown);
+}
+
+#[test]
+fn test_controller_status_from_registers_flags_sensor_fault() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    for (value, celsius) in [
+        (0x0000, -20),
+        (20, -20),
+        (160, 120),
+        (0x00FF, 120),
+        (0xFFFF, 120),
+    ] {
+        block[0] = value;
+        let status = ControllerStatus::from_registers(&block);
+        assert!(status.sensor_fault, "{:#06X}", value);
+        assert_eq!(status.temperature_current.value, celsius, "{:#06X}", value);
+    }
+    for (value, celsius) in [(21, -19), (71, 31), (159, 119)] {
+        block[0] = value;
+        let status = ControllerStatus::from_registers(&block);
+        assert!(!status.sensor_fault, "{:#06X}", value);
+        assert_eq!(status.temperature_current.value, celsius);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_controller_status_sensor_fault_json() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[0] = 0xFFFF;
+    block[2] = 0x0001;
+    let status = ControllerStatus::from_registers(&block);
+
+    let mut json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["temperature"]["sensor_fault"], true);
+    let decoded: ControllerStatus = serde_json::from_value(json.clone()).unwrap();
+    assert!(decoded.sensor_fault);
+
+    // Documents written before the flag existed report a working sensor
+    json["temperature"]
+        .as_object_mut()
+        .unwrap()
+        .remove("sensor_fault");
+    let decoded: ControllerStatus = serde_json::from_value(json).unwrap();
+    assert!(!decoded.sensor_fault);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_controller_status_manual_speed_json_round_trip() {
+    let block = [
+        71, 0x000F, 1, 40, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let mut status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.manual_speed, None);
+    status.mode = OperatingMode::Manual;
+    status.manual_speed = Some(40);
+
+    let mut json = serde_json::to_value(&status).unwrap();
+    assert_eq!(json["manual_speed"], 40);
+    let decoded: ControllerStatus = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(decoded, status);
+
+    json["manual_speed"] = 101.into();
+    assert!(serde_json::from_value::<ControllerStatus>(json.clone()).is_err());
+
+    // Documents written before the field existed have no speed
+    json.as_object_mut().unwrap().remove("manual_speed");
+    let decoded: ControllerStatus = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.manual_speed, None);
+
+    status.mode = OperatingMode::Temperature;
+    status.manual_speed = None;
+    let json = serde_json::to_value(&status).unwrap();
+    assert!(json["manual_speed"].is_null());
+    assert_eq!(
+        serde_json::from_value::<ControllerStatus>(json).unwrap(),
+        status
+    );
+}
+
+#[test]
+fn test_operating_mode_state_register_round_trip() {
+    let states = (0..=100)
+        .map(OperatingModeState::Manual)
+        .chain([OperatingModeState::Temperature]);
+    for state in states {
+        let value = state.to_register_value().unwrap();
+        assert_eq!(OperatingModeState::from_written_value(value), state);
+        assert_eq!(OperatingMode::from_written_value(value), state.mode());
+    }
+    assert_eq!(OperatingModeState::Unknown.to_register_value(), None);
+    assert_eq!(OperatingModeState::Manual(101).to_register_value(), None);
+    for value in [101, 0x00FF, 0xFFFE] {
+        assert_eq!(
+            OperatingModeState::from_written_value(value),
+            OperatingModeState::Unknown
+        );
+    }
+}
+
+#[test]
+fn test_controller_status_mode_state() {
+    let block = [
+        71, 0x000F, 1, 40, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let mut status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.mode_state(), OperatingModeState::Unknown);
+
+    status.mode = OperatingMode::Manual;
+    assert_eq!(status.mode_state(), OperatingModeState::Unknown);
+    status.manual_speed = Some(40);
+    assert_eq!(status.mode_state(), OperatingModeState::Manual(40));
+
+    status.mode = OperatingMode::Temperature;
+    status.manual_speed = None;
+    assert_eq!(status.mode_state(), OperatingModeState::Temperature);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_operating_mode_state_json() {
+    use serde_json::json;
+
+    let cases = [
+        (
+            OperatingModeState::Temperature,
+            json!({"mode": "TEMPERATURE"}),
+        ),
+        (
+            OperatingModeState::Manual(40),
+            json!({"mode": "MANUAL", "speed": 40}),
+        ),
+        (OperatingModeState::Unknown, json!({"mode": "UNKNOWN"})),
+    ];
+    for (state, expected) in cases {
+        assert_eq!(serde_json::to_value(state).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<OperatingModeState>(expected).unwrap(),
+            state
+        );
+    }
+
+    for invalid in [
+        json!({"mode": "MANUAL", "speed": 101}),
+        json!({"mode": "MANUAL"}),
+        json!({"mode": "TEMPERATURE", "speed": 40}),
+        json!({"mode": "AUTO"}),
+    ] {
+        assert!(serde_json::from_value::<OperatingModeState>(invalid).is_err());
+    }
+}
+
+#[test]
+fn test_controller_status_from_registers_rejects_invalid_address() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    for value in [0x0000, 0x00FF, 0x0100, 0x0101] {
+        block[2] = value;
+        let status = ControllerStatus::from_registers(&block);
+        assert_eq!(status.modbus_address, 0, "{:#06X}", value);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_controller_status_json_enforces_address_range() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[2] = 0x0100;
+    let status = ControllerStatus::from_registers(&block);
+    assert!(serde_json::to_value(&status).is_err());
+
+    block[2] = 0x0001;
+    let mut json = serde_json::to_value(ControllerStatus::from_registers(&block)).unwrap();
+    json["modbus_address"] = 255.into();
+    assert!(serde_json::from_value::<ControllerStatus>(json).is_err());
+}
+
+#[test]
+fn test_controller_status_from_registers_defaults_unknown_values() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[5] = 0x0007;
+    block[11] = 0x0042;
+
+    let status = ControllerStatus::from_registers(&block);
+
+    assert_eq!(status.mode, OperatingMode::Unknown);
+    assert_eq!(status.work_mode, WorkMode::MinimumSpeed);
+    assert_eq!(status.pwm_frequency, PwmFrequency::Hz25000);
+}
+
+#[test]
+fn test_controller_status_from_registers_clamps_thresholds() {
+    let mut block = [0u16; ControllerStatus::REGISTER_COUNT as usize];
+    block[12] = 0x0000;
+    block[13] = 0xFFFF;
+
+    let status = ControllerStatus::from_registers(&block);
+
+    assert_eq!(status.temperature_low_threshold.value, -20);
+    assert_eq!(status.temperature_high_threshold.value, 120);
+}
+
+#[test]
+fn test_controller_status_expected_duty_cycle() {
+    // 31°C between 25°C and 40°C, shutdown work mode
+    let mut block = [
+        71, 0x000F, 1, 0xFFFF, 0x415A, 0, 4, 1400, 1400, 1400, 1400, 0x0005, 65, 80, 0x000F,
+    ];
+    let status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.expected_duty_cycle(), Some(52));
+
+    // Same readings in Fahrenheit
+    let mut in_fahrenheit = status.clone();
+    for temp in [
+        &mut in_fahrenheit.temperature_current,
+        &mut in_fahrenheit.temperature_low_threshold,
+        &mut in_fahrenheit.temperature_high_threshold,
+    ] {
+        *temp = temp.to_unit(TemperatureUnit::Fahrenheit);
+    }
+    assert_eq!(in_fahrenheit.expected_duty_cycle(), Some(52));
+
+    // 20°C, below the 22°C hysteresis limit
+    block[0] = 60;
+    assert_eq!(
+        ControllerStatus::from_registers(&block).expected_duty_cycle(),
+        Some(0)
+    );
+    block[5] = 1;
+    assert_eq!(
+        ControllerStatus::from_registers(&block).expected_duty_cycle(),
+        Some(20)
+    );
+
+    block[0] = 0xFFFF;
+    assert_eq!(
+        ControllerStatus::from_registers(&block).expected_duty_cycle(),
+        None
+    );
+
+    let mut manual = status;
+    manual.mode = OperatingMode::Manual;
+    manual.manual_speed = Some(60);
+    assert_eq!(manual.expected_duty_cycle(), Some(60));
+}
+
+#[test]
+fn test_controller_status_stalled_fans() {
+    // Three fans configured; fan 2 stopped by the controller, fan 3 faulted,
+    // fan 4 beyond the fan count
+    let block = [
+        71, 0b1101, 1, 0xFFFF, 0x415A, 0, 3, 0, 0, 0, 0, 0x0005, 65, 80, 0b1011,
+    ];
+    let status = ControllerStatus::from_registers(&block);
+    assert_eq!(status.stalled_fans(300), vec![1]);
+
+    let mut spinning = block;
+    spinning[7] = 1400;
+    let status = ControllerStatus::from_registers(&spinning);
+    assert!(status.stalled_fans(300).is_empty());
+    // Slower than the limit counts as stalled
+    assert_eq!(status.stalled_fans(1500), vec![1]);
+
+    // All four running and healthy, none turning
+    let mut all = block;
+    all[1] = 0b1111;
+    all[6] = 4;
+    all[14] = 0b1111;
+    let status = ControllerStatus::from_registers(&all);
+    assert_eq!(status.stalled_fans(300), vec![1, 2, 3, 4]);
+    assert!(status.stalled_fans(0).is_empty());
+}
+
+#[test]
+fn test_status_diff() {
+    let mut block = [
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let before = ControllerStatus::from_registers(&block);
+    assert!(before.diff(&before).is_empty());
+
+    block[0] = 0xFFFF; // Sensor unplugged
+    block[8] = 1100; // Fan 2 slows down
+    block[9] = 0;
+    block[14] = 0x000B; // Fan 3 fault
+    let after = ControllerStatus::from_registers(&block);
+    let diff = before.diff(&after);
+
+    assert_eq!(
+        diff.sensor_fault,
+        Some(Change {
+            from: false,
+            to: true
+        })
+    );
+    assert_eq!(diff.temperature_current.unwrap().to.value, 120);
+    assert_eq!(diff.mode, None);
+    assert_eq!(diff.temperature_low_threshold, None);
+    assert_eq!(diff.fans.len(), 2);
+    assert_eq!(diff.fans[0].index, 2);
+    assert_eq!(
+        diff.fans[0].rpm,
+        Some(Change {
+            from: 1400,
+            to: 1100
+        })
+    );
+    assert_eq!(diff.fans[0].status, None);
+    assert!(diff.fans[1].fault_raised());
+    assert_eq!(diff.faults_raised().collect::<Vec<_>>(), vec![3]);
+
+    let reverse = after.diff(&before);
+    assert!(reverse.fans[1].fault_cleared());
+    assert_eq!(reverse.faults_raised().count(), 0);
+
+    let mut manual = before.clone();
+    manual.mode = OperatingMode::Manual;
+    manual.manual_speed = Some(40);
+    let mut faster = manual.clone();
+    faster.manual_speed = Some(60);
+    assert_eq!(
+        manual.diff(&faster).manual_speed,
+        Some(Change {
+            from: Some(40),
+            to: Some(60)
+        })
+    );
+    assert_eq!(manual.diff(&faster).mode, None);
+}
+
+#[test]
+fn test_status_diff_rpm_threshold() {
+    let mut block = [
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let before = ControllerStatus::from_registers(&block);
+    block[7] = 1450; // Within the threshold
+    block[8] = 1349; // Just beyond it
+    block[9] = 0; // Stopped with a fault
+    block[14] = 0x000B;
+    let after = ControllerStatus::from_registers(&block);
+
+    let diff = before.diff_with_rpm_threshold(&after, 50);
+
+    let indices: Vec<u8> = diff.fans.iter().map(|fan| fan.index).collect();
+    assert_eq!(indices, vec![2, 3]);
+    assert_eq!(diff.fans[0].rpm_delta(), Some(-51));
+    assert!(diff.fans[1].fault_raised());
+
+    // Jitter alone leaves the diff empty, status changes never do
+    block[8] = 1400;
+    block[9] = 1400;
+    block[14] = 0x000F;
+    let recovered = ControllerStatus::from_registers(&block);
+    assert!(before.diff_with_rpm_threshold(&recovered, 50).is_empty());
+    assert!(!before.diff(&recovered).is_empty());
+    let diff = after.diff_with_rpm_threshold(&recovered, u16::MAX);
+    assert_eq!(diff.fans.len(), 1);
+    assert!(diff.fans[0].fault_cleared());
+    assert_eq!(diff.fans[0].rpm, None);
+}
+
+#[test]
+fn test_status_diff_display() {
+    let mut block = [
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let before = ControllerStatus::from_registers(&block);
+    assert_eq!(before.diff(&before).to_string(), "");
+
+    block[0] = 73;
+    block[9] = 0;
+    block[14] = 0x000B;
+    let after = ControllerStatus::from_registers(&block);
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.temperature_delta(), Some(2));
+    assert_eq!(
+        diff.to_string(),
+        "temperature: 31°C → 33°C (+2°C)\n\
+         fan 3: Normal → Fault\n\
+         fan 3: 1400 → 0 rpm (-1400)"
+    );
+    assert_eq!(
+        after.diff(&before).to_string(),
+        "temperature: 33°C → 31°C (-2°C)\n\
+         fan 3: Fault → Normal\n\
+         fan 3: 0 → 1400 rpm (+1400)"
+    );
+}
+
+#[test]
+fn test_status_diff_mode_flip() {
+    let block = [
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let auto = ControllerStatus::from_registers(&block);
+    let mut manual = auto.clone();
+    manual.mode = OperatingMode::Manual;
+    manual.manual_speed = Some(40);
+
+    let diff = auto.diff(&manual);
+
+    assert_eq!(
+        diff.mode,
+        Some(Change {
+            from: OperatingMode::Temperature,
+            to: OperatingMode::Manual
+        })
+    );
+    assert_eq!(
+        diff.to_string(),
+        "mode: Temperature → Manual\nmanual speed: - → 40%"
+    );
+    assert_eq!(
+        manual.diff(&auto).to_string(),
+        "mode: Manual → Temperature\nmanual speed: 40% → -"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_status_diff_json() {
+    let mut block = [
+        71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+    ];
+    let before = ControllerStatus::from_registers(&block);
+    assert_eq!(serde_json::to_string(&before.diff(&before)).unwrap(), "{}");
+
+    block[9] = 0;
+    block[14] = 0x000B;
+    let after = ControllerStatus::from_registers(&block);
+    assert_eq!(
+        serde_json::to_value(before.diff(&after)).unwrap(),
+        serde_json::json!({
+            "fans": [{
+                "index": 3,
+                "status": {"from": "NORMAL", "to": "FAULT"},
+                "rpm": {"from": 1400, "to": 0}
+            }]
+        })
+    );
+}
@@ -0,0 +1,96 @@
+#![cfg(all(feature = "test-mock", feature = "stream"))]
+
+mod mock;
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use mock::create_test_client;
+use tokio::time::Instant;
+
+const INTERVAL: Duration = Duration::from_secs(2);
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_yields_at_interval() {
+    let (mut client, mock) = create_test_client().await;
+    let started = Instant::now();
+    let mut watch = client.watch(INTERVAL).unwrap();
+    assert_eq!(watch.period(), INTERVAL);
+
+    let first = watch.next().await.unwrap().unwrap();
+    assert_eq!(first.temperature_current.value, 31);
+    assert_eq!(started.elapsed(), Duration::ZERO);
+
+    mock.write_register(0x0000, 85);
+    let second = watch.next().await.unwrap().unwrap();
+    assert_eq!(second.temperature_current.value, 45);
+    assert_eq!(started.elapsed(), INTERVAL);
+
+    watch.next().await.unwrap().unwrap();
+    assert_eq!(started.elapsed(), 2 * INTERVAL);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_watch_yields_error_and_recovers() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    client.set_retries(0);
+    let mut watch = client.watch(INTERVAL).unwrap();
+
+    assert!(watch.next().await.unwrap().is_ok());
+    probe.set_fail_reads(true);
+    assert!(watch.next().await.unwrap().unwrap_err().is_modbus());
+    probe.set_fail_reads(false);
+    assert!(watch.next().await.unwrap().is_ok());
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slow_read_postpones_next_tick() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    probe.set_read_delay(Duration::from_secs(3));
+    let started = Instant::now();
+    let mut watch = client.watch(INTERVAL).unwrap();
+
+    watch.next().await.unwrap().unwrap();
+    assert_eq!(started.elapsed(), Duration::from_secs(3));
+    // The missed tick fires once, not once per interval missed
+    watch.next().await.unwrap().unwrap();
+    assert_eq!(started.elapsed(), Duration::from_secs(6));
+    watch.next().await.unwrap().unwrap();
+    assert_eq!(started.elapsed(), Duration::from_secs(9));
+    assert_eq!(probe.reads(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_watch_stops_polling() {
+    let (mut client, _mock) = create_test_client().await;
+    let probe = client.mock_probe().unwrap();
+    let read_delay = Duration::from_millis(100);
+    probe.set_read_delay(read_delay);
+
+    {
+        let mut watch = client.watch(INTERVAL).unwrap();
+        watch.next().await.unwrap().unwrap();
+        // Dropped with the second read in flight
+        let in_flight = INTERVAL - read_delay / 2;
+        assert!(tokio::time::timeout(in_flight, watch.next()).await.is_err());
+    }
+    tokio::time::sleep(10 * INTERVAL).await;
+    assert_eq!(probe.reads(), 2);
+
+    // The client is usable again once the stream is gone
+    probe.set_read_delay(Duration::ZERO);
+    assert_eq!(client.status().await.unwrap().temperature_current.value, 31);
+}
+
+#[tokio::test]
+async fn test_watch_rejects_zero_interval() {
+    let (mut client, _mock) = create_test_client().await;
+    assert!(client
+        .watch(Duration::ZERO)
+        .err()
+        .unwrap()
+        .is_invalid_parameter());
+}
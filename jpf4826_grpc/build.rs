@@ -0,0 +1,10 @@
+//! Generates the gRPC service code from `proto/`.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the vendored compiler so builds do not need a system protoc
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+    tonic_prost_build::compile_protos("proto/jpf4826/v1/controller.proto")?;
+    Ok(())
+}
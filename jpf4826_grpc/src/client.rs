@@ -0,0 +1,121 @@
+//! Thin client for the `jpf4826.v1.Controller` service.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Status, Streaming};
+
+use crate::proto;
+use crate::proto::controller_client::ControllerClient;
+
+/// Client for a remote controller.
+///
+/// Errors are returned as the [`Status`] sent by the server; see
+/// `proto/jpf4826/v1/controller.proto` for the code mapping.
+///
+/// # Examples
+///
+/// ```no_run
+/// use jpf4826_grpc::RemoteClient;
+/// use tonic::transport::Endpoint;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let endpoint = Endpoint::from_static("http://gateway.local:50051");
+/// let mut client = RemoteClient::connect(endpoint).await?;
+///
+/// let status = client.get_status().await?;
+/// println!("Temperature: {}°C", status.temperature);
+/// client.set_speed(60).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RemoteClient {
+    inner: ControllerClient<Channel>,
+}
+
+impl RemoteClient {
+    /// Connects to `endpoint`.
+    ///
+    /// TLS, timeouts and keep-alive are configured on the endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the connection cannot be established.
+    pub async fn connect(endpoint: Endpoint) -> Result<Self, tonic::transport::Error> {
+        Ok(Self::new(endpoint.connect().await?))
+    }
+
+    /// Creates a client on an existing channel.
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            inner: ControllerClient::new(channel),
+        }
+    }
+
+    /// Reads a status snapshot.
+    pub async fn get_status(&mut self) -> Result<proto::ControllerStatus, Status> {
+        let response = self.inner.get_status(proto::GetStatusRequest {}).await?;
+        Ok(response.into_inner())
+    }
+
+    /// Streams a status snapshot every `interval` until dropped.
+    ///
+    /// The server ends the stream with an error status when a poll fails.
+    pub async fn stream_status(
+        &mut self,
+        interval: Duration,
+    ) -> Result<Streaming<proto::ControllerStatus>, Status> {
+        let interval_ms = u32::try_from(interval.as_millis()).unwrap_or(u32::MAX);
+        let response = self
+            .inner
+            .stream_status(proto::StreamStatusRequest { interval_ms })
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Sets a manual speed percentage (0-100), switching to manual mode.
+    pub async fn set_speed(&mut self, percent: u8) -> Result<(), Status> {
+        self.inner
+            .set_speed(proto::SetSpeedRequest {
+                percent: u32::from(percent),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Switches to temperature mode.
+    pub async fn set_auto_speed(&mut self) -> Result<(), Status> {
+        self.set_mode(proto::Mode::Temperature, 0).await
+    }
+
+    /// Switches to `mode`; `speed` is used in manual mode only.
+    pub async fn set_mode(&mut self, mode: proto::Mode, speed: u8) -> Result<(), Status> {
+        self.inner
+            .set_mode(proto::SetModeRequest {
+                mode: mode.into(),
+                speed: u32::from(speed),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the start and full speed temperatures in °C.
+    pub async fn set_thresholds(&mut self, low: i16, high: i16) -> Result<(), Status> {
+        self.inner
+            .set_thresholds(proto::SetThresholdsRequest {
+                low: i32::from(low),
+                high: i32::from(high),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Resets the controller.
+    pub async fn reset(&mut self) -> Result<(), Status> {
+        self.inner.reset(proto::ResetRequest {}).await?;
+        Ok(())
+    }
+}
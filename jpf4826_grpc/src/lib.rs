@@ -0,0 +1,43 @@
+//! gRPC remote control for the JPF4826 fan controller.
+//!
+//! [`ControllerService`] serves a [`Jpf4826Client`] over the
+//! `jpf4826.v1.Controller` service defined in
+//! `proto/jpf4826/v1/controller.proto`, and [`RemoteClient`] calls it.
+//!
+//! Transport security and authentication are left to the embedding
+//! application: add [`ControllerService::into_server`] to a
+//! [`tonic::transport::Server`] configured with TLS (`tls` feature) or
+//! wrap it with an interceptor, and pass a configured
+//! [`tonic::transport::Endpoint`] to [`RemoteClient::connect`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use jpf4826_driver::Jpf4826Client;
+//! use jpf4826_grpc::ControllerService;
+//! use tonic::transport::Server;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Jpf4826Client::new("/dev/ttyUSB0", 1).await?;
+//! Server::builder()
+//!     .add_service(ControllerService::new(client).into_server())
+//!     .serve("0.0.0.0:50051".parse()?)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Jpf4826Client`]: jpf4826_driver::Jpf4826Client
+
+// Rust guideline compliant 2026-10-16
+
+mod client;
+mod server;
+
+pub use client::RemoteClient;
+pub use server::{ControllerService, DEFAULT_STREAM_INTERVAL, MIN_STREAM_INTERVAL};
+
+/// Generated protobuf messages and service stubs.
+pub mod proto {
+    tonic::include_proto!("jpf4826.v1");
+}
@@ -0,0 +1,243 @@
+//! Service implementation backed by a [`Jpf4826Client`].
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use jpf4826_driver::{
+    ControllerStatus, FanStatus, Jpf4826Client, Jpf4826Error, OperatingMode, SharedClient, WorkMode,
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use crate::proto;
+use crate::proto::controller_server::{Controller, ControllerServer};
+
+/// Poll interval of `StreamStatus` when the request leaves it unset.
+pub const DEFAULT_STREAM_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Shortest poll interval accepted by `StreamStatus`.
+pub const MIN_STREAM_INTERVAL: Duration = Duration::from_millis(100);
+
+/// `jpf4826.v1.Controller` service wrapping a client.
+///
+/// Requests are serialized on a [`SharedClient`], so several connections
+/// and streams can use the same controller; concurrent status reads from
+/// different callers share one bus transaction.
+#[derive(Clone)]
+pub struct ControllerService {
+    client: SharedClient,
+}
+
+impl ControllerService {
+    /// Creates a service owning `client`.
+    pub fn new(client: Jpf4826Client) -> Self {
+        Self::from_shared(SharedClient::new(client))
+    }
+
+    /// Creates a service on a client shared with the embedding application.
+    pub fn from_shared(client: SharedClient) -> Self {
+        Self { client }
+    }
+
+    /// Wraps the service for [`tonic::transport::Server::add_service`].
+    pub fn into_server(self) -> ControllerServer<Self> {
+        ControllerServer::new(self)
+    }
+}
+
+#[tonic::async_trait]
+impl Controller for ControllerService {
+    type StreamStatusStream = ReceiverStream<Result<proto::ControllerStatus, Status>>;
+
+    async fn get_status(
+        &self,
+        _request: Request<proto::GetStatusRequest>,
+    ) -> Result<Response<proto::ControllerStatus>, Status> {
+        let status = self
+            .client
+            .lock()
+            .await
+            .status()
+            .await
+            .map_err(|e| to_status(&e))?;
+        Ok(Response::new(to_proto(&status)))
+    }
+
+    async fn stream_status(
+        &self,
+        request: Request<proto::StreamStatusRequest>,
+    ) -> Result<Response<Self::StreamStatusStream>, Status> {
+        let interval = match request.into_inner().interval_ms {
+            0 => DEFAULT_STREAM_INTERVAL,
+            ms => Duration::from_millis(u64::from(ms)),
+        };
+        if interval < MIN_STREAM_INTERVAL {
+            return Err(Status::invalid_argument(format!(
+                "Stream interval must be at least {} ms",
+                MIN_STREAM_INTERVAL.as_millis()
+            )));
+        }
+
+        let (tx, rx) = mpsc::channel(1);
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = tx.closed() => break,
+                    _ = ticker.tick() => {}
+                }
+                let result = client.status().await;
+                let item = result.as_ref().map(to_proto).map_err(|e| {
+                    log::warn!("Status stream poll failed: {}", e);
+                    to_status(e)
+                });
+                let failed = item.is_err();
+                if tx.send(item).await.is_err() || failed {
+                    break;
+                }
+            }
+            log::debug!("Status stream closed");
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn set_speed(
+        &self,
+        request: Request<proto::SetSpeedRequest>,
+    ) -> Result<Response<proto::SetSpeedResponse>, Status> {
+        let percent = to_percent(request.into_inner().percent)?;
+        self.client
+            .lock()
+            .await
+            .set_fan_speed(percent)
+            .await
+            .map_err(|e| to_status(&e))?;
+        Ok(Response::new(proto::SetSpeedResponse {}))
+    }
+
+    async fn set_mode(
+        &self,
+        request: Request<proto::SetModeRequest>,
+    ) -> Result<Response<proto::SetModeResponse>, Status> {
+        let request = request.into_inner();
+        let mut client = self.client.lock().await;
+        match request.mode() {
+            proto::Mode::Temperature => client.set_auto_speed().await.map_err(|e| to_status(&e))?,
+            proto::Mode::Manual => client
+                .set_manual_speed(to_percent(request.speed)?)
+                .await
+                .map_err(|e| to_status(&e))?,
+            proto::Mode::Unspecified => {
+                return Err(Status::invalid_argument("Mode must be specified"));
+            }
+        }
+        Ok(Response::new(proto::SetModeResponse {}))
+    }
+
+    async fn set_thresholds(
+        &self,
+        request: Request<proto::SetThresholdsRequest>,
+    ) -> Result<Response<proto::SetThresholdsResponse>, Status> {
+        let request = request.into_inner();
+        let low = to_temperature(request.low)?;
+        let high = to_temperature(request.high)?;
+        self.client
+            .lock()
+            .await
+            .set_temperature_threshold(low, high)
+            .await
+            .map_err(|e| to_status(&e))?;
+        Ok(Response::new(proto::SetThresholdsResponse {}))
+    }
+
+    async fn reset(
+        &self,
+        _request: Request<proto::ResetRequest>,
+    ) -> Result<Response<proto::ResetResponse>, Status> {
+        self.client
+            .lock()
+            .await
+            .reset()
+            .await
+            .map_err(|e| to_status(&e))?;
+        Ok(Response::new(proto::ResetResponse {}))
+    }
+}
+
+/// Converts a driver status into its protobuf message.
+fn to_proto(status: &ControllerStatus) -> proto::ControllerStatus {
+    proto::ControllerStatus {
+        modbus_address: u32::from(status.modbus_address),
+        temperature: i32::from(status.temperature_current.value),
+        sensor_fault: status.sensor_fault,
+        low_threshold: i32::from(status.temperature_low_threshold.value),
+        high_threshold: i32::from(status.temperature_high_threshold.value),
+        eco_mode: status.work_mode == WorkMode::Shutdown,
+        pwm_frequency_hz: status.pwm_frequency.to_hz(),
+        fan_count: u32::from(status.fan_count),
+        fans: status
+            .fans
+            .iter()
+            .map(|fan| proto::Fan {
+                index: u32::from(fan.index.get()),
+                rpm: u32::from(fan.rpm),
+                fault: fan.status == FanStatus::Fault,
+            })
+            .collect(),
+        mode: match status.mode {
+            OperatingMode::Temperature => proto::Mode::Temperature,
+            OperatingMode::Manual => proto::Mode::Manual,
+            OperatingMode::Unknown => proto::Mode::Unspecified,
+        }
+        .into(),
+        manual_speed: status.manual_speed.map(u32::from),
+    }
+}
+
+fn to_percent(value: u32) -> Result<u8, Status> {
+    u8::try_from(value)
+        .ok()
+        .filter(|percent| *percent <= 100)
+        .ok_or_else(|| Status::invalid_argument(format!("Speed must be 0-100, got {}", value)))
+}
+
+fn to_temperature(value: i32) -> Result<i16, Status> {
+    i16::try_from(value)
+        .map_err(|_| Status::invalid_argument(format!("Temperature out of range: {}", value)))
+}
+
+/// Maps a driver error onto a gRPC status.
+///
+/// Failures of the link become `UNAVAILABLE`, so callers may retry them.
+/// Refusals by the client's own policy (write budget, strict mode) and
+/// settings the controller did not take over become `RESOURCE_EXHAUSTED`
+/// and `FAILED_PRECONDITION`. Anything the driver cannot classify, such
+/// as a Modbus exception, becomes `INTERNAL`.
+fn to_status(err: &Jpf4826Error) -> Status {
+    let message = err.to_string();
+    if err.is_timeout() {
+        Status::deadline_exceeded(message)
+    } else if err.is_link_failure() || err.is_serial() {
+        Status::unavailable(message)
+    } else if err.is_invalid_response() || err.response_length_mismatch().is_some() {
+        Status::data_loss(message)
+    } else if err.is_invalid_parameter() {
+        Status::invalid_argument(message)
+    } else if err.is_write_budget_exceeded() {
+        Status::resource_exhausted(message)
+    } else if err.is_mode_conflict()
+        || err.is_address_change_failed()
+        || err.is_pwm_frequency_mismatch()
+    {
+        Status::failed_precondition(message)
+    } else if err.is_unsupported() || err.is_function_rejected() {
+        Status::unimplemented(message)
+    } else {
+        Status::internal(message)
+    }
+}
@@ -0,0 +1,190 @@
+//! In-process server and client tests against the mock backend.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use jpf4826_driver::budget::WriteBudget;
+use jpf4826_driver::Jpf4826Client;
+use jpf4826_grpc::proto::Mode;
+use jpf4826_grpc::{ControllerService, RemoteClient};
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tokio_stream::StreamExt;
+use tonic::transport::{Endpoint, Server};
+use tonic::Code;
+
+type Registers = Arc<Mutex<HashMap<u16, u16>>>;
+
+/// Register image matching the driver's MockController defaults.
+fn default_registers() -> Registers {
+    let registers = HashMap::from([
+        (0x0000, 71),     // 31°C
+        (0x0001, 0x000F), // all fans running
+        (0x0002, 0x0001), // Modbus address 1
+        (0x0003, 0xFFFF), // temperature mode
+        (0x0004, 0x465A),
+        (0x0005, 0x0001), // minimum speed work mode
+        (0x0006, 0x0004), // 4 fans
+        (0x0007, 1400),
+        (0x0008, 1400),
+        (0x0009, 1400),
+        (0x000A, 1400),
+        (0x000B, 0x0005), // 25 kHz
+        (0x000C, 70),     // 30°C
+        (0x000D, 90),     // 50°C
+        (0x000E, 0x000F), // no faults
+    ]);
+    Arc::new(Mutex::new(registers))
+}
+
+fn register(registers: &Registers, addr: u16) -> u16 {
+    registers.lock().unwrap()[&addr]
+}
+
+/// Serves a mock-backed controller on a loopback port and connects to it.
+async fn start(registers: Registers) -> RemoteClient {
+    serve(Jpf4826Client::new_mock(registers, 1).await).await
+}
+
+/// Serves `client` on a loopback port and connects to it.
+async fn serve(client: Jpf4826Client) -> RemoteClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(ControllerService::new(client).into_server())
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    let endpoint = Endpoint::from_shared(format!("http://{}", addr)).unwrap();
+    RemoteClient::connect(endpoint).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_get_status() {
+    let registers = default_registers();
+    registers.lock().unwrap().insert(0x000E, 0x000B); // fan 3 fault
+    let mut client = start(registers).await;
+
+    let status = client.get_status().await.unwrap();
+
+    assert_eq!(status.modbus_address, 1);
+    assert_eq!(status.temperature, 31);
+    assert_eq!(status.low_threshold, 30);
+    assert_eq!(status.high_threshold, 50);
+    assert!(!status.eco_mode);
+    assert_eq!(status.pwm_frequency_hz, 25000);
+    assert_eq!(status.fan_count, 4);
+    assert_eq!(status.fans.len(), 4);
+    assert_eq!(status.fans[0].index, 1);
+    assert_eq!(status.fans[0].rpm, 1400);
+    assert!(status.fans[2].fault);
+    assert!(!status.fans[3].fault);
+    assert_eq!(status.manual_speed, None);
+}
+
+#[tokio::test]
+async fn test_stream_status_follows_changes() {
+    let registers = default_registers();
+    let mut client = start(registers.clone()).await;
+
+    let mut stream = client
+        .stream_status(Duration::from_millis(100))
+        .await
+        .unwrap();
+
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first.temperature, 31);
+
+    registers.lock().unwrap().insert(0x0000, 76); // 36°C
+    let mut updated = stream.next().await.unwrap().unwrap();
+    // A poll may already have been in flight when the register changed
+    if updated.temperature == 31 {
+        updated = stream.next().await.unwrap().unwrap();
+    }
+    assert_eq!(updated.temperature, 36);
+}
+
+#[tokio::test]
+async fn test_stream_status_rejects_short_interval() {
+    let mut client = start(default_registers()).await;
+
+    let err = client
+        .stream_status(Duration::from_millis(10))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_commands_map_to_driver() {
+    let registers = default_registers();
+    let mut client = start(registers.clone()).await;
+
+    client.set_speed(75).await.unwrap();
+    assert_eq!(register(&registers, 0x0003), 75);
+
+    client.set_auto_speed().await.unwrap();
+    assert_eq!(register(&registers, 0x0003), 0xFFFF);
+
+    client.set_mode(Mode::Manual, 40).await.unwrap();
+    assert_eq!(register(&registers, 0x0003), 40);
+    assert_eq!(client.get_status().await.unwrap().manual_speed, Some(40));
+
+    client.set_thresholds(25, 40).await.unwrap();
+    assert_eq!(register(&registers, 0x000C), 65);
+    assert_eq!(register(&registers, 0x000D), 80);
+
+    client.reset().await.unwrap();
+    assert_eq!(register(&registers, 0x0020), 0x00AA);
+}
+
+#[tokio::test]
+async fn test_invalid_arguments() {
+    let mut client = start(default_registers()).await;
+
+    let errors = [
+        client.set_speed(150).await.unwrap_err(),
+        client.set_mode(Mode::Unspecified, 0).await.unwrap_err(),
+        client.set_mode(Mode::Manual, 101).await.unwrap_err(),
+        client.set_thresholds(50, 30).await.unwrap_err(),
+        client.set_thresholds(-40, 30).await.unwrap_err(),
+    ];
+
+    for err in errors {
+        assert_eq!(err.code(), Code::InvalidArgument, "{}", err.message());
+    }
+}
+
+#[tokio::test]
+async fn test_strict_mode_conflict_is_failed_precondition() {
+    let registers = default_registers();
+    let mut driver = Jpf4826Client::new_mock(registers.clone(), 1).await;
+    driver.set_strict_mode(true);
+    let mut client = serve(driver).await;
+
+    let err = client.set_speed(75).await.unwrap_err();
+    assert_eq!(err.code(), Code::FailedPrecondition, "{}", err.message());
+    assert_eq!(register(&registers, 0x0003), 0xFFFF);
+
+    // Selecting manual mode explicitly is never a conflict
+    client.set_mode(Mode::Manual, 40).await.unwrap();
+    client.set_speed(75).await.unwrap();
+    assert_eq!(register(&registers, 0x0003), 75);
+}
+
+#[tokio::test]
+async fn test_write_budget_is_resource_exhausted() {
+    let mut driver = Jpf4826Client::new_mock(default_registers(), 1).await;
+    driver
+        .set_write_budget(Some(WriteBudget::new(1, Duration::from_secs(60))))
+        .unwrap();
+    let mut client = serve(driver).await;
+
+    client.set_thresholds(25, 40).await.unwrap();
+    let err = client.set_thresholds(26, 41).await.unwrap_err();
+    assert_eq!(err.code(), Code::ResourceExhausted, "{}", err.message());
+}
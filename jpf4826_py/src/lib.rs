@@ -0,0 +1,332 @@
+//! Python bindings for the JPF4826 fan controller driver.
+//!
+//! Exposes a blocking `Jpf4826Client` class to Python. The async driver runs
+//! on a private single-threaded tokio runtime owned by each client, so Python
+//! callers never deal with an event loop.
+
+// Rust guideline compliant 2026-10-16
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use jpf4826_driver::budget::WriteBudget;
+use jpf4826_driver::{Jpf4826Client as DriverClient, Jpf4826Error as DriverError};
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+use serde_json::Value;
+
+create_exception!(
+    jpf4826,
+    Jpf4826Error,
+    PyException,
+    "Base class for all JPF4826 driver errors."
+);
+create_exception!(
+    jpf4826,
+    ModbusError,
+    Jpf4826Error,
+    "Modbus protocol communication failed."
+);
+create_exception!(
+    jpf4826,
+    SerialError,
+    Jpf4826Error,
+    "The serial port could not be opened or used."
+);
+create_exception!(
+    jpf4826,
+    InvalidParameterError,
+    Jpf4826Error,
+    "An argument was rejected before anything was sent to the controller."
+);
+create_exception!(
+    jpf4826,
+    Jpf4826TimeoutError,
+    Jpf4826Error,
+    "The controller did not answer within the configured timeout."
+);
+create_exception!(
+    jpf4826,
+    WriteBudgetExceededError,
+    Jpf4826Error,
+    "A write was refused because the register's write budget is used up."
+);
+create_exception!(
+    jpf4826,
+    ModeConflictError,
+    Jpf4826Error,
+    "A speed write in strict mode would have left temperature mode."
+);
+create_exception!(
+    jpf4826,
+    UnsupportedError,
+    Jpf4826Error,
+    "The operation is not supported on the controller's model."
+);
+
+/// Maps a driver error onto the Python exception hierarchy.
+///
+/// An out-of-range value read from the controller and a rejected function
+/// code become `ModbusError`. Kinds without a subclass of their own, such
+/// as a failed address change, are raised as the base `Jpf4826Error`.
+fn to_py_err(err: DriverError) -> PyErr {
+    let message = err.to_string();
+    if err.is_timeout() {
+        Jpf4826TimeoutError::new_err(message)
+    } else if err.is_modbus() || err.is_invalid_response() || err.is_function_rejected() {
+        ModbusError::new_err(message)
+    } else if err.is_serial() {
+        SerialError::new_err(message)
+    } else if err.is_invalid_parameter() {
+        InvalidParameterError::new_err(message)
+    } else if err.is_write_budget_exceeded() {
+        WriteBudgetExceededError::new_err(message)
+    } else if err.is_mode_conflict() {
+        ModeConflictError::new_err(message)
+    } else if err.is_unsupported() {
+        UnsupportedError::new_err(message)
+    } else {
+        Jpf4826Error::new_err(message)
+    }
+}
+
+/// Converts a positive number of seconds from Python into a duration.
+fn positive_seconds(what: &str, secs: f64) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(secs)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| {
+            InvalidParameterError::new_err(format!(
+                "{what} must be a positive number of seconds, got {secs}"
+            ))
+        })
+}
+
+/// Converts a JSON value into the equivalent Python object.
+fn json_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    let object = match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(flag) => flag.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(number) => {
+            if let Some(int) = number.as_i64() {
+                int.into_pyobject(py)?.into_any()
+            } else if let Some(uint) = number.as_u64() {
+                uint.into_pyobject(py)?.into_any()
+            } else {
+                number
+                    .as_f64()
+                    .unwrap_or(f64::NAN)
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        }
+        Value::String(text) => PyString::new(py, text).into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item)?)?;
+            }
+            dict.into_any()
+        }
+    };
+    Ok(object)
+}
+
+/// Builds the per-client runtime driving the async driver.
+fn new_runtime() -> PyResult<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| Jpf4826Error::new_err(format!("Failed to start async runtime: {e}")))
+}
+
+/// Blocking JPF4826 client for Python.
+///
+/// Create one with `Jpf4826Client.connect(port, addr)`. All methods block
+/// until the controller answers and release the GIL while waiting.
+#[pyclass(name = "Jpf4826Client", module = "jpf4826")]
+struct PyJpf4826Client {
+    runtime: tokio::runtime::Runtime,
+    client: Mutex<DriverClient>,
+    #[cfg(feature = "mock")]
+    registers: Option<MockRegisters>,
+}
+
+#[cfg(feature = "mock")]
+type MockRegisters = std::sync::Arc<Mutex<std::collections::HashMap<u16, u16>>>;
+
+impl PyJpf4826Client {
+    /// Locks the driver client for a call that does not touch the bus.
+    fn lock(&self) -> MutexGuard<'_, DriverClient> {
+        self.client.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs a driver operation to completion with the GIL released.
+    fn call<T, F>(&self, py: Python<'_>, op: F) -> PyResult<T>
+    where
+        T: Send,
+        F: AsyncFnOnce(&mut DriverClient) -> jpf4826_driver::Result<T> + Send,
+    {
+        py.allow_threads(|| {
+            let mut client = self.lock();
+            self.runtime.block_on(op(&mut client))
+        })
+        .map_err(to_py_err)
+    }
+}
+
+#[pymethods]
+impl PyJpf4826Client {
+    /// Opens the serial port and connects to the controller at `addr`.
+    ///
+    /// `timeout` is the per-operation timeout in seconds (default 10).
+    #[staticmethod]
+    #[pyo3(signature = (port, addr, timeout = None))]
+    fn connect(py: Python<'_>, port: &str, addr: u8, timeout: Option<f64>) -> PyResult<Self> {
+        let timeout = match timeout {
+            Some(secs) => positive_seconds("Timeout", secs)?,
+            None => jpf4826_driver::DEFAULT_TIMEOUT,
+        };
+
+        let runtime = new_runtime()?;
+        let client = py
+            .allow_threads(|| runtime.block_on(DriverClient::with_timeout(port, addr, timeout)))
+            .map_err(to_py_err)?;
+
+        Ok(Self {
+            runtime,
+            client: Mutex::new(client),
+            #[cfg(feature = "mock")]
+            registers: None,
+        })
+    }
+
+    /// Creates a client backed by an in-memory register map (testing only).
+    #[cfg(feature = "mock")]
+    #[staticmethod]
+    #[pyo3(signature = (registers, addr = 1))]
+    fn mock(registers: std::collections::HashMap<u16, u16>, addr: u8) -> PyResult<Self> {
+        let runtime = new_runtime()?;
+        let registers = std::sync::Arc::new(Mutex::new(registers));
+        let client = runtime.block_on(DriverClient::new_mock(registers.clone(), addr));
+
+        Ok(Self {
+            runtime,
+            client: Mutex::new(client),
+            registers: Some(registers),
+        })
+    }
+
+    /// Returns a raw value from the mock register map (testing only).
+    #[cfg(feature = "mock")]
+    fn mock_register(&self, addr: u16) -> Option<u16> {
+        let registers = self.registers.as_ref()?;
+        let registers = registers.lock().unwrap_or_else(PoisonError::into_inner);
+        registers.get(&addr).copied()
+    }
+
+    /// Reads the full controller status.
+    ///
+    /// Returns a dict shaped like the `jpf4826ctl status --json` output.
+    fn status<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let status = self.call(py, async |client| client.status().await)?;
+        let value = serde_json::to_value(&status)
+            .map_err(|e| Jpf4826Error::new_err(format!("Failed to encode status: {e}")))?;
+        json_to_py(py, &value)
+    }
+
+    /// Sets a manual fan speed percentage (0-100), enabling manual mode.
+    fn set_speed(&self, py: Python<'_>, percent: u8) -> PyResult<()> {
+        self.call(py, async |client| client.set_fan_speed(percent).await)
+    }
+
+    /// Sets the start (`low`) and full speed (`high`) temperatures in Celsius.
+    fn set_thresholds(&self, py: Python<'_>, low: i16, high: i16) -> PyResult<()> {
+        self.call(py, async |client| {
+            client.set_temperature_threshold(low, high).await
+        })
+    }
+
+    /// Switches the operating mode.
+    ///
+    /// `mode` is `"temperature"` for automatic control or `"manual"`, which
+    /// requires `speed` (0-100).
+    #[pyo3(signature = (mode, speed = None))]
+    fn set_mode(&self, py: Python<'_>, mode: &str, speed: Option<u8>) -> PyResult<()> {
+        match (mode, speed) {
+            ("temperature", None) => self.call(py, async |client| client.set_auto_speed().await),
+            ("manual", Some(percent)) => {
+                self.call(py, async |client| client.set_manual_speed(percent).await)
+            }
+            ("temperature", Some(_)) => Err(InvalidParameterError::new_err(
+                "Temperature mode does not take a speed",
+            )),
+            ("manual", None) => Err(InvalidParameterError::new_err(
+                "Manual mode requires a speed (0-100)",
+            )),
+            (other, _) => Err(InvalidParameterError::new_err(format!(
+                "Unknown mode {other:?}, expected \"temperature\" or \"manual\""
+            ))),
+        }
+    }
+
+    /// Makes `set_speed` refuse to switch the controller out of temperature
+    /// mode, raising `ModeConflictError` instead.
+    ///
+    /// `set_mode("manual", speed)` still switches modes. Off by default.
+    fn set_strict_mode(&self, strict: bool) {
+        self.lock().set_strict_mode(strict);
+    }
+
+    /// Limits each configuration register to `max_writes` writes within
+    /// `window` seconds; further writes raise `WriteBudgetExceededError`.
+    ///
+    /// `max_writes=None` removes the limit.
+    #[pyo3(signature = (max_writes, window = 60.0))]
+    fn set_write_budget(&self, max_writes: Option<u32>, window: f64) -> PyResult<()> {
+        let budget = match max_writes {
+            Some(max_writes) => Some(WriteBudget::new(
+                max_writes,
+                positive_seconds("Window", window)?,
+            )),
+            None => None,
+        };
+        self.lock().set_write_budget(budget).map_err(to_py_err)
+    }
+
+    /// Resets the controller.
+    fn reset(&self, py: Python<'_>) -> PyResult<()> {
+        self.call(py, async |client| client.reset().await)
+    }
+}
+
+/// Python module definition.
+#[pymodule]
+fn jpf4826(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add_class::<PyJpf4826Client>()?;
+    m.add("Jpf4826Error", py.get_type::<Jpf4826Error>())?;
+    m.add("ModbusError", py.get_type::<ModbusError>())?;
+    m.add("SerialError", py.get_type::<SerialError>())?;
+    m.add(
+        "InvalidParameterError",
+        py.get_type::<InvalidParameterError>(),
+    )?;
+    m.add("Jpf4826TimeoutError", py.get_type::<Jpf4826TimeoutError>())?;
+    m.add(
+        "WriteBudgetExceededError",
+        py.get_type::<WriteBudgetExceededError>(),
+    )?;
+    m.add("ModeConflictError", py.get_type::<ModeConflictError>())?;
+    m.add("UnsupportedError", py.get_type::<UnsupportedError>())?;
+    Ok(())
+}
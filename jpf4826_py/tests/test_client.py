@@ -0,0 +1,173 @@
+"""Tests for the jpf4826 Python bindings against the mock backend.
+
+Build the extension with the mock backend before running:
+
+    maturin develop --features mock
+    pytest tests
+"""
+
+import pytest
+
+import jpf4826
+
+
+def default_registers():
+    """Register image matching the driver's MockController defaults."""
+    return {
+        0x0000: 71,  # 31°C
+        0x0001: 0x000F,  # all fans running
+        0x0002: 0x0001,  # Modbus address 1
+        0x0003: 0xFFFF,  # temperature mode
+        0x0004: 0x465A,  # 30°C / 50°C combined
+        0x0005: 0x0001,  # minimum speed work mode
+        0x0006: 0x0004,  # 4 fans
+        0x0007: 1400,
+        0x0008: 1400,
+        0x0009: 1400,
+        0x000A: 1400,
+        0x000B: 0x0005,  # 25 kHz
+        0x000C: 70,  # 30°C
+        0x000D: 90,  # 50°C
+        0x000E: 0x000F,  # no faults
+    }
+
+
+@pytest.fixture
+def client():
+    return jpf4826.Jpf4826Client.mock(default_registers())
+
+
+def test_status_matches_json_schema_shape(client):
+    status = client.status()
+
+    assert status["modbus_address"] == 1
+    assert status["fan_count"] == 4
+    assert status["pwm_frequency"] == {"value": 25000, "unit": "Hz"}
+    assert status["temperature"]["current"] == {"value": 31, "unit": "CELSIUS"}
+    assert status["temperature"]["low_threshold"]["value"] == 30
+    assert status["temperature"]["high_threshold"]["value"] == 50
+    assert [fan["index"] for fan in status["fans"]] == [1, 2, 3, 4]
+    assert all(fan["status"] == "NORMAL" for fan in status["fans"])
+
+
+def test_status_reports_fan_fault():
+    registers = default_registers()
+    registers[0x000E] = 0x000B  # fan 3 fault
+    client = jpf4826.Jpf4826Client.mock(registers)
+
+    fans = client.status()["fans"]
+
+    assert fans[2]["status"] == "FAULT"
+    assert fans[0]["status"] == "NORMAL"
+
+
+def test_set_speed_writes_manual_speed(client):
+    client.set_speed(75)
+
+    assert client.mock_register(0x0003) == 75
+
+
+def test_set_thresholds_writes_both_registers(client):
+    client.set_thresholds(25, 40)
+
+    assert client.mock_register(0x000C) == 65
+    assert client.mock_register(0x000D) == 80
+
+
+def test_set_mode_temperature(client):
+    client.set_speed(50)
+    client.set_mode("temperature")
+
+    assert client.mock_register(0x0003) == 0xFFFF
+
+
+def test_set_mode_manual_requires_speed(client):
+    with pytest.raises(jpf4826.InvalidParameterError):
+        client.set_mode("manual")
+
+    client.set_mode("manual", speed=60)
+    assert client.mock_register(0x0003) == 60
+
+
+def test_set_mode_rejects_unknown_mode(client):
+    with pytest.raises(jpf4826.InvalidParameterError):
+        client.set_mode("turbo")
+
+
+def test_reset_writes_reset_command(client):
+    client.reset()
+
+    assert client.mock_register(0x0020) == 0x00AA
+
+
+def test_invalid_speed_raises_invalid_parameter(client):
+    with pytest.raises(jpf4826.InvalidParameterError) as excinfo:
+        client.set_speed(150)
+
+    assert "150" in str(excinfo.value)
+
+
+def test_inverted_thresholds_raise_invalid_parameter(client):
+    with pytest.raises(jpf4826.InvalidParameterError):
+        client.set_thresholds(50, 30)
+
+
+def test_strict_mode_raises_mode_conflict(client):
+    client.set_strict_mode(True)
+
+    with pytest.raises(jpf4826.ModeConflictError):
+        client.set_speed(60)
+    assert client.mock_register(0x0003) == 0xFFFF
+
+    # An explicit mode switch is still allowed
+    client.set_mode("manual", speed=60)
+    client.set_speed(80)
+    assert client.mock_register(0x0003) == 80
+
+
+def test_write_budget_raises_write_budget_exceeded(client):
+    client.set_write_budget(1, window=60)
+    client.set_thresholds(25, 40)
+
+    with pytest.raises(jpf4826.WriteBudgetExceededError) as excinfo:
+        client.set_thresholds(20, 45)
+
+    assert isinstance(excinfo.value, jpf4826.Jpf4826Error)
+    assert client.mock_register(0x000C) == 65
+
+    client.set_write_budget(None)
+    client.set_thresholds(20, 45)
+    assert client.mock_register(0x000C) == 60
+
+
+def test_write_budget_rejects_empty_budget(client):
+    with pytest.raises(jpf4826.InvalidParameterError):
+        client.set_write_budget(0)
+    with pytest.raises(jpf4826.InvalidParameterError):
+        client.set_write_budget(1, window=0)
+
+
+def test_exception_hierarchy():
+    for error in (
+        jpf4826.ModbusError,
+        jpf4826.SerialError,
+        jpf4826.InvalidParameterError,
+        jpf4826.Jpf4826TimeoutError,
+        jpf4826.WriteBudgetExceededError,
+        jpf4826.ModeConflictError,
+        jpf4826.UnsupportedError,
+    ):
+        assert issubclass(error, jpf4826.Jpf4826Error)
+    assert issubclass(jpf4826.Jpf4826Error, Exception)
+    # The builtin TimeoutError must not be shadowed by a star import
+    assert not hasattr(jpf4826, "TimeoutError")
+
+
+def test_connect_to_missing_port_raises_serial_error():
+    with pytest.raises(jpf4826.SerialError):
+        jpf4826.Jpf4826Client.connect("/nonexistent/jpf4826", 1)
+
+
+def test_connect_rejects_non_positive_timeout():
+    with pytest.raises(jpf4826.InvalidParameterError):
+        jpf4826.Jpf4826Client.connect("/nonexistent/jpf4826", 1, timeout=0)
@@ -2,7 +2,11 @@
 
 // Rust guideline compliant 2026-01-27
 
-use clap::{Parser, Subcommand};
+use crate::output::STATUS_KEYS;
+use clap::{Parser, Subcommand, ValueEnum};
+use jpf4826_driver::profile::Thresholds;
+use jpf4826_driver::TemperatureUnit;
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// Default timeout in seconds, must match jpf4826_driver::DEFAULT_TIMEOUT.
@@ -27,15 +31,16 @@ pub struct Cli {
     )]
     pub port: Option<String>,
 
-    /// Modbus device address (1-254)
+    /// Modbus device address (1-254), or a list such as 1,2,3-6 for set
     #[arg(
         short = 'a',
         long = "addr",
         env = "JPF4826_ADDR",
-        value_parser = clap::value_parser!(u8).range(1..=254),
-        help = "Modbus address (falls back to JPF4826_ADDR env var)"
+        value_name = "ADDR",
+        value_parser = parse_addresses,
+        help = "Modbus address, or a list like 1,2,3-6 for set (falls back to JPF4826_ADDR env var)"
     )]
-    pub addr: Option<u8>,
+    pub addr: Option<AddressList>,
 
     /// Enable verbose logging (debug output)
     #[arg(short = 'v', long = "verbose", global = true)]
@@ -62,13 +67,48 @@ pub struct Cli {
 pub enum Commands {
     /// Display controller status
     Status {
-        /// Output in JSON format
-        #[arg(long)]
+        /// Output in JSON format (same as --format=json)
+        #[arg(long, conflicts_with = "format")]
         json: bool,
 
-        /// Temperature unit (0=Celsius, 1=Fahrenheit)
-        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=1))]
-        temp_unit: Option<u8>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Temperature unit (c/celsius/0, f/fahrenheit/1, k/kelvin/2)
+        #[arg(long, value_parser = parse_temp_unit)]
+        temp_unit: Option<TemperatureUnit>,
+
+        /// Exit with code 3 unless N fans are configured (0-4)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(0..=4))]
+        expect_fan_count: Option<u8>,
+
+        /// Exit with code 3 unless the PWM frequency is HZ
+        #[arg(long, value_name = "HZ", value_parser = validate_pwm_freq)]
+        expect_pwm: Option<u32>,
+
+        /// Exit with code 3 unless the thresholds are LOW:HIGH °C
+        #[arg(long, value_name = "LOW:HIGH", value_parser = parse_thresholds)]
+        expect_thresholds: Option<Thresholds>,
+
+        /// Draw a speed bar per fan: auto (on terminals), always or never
+        #[arg(long, value_name = "WHEN", value_enum, default_value_t = BarsMode::Auto, num_args = 0..=1, default_missing_value = "always")]
+        bars: BarsMode,
+
+        /// RPM drawn as a full bar (default: the fastest fan)
+        #[arg(long, value_name = "RPM", value_parser = clap::value_parser!(u16).range(1..))]
+        nominal_rpm: Option<u16>,
+    },
+
+    /// Print one status field, using the keys of `status --format kv`
+    Get {
+        /// Field key (e.g. temperature.current, fan.1.rpm)
+        #[arg(value_parser = clap::builder::PossibleValuesParser::new(STATUS_KEYS))]
+        key: String,
+
+        /// Temperature unit (c/celsius/0, f/fahrenheit/1, k/kelvin/2)
+        #[arg(long, value_parser = parse_temp_unit)]
+        temp_unit: Option<TemperatureUnit>,
     },
 
     /// Set controller registers
@@ -104,10 +144,153 @@ pub enum Commands {
         /// Manual speed percentage (0-100, switches to manual mode)
         #[arg(long, value_parser = clap::value_parser!(u8).range(0..=100), conflicts_with = "auto_speed")]
         manual_speed: Option<u8>,
+
+        /// Print one result per controller as a JSON array
+        #[arg(long)]
+        json: bool,
+
+        /// Apply a named profile (built-in: quiet, performance)
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["low_temp", "high_temp", "eco", "fan_qty", "pwm_freq"])]
+        profile: Option<String>,
+
+        /// Profile document (default: $XDG_CONFIG_HOME/jpf4826ctl/profiles.json)
+        #[arg(long, value_name = "PATH", env = "JPF4826_PROFILE_FILE")]
+        profile_file: Option<PathBuf>,
     },
 
     /// Reset the controller
     Reset,
+
+    /// Pulse the fans to locate the controller, then restore its speed setting
+    Identify {
+        /// How long to pulse (e.g. 10s, 2m; bare numbers are seconds)
+        #[arg(long, default_value = "10s", value_parser = parse_duration)]
+        duration: Duration,
+    },
+
+    /// Poll controller status continuously (daemon mode)
+    Watch {
+        /// Seconds between polls (1-3600)
+        #[arg(long, default_value = "10", value_parser = clap::value_parser!(u64).range(1..=3600))]
+        interval: u64,
+
+        /// Output one JSON document per line
+        #[arg(long)]
+        json: bool,
+
+        /// After the first snapshot, print only fields that changed
+        #[arg(long)]
+        diff: bool,
+
+        /// With --diff, print a complete snapshot every N samples
+        #[arg(long, value_name = "N", requires = "diff", value_parser = clap::value_parser!(u32).range(1..))]
+        full_every: Option<u32>,
+
+        /// Temperature unit (c/celsius/0, f/fahrenheit/1, k/kelvin/2)
+        #[arg(long, value_parser = parse_temp_unit)]
+        temp_unit: Option<TemperatureUnit>,
+
+        /// Draw speed bars and a temperature sparkline: auto (on terminals), always or never
+        #[arg(long, value_name = "WHEN", value_enum, default_value_t = BarsMode::Auto, num_args = 0..=1, default_missing_value = "always")]
+        bars: BarsMode,
+
+        /// RPM drawn as a full bar (default: the fastest fan seen)
+        #[arg(long, value_name = "RPM", value_parser = clap::value_parser!(u16).range(1..))]
+        nominal_rpm: Option<u16>,
+
+        /// Export metrics over OTLP (configured by OTEL_* env vars)
+        #[cfg(feature = "otel")]
+        #[arg(long)]
+        otel: bool,
+
+        /// OTLP/gRPC endpoint (default: OTEL_EXPORTER_OTLP_ENDPOINT)
+        #[cfg(feature = "otel")]
+        #[arg(long, requires = "otel")]
+        otel_endpoint: Option<String>,
+    },
+
+    /// Print the JSON schema of a document printed by this binary
+    Schema {
+        /// Document to describe
+        #[arg(value_enum, default_value_t = SchemaDocument::Status)]
+        document: SchemaDocument,
+
+        /// Write the schema to FILE instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Run as a background service
+    #[cfg(feature = "dbus")]
+    Daemon {
+        #[command(subcommand)]
+        command: DaemonCommands,
+    },
+}
+
+/// Services provided by the daemon command
+#[cfg(feature = "dbus")]
+#[derive(Subcommand, Debug)]
+pub enum DaemonCommands {
+    /// Expose status and control over D-Bus (io.github.jpf4826.Controller1)
+    Dbus {
+        /// Message bus to register on
+        #[arg(long, value_enum, default_value_t = DbusBus::Session)]
+        bus: DbusBus,
+
+        /// Bus address overriding --bus (e.g., unix:path=/run/dbus/custom)
+        #[arg(long)]
+        address: Option<String>,
+
+        /// Seconds between polls (1-3600)
+        #[arg(long, default_value = "10", value_parser = clap::value_parser!(u64).range(1..=3600))]
+        interval: u64,
+    },
+}
+
+/// Message buses the D-Bus service can register on
+#[cfg(feature = "dbus")]
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbusBus {
+    /// Per-user session bus
+    Session,
+    /// System-wide bus (requires a bus policy file)
+    System,
+}
+
+/// Output formats of the status command
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// JSON matching schemas/jpf4826-status-response.schema.json
+    Json,
+    /// Text in the style of lm-sensors `sensors`
+    Sensors,
+    /// JSON in the style of lm-sensors `sensors -j`
+    SensorsJson,
+    /// One `key=value` line per field, for shell scripts
+    Kv,
+}
+
+/// Documents the schema command can describe
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaDocument {
+    /// Output of `status --json` and each `watch --json` line
+    Status,
+    /// Controller configuration, as in a profile
+    Config,
+}
+
+/// When to draw bars and sparklines in text output
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BarsMode {
+    /// Only when stdout is a terminal
+    Auto,
+    /// Always, at the width in `COLUMNS` (default 80)
+    Always,
+    /// Never
+    Never,
 }
 
 /// Validates PWM frequency value
@@ -123,6 +306,91 @@ fn validate_pwm_freq(s: &str) -> Result<u32, String> {
     }
 }
 
+/// Parses a temperature unit by name, initial or number.
+///
+/// The numbers `0` and `1` are the original Celsius and Fahrenheit codes.
+fn parse_temp_unit(s: &str) -> Result<TemperatureUnit, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "0" | "c" | "celsius" => Ok(TemperatureUnit::Celsius),
+        "1" | "f" | "fahrenheit" => Ok(TemperatureUnit::Fahrenheit),
+        "2" | "k" | "kelvin" => Ok(TemperatureUnit::Kelvin),
+        _ => Err(format!(
+            "Invalid temperature unit: {}. Valid values: c, f, k (or 0, 1, 2)",
+            s
+        )),
+    }
+}
+
+/// Parses start and full speed temperatures given as `LOW:HIGH`.
+fn parse_thresholds(s: &str) -> Result<Thresholds, String> {
+    let invalid = || format!("Invalid thresholds: {}. Expected LOW:HIGH, e.g. 30:50", s);
+    let (low, high) = s.split_once(':').ok_or_else(invalid)?;
+    let low: i16 = low.trim().parse().map_err(|_| invalid())?;
+    let high: i16 = high.trim().parse().map_err(|_| invalid())?;
+    Ok(Thresholds { low, high })
+}
+
+/// Longest accepted identify duration.
+const MAX_IDENTIFY_DURATION: Duration = Duration::from_secs(3600);
+
+/// Modbus addresses given to `--addr`, in command-line order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressList(pub Vec<u8>);
+
+/// Parses comma-separated addresses and inclusive ranges, e.g. `1,2,3-6`.
+fn parse_addresses(s: &str) -> Result<AddressList, String> {
+    let parse = |value: &str| -> Result<u8, String> {
+        value
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|addr| (1..=254).contains(addr))
+            .ok_or_else(|| format!("Invalid Modbus address: {}. Valid range: 1-254", value))
+    };
+
+    let mut addrs = Vec::new();
+    for item in s.split(',') {
+        let (first, last) = match item.split_once('-') {
+            Some((first, last)) => (parse(first)?, parse(last)?),
+            None => {
+                let addr = parse(item)?;
+                (addr, addr)
+            }
+        };
+        if first > last {
+            return Err(format!("Invalid address range: {}", item));
+        }
+        for addr in first..=last {
+            if addrs.contains(&addr) {
+                return Err(format!("Modbus address {} given twice", addr));
+            }
+            addrs.push(addr);
+        }
+    }
+    Ok(AddressList(addrs))
+}
+
+/// Parses a duration of whole seconds (`10`, `10s`) or minutes (`2m`).
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (digits, scale) = match s.strip_suffix('m') {
+        Some(minutes) => (minutes, 60),
+        None => (s.strip_suffix('s').unwrap_or(s), 1),
+    };
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| format!("Invalid duration: {}. Examples: 30, 10s, 2m", s))?;
+    let duration = Duration::from_secs(value.saturating_mul(scale));
+
+    if duration.is_zero() || duration > MAX_IDENTIFY_DURATION {
+        return Err(format!(
+            "Duration {} out of range (1s-{}m)",
+            s,
+            MAX_IDENTIFY_DURATION.as_secs() / 60
+        ));
+    }
+    Ok(duration)
+}
+
 impl Cli {
     /// Validates and retrieves the serial port, either from args or environment
     pub fn get_port(&self) -> Result<String, String> {
@@ -132,10 +400,25 @@ impl Cli {
     }
 
     /// Validates and retrieves the Modbus address, either from args or environment
+    ///
+    /// Fails if a list of several addresses was given.
     pub fn get_addr(&self) -> Result<u8, String> {
-        self.addr.ok_or_else(|| {
-            "Modbus address not specified. Use --addr or set JPF4826_ADDR".to_string()
-        })
+        match self.get_addrs()?.as_slice() {
+            [addr] => Ok(*addr),
+            _ => {
+                Err("Several Modbus addresses given; only set accepts an address list".to_string())
+            }
+        }
+    }
+
+    /// Validates and retrieves the Modbus addresses, either from args or environment
+    pub fn get_addrs(&self) -> Result<Vec<u8>, String> {
+        self.addr
+            .as_ref()
+            .map(|list| list.0.clone())
+            .ok_or_else(|| {
+                "Modbus address not specified. Use --addr or set JPF4826_ADDR".to_string()
+            })
     }
 
     /// Returns the timeout as a Duration.
@@ -143,3 +426,74 @@ impl Cli {
         Duration::from_secs(self.timeout)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_temp_unit() {
+        for (input, unit) in [
+            ("0", TemperatureUnit::Celsius),
+            ("c", TemperatureUnit::Celsius),
+            ("1", TemperatureUnit::Fahrenheit),
+            ("Fahrenheit", TemperatureUnit::Fahrenheit),
+            ("2", TemperatureUnit::Kelvin),
+            ("k", TemperatureUnit::Kelvin),
+            ("KELVIN", TemperatureUnit::Kelvin),
+        ] {
+            assert_eq!(parse_temp_unit(input), Ok(unit), "{}", input);
+        }
+        assert!(parse_temp_unit("3").is_err());
+        assert!(parse_temp_unit("rankine").is_err());
+    }
+
+    #[test]
+    fn test_parse_addresses() {
+        assert_eq!(parse_addresses("7"), Ok(AddressList(vec![7])));
+        assert_eq!(
+            parse_addresses("1,2,3-6"),
+            Ok(AddressList(vec![1, 2, 3, 4, 5, 6]))
+        );
+        assert_eq!(parse_addresses("9, 4-5"), Ok(AddressList(vec![9, 4, 5])));
+        assert_eq!(parse_addresses("254-254"), Ok(AddressList(vec![254])));
+        for invalid in ["0", "255", "1,,2", "6-3", "1-x", "", "1,2-4,3"] {
+            assert!(parse_addresses(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_single_address_commands_reject_lists() {
+        let cli = Cli::try_parse_from(["jpf4826ctl", "-a", "1,3", "status"]).unwrap();
+        assert_eq!(cli.get_addrs(), Ok(vec![1, 3]));
+        assert!(cli.get_addr().is_err());
+
+        let cli = Cli::try_parse_from(["jpf4826ctl", "-a", "3", "status"]).unwrap();
+        assert_eq!(cli.get_addr(), Ok(3));
+    }
+
+    #[test]
+    fn test_temp_unit_reaches_commands() {
+        let cli = Cli::try_parse_from(["jpf4826ctl", "status", "--temp-unit", "k"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Status {
+                temp_unit: Some(TemperatureUnit::Kelvin),
+                ..
+            })
+        ));
+
+        let cli = Cli::try_parse_from(["jpf4826ctl", "watch", "--temp-unit=kelvin"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Watch {
+                temp_unit: Some(TemperatureUnit::Kelvin),
+                ..
+            })
+        ));
+
+        assert!(
+            Cli::try_parse_from(["jpf4826ctl", "get", "fan.1.rpm", "--temp-unit", "x"]).is_err()
+        );
+    }
+}
@@ -0,0 +1,649 @@
+//! Daemon command implementation.
+//!
+//! `daemon dbus` exposes the controller as a D-Bus service: the latest
+//! polled status is published as properties (with `PropertiesChanged`
+//! emitted when a poll detects a change) and control methods map to the
+//! driver. The interface is described in
+//! `dbus/io.github.jpf4826.Controller1.xml`.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use anyhow::Context;
+use jpf4826_driver::{ControllerStatus, FanStatus, Jpf4826Client, Jpf4826Error, WorkMode};
+use tokio::sync::Mutex;
+use zbus::object_server::{InterfaceRef, SignalEmitter};
+use zbus::{connection, fdo, interface, Connection};
+
+use crate::cli::DbusBus;
+
+/// Well-known bus name and interface name of the service.
+pub const BUS_NAME: &str = "io.github.jpf4826.Controller1";
+
+/// Object path of the controller object.
+pub const OBJECT_PATH: &str = "/io/github/jpf4826/Controller1";
+
+/// Options for `daemon dbus`.
+#[derive(Debug, Clone)]
+pub struct DbusArgs {
+    /// Bus to register on.
+    pub bus: DbusBus,
+    /// Bus address overriding `bus`.
+    pub address: Option<String>,
+    /// Delay between polls.
+    pub interval: Duration,
+}
+
+/// Executes `daemon dbus`.
+///
+/// Registers the service and polls the controller until SIGINT or SIGTERM
+/// is received. Failed polls are logged and retried on the next cycle.
+pub async fn execute_dbus(client: Jpf4826Client, args: DbusArgs) -> anyhow::Result<()> {
+    let service = ControllerService::new(client).await?;
+
+    let builder = match (&args.address, args.bus) {
+        (Some(address), _) => connection::Builder::address(address.as_str())?,
+        (None, DbusBus::Session) => connection::Builder::session()?,
+        (None, DbusBus::System) => connection::Builder::system()?,
+    };
+    let connection = builder
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, service)?
+        .build()
+        .await
+        .context("Failed to register D-Bus service")?;
+    log::debug!("Registered {} at {}", BUS_NAME, OBJECT_PATH);
+
+    let iface = interface_ref(&connection).await?;
+    let mut ticker = tokio::time::interval(args.interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The service was created from a fresh status; skip the immediate tick
+    ticker.tick().await;
+
+    let shutdown = crate::commands::watch::shutdown_signal();
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => break,
+            _ = ticker.tick() => {}
+        }
+        if let Err(e) = refresh(&iface).await {
+            log::warn!("Status poll failed: {:#}", e);
+        }
+    }
+
+    log::debug!("Shutting down D-Bus service");
+    Ok(())
+}
+
+/// Returns the served controller interface of `connection`.
+pub(crate) async fn interface_ref(
+    connection: &Connection,
+) -> zbus::Result<InterfaceRef<ControllerService>> {
+    connection
+        .object_server()
+        .interface::<_, ControllerService>(OBJECT_PATH)
+        .await
+}
+
+/// Polls the controller and emits `PropertiesChanged` for changed values.
+pub(crate) async fn refresh(iface: &InterfaceRef<ControllerService>) -> anyhow::Result<()> {
+    let mut service = iface.get_mut().await;
+    let status = service.client.get_mut().status().await?;
+    let previous = std::mem::replace(&mut service.status, status);
+    service
+        .emit_changes(&previous, iface.signal_emitter())
+        .await?;
+    Ok(())
+}
+
+/// Operating mode as last set through this service.
+///
+/// The controller does not report its mode reliably (in temperature mode
+/// register 0x0003 reads back the computed speed), so the mode is `unknown`
+/// until one of the mode-changing methods is called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Unknown,
+    Temperature,
+    Manual,
+}
+
+impl Mode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Mode::Unknown => "unknown",
+            Mode::Temperature => "temperature",
+            Mode::Manual => "manual",
+        }
+    }
+}
+
+/// D-Bus object wrapping a connected client.
+pub struct ControllerService {
+    // zbus requires interfaces to be `Sync`; the client is only `Send`
+    client: Mutex<Jpf4826Client>,
+    status: ControllerStatus,
+    mode: Mode,
+}
+
+impl ControllerService {
+    /// Creates the service, reading the initial status.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the initial status read fails.
+    pub async fn new(mut client: Jpf4826Client) -> anyhow::Result<Self> {
+        let status = client
+            .status()
+            .await
+            .context("Failed to read initial status")?;
+        Ok(Self {
+            client: Mutex::new(client),
+            status,
+            mode: Mode::Unknown,
+        })
+    }
+
+    /// Emits `PropertiesChanged` for every property differing from `previous`.
+    async fn emit_changes(
+        &self,
+        previous: &ControllerStatus,
+        emitter: &SignalEmitter<'_>,
+    ) -> zbus::Result<()> {
+        let current = &self.status;
+        if previous.temperature_current != current.temperature_current {
+            self.temperature_changed(emitter).await?;
+        }
+        if previous.temperature_low_threshold != current.temperature_low_threshold {
+            self.low_threshold_changed(emitter).await?;
+        }
+        if previous.temperature_high_threshold != current.temperature_high_threshold {
+            self.high_threshold_changed(emitter).await?;
+        }
+        if previous.work_mode != current.work_mode {
+            self.eco_mode_changed(emitter).await?;
+        }
+        if fan_rpms(previous) != fan_rpms(current) {
+            self.fan_rpm_changed(emitter).await?;
+        }
+        if fan_faults(previous) != fan_faults(current) {
+            self.fan_fault_changed(emitter).await?;
+        }
+        Ok(())
+    }
+
+    async fn set_mode_state(&mut self, mode: Mode, emitter: &SignalEmitter<'_>) -> fdo::Result<()> {
+        if self.mode != mode {
+            self.mode = mode;
+            self.mode_changed(emitter).await?;
+        }
+        Ok(())
+    }
+}
+
+#[interface(name = "io.github.jpf4826.Controller1")]
+impl ControllerService {
+    /// Current temperature in °C.
+    #[zbus(property)]
+    fn temperature(&self) -> i16 {
+        self.status.temperature_current.value
+    }
+
+    /// Temperature in °C at which fans start spinning.
+    #[zbus(property)]
+    fn low_threshold(&self) -> i16 {
+        self.status.temperature_low_threshold.value
+    }
+
+    /// Temperature in °C at which fans reach full speed.
+    #[zbus(property)]
+    fn high_threshold(&self) -> i16 {
+        self.status.temperature_high_threshold.value
+    }
+
+    /// Operating mode: `temperature`, `manual` or `unknown`.
+    #[zbus(property)]
+    fn mode(&self) -> String {
+        self.mode.as_str().to_string()
+    }
+
+    /// ECO mode (true = fans stop below the start temperature).
+    #[zbus(property)]
+    fn eco_mode(&self) -> bool {
+        self.status.work_mode == WorkMode::Shutdown
+    }
+
+    /// Speed of fans 1-4 in RPM.
+    #[zbus(property)]
+    fn fan_rpm(&self) -> Vec<u16> {
+        fan_rpms(&self.status)
+    }
+
+    /// Fault state of fans 1-4.
+    #[zbus(property)]
+    fn fan_fault(&self) -> Vec<bool> {
+        fan_faults(&self.status)
+    }
+
+    /// Sets a manual speed percentage (0-100), switching to manual mode.
+    async fn set_speed(
+        &mut self,
+        percent: u8,
+        #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+    ) -> fdo::Result<()> {
+        self.client
+            .get_mut()
+            .set_fan_speed(percent)
+            .await
+            .map_err(to_fdo_error)?;
+        self.set_mode_state(Mode::Manual, &emitter).await
+    }
+
+    /// Switches to `temperature` mode, or `manual` mode at `speed` percent.
+    ///
+    /// `speed` is ignored in temperature mode.
+    async fn set_mode(
+        &mut self,
+        mode: &str,
+        speed: u8,
+        #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+    ) -> fdo::Result<()> {
+        match mode {
+            "temperature" => {
+                self.client
+                    .get_mut()
+                    .set_auto_speed()
+                    .await
+                    .map_err(to_fdo_error)?;
+                self.set_mode_state(Mode::Temperature, &emitter).await
+            }
+            "manual" => {
+                self.client
+                    .get_mut()
+                    .set_manual_speed(speed)
+                    .await
+                    .map_err(to_fdo_error)?;
+                self.set_mode_state(Mode::Manual, &emitter).await
+            }
+            other => Err(fdo::Error::InvalidArgs(format!(
+                "Unknown mode {:?}, expected \"temperature\" or \"manual\"",
+                other
+            ))),
+        }
+    }
+
+    /// Sets the start and full speed temperatures in °C.
+    async fn set_thresholds(
+        &mut self,
+        low: i16,
+        high: i16,
+        #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+    ) -> fdo::Result<()> {
+        self.client
+            .get_mut()
+            .set_temperature_threshold(low, high)
+            .await
+            .map_err(to_fdo_error)?;
+
+        let previous = self.status.clone();
+        self.status.temperature_low_threshold.value = low;
+        self.status.temperature_high_threshold.value = high;
+        self.emit_changes(&previous, &emitter).await?;
+        Ok(())
+    }
+
+    /// Sets the ECO work mode.
+    async fn set_eco_mode(
+        &mut self,
+        enabled: bool,
+        #[zbus(signal_emitter)] emitter: SignalEmitter<'_>,
+    ) -> fdo::Result<()> {
+        let mode = if enabled {
+            WorkMode::Shutdown
+        } else {
+            WorkMode::MinimumSpeed
+        };
+        self.client
+            .get_mut()
+            .set_eco(mode)
+            .await
+            .map_err(to_fdo_error)?;
+
+        let previous = self.status.clone();
+        self.status.work_mode = mode;
+        self.emit_changes(&previous, &emitter).await?;
+        Ok(())
+    }
+
+    /// Resets the controller.
+    async fn reset(&mut self) -> fdo::Result<()> {
+        self.client.get_mut().reset().await.map_err(to_fdo_error)
+    }
+}
+
+fn fan_rpms(status: &ControllerStatus) -> Vec<u16> {
+    status.fans.iter().map(|fan| fan.rpm).collect()
+}
+
+fn fan_faults(status: &ControllerStatus) -> Vec<bool> {
+    status
+        .fans
+        .iter()
+        .map(|fan| fan.status == FanStatus::Fault)
+        .collect()
+}
+
+/// Maps a driver error onto a D-Bus error.
+///
+/// Argument validation becomes `InvalidArgs`, an exhausted write budget
+/// `LimitsExceeded` and an operation the controller lacks `NotSupported`.
+/// Bus, serial and controller-side failures, including a strict-mode
+/// conflict, are reported as `Failed`.
+fn to_fdo_error(err: Jpf4826Error) -> fdo::Error {
+    if err.is_timeout() {
+        fdo::Error::TimedOut(err.to_string())
+    } else if err.is_invalid_parameter() {
+        fdo::Error::InvalidArgs(err.to_string())
+    } else if err.is_write_budget_exceeded() {
+        fdo::Error::LimitsExceeded(err.to_string())
+    } else if err.is_unsupported() || err.is_function_rejected() {
+        fdo::Error::NotSupported(err.to_string())
+    } else {
+        fdo::Error::Failed(err.to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use zbus::proxy::CacheProperties;
+    use zbus::zvariant::OwnedValue;
+
+    type Registers = Arc<Mutex<HashMap<u16, u16>>>;
+
+    #[zbus::proxy(
+        interface = "io.github.jpf4826.Controller1",
+        default_service = "io.github.jpf4826.Controller1",
+        default_path = "/io/github/jpf4826/Controller1"
+    )]
+    trait Controller1 {
+        fn set_speed(&self, percent: u8) -> zbus::Result<()>;
+        fn set_mode(&self, mode: &str, speed: u8) -> zbus::Result<()>;
+        fn set_thresholds(&self, low: i16, high: i16) -> zbus::Result<()>;
+        fn set_eco_mode(&self, enabled: bool) -> zbus::Result<()>;
+        fn reset(&self) -> zbus::Result<()>;
+        #[zbus(property)]
+        fn temperature(&self) -> zbus::Result<i16>;
+        #[zbus(property)]
+        fn low_threshold(&self) -> zbus::Result<i16>;
+        #[zbus(property)]
+        fn high_threshold(&self) -> zbus::Result<i16>;
+        #[zbus(property)]
+        fn mode(&self) -> zbus::Result<String>;
+        #[zbus(property)]
+        fn eco_mode(&self) -> zbus::Result<bool>;
+        #[zbus(property)]
+        fn fan_rpm(&self) -> zbus::Result<Vec<u16>>;
+        #[zbus(property)]
+        fn fan_fault(&self) -> zbus::Result<Vec<bool>>;
+    }
+
+    /// Register image matching the driver's MockController defaults.
+    fn default_registers() -> Registers {
+        let registers = HashMap::from([
+            (0x0000, 71),     // 31°C
+            (0x0001, 0x000F), // all fans running
+            (0x0002, 0x0001), // Modbus address 1
+            (0x0003, 0xFFFF), // temperature mode
+            (0x0004, 0x465A),
+            (0x0005, 0x0001), // minimum speed work mode
+            (0x0006, 0x0004), // 4 fans
+            (0x0007, 1400),
+            (0x0008, 1400),
+            (0x0009, 1400),
+            (0x000A, 1400),
+            (0x000B, 0x0005), // 25 kHz
+            (0x000C, 70),     // 30°C
+            (0x000D, 90),     // 50°C
+            (0x000E, 0x000F), // no faults
+        ]);
+        Arc::new(Mutex::new(registers))
+    }
+
+    /// Serves a mock-backed controller on a private peer-to-peer bus.
+    async fn private_bus(registers: Registers) -> (Connection, Connection) {
+        serve(Jpf4826Client::new_mock(registers, 1).await).await
+    }
+
+    /// Serves `client` on a private peer-to-peer bus.
+    async fn serve(client: Jpf4826Client) -> (Connection, Connection) {
+        let service = ControllerService::new(client).await.unwrap();
+        let (server_socket, client_socket) = tokio::net::UnixStream::pair().unwrap();
+
+        let server = connection::Builder::unix_stream(server_socket)
+            .server(zbus::Guid::generate())
+            .unwrap()
+            .p2p()
+            .serve_at(OBJECT_PATH, service)
+            .unwrap()
+            .build();
+        let client = connection::Builder::unix_stream(client_socket)
+            .p2p()
+            .build();
+        tokio::try_join!(server, client).unwrap()
+    }
+
+    async fn proxy(connection: &Connection) -> Controller1Proxy<'_> {
+        Controller1Proxy::builder(connection)
+            .cache_properties(CacheProperties::No)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    fn register(registers: &Registers, addr: u16) -> u16 {
+        registers.lock().unwrap()[&addr]
+    }
+
+    /// Waits for the next PropertiesChanged signal and returns the changed names.
+    async fn next_properties_changed(stream: &mut zbus::MessageStream) -> Vec<String> {
+        while let Some(message) = stream.next().await {
+            let message = message.unwrap();
+            let header = message.header();
+            if header.member().map(|m| m.as_str()) == Some("PropertiesChanged") {
+                let (_, changed, _): (String, HashMap<String, OwnedValue>, Vec<String>) =
+                    message.body().deserialize().unwrap();
+                let mut names: Vec<_> = changed.into_keys().collect();
+                names.sort();
+                return names;
+            }
+        }
+        panic!("connection closed");
+    }
+
+    #[tokio::test]
+    async fn test_properties_reflect_status() {
+        let registers = default_registers();
+        registers.lock().unwrap().insert(0x000E, 0x000B); // fan 3 fault
+        let (_server, client) = private_bus(registers).await;
+        let proxy = proxy(&client).await;
+
+        assert_eq!(proxy.temperature().await.unwrap(), 31);
+        assert_eq!(proxy.low_threshold().await.unwrap(), 30);
+        assert_eq!(proxy.high_threshold().await.unwrap(), 50);
+        assert_eq!(proxy.mode().await.unwrap(), "unknown");
+        assert!(!proxy.eco_mode().await.unwrap());
+        assert_eq!(proxy.fan_rpm().await.unwrap(), vec![1400; 4]);
+        assert_eq!(
+            proxy.fan_fault().await.unwrap(),
+            vec![false, false, true, false]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_methods_map_to_driver() {
+        let registers = default_registers();
+        let (_server, client) = private_bus(registers.clone()).await;
+        let proxy = proxy(&client).await;
+
+        proxy.set_speed(75).await.unwrap();
+        assert_eq!(register(&registers, 0x0003), 75);
+        assert_eq!(proxy.mode().await.unwrap(), "manual");
+
+        proxy.set_mode("temperature", 0).await.unwrap();
+        assert_eq!(register(&registers, 0x0003), 0xFFFF);
+        assert_eq!(proxy.mode().await.unwrap(), "temperature");
+
+        proxy.set_mode("manual", 40).await.unwrap();
+        assert_eq!(register(&registers, 0x0003), 40);
+
+        proxy.set_thresholds(25, 40).await.unwrap();
+        assert_eq!(register(&registers, 0x000C), 65);
+        assert_eq!(register(&registers, 0x000D), 80);
+        assert_eq!(proxy.low_threshold().await.unwrap(), 25);
+
+        proxy.set_eco_mode(true).await.unwrap();
+        assert_eq!(register(&registers, 0x0005), 0x0000);
+
+        proxy.reset().await.unwrap();
+        assert_eq!(register(&registers, 0x0020), 0x00AA);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_arguments_are_rejected() {
+        let (_server, client) = private_bus(default_registers()).await;
+        let proxy = proxy(&client).await;
+
+        for result in [
+            proxy.set_speed(150).await,
+            proxy.set_mode("turbo", 0).await,
+            proxy.set_thresholds(50, 30).await,
+        ] {
+            match result {
+                Err(zbus::Error::MethodError(name, _, _)) => {
+                    assert_eq!(name.as_str(), "org.freedesktop.DBus.Error.InvalidArgs")
+                }
+                other => panic!("expected InvalidArgs, got {:?}", other),
+            }
+        }
+    }
+
+    fn error_name<T: std::fmt::Debug>(result: zbus::Result<T>) -> String {
+        match result {
+            Err(zbus::Error::MethodError(name, _, _)) => name.to_string(),
+            other => panic!("expected a method error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strict_mode_conflict_is_failed() {
+        let registers = default_registers();
+        let mut driver = Jpf4826Client::new_mock(registers.clone(), 1).await;
+        driver.set_strict_mode(true);
+        let (_server, client) = serve(driver).await;
+        let proxy = proxy(&client).await;
+
+        assert_eq!(
+            error_name(proxy.set_speed(75).await),
+            "org.freedesktop.DBus.Error.Failed"
+        );
+        assert_eq!(register(&registers, 0x0003), 0xFFFF);
+
+        // Selecting manual mode explicitly is never a conflict
+        proxy.set_mode("manual", 40).await.unwrap();
+        assert_eq!(register(&registers, 0x0003), 40);
+        assert_eq!(proxy.mode().await.unwrap(), "manual");
+        proxy.set_speed(75).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_budget_is_limits_exceeded() {
+        let mut driver = Jpf4826Client::new_mock(default_registers(), 1).await;
+        driver
+            .set_write_budget(Some(jpf4826_driver::budget::WriteBudget::new(
+                1,
+                Duration::from_secs(60),
+            )))
+            .unwrap();
+        let (_server, client) = serve(driver).await;
+        let proxy = proxy(&client).await;
+
+        proxy.set_thresholds(25, 40).await.unwrap();
+        assert_eq!(
+            error_name(proxy.set_thresholds(26, 41).await),
+            "org.freedesktop.DBus.Error.LimitsExceeded"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_emits_properties_changed() {
+        let registers = default_registers();
+        let (server, client) = private_bus(registers.clone()).await;
+        let mut signals = zbus::MessageStream::from(&client);
+        let iface = interface_ref(&server).await.unwrap();
+
+        registers.lock().unwrap().insert(0x0000, 76); // 36°C
+        registers.lock().unwrap().insert(0x0009, 0);
+        refresh(&iface).await.unwrap();
+
+        let first = next_properties_changed(&mut signals).await;
+        let second = next_properties_changed(&mut signals).await;
+        let mut changed = [first, second].concat();
+        changed.sort();
+        assert_eq!(changed, vec!["FanRpm", "Temperature"]);
+        assert_eq!(proxy(&client).await.temperature().await.unwrap(), 36);
+    }
+
+    #[tokio::test]
+    async fn test_introspection_matches_xml() {
+        let (_server, client) = private_bus(default_registers()).await;
+        let introspectable = zbus::fdo::IntrospectableProxy::builder(&client)
+            .destination(BUS_NAME)
+            .unwrap()
+            .path(OBJECT_PATH)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let live = introspectable.introspect().await.unwrap();
+        let expected = include_str!("../../dbus/io.github.jpf4826.Controller1.xml");
+
+        assert_eq!(
+            interface_lines(&live),
+            interface_lines(expected),
+            "live introspection:\n{}",
+            live
+        );
+    }
+
+    /// Returns the trimmed lines of the controller interface element,
+    /// without comments.
+    fn interface_lines(xml: &str) -> Vec<String> {
+        let start = xml
+            .find(r#"<interface name="io.github.jpf4826.Controller1">"#)
+            .expect("interface missing");
+        let end = start + xml[start..].find("</interface>").unwrap();
+
+        let mut element = &xml[start..end];
+        let mut stripped = String::new();
+        while let Some(open) = element.find("<!--") {
+            stripped.push_str(&element[..open]);
+            let close = element[open..].find("-->").unwrap();
+            element = &element[open + close + 3..];
+        }
+        stripped.push_str(element);
+
+        stripped
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
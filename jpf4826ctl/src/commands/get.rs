@@ -0,0 +1,38 @@
+//! Get command implementation.
+
+// Rust guideline compliant 2026-10-16
+
+use crate::output::{convert_temperatures, status_field};
+use jpf4826_driver::{Jpf4826Client, TemperatureUnit};
+
+/// Executes the get command.
+///
+/// Reads the controller status and prints the unquoted value of one field
+/// of the `kv` status format, so `$(jpf4826ctl get KEY)` yields the same
+/// value `status --format kv` prints for `KEY`.
+///
+/// # Arguments
+///
+/// * `client` - Connected JPF4826 client
+/// * `key` - Field key, one of [`STATUS_KEYS`](crate::output::STATUS_KEYS)
+/// * `temp_unit` - Temperature unit to convert to, Celsius if `None`
+///
+/// # Errors
+///
+/// Returns error if the status cannot be read or the controller has no
+/// such fan.
+pub async fn execute(
+    client: &mut Jpf4826Client,
+    key: &str,
+    temp_unit: Option<TemperatureUnit>,
+) -> anyhow::Result<()> {
+    let mut status = client.status().await?;
+    if let Some(unit) = temp_unit {
+        status = convert_temperatures(status, unit);
+    }
+
+    let value = status_field(&status, key)
+        .ok_or_else(|| anyhow::anyhow!("Field '{}' is not reported by the controller", key))?;
+    println!("{}", value);
+    Ok(())
+}
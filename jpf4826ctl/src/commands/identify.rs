@@ -0,0 +1,78 @@
+//! Identify command implementation.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use crate::interrupt::{self, StopRequest};
+use jpf4826_driver::Jpf4826Client;
+
+/// Executes the identify command.
+///
+/// Pulses the fans for `duration` so the controller can be located, then
+/// restores the previous speed setting. The first SIGINT or SIGTERM stops
+/// the pulses early and restores the setting as well (see
+/// [`interrupt`](crate::interrupt)).
+///
+/// # Arguments
+///
+/// * `client` - Connected JPF4826 client
+/// * `duration` - How long to pulse the fans
+///
+/// # Errors
+///
+/// Returns error if the speed setting cannot be read, a pulse fails or the
+/// setting cannot be restored, or
+/// [`Interrupted`](crate::interrupt::Interrupted) after a signal.
+pub async fn execute(client: &mut Jpf4826Client, duration: Duration) -> anyhow::Result<()> {
+    println!(
+        "Pulsing fans for {}s, press Ctrl+C to stop early...",
+        duration.as_secs()
+    );
+    interrupt::run_interruptible(async |stop| pulse(client, duration, stop).await).await?;
+    println!("✓ Speed setting restored");
+    Ok(())
+}
+
+/// Pulses the fans until `duration` elapses or a stop is requested.
+async fn pulse(
+    client: &mut Jpf4826Client,
+    duration: Duration,
+    stop: StopRequest,
+) -> anyhow::Result<()> {
+    client.identify_until(duration, stop.requested()).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::{finish, stop_channel, Interrupted, Signal};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_interrupted_identify_restores_speed() {
+        let registers = Arc::new(Mutex::new(HashMap::from([(0x0003, 0xFFFF)])));
+        let mut client = Jpf4826Client::new_mock(registers.clone(), 1).await;
+        let (sender, stop) = stop_channel();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(1500)).await;
+            sender.send_replace(Some(Signal::Interrupt));
+        });
+
+        let err = finish(stop, async |stop| {
+            pulse(&mut client, Duration::from_secs(60), stop).await
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Interrupted>().unwrap(),
+            Interrupted(Signal::Interrupt)
+        );
+        let written = client.mock_probe().unwrap().write_log();
+        assert_eq!(written, vec![(0x0003, 100), (0x0003, 20), (0x0003, 0xFFFF)]);
+        assert_eq!(registers.lock().unwrap()[&0x0003], 0xFFFF);
+    }
+}
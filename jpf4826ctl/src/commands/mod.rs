@@ -2,6 +2,12 @@
 
 // Rust guideline compliant 2026-01-06
 
+#[cfg(feature = "dbus")]
+pub mod daemon;
+pub mod get;
+pub mod identify;
 pub mod reset;
+pub mod schema;
 pub mod set;
 pub mod status;
+pub mod watch;
@@ -0,0 +1,125 @@
+//! Schema command implementation.
+
+// Rust guideline compliant 2026-10-16
+
+use crate::cli::SchemaDocument;
+use anyhow::Context;
+use jpf4826_driver::schema::{schema, Document, SCHEMA_VERSION};
+use std::path::Path;
+
+/// Executes the schema command.
+///
+/// Prints the JSON schema of `document` as built into this binary, or
+/// writes it to `output`. The schema version is reported on stderr so
+/// stdout stays a valid JSON document.
+///
+/// # Arguments
+///
+/// * `document` - Document to describe
+/// * `output` - File to write instead of stdout
+///
+/// # Errors
+///
+/// Returns an error if `output` cannot be written.
+pub fn execute(document: SchemaDocument, output: Option<&Path>) -> anyhow::Result<()> {
+    let schema = render(document)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, schema)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "Wrote {} schema version {} to {}",
+                name(document),
+                SCHEMA_VERSION,
+                path.display()
+            );
+        }
+        None => {
+            eprintln!("Schema version: {}", SCHEMA_VERSION);
+            print!("{}", schema);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the schema of `document` as pretty-printed JSON with a trailing
+/// newline.
+fn render(document: SchemaDocument) -> anyhow::Result<String> {
+    let document = match document {
+        SchemaDocument::Status => Document::Status,
+        SchemaDocument::Config => Document::Config,
+    };
+    Ok(serde_json::to_string_pretty(&schema(document))? + "\n")
+}
+
+fn name(document: SchemaDocument) -> &'static str {
+    match document {
+        SchemaDocument::Status => "status",
+        SchemaDocument::Config => "config",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::format_status_json;
+    use jpf4826_driver::schema::VERSION_KEYWORD;
+    use jpf4826_driver::{ControllerConfig, Jpf4826Client};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    fn validator(document: SchemaDocument) -> jsonschema::Validator {
+        let schema: serde_json::Value = serde_json::from_str(&render(document).unwrap()).unwrap();
+        assert_eq!(schema[VERSION_KEYWORD], SCHEMA_VERSION);
+        jsonschema::validator_for(&schema).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_status_schema_validates_status_output() {
+        let registers = HashMap::from([
+            (0x0000, 71),
+            (0x0001, 0x000F),
+            (0x0002, 0x0001),
+            (0x0003, 0xFFFF),
+            (0x0004, 0x465A),
+            (0x0005, 0x0001),
+            (0x0006, 0x0004),
+            (0x0007, 1400),
+            (0x0008, 1400),
+            (0x0009, 0),
+            (0x000A, 1400),
+            (0x000B, 0x0005),
+            (0x000C, 70),
+            (0x000D, 90),
+            (0x000E, 0x000B),
+        ]);
+        let mut client = Jpf4826Client::new_mock(Arc::new(Mutex::new(registers)), 1).await;
+        let status = client.status().await.unwrap();
+        let output: serde_json::Value =
+            serde_json::from_str(&format_status_json(&status).unwrap()).unwrap();
+
+        let validator = validator(SchemaDocument::Status);
+
+        if let Err(error) = validator.validate(&output) {
+            panic!("Status output does not match printed schema:\n{}", error);
+        }
+    }
+
+    #[test]
+    fn test_config_schema_validates_factory_config() {
+        let config = serde_json::to_value(ControllerConfig::factory()).unwrap();
+
+        assert!(validator(SchemaDocument::Config).is_valid(&config));
+    }
+
+    #[test]
+    fn test_output_file() {
+        let path = std::env::temp_dir().join(format!("jpf4826-schema-{}.json", std::process::id()));
+
+        execute(SchemaDocument::Config, Some(&path)).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, render(SchemaDocument::Config).unwrap());
+    }
+}
@@ -1,8 +1,33 @@
 //! Set command implementation.
+//!
+//! The requested changes are first turned into a write plan by [`plan`]:
+//! settings in adjacent registers share one Write Multiple Registers frame,
+//! a threshold pair goes to the combined register 0x0004, and a Modbus
+//! address change runs last because the controller only answers on the new
+//! address afterwards. The plan is then executed in order.
+//!
+//! A `--profile` is applied before the plan through
+//! [`Jpf4826Client::apply_profile`], which skips settings the controller
+//! already holds. Profiles come from the driver's built-ins or a JSON
+//! profile document (see [`load_profiles`]).
+//!
+//! With several addresses, [`execute_many`] applies the same plan to each
+//! controller in turn over one connection and reports per setting, carrying
+//! on past controllers that fail.
 
-// Rust guideline compliant 2026-01-16
+// Rust guideline compliant 2026-10-16
 
-use jpf4826_driver::{Jpf4826Client, PwmFrequency, WorkMode};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use jpf4826_driver::conversions::encode_combined_temperature;
+use jpf4826_driver::manager::{Jpf4826Manager, Target};
+use jpf4826_driver::profile::Thresholds;
+use jpf4826_driver::registers::RegisterAddress;
+use jpf4826_driver::{
+    ApplyReport, ControllerConfig, Jpf4826Client, Profile, ProfileSet, PwmFrequency, WorkMode,
+};
+use serde_json::json;
 
 /// Arguments for the set command.
 #[derive(Debug)]
@@ -15,6 +40,8 @@ pub struct SetArgs {
     pub fan_qty: Option<u8>,
     pub pwm_freq: Option<u32>,
     pub manual_speed: Option<u8>,
+    pub profile: Option<String>,
+    pub profile_file: Option<PathBuf>,
 }
 
 impl SetArgs {
@@ -28,105 +55,1049 @@ impl SetArgs {
             && self.fan_qty.is_none()
             && self.pwm_freq.is_none()
             && self.manual_speed.is_none()
+            && self.profile.is_none()
     }
 }
 
-/// Executes the set command.
-///
-/// Applies one or more configuration changes to the controller.
-///
-/// # Arguments
-///
-/// * `client` - Connected JPF4826 client
-/// * `args` - Set command arguments
-pub async fn execute(client: &mut Jpf4826Client, args: SetArgs) -> anyhow::Result<()> {
-    let mut operations_count = 0;
+/// A single register value requested on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Automatic temperature mode (0xFFFF in register 0x0003).
+    AutoSpeed,
+    /// Manual speed percentage (register 0x0003).
+    ManualSpeed(u8),
+    /// Start and full speed temperature (combined register 0x0004).
+    Thresholds { low: i16, high: i16 },
+    /// ECO/work mode (register 0x0005).
+    Eco(WorkMode),
+    /// Fan quantity, 0 disables fault detection (register 0x0006).
+    FanQuantity(u8),
+    /// PWM frequency (register 0x000B).
+    PwmFrequency(PwmFrequency),
+}
 
-    // Set automatic temperature mode
-    if args.auto_speed {
-        client.set_auto_speed().await?;
-        operations_count += 1;
-        println!("✓ Operating mode set to Temperature (automatic)");
+impl Change {
+    /// Returns the register written by this change.
+    pub fn register(self) -> RegisterAddress {
+        match self {
+            Change::AutoSpeed | Change::ManualSpeed(_) => RegisterAddress::ManualSpeedControl,
+            Change::Thresholds { .. } => RegisterAddress::CombinedTemperature,
+            Change::Eco(_) => RegisterAddress::WorkMode,
+            Change::FanQuantity(_) => RegisterAddress::FanQuantity,
+            Change::PwmFrequency(_) => RegisterAddress::PwmFrequency,
+        }
     }
 
-    // Set Modbus address
-    if let Some(addr) = args.modbus_addr {
-        client.set_addr(addr).await?;
-        operations_count += 1;
-        println!("✓ Modbus address set to {}", addr);
+    /// Returns the raw register value.
+    pub fn value(self) -> u16 {
+        match self {
+            Change::AutoSpeed => 0xFFFF,
+            Change::ManualSpeed(speed) => u16::from(speed),
+            Change::Thresholds { low, high } => encode_combined_temperature(low, high),
+            Change::Eco(mode) => mode.to_register_value(),
+            Change::FanQuantity(qty) => u16::from(qty),
+            Change::PwmFrequency(freq) => freq.to_register_value(),
+        }
     }
 
-    // Set temperature thresholds (can be set individually or together)
-    match (args.low_temp, args.high_temp) {
-        (Some(low), Some(high)) => {
-            // Set both thresholds at once
-            client.set_temperature_threshold(low, high).await?;
-            operations_count += 1;
-            println!(
-                "✓ Temperature thresholds set: {}°C (low) to {}°C (high)",
+    /// Returns the name of the option requesting this change.
+    fn option(self) -> &'static str {
+        match self {
+            Change::AutoSpeed => "auto-speed",
+            Change::ManualSpeed(_) => "manual-speed",
+            Change::Thresholds { .. } => "thresholds",
+            Change::Eco(_) => "eco",
+            Change::FanQuantity(_) => "fan-qty",
+            Change::PwmFrequency(_) => "pwm-freq",
+        }
+    }
+
+    /// Returns the confirmation line printed once the change is applied.
+    fn describe(self) -> String {
+        match self {
+            Change::AutoSpeed => "Operating mode set to Temperature (automatic)".to_string(),
+            Change::ManualSpeed(speed) => {
+                format!("Manual speed set to {}% (manual mode enabled)", speed)
+            }
+            Change::Thresholds { low, high } => format!(
+                "Temperature thresholds set: {}°C (low) to {}°C (high)",
                 low, high
-            );
+            ),
+            Change::Eco(WorkMode::Shutdown) => {
+                "Work mode set to Shutdown (ECO on: fans stop below start temperature)".to_string()
+            }
+            Change::Eco(WorkMode::MinimumSpeed) => {
+                "Work mode set to Minimum Speed (ECO off: fans keep 20% below start temperature)"
+                    .to_string()
+            }
+            Change::FanQuantity(0) => "Fault detection disabled".to_string(),
+            Change::FanQuantity(qty) => format!("Fan quantity set to {}", qty),
+            Change::PwmFrequency(freq) => format!("PWM frequency set to {} Hz", freq.to_hz()),
         }
-        (Some(low), None) => {
-            // Set only low threshold
-            client.set_start_temperature(low).await?;
-            operations_count += 1;
-            println!("✓ Start temperature set to {}°C", low);
+    }
+}
+
+/// Returns the changes a profile configuration asks for.
+fn config_changes(config: &ControllerConfig) -> Vec<Change> {
+    let mut changes = Vec::new();
+    if let Some(Thresholds { low, high }) = config.thresholds {
+        changes.push(Change::Thresholds { low, high });
+    }
+    if let Some(mode) = config.work_mode {
+        changes.push(Change::Eco(mode));
+    }
+    if let Some(qty) = config.fan_count {
+        changes.push(Change::FanQuantity(qty));
+    }
+    if let Some(freq) = config.pwm_frequency {
+        changes.push(Change::PwmFrequency(freq));
+    }
+    changes
+}
+
+/// One step of a write plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// Writes changes to consecutive registers in one transaction.
+    ///
+    /// A single change uses Write Single Register (0x06), several use
+    /// Write Multiple Registers (0x10).
+    Write(Vec<Change>),
+    /// Sets only the start temperature; the full speed threshold is read
+    /// first to validate the new value against it.
+    StartTemperature(i16),
+    /// Sets only the full speed temperature; the start threshold is read
+    /// first to validate the new value against it.
+    FullSpeedTemperature(i16),
+    /// Changes the controller's Modbus address.
+    ModbusAddress(u8),
+}
+
+impl Operation {
+    /// Returns the names of the options the operation applies.
+    fn options(&self) -> Vec<&'static str> {
+        match self {
+            Operation::Write(changes) => changes.iter().map(|change| change.option()).collect(),
+            Operation::StartTemperature(_) => vec!["low-temp"],
+            Operation::FullSpeedTemperature(_) => vec!["high-temp"],
+            Operation::ModbusAddress(_) => vec!["modbus-addr"],
         }
-        (None, Some(high)) => {
-            // Set only high threshold
-            client.set_full_speed_temperature(high).await?;
-            operations_count += 1;
-            println!("✓ Full speed temperature set to {}°C", high);
+    }
+
+    /// Returns the number of Modbus transactions the operation takes.
+    pub fn transactions(&self) -> usize {
+        match self {
+            Operation::Write(_) | Operation::ModbusAddress(_) => 1,
+            Operation::StartTemperature(_) | Operation::FullSpeedTemperature(_) => 2,
         }
-        (None, None) => {}
     }
+}
 
-    // Set ECO mode
+/// Computes the ordered operations applying `args`.
+///
+/// # Errors
+///
+/// Returns error if the PWM frequency is not supported or the high
+/// threshold is not above the low threshold.
+pub fn plan(args: &SetArgs) -> anyhow::Result<Vec<Operation>> {
+    let mut changes = Vec::new();
+    if args.auto_speed {
+        changes.push(Change::AutoSpeed);
+    }
+    if let Some(speed) = args.manual_speed {
+        changes.push(Change::ManualSpeed(speed));
+    }
+    if let (Some(low), Some(high)) = (args.low_temp, args.high_temp) {
+        if high <= low {
+            anyhow::bail!(
+                "High temperature {}°C must be greater than low temperature {}°C",
+                high,
+                low
+            );
+        }
+        changes.push(Change::Thresholds { low, high });
+    }
     if let Some(eco) = args.eco {
         let work_mode = match eco {
             0 => WorkMode::MinimumSpeed,
             1 => WorkMode::Shutdown,
             _ => unreachable!("clap should validate this"),
         };
-        client.set_eco(work_mode).await?;
-        operations_count += 1;
-        println!("✓ ECO mode set to {:?}", work_mode);
+        changes.push(Change::Eco(work_mode));
     }
-
-    // Set fan quantity
     if let Some(qty) = args.fan_qty {
-        client.set_fan_count(qty).await?;
-        operations_count += 1;
-        if qty == 0 {
-            println!("✓ Fault detection disabled");
-        } else {
-            println!("✓ Fan quantity set to {}", qty);
-        }
+        changes.push(Change::FanQuantity(qty));
     }
-
-    // Set PWM frequency
     if let Some(freq_hz) = args.pwm_freq {
         let freq = PwmFrequency::from_hz(freq_hz)
             .ok_or_else(|| anyhow::anyhow!("Invalid PWM frequency: {}", freq_hz))?;
-        client.set_pwm_frequency(freq).await?;
-        operations_count += 1;
-        println!("✓ PWM frequency set to {} Hz", freq_hz);
+        changes.push(Change::PwmFrequency(freq));
     }
 
-    // Set manual speed (automatically switches to manual mode)
-    if let Some(speed) = args.manual_speed {
-        client.set_fan_speed(speed).await?;
-        operations_count += 1;
-        println!("✓ Manual speed set to {}% (manual mode enabled)", speed);
+    // Group runs of adjacent registers into one frame each
+    changes.sort_by_key(|change| change.register().addr());
+    let mut operations = Vec::new();
+    let mut group: Vec<Change> = Vec::new();
+    for change in changes {
+        if let Some(last) = group.last() {
+            if change.register().addr() != last.register().addr() + 1 {
+                operations.push(Operation::Write(std::mem::take(&mut group)));
+            }
+        }
+        group.push(change);
+    }
+    if !group.is_empty() {
+        operations.push(Operation::Write(group));
+    }
+
+    // A single threshold needs the other one from the controller
+    match (args.low_temp, args.high_temp) {
+        (Some(low), None) => operations.push(Operation::StartTemperature(low)),
+        (None, Some(high)) => operations.push(Operation::FullSpeedTemperature(high)),
+        _ => {}
+    }
+
+    // Later requests would go to the old address, so this comes last
+    if let Some(addr) = args.modbus_addr {
+        operations.push(Operation::ModbusAddress(addr));
+    }
+
+    Ok(operations)
+}
+
+/// Default location of the profile document.
+fn default_profile_file() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("jpf4826ctl").join("profiles.json"))
+}
+
+/// Loads user-defined profiles from `path`, or from the default location.
+///
+/// A missing file at the default location yields an empty set; an
+/// explicitly given file must exist.
+///
+/// # Errors
+///
+/// Returns error if the file cannot be read or is not a valid profile
+/// document.
+pub fn load_profiles(path: Option<&Path>) -> anyhow::Result<ProfileSet> {
+    let (path, required) = match path {
+        Some(path) => (path.to_path_buf(), true),
+        None => match default_profile_file() {
+            Some(path) => (path, false),
+            None => return Ok(ProfileSet::default()),
+        },
+    };
+
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if !required && err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(ProfileSet::default());
+        }
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("Failed to read profile file {}", path.display()))
+        }
+    };
+    serde_json::from_str(&text).with_context(|| format!("Invalid profile file {}", path.display()))
+}
+
+/// Finds the profile called `name` among `profiles` and the built-ins.
+///
+/// # Errors
+///
+/// Returns error listing the available profiles if none matches.
+pub fn find_profile(profiles: &ProfileSet, name: &str) -> anyhow::Result<Profile> {
+    profiles.resolve(name).ok_or_else(|| {
+        let mut names: Vec<String> = Profile::builtins()
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+        for profile in profiles.iter() {
+            if !names.contains(&profile.name) {
+                names.push(profile.name.clone());
+            }
+        }
+        anyhow::anyhow!(
+            "Unknown profile '{}' (available: {})",
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+/// Prints the outcome of applying `profile`.
+fn print_profile_report(profile: &Profile, report: &ApplyReport) {
+    println!("Profile '{}':", profile.name);
+    for change in config_changes(&profile.config) {
+        if report.written.contains(&change.register()) {
+            println!("✓ {}", change.describe());
+        } else {
+            println!("= {} (unchanged)", change.describe());
+        }
+    }
+}
+
+/// Executes the set command.
+///
+/// Applies one or more configuration changes to the controller using as
+/// few Modbus transactions as possible.
+///
+/// # Arguments
+///
+/// * `client` - Connected JPF4826 client
+/// * `args` - Set command arguments
+pub async fn execute(client: &mut Jpf4826Client, args: SetArgs) -> anyhow::Result<()> {
+    let profile = match &args.profile {
+        Some(name) => Some(find_profile(
+            &load_profiles(args.profile_file.as_deref())?,
+            name,
+        )?),
+        None => None,
+    };
+    let operations = plan(&args)?;
+    let mut settings = operations
+        .iter()
+        .map(|operation| match operation {
+            Operation::Write(changes) => changes.len(),
+            _ => 1,
+        })
+        .sum::<usize>();
+    let mut transactions = 0;
+
+    if let Some(profile) = &profile {
+        let report = client
+            .apply_profile(profile)
+            .await
+            .with_context(|| format!("Failed to apply profile '{}'", profile.name))?;
+        print_profile_report(profile, &report);
+        settings += report.written.len();
+        // The current settings are read once before writing
+        transactions += 1 + report.transactions;
     }
 
-    if operations_count > 0 {
+    transactions += apply(client, &operations).await?;
+
+    if settings > 0 {
         println!(
-            "\n{} operation(s) completed successfully.",
-            operations_count
+            "\n{} setting(s) applied in {} Modbus transaction(s).",
+            settings, transactions
         );
     }
 
     Ok(())
 }
+
+/// Runs `operations` in order, returning the number of transactions used.
+async fn apply(client: &mut Jpf4826Client, operations: &[Operation]) -> anyhow::Result<usize> {
+    let mut transactions = 0;
+
+    for operation in operations {
+        run(client, operation).await?;
+        match operation {
+            Operation::Write(changes) => {
+                for change in changes {
+                    println!("✓ {}", change.describe());
+                }
+            }
+            Operation::StartTemperature(low) => {
+                println!("✓ Start temperature set to {}°C", low);
+            }
+            Operation::FullSpeedTemperature(high) => {
+                println!("✓ Full speed temperature set to {}°C", high);
+            }
+            Operation::ModbusAddress(addr) => {
+                println!("✓ Modbus address set to {}", addr);
+            }
+        }
+        transactions += operation.transactions();
+    }
+
+    Ok(transactions)
+}
+
+/// Performs one operation of a plan.
+async fn run(client: &mut Jpf4826Client, operation: &Operation) -> anyhow::Result<()> {
+    match operation {
+        Operation::Write(changes) => {
+            let register = changes[0].register();
+            let result = if let [change] = changes.as_slice() {
+                client.write(register, change.value()).await
+            } else {
+                let values: Vec<u16> = changes.iter().map(|change| change.value()).collect();
+                client.write_multiple(register, &values).await
+            };
+            result
+                .with_context(|| format!("Failed to write register 0x{:04X}", register.addr()))?;
+        }
+        Operation::StartTemperature(low) => client.set_start_temperature(*low).await?,
+        Operation::FullSpeedTemperature(high) => client.set_full_speed_temperature(*high).await?,
+        Operation::ModbusAddress(addr) => client.set_addr(*addr).await?,
+    }
+    Ok(())
+}
+
+/// Outcome of one setting on one controller of a multi-address set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldOutcome {
+    /// Written to the controller.
+    Applied,
+    /// Already held by the controller (profiles only).
+    Unchanged,
+    /// The write failed with this error.
+    Failed(String),
+    /// Not attempted after an earlier failure on the same controller.
+    Skipped,
+}
+
+impl FieldOutcome {
+    fn label(&self) -> &'static str {
+        match self {
+            FieldOutcome::Applied => "applied",
+            FieldOutcome::Unchanged => "unchanged",
+            FieldOutcome::Failed(_) => "failed",
+            FieldOutcome::Skipped => "skipped",
+        }
+    }
+}
+
+/// Result of a multi-address set on one controller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceReport {
+    pub address: u8,
+    /// Outcome per option, in the order applied.
+    pub fields: Vec<(&'static str, FieldOutcome)>,
+    /// First error on this controller, including a failed connection.
+    pub error: Option<String>,
+}
+
+impl DeviceReport {
+    /// Returns whether every setting reached the controller.
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Executes the set command on several controllers of one bus.
+///
+/// Connects once and applies the same profile and plan to each address in
+/// turn. A controller that fails does not stop the others; its remaining
+/// settings are skipped.
+///
+/// # Arguments
+///
+/// * `manager` - Manager to open the port through
+/// * `port` - Serial port of the controllers
+/// * `addrs` - Modbus addresses, in the order to configure them
+/// * `args` - Set command arguments
+/// * `json` - Print a JSON array instead of a table
+///
+/// # Errors
+///
+/// Returns error if the arguments are invalid, `--modbus-addr` is given
+/// with several addresses, or any controller failed.
+pub async fn execute_many(
+    manager: &Jpf4826Manager,
+    port: &str,
+    addrs: &[u8],
+    args: SetArgs,
+    json: bool,
+) -> anyhow::Result<()> {
+    if args.modbus_addr.is_some() && addrs.len() > 1 {
+        anyhow::bail!("--modbus-addr cannot be used with several addresses in --addr");
+    }
+    let profile = match &args.profile {
+        Some(name) => Some(find_profile(
+            &load_profiles(args.profile_file.as_deref())?,
+            name,
+        )?),
+        None => None,
+    };
+    let operations = plan(&args)?;
+
+    let reports = apply_many(manager, port, addrs, profile.as_ref(), &operations).await?;
+    if json {
+        println!("{}", format_reports_json(&reports));
+    } else {
+        print!("{}", format_reports_table(&reports));
+    }
+
+    let failed = reports.iter().filter(|report| !report.is_ok()).count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} controller(s) failed", failed, reports.len());
+    }
+    Ok(())
+}
+
+/// Applies `profile` and `operations` to each address on `port`.
+///
+/// # Errors
+///
+/// Returns error if an address cannot be added to `manager`.
+pub async fn apply_many(
+    manager: &Jpf4826Manager,
+    port: &str,
+    addrs: &[u8],
+    profile: Option<&Profile>,
+    operations: &[Operation],
+) -> anyhow::Result<Vec<DeviceReport>> {
+    let mut reports = Vec::with_capacity(addrs.len());
+    for &address in addrs {
+        let target = Target::new(port, address);
+        let name = target.name();
+        manager.add(target)?;
+        let handle = manager.get(&name).expect("added above");
+
+        let result = handle
+            .with_client(async |client| Ok(apply_device(client, profile, operations).await))
+            .await;
+        let report = match result {
+            Ok((fields, error)) => DeviceReport {
+                address,
+                fields,
+                error,
+            },
+            // The port could not be opened; nothing was attempted
+            Err(e) => DeviceReport {
+                address,
+                fields: field_names(profile, operations)
+                    .into_iter()
+                    .map(|name| (name, FieldOutcome::Skipped))
+                    .collect(),
+                error: Some(e.to_string()),
+            },
+        };
+        log::debug!("Controller {}: {:?}", address, report);
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+/// Returns the option names reported for `profile` and `operations`.
+fn field_names(profile: Option<&Profile>, operations: &[Operation]) -> Vec<&'static str> {
+    profile
+        .map(|_| "profile")
+        .into_iter()
+        .chain(operations.iter().flat_map(Operation::options))
+        .collect()
+}
+
+/// Applies `profile` and `operations` to one controller, stopping at the
+/// first failure.
+async fn apply_device(
+    client: &mut Jpf4826Client,
+    profile: Option<&Profile>,
+    operations: &[Operation],
+) -> (Vec<(&'static str, FieldOutcome)>, Option<String>) {
+    let mut fields = Vec::new();
+    let mut error = None;
+
+    if let Some(profile) = profile {
+        let outcome = match client.apply_profile(profile).await {
+            Ok(report) if report.written.is_empty() => FieldOutcome::Unchanged,
+            Ok(_) => FieldOutcome::Applied,
+            Err(e) => {
+                let message = format!("Failed to apply profile '{}': {}", profile.name, e);
+                error = Some(message.clone());
+                FieldOutcome::Failed(message)
+            }
+        };
+        fields.push(("profile", outcome));
+    }
+
+    for operation in operations {
+        let outcome = if error.is_some() {
+            FieldOutcome::Skipped
+        } else {
+            match run(client, operation).await {
+                Ok(()) => FieldOutcome::Applied,
+                Err(e) => {
+                    let message = format!("{:#}", e);
+                    error = Some(message.clone());
+                    FieldOutcome::Failed(message)
+                }
+            }
+        };
+        for name in operation.options() {
+            fields.push((name, outcome.clone()));
+        }
+    }
+
+    (fields, error)
+}
+
+/// Formats multi-address results as a table with one column per option.
+pub fn format_reports_table(reports: &[DeviceReport]) -> String {
+    let Some(first) = reports.first() else {
+        return String::new();
+    };
+    let widths: Vec<usize> = first
+        .fields
+        .iter()
+        .map(|(name, _)| name.len().max("unchanged".len()))
+        .collect();
+
+    let mut output = format!("{:<9}", "Address");
+    for ((name, _), width) in first.fields.iter().zip(&widths) {
+        output.push_str(&format!("{:<width$}  ", name));
+    }
+    output.push_str("Result\n");
+    for report in reports {
+        output.push_str(&format!("{:<9}", report.address));
+        for ((_, outcome), width) in report.fields.iter().zip(&widths) {
+            let cell = match outcome {
+                FieldOutcome::Applied => "ok",
+                FieldOutcome::Unchanged => "unchanged",
+                FieldOutcome::Failed(_) => "FAILED",
+                FieldOutcome::Skipped => "-",
+            };
+            output.push_str(&format!("{:<width$}  ", cell));
+        }
+        output.push_str(report.error.as_deref().unwrap_or("ok"));
+        output.push('\n');
+    }
+
+    let failed = reports.iter().filter(|report| !report.is_ok()).count();
+    output.push_str(&format!(
+        "\n{} of {} controller(s) updated.\n",
+        reports.len() - failed,
+        reports.len()
+    ));
+    output
+}
+
+/// Formats multi-address results as a JSON array, one object per
+/// controller.
+pub fn format_reports_json(reports: &[DeviceReport]) -> String {
+    let devices: Vec<serde_json::Value> = reports
+        .iter()
+        .map(|report| {
+            let fields: Vec<serde_json::Value> = report
+                .fields
+                .iter()
+                .map(|(name, outcome)| match outcome {
+                    FieldOutcome::Failed(error) => {
+                        json!({"name": name, "result": outcome.label(), "error": error})
+                    }
+                    _ => json!({"name": name, "result": outcome.label()}),
+                })
+                .collect();
+            json!({
+                "address": report.address,
+                "success": report.is_ok(),
+                "error": report.error,
+                "fields": fields,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(devices).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jpf4826_driver::client::MockBus;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    fn args() -> SetArgs {
+        SetArgs {
+            auto_speed: false,
+            modbus_addr: None,
+            low_temp: None,
+            high_temp: None,
+            eco: None,
+            fan_qty: None,
+            pwm_freq: None,
+            manual_speed: None,
+            profile: None,
+            profile_file: None,
+        }
+    }
+
+    /// Writes `contents` to a fresh file in the temp directory.
+    fn profile_file(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("jpf4826ctl-{}-{}.json", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    type Registers = Arc<Mutex<HashMap<u16, u16>>>;
+
+    fn mock_registers() -> Registers {
+        Arc::new(Mutex::new(HashMap::from([
+            (0x0002, 1),
+            (0x0003, 0xFFFF),
+            (0x0004, 0x465A),
+            (0x0005, 1),
+            (0x0006, 4),
+            (0x0007, 0),
+            (0x0008, 0),
+            (0x0009, 0),
+            (0x000A, 0),
+            (0x000B, 5),
+        ])))
+    }
+
+    #[test]
+    fn test_plan_groups_adjacent_registers() {
+        let operations = plan(&SetArgs {
+            auto_speed: true,
+            low_temp: Some(35),
+            high_temp: Some(55),
+            eco: Some(1),
+            fan_qty: Some(2),
+            pwm_freq: Some(1000),
+            ..args()
+        })
+        .unwrap();
+
+        assert_eq!(
+            operations,
+            vec![
+                Operation::Write(vec![
+                    Change::AutoSpeed,
+                    Change::Thresholds { low: 35, high: 55 },
+                    Change::Eco(WorkMode::Shutdown),
+                    Change::FanQuantity(2),
+                ]),
+                Operation::Write(vec![Change::PwmFrequency(PwmFrequency::Hz1000)]),
+            ]
+        );
+        let values: Vec<u16> = match &operations[0] {
+            Operation::Write(changes) => changes.iter().map(|c| c.value()).collect(),
+            other => panic!("unexpected operation {:?}", other),
+        };
+        assert_eq!(values, vec![0xFFFF, 0x4B5F, 0x0000, 0x0002]);
+    }
+
+    #[test]
+    fn test_plan_splits_non_adjacent_registers() {
+        // 0x0003 and 0x0005 with a gap at 0x0004
+        let operations = plan(&SetArgs {
+            manual_speed: Some(60),
+            eco: Some(0),
+            ..args()
+        })
+        .unwrap();
+
+        assert_eq!(
+            operations,
+            vec![
+                Operation::Write(vec![Change::ManualSpeed(60)]),
+                Operation::Write(vec![Change::Eco(WorkMode::MinimumSpeed)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_orders_address_change_last() {
+        let operations = plan(&SetArgs {
+            modbus_addr: Some(7),
+            low_temp: Some(25),
+            fan_qty: Some(0),
+            ..args()
+        })
+        .unwrap();
+
+        assert_eq!(
+            operations,
+            vec![
+                Operation::Write(vec![Change::FanQuantity(0)]),
+                Operation::StartTemperature(25),
+                Operation::ModbusAddress(7),
+            ]
+        );
+        let transactions: usize = operations.iter().map(Operation::transactions).sum();
+        assert_eq!(transactions, 4);
+    }
+
+    #[test]
+    fn test_plan_rejects_invalid_arguments() {
+        let inverted = plan(&SetArgs {
+            low_temp: Some(50),
+            high_temp: Some(50),
+            ..args()
+        });
+        assert!(inverted.is_err());
+
+        let freq = plan(&SetArgs {
+            pwm_freq: Some(1234),
+            ..args()
+        });
+        assert!(freq.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_uses_planned_transaction_count() {
+        let registers = Arc::new(Mutex::new(HashMap::from([
+            (0x0002, 1),
+            (0x0003, 40),
+            (0x0004, 0x465A),
+            (0x0005, 1),
+            (0x0006, 4),
+            (0x000B, 5),
+        ])));
+        let mut client = Jpf4826Client::new_mock(registers.clone(), 1).await;
+        let probe = client.mock_probe().unwrap();
+
+        // Seven settings, one register each when written individually
+        let operations = plan(&SetArgs {
+            auto_speed: true,
+            modbus_addr: Some(9),
+            low_temp: Some(20),
+            high_temp: Some(45),
+            eco: Some(1),
+            fan_qty: Some(3),
+            pwm_freq: Some(2000),
+            manual_speed: None,
+            profile: None,
+            profile_file: None,
+        })
+        .unwrap();
+        let transactions = apply(&mut client, &operations).await.unwrap();
+
+        assert_eq!(transactions, 3);
+        assert_eq!(probe.reads() + probe.writes(), transactions);
+        let registers = registers.lock().unwrap();
+        let value = |addr: u16| registers[&addr];
+        assert_eq!(value(0x0003), 0xFFFF);
+        assert_eq!(value(0x0004), encode_combined_temperature(20, 45));
+        assert_eq!(value(0x0005), 0);
+        assert_eq!(value(0x0006), 3);
+        assert_eq!(value(0x000B), 2);
+        assert_eq!(value(0x0002), 9);
+        assert_eq!(client.slave_addr(), 9);
+    }
+
+    #[test]
+    fn test_find_builtin_profile() {
+        let profile = find_profile(&ProfileSet::default(), "quiet").unwrap();
+        assert_eq!(profile, Profile::quiet());
+
+        let err = find_profile(&ProfileSet::default(), "turbo").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown profile 'turbo' (available: quiet, performance)"
+        );
+    }
+
+    #[test]
+    fn test_load_profiles_from_file() {
+        let path = profile_file(
+            "night",
+            r#"{"profiles": [{"name": "night", "config": {"fan_count": 2}}]}"#,
+        );
+        let profiles = load_profiles(Some(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let night = find_profile(&profiles, "night").unwrap();
+        assert_eq!(night.config.fan_count, Some(2));
+        assert_eq!(
+            find_profile(&profiles, "turbo").unwrap_err().to_string(),
+            "Unknown profile 'turbo' (available: quiet, performance, night)"
+        );
+    }
+
+    #[test]
+    fn test_load_profiles_rejects_bad_files() {
+        let path = profile_file(
+            "inverted",
+            r#"{"profiles": [{"name": "hot", "config": {"thresholds": {"low": 60, "high": 40}}}]}"#,
+        );
+        let err = load_profiles(Some(&path)).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().starts_with("Invalid profile file"));
+        assert!(format!("{:#}", err).contains("Profile 'hot'"));
+
+        let missing = std::env::temp_dir().join("jpf4826ctl-missing-profiles.json");
+        let err = load_profiles(Some(&missing)).unwrap_err();
+        assert!(err.to_string().starts_with("Failed to read profile file"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_applies_profile_before_plan() {
+        let registers = mock_registers();
+        let mut client = Jpf4826Client::new_mock(registers.clone(), 1).await;
+        let probe = client.mock_probe().unwrap();
+
+        execute(
+            &mut client,
+            SetArgs {
+                manual_speed: Some(40),
+                profile: Some("quiet".to_string()),
+                ..args()
+            },
+        )
+        .await
+        .unwrap();
+
+        // One read, two profile frames and the manual speed write
+        assert_eq!((probe.reads(), probe.writes()), (1, 3));
+        let registers = registers.lock().unwrap();
+        assert_eq!(registers[&0x0003], 40);
+        assert_eq!(registers[&0x0004], encode_combined_temperature(35, 55));
+        assert_eq!(registers[&0x0005], 0);
+        assert_eq!(registers[&0x000B], 4);
+    }
+
+    /// Returns a manager with controllers at `addrs` on port "bus".
+    fn mock_manager(addrs: &[u8]) -> (Jpf4826Manager, Vec<Registers>) {
+        let bus = MockBus::new();
+        let devices: Vec<_> = addrs
+            .iter()
+            .map(|&addr| {
+                let registers = mock_registers();
+                bus.add_device(addr, registers.clone());
+                registers
+            })
+            .collect();
+        let manager = Jpf4826Manager::new_mock(HashMap::from([("bus".to_string(), bus)]))
+            .with_timeout(std::time::Duration::from_millis(50));
+        (manager, devices)
+    }
+
+    #[tokio::test]
+    async fn test_apply_many_updates_every_controller() {
+        let (manager, devices) = mock_manager(&[1, 2, 3]);
+        let operations = plan(&SetArgs {
+            eco: Some(1),
+            pwm_freq: Some(1000),
+            ..args()
+        })
+        .unwrap();
+
+        let reports = apply_many(&manager, "bus", &[1, 2, 3], None, &operations)
+            .await
+            .unwrap();
+
+        assert_eq!(reports.len(), 3);
+        for (report, registers) in reports.iter().zip(&devices) {
+            assert!(report.is_ok());
+            assert_eq!(
+                report.fields,
+                vec![
+                    ("eco", FieldOutcome::Applied),
+                    ("pwm-freq", FieldOutcome::Applied)
+                ]
+            );
+            let registers = registers.lock().unwrap();
+            assert_eq!((registers[&0x0005], registers[&0x000B]), (0, 1));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_many_continues_past_failed_controller() {
+        let (manager, devices) = mock_manager(&[1, 3]);
+        let operations = plan(&SetArgs {
+            manual_speed: Some(40),
+            fan_qty: Some(2),
+            ..args()
+        })
+        .unwrap();
+
+        let reports = apply_many(&manager, "bus", &[1, 2, 3], None, &operations)
+            .await
+            .unwrap();
+
+        let outcomes: Vec<bool> = reports.iter().map(DeviceReport::is_ok).collect();
+        assert_eq!(outcomes, vec![true, false, true]);
+        let failed = &reports[1];
+        assert_eq!(failed.address, 2);
+        assert!(matches!(
+            failed.fields[0],
+            ("manual-speed", FieldOutcome::Failed(_))
+        ));
+        assert_eq!(failed.fields[1], ("fan-qty", FieldOutcome::Skipped));
+        assert!(failed
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("Failed to write register 0x0003"));
+        for registers in &devices {
+            let registers = registers.lock().unwrap();
+            assert_eq!((registers[&0x0003], registers[&0x0006]), (40, 2));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_many_fails_if_any_controller_failed() {
+        let (manager, _devices) = mock_manager(&[1]);
+        let err = execute_many(
+            &manager,
+            "bus",
+            &[1, 2],
+            SetArgs {
+                eco: Some(1),
+                ..args()
+            },
+            true,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.to_string(), "1 of 2 controller(s) failed");
+
+        let err = execute_many(
+            &manager,
+            "bus",
+            &[1, 2],
+            SetArgs {
+                modbus_addr: Some(5),
+                ..args()
+            },
+            false,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("--modbus-addr"));
+    }
+
+    #[test]
+    fn test_format_reports() {
+        let reports = vec![
+            DeviceReport {
+                address: 1,
+                fields: vec![
+                    ("profile", FieldOutcome::Unchanged),
+                    ("eco", FieldOutcome::Applied),
+                ],
+                error: None,
+            },
+            DeviceReport {
+                address: 12,
+                fields: vec![
+                    ("profile", FieldOutcome::Failed("timed out".to_string())),
+                    ("eco", FieldOutcome::Skipped),
+                ],
+                error: Some("timed out".to_string()),
+            },
+        ];
+
+        assert_eq!(
+            format_reports_table(&reports),
+            "Address  profile    eco        Result\n\
+             1        unchanged  ok         ok\n\
+             12       FAILED     -          timed out\n\
+             \n\
+             1 of 2 controller(s) updated.\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&format_reports_json(&reports)).unwrap();
+        assert_eq!(
+            json[1],
+            json!({
+                "address": 12,
+                "success": false,
+                "error": "timed out",
+                "fields": [
+                    {"name": "profile", "result": "failed", "error": "timed out"},
+                    {"name": "eco", "result": "skipped"},
+                ],
+            })
+        );
+        assert_eq!(json[0]["success"], true);
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+    }
+}
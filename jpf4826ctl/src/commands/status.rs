@@ -2,57 +2,194 @@
 
 // Rust guideline compliant 2026-01-06
 
-use crate::output::{convert_to_fahrenheit, format_status_json, format_status_text};
-use jpf4826_driver::Jpf4826Client;
+use crate::cli::OutputFormat;
+use crate::output::{
+    convert_temperatures, format_status_json, format_status_kv, format_status_sensors,
+    format_status_sensors_json, format_status_text_with,
+};
+use crate::visual::Visuals;
+use jpf4826_driver::{ConfigMismatch, ControllerConfig, Jpf4826Client, TemperatureUnit};
+use std::fmt;
+
+/// Exit code when the controller does not match the `--expect-*` options.
+pub const EXIT_MISMATCH: i32 = 3;
+
+/// Settings that differ from the `--expect-*` options.
+#[derive(Debug)]
+pub struct ExpectationMismatch(pub Vec<ConfigMismatch>);
+
+impl fmt::Display for ExpectationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Controller does not match expectations:")?;
+        for mismatch in &self.0 {
+            write!(f, "\n  - {}", mismatch)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ExpectationMismatch {}
 
 /// Executes the status command.
 ///
-/// Reads controller status and outputs in text or JSON format.
+/// Reads controller status and outputs it in the requested format, then
+/// compares it against `expected`.
 ///
 /// # Arguments
 ///
 /// * `client` - Connected JPF4826 client
-/// * `json` - Output JSON format if true, text otherwise
-/// * `temp_unit` - Temperature unit (0=Celsius, 1=Fahrenheit)
+/// * `format` - Output format
+/// * `temp_unit` - Temperature unit to convert to, Celsius if `None`
+/// * `visuals` - Speed bars to draw in text output, if any
+/// * `expected` - Settings the controller must have; `None` fields are
+///   not checked
+///
+/// # Errors
+///
+/// Returns [`ExpectationMismatch`] listing every setting that differs from
+/// `expected`, after printing the status.
 pub async fn execute(
     client: &mut Jpf4826Client,
-    json: bool,
-    temp_unit: Option<u8>,
+    format: OutputFormat,
+    temp_unit: Option<TemperatureUnit>,
+    visuals: Option<&Visuals>,
+    expected: &ControllerConfig,
 ) -> anyhow::Result<()> {
     log::debug!("Starting status command execution");
-    log::debug!(
-        "Output format: {}, Temp unit: {:?}",
-        if json { "JSON" } else { "Text" },
-        temp_unit
-    );
+    log::debug!("Output format: {:?}, Temp unit: {:?}", format, temp_unit);
 
     // Read status from controller
     log::debug!("Reading status from controller...");
     let mut status = client.status().await?;
     log::debug!("Status received successfully");
     log::debug!(
-        "Raw status: temp={}, fans={}, eco_mode={}",
+        "Raw status: temp={}, fans={}, work_mode={:?}",
         status.temperature_current.value,
         status.fans.len(),
-        status.eco_mode
+        status.work_mode
     );
+    // Compare before converting, thresholds are expected in Celsius
+    let mismatches = expected.verify(&status);
 
-    // Convert to Fahrenheit if requested
-    if temp_unit == Some(1) {
-        log::debug!("Converting temperature to Fahrenheit");
-        status = convert_to_fahrenheit(status);
+    if let Some(unit) = temp_unit {
+        log::debug!("Converting temperature to {:?}", unit);
+        status = convert_temperatures(status, unit);
     }
 
     // Output in requested format
     log::debug!("Formatting output...");
-    if json {
-        let output = format_status_json(&status)?;
-        println!("{}", output);
-    } else {
-        let output = format_status_text(&status);
-        print!("{}", output);
+    match format {
+        OutputFormat::Text => print!("{}", format_status_text_with(&status, visuals)),
+        OutputFormat::Json => println!("{}", format_status_json(&status)?),
+        OutputFormat::Sensors => print!("{}", format_status_sensors(&status)),
+        OutputFormat::SensorsJson => print!("{}", format_status_sensors_json(&status)),
+        OutputFormat::Kv => print!("{}", format_status_kv(&status)),
+    }
+
+    if !mismatches.is_empty() {
+        return Err(ExpectationMismatch(mismatches).into());
     }
 
     log::debug!("Status command completed successfully");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jpf4826_driver::profile::Thresholds;
+    use jpf4826_driver::PwmFrequency;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Creates a client for a controller with 4 fans, 30-50°C thresholds
+    /// and 25 kHz PWM.
+    async fn mock_client() -> Jpf4826Client {
+        let registers = Arc::new(Mutex::new(HashMap::from([
+            (0x0000, 71),
+            (0x0002, 1),
+            (0x0003, 0xFFFF),
+            (0x0004, 0x465A),
+            (0x0005, 1),
+            (0x0006, 4),
+            (0x000B, 5),
+            (0x000C, 70),
+            (0x000D, 90),
+            (0x000E, 0x000F),
+        ])));
+        Jpf4826Client::new_mock(registers, 1).await
+    }
+
+    async fn mismatches(expected: ControllerConfig) -> Vec<ConfigMismatch> {
+        let mut client = mock_client().await;
+        match execute(&mut client, OutputFormat::Kv, None, None, &expected).await {
+            Ok(()) => Vec::new(),
+            Err(e) => e.downcast::<ExpectationMismatch>().unwrap().0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_matching_expectations_succeed() {
+        let expected = ControllerConfig {
+            thresholds: Some(Thresholds { low: 30, high: 50 }),
+            fan_count: Some(4),
+            pwm_frequency: Some(PwmFrequency::Hz25000),
+            ..ControllerConfig::default()
+        };
+        assert!(mismatches(expected).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fan_count_mismatch() {
+        let expected = ControllerConfig {
+            fan_count: Some(2),
+            ..ControllerConfig::default()
+        };
+        assert_eq!(
+            mismatches(expected).await,
+            vec![ConfigMismatch::FanCount {
+                expected: 2,
+                actual: 4
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_every_mismatch_is_listed() {
+        let expected = ControllerConfig {
+            thresholds: Some(Thresholds { low: 35, high: 55 }),
+            fan_count: Some(3),
+            pwm_frequency: Some(PwmFrequency::Hz1000),
+            ..ControllerConfig::default()
+        };
+        let mismatches = mismatches(expected).await;
+        assert_eq!(mismatches.len(), 3);
+
+        let message = ExpectationMismatch(mismatches).to_string();
+        assert_eq!(
+            message,
+            "Controller does not match expectations:\n  \
+             - Thresholds are 30-50°C, expected 35-55°C\n  \
+             - Fan count is 4, expected 3\n  \
+             - PWM frequency is 25000 Hz, expected 1000 Hz"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_thresholds_are_compared_in_celsius() {
+        let mut client = mock_client().await;
+        let expected = ControllerConfig {
+            thresholds: Some(Thresholds { low: 30, high: 50 }),
+            ..ControllerConfig::default()
+        };
+        execute(
+            &mut client,
+            OutputFormat::Text,
+            Some(TemperatureUnit::Fahrenheit),
+            None,
+            &expected,
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,560 @@
+//! Watch command implementation.
+//!
+//! Polls the controller status at a fixed interval until interrupted, which
+//! makes it suitable for running as a long-lived service. With `--diff`,
+//! only the fields that changed since the previous sample are printed.
+
+// Rust guideline compliant 2026-10-16
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::notify::Notifier;
+use crate::output::{
+    convert_temperatures, format_status_diff_text, format_status_text, format_status_text_with,
+    format_temperature_trend,
+};
+use crate::visual::{Visuals, MAX_SPARKLINE_WIDTH};
+use jpf4826_driver::{ControllerStatus, Jpf4826Client, TemperatureUnit};
+
+/// Options for the watch command.
+#[derive(Debug, Clone)]
+pub struct WatchArgs {
+    /// Delay between polls.
+    pub interval: Duration,
+    /// Emit one JSON document per line instead of text.
+    pub json: bool,
+    /// Print changes between samples instead of every snapshot.
+    pub diff: bool,
+    /// With `diff`, print a complete snapshot every this many samples.
+    pub full_every: Option<u32>,
+    /// Temperature unit to convert to, Celsius if `None`.
+    pub temp_unit: Option<TemperatureUnit>,
+    /// Bars and sparkline to draw in text snapshots, if any.
+    pub visuals: Option<Visuals>,
+    /// Export metrics over OTLP.
+    #[cfg(feature = "otel")]
+    pub otel: bool,
+    /// OTLP endpoint overriding `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    #[cfg(feature = "otel")]
+    pub otel_endpoint: Option<String>,
+}
+
+/// Executes the watch command.
+///
+/// Prints every status snapshot until SIGINT or SIGTERM is received.
+/// Failed polls are logged and retried on the next cycle.
+pub async fn execute(
+    client: &mut Jpf4826Client,
+    port: &str,
+    addr: u8,
+    args: WatchArgs,
+    notifier: &mut impl Notifier,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "otel")]
+    let telemetry = if args.otel {
+        Some(crate::telemetry::Telemetry::otlp(
+            port,
+            addr,
+            args.otel_endpoint.as_deref(),
+        )?)
+    } else {
+        None
+    };
+    #[cfg(not(feature = "otel"))]
+    let _ = port;
+
+    let mut renderer = Renderer::new(&args);
+
+    poll_loop(
+        args.interval,
+        addr,
+        notifier,
+        shutdown_signal(),
+        async || {
+            let result = client.status().await;
+            #[cfg(feature = "otel")]
+            if let Some(telemetry) = &telemetry {
+                match &result {
+                    Ok(status) => telemetry.metrics().record_status(status),
+                    Err(_) => telemetry.metrics().record_error(),
+                }
+            }
+            Ok(result?)
+        },
+        |status| {
+            let status = match args.temp_unit {
+                Some(unit) => convert_temperatures(status, unit),
+                None => status,
+            };
+            if let Some(output) = renderer.render(status)? {
+                println!("{}", output);
+            }
+            Ok(())
+        },
+    )
+    .await
+}
+
+/// Turns successive snapshots into watch output.
+struct Renderer {
+    json: bool,
+    diff: bool,
+    full_every: Option<u32>,
+    previous: Option<ControllerStatus>,
+    /// Samples rendered so far.
+    samples: u32,
+    visuals: Option<Visuals>,
+    /// Current temperature of recent samples, `None` on sensor faults.
+    history: VecDeque<Option<i16>>,
+    /// Fastest fan seen, the bar scale without a nominal RPM.
+    max_rpm: u16,
+}
+
+impl Renderer {
+    fn new(args: &WatchArgs) -> Self {
+        Self {
+            json: args.json,
+            diff: args.diff,
+            full_every: args.full_every,
+            previous: None,
+            samples: 0,
+            visuals: args.visuals,
+            history: VecDeque::with_capacity(MAX_SPARKLINE_WIDTH),
+            max_rpm: 0,
+        }
+    }
+
+    /// Returns the output for `status`, or `None` if nothing changed.
+    fn render(&mut self, status: ControllerStatus) -> anyhow::Result<Option<String>> {
+        self.record(&status);
+        if !self.diff {
+            return Ok(Some(self.format_full(&status)?));
+        }
+
+        let full_due = self
+            .full_every
+            .is_some_and(|n| self.samples.is_multiple_of(n));
+        self.samples = self.samples.wrapping_add(1);
+        let output = match self.previous.take() {
+            Some(previous) if !full_due => {
+                let diff = previous.diff(&status);
+                if diff.is_empty() {
+                    None
+                } else if self.json {
+                    Some(tagged("diff", serde_json::to_string(&diff)?))
+                } else {
+                    Some(format_status_diff_text(&diff))
+                }
+            }
+            _ => Some(self.format_full(&status)?),
+        };
+        self.previous = Some(status);
+        Ok(output)
+    }
+
+    /// Adds `status` to the temperature history and fan speed maximum.
+    fn record(&mut self, status: &ControllerStatus) {
+        if self.history.len() == MAX_SPARKLINE_WIDTH {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back((!status.sensor_fault).then_some(status.temperature_current.value));
+        let fastest = status.fans.iter().map(|fan| fan.rpm).max().unwrap_or(0);
+        self.max_rpm = self.max_rpm.max(fastest);
+    }
+
+    /// Formats a complete snapshot, tagged as `full` in JSON diff mode.
+    fn format_full(&self, status: &ControllerStatus) -> anyhow::Result<String> {
+        Ok(match (self.json, self.diff) {
+            (true, true) => tagged("full", serde_json::to_string(status)?),
+            (true, false) => serde_json::to_string(status)?,
+            (false, _) => match &self.visuals {
+                Some(visuals) => {
+                    // Scale to the fastest fan seen so far, not just now
+                    let visuals = Visuals {
+                        nominal_rpm: visuals.nominal_rpm.or(Some(self.max_rpm)),
+                        ..*visuals
+                    };
+                    let history: Vec<_> = self.history.iter().copied().collect();
+                    let mut output = format_status_text_with(status, Some(&visuals));
+                    output.push_str(&format_temperature_trend(&history, &visuals));
+                    output
+                }
+                None => format_status_text(status),
+            },
+        })
+    }
+}
+
+/// Wraps the serialized `json` as `{"<tag>": value}`, the JSON line format of `--diff`.
+///
+/// Complete snapshots are tagged `full` and changes `diff`.
+fn tagged(tag: &str, json: String) -> String {
+    format!("{{\"{}\":{}}}", tag, json)
+}
+
+/// Runs `poll` every `interval` until `shutdown` resolves.
+///
+/// Sends `ready` after the first successful poll, `watchdog` after every
+/// successful poll, a `status` line after every poll, and `stopping` when
+/// the loop ends, whether on shutdown or because `render` failed.
+pub(crate) async fn poll_loop(
+    interval: Duration,
+    addr: u8,
+    notifier: &mut impl Notifier,
+    shutdown: impl Future<Output = ()>,
+    mut poll: impl AsyncFnMut() -> anyhow::Result<ControllerStatus>,
+    mut render: impl FnMut(ControllerStatus) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut ready = false;
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    tokio::pin!(shutdown);
+
+    let result = loop {
+        tokio::select! {
+            _ = &mut shutdown => break Ok(()),
+            _ = ticker.tick() => {}
+        }
+
+        match poll().await {
+            Ok(status) => {
+                notifier.status(&format!(
+                    "Polling controller 0x{:02X}, {}°C",
+                    addr, status.temperature_current.value
+                ));
+                if let Err(e) = render(status) {
+                    break Err(e);
+                }
+                if !ready {
+                    notifier.ready();
+                    ready = true;
+                }
+                notifier.watchdog();
+            }
+            Err(e) => {
+                log::warn!("Status poll failed: {:#}", e);
+                notifier.status(&format!(
+                    "Polling controller 0x{:02X}, last error: {:#}",
+                    addr, e
+                ));
+            }
+        }
+    };
+
+    log::debug!("Shutting down watch loop");
+    notifier.stopping();
+    result
+}
+
+/// Resolves when the process receives SIGINT or, on Unix, SIGTERM.
+pub(crate) async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+                return;
+            }
+            Err(e) => log::warn!("Failed to install SIGTERM handler: {}", e),
+        }
+    }
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct RecordingNotifier {
+        events: Vec<String>,
+    }
+
+    impl Notifier for RecordingNotifier {
+        fn ready(&mut self) {
+            self.events.push("READY=1".into());
+        }
+
+        fn watchdog(&mut self) {
+            self.events.push("WATCHDOG=1".into());
+        }
+
+        fn stopping(&mut self) {
+            self.events.push("STOPPING=1".into());
+        }
+
+        fn status(&mut self, message: &str) {
+            self.events.push(format!("STATUS={}", message));
+        }
+    }
+
+    type Registers = Arc<Mutex<HashMap<u16, u16>>>;
+
+    fn default_registers() -> Registers {
+        let registers = HashMap::from([
+            (0x0000, 71),     // 31°C
+            (0x0001, 0x000F), // all fans running
+            (0x0002, 0x0001), // Modbus address 1
+            (0x0003, 0xFFFF), // temperature mode
+            (0x0004, 0x465A),
+            (0x0005, 0x0001), // minimum speed work mode
+            (0x0006, 0x0004), // 4 fans
+            (0x0007, 1400),
+            (0x0008, 1400),
+            (0x0009, 1400),
+            (0x000A, 1400),
+            (0x000B, 0x0005), // 25 kHz
+            (0x000C, 70),     // 30°C
+            (0x000D, 90),     // 50°C
+            (0x000E, 0x000F), // no faults
+        ]);
+        Arc::new(Mutex::new(registers))
+    }
+
+    fn diff_args(json: bool, full_every: Option<u32>) -> WatchArgs {
+        WatchArgs {
+            interval: Duration::from_secs(10),
+            json,
+            diff: true,
+            full_every,
+            temp_unit: None,
+            visuals: None,
+            #[cfg(feature = "otel")]
+            otel: false,
+            #[cfg(feature = "otel")]
+            otel_endpoint: None,
+        }
+    }
+
+    /// Applies each step's register writes to a mock controller, then
+    /// renders one status poll.
+    async fn run_scenario(args: WatchArgs, steps: &[&[(u16, u16)]]) -> Vec<Option<String>> {
+        let registers = default_registers();
+        let mut client = Jpf4826Client::new_mock(registers.clone(), 1).await;
+        let mut renderer = Renderer::new(&args);
+        let mut output = Vec::new();
+        for step in steps {
+            registers.lock().unwrap().extend(step.iter().copied());
+            let status = client.status().await.unwrap();
+            output.push(renderer.render(status).unwrap());
+        }
+        output
+    }
+
+    #[tokio::test]
+    async fn test_diff_text_sequence() {
+        let output = run_scenario(
+            diff_args(false, None),
+            &[
+                &[],
+                &[(0x0000, 73)],
+                &[(0x0008, 1100), (0x0009, 0), (0x000E, 0x000B)],
+                &[],
+                &[(0x0009, 1400), (0x000E, 0x000F)],
+            ],
+        )
+        .await;
+
+        assert!(output[0]
+            .as_deref()
+            .unwrap()
+            .starts_with("Mode                  Temperature\n"));
+        assert_eq!(
+            output[1..],
+            [
+                Some("Temperature           31 ℃ -> 33 ℃\n".to_string()),
+                Some(
+                    "\
+Fan 2 Speed (RPM)     1400 -> 1100
+!! Fan 3 Status       Normal -> FAULT
+Fan 3 Speed (RPM)     1400 -> 0
+"
+                    .to_string()
+                ),
+                None,
+                Some(
+                    "\
+Fan 3 Status          Fault -> Normal
+Fan 3 Speed (RPM)     0 -> 1400
+"
+                    .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diff_full_every() {
+        let output =
+            run_scenario(diff_args(false, Some(2)), &[&[], &[(0x0000, 73)], &[], &[]]).await;
+
+        let full =
+            |line: &Option<String>| line.as_deref().is_some_and(|s| s.contains("Fan Status"));
+        assert!(full(&output[0]));
+        assert_eq!(
+            output[1].as_deref(),
+            Some("Temperature           31 ℃ -> 33 ℃\n")
+        );
+        assert!(full(&output[2]));
+        assert_eq!(output[3], None);
+    }
+
+    #[tokio::test]
+    async fn test_diff_json_sequence() {
+        let output = run_scenario(
+            diff_args(true, None),
+            &[&[], &[(0x0009, 0), (0x000E, 0x000B)], &[]],
+        )
+        .await;
+
+        let first: serde_json::Value = serde_json::from_str(output[0].as_deref().unwrap()).unwrap();
+        assert_eq!(first["full"]["modbus_address"], 1);
+        let second: serde_json::Value =
+            serde_json::from_str(output[1].as_deref().unwrap()).unwrap();
+        assert_eq!(
+            second,
+            serde_json::json!({
+                "diff": {
+                    "fans": [{
+                        "index": 3,
+                        "status": {"from": "NORMAL", "to": "FAULT"},
+                        "rpm": {"from": 1400, "to": 0}
+                    }]
+                }
+            })
+        );
+        assert_eq!(output[2], None);
+    }
+
+    #[tokio::test]
+    async fn test_without_diff_every_sample_is_full() {
+        let mut args = diff_args(true, None);
+        args.diff = false;
+        let output = run_scenario(args, &[&[], &[]]).await;
+
+        for line in output {
+            let value: serde_json::Value = serde_json::from_str(&line.unwrap()).unwrap();
+            assert_eq!(value["modbus_address"], 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_text_snapshots_draw_bars_and_trend() {
+        let mut args = diff_args(false, None);
+        args.diff = false;
+        args.visuals = Some(Visuals {
+            width: 40,
+            nominal_rpm: None,
+        });
+        let output = run_scenario(
+            args,
+            &[&[], &[(0x0000, 73)], &[(0x0000, 75), (0x0007, 700)], &[]],
+        )
+        .await;
+
+        assert!(!output[0].as_deref().unwrap().contains("Trend"));
+        let last = output[3].as_deref().unwrap();
+        assert!(last.contains("Speed (RPM)   700   ██████░░░░░░\n"));
+        assert!(last.contains("Speed (RPM)   1400  ████████████\n"));
+        assert!(last.ends_with("Trend                 ▁▄██\n"));
+    }
+
+    fn test_status() -> ControllerStatus {
+        ControllerStatus::from_registers(&[
+            71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1400, 1400, 1400, 0x0005, 70, 90, 0x000F,
+        ])
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_notification_sequence() {
+        let mut notifier = RecordingNotifier::default();
+        let mut polls = 0;
+        let mut rendered = 0;
+
+        // Polls at t=0, 10, 20; the second one fails
+        poll_loop(
+            Duration::from_secs(10),
+            1,
+            &mut notifier,
+            tokio::time::sleep(Duration::from_secs(25)),
+            async || {
+                polls += 1;
+                if polls == 2 {
+                    anyhow::bail!("bus error");
+                }
+                Ok(test_status())
+            },
+            |_| {
+                rendered += 1;
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rendered, 2);
+        assert_eq!(
+            notifier.events,
+            vec![
+                "STATUS=Polling controller 0x01, 31°C",
+                "READY=1",
+                "WATCHDOG=1",
+                "STATUS=Polling controller 0x01, last error: bus error",
+                "STATUS=Polling controller 0x01, 31°C",
+                "WATCHDOG=1",
+                "STOPPING=1",
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_not_ready_until_first_successful_poll() {
+        let mut notifier = RecordingNotifier::default();
+
+        poll_loop(
+            Duration::from_secs(10),
+            1,
+            &mut notifier,
+            tokio::time::sleep(Duration::from_secs(15)),
+            async || anyhow::bail!("no response"),
+            |_| Ok(()),
+        )
+        .await
+        .unwrap();
+
+        assert!(!notifier.events.iter().any(|e| e == "READY=1"));
+        assert!(!notifier.events.iter().any(|e| e == "WATCHDOG=1"));
+        assert_eq!(notifier.events.last().unwrap(), "STOPPING=1");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_render_failure_still_sends_stopping() {
+        let mut notifier = RecordingNotifier::default();
+
+        let err = poll_loop(
+            Duration::from_secs(10),
+            1,
+            &mut notifier,
+            std::future::pending(),
+            async || Ok(test_status()),
+            |_| anyhow::bail!("broken pipe"),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "broken pipe");
+        assert_eq!(
+            notifier.events,
+            vec!["STATUS=Polling controller 0x01, 31°C", "STOPPING=1"]
+        );
+    }
+}
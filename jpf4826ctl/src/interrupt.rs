@@ -0,0 +1,228 @@
+//! Interruption of commands that temporarily change the controller.
+//!
+//! Commands such as `identify` put the controller into a temporary state and
+//! must restore the original configuration before exiting. They run through
+//! [`run_interruptible`]: the first SIGINT or SIGTERM only asks the command
+//! to stop, so it takes the same restore path as on normal completion, and
+//! the command then fails with [`Interrupted`]. A second signal exits
+//! immediately without waiting for the restoration.
+
+// Rust guideline compliant 2026-10-16
+
+use std::fmt;
+
+use tokio::sync::watch;
+
+/// Signal that interrupted a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// SIGINT, e.g. Ctrl+C.
+    Interrupt,
+    /// SIGTERM.
+    Terminate,
+}
+
+impl Signal {
+    /// Returns the conventional exit code, 128 plus the signal number.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Signal::Interrupt => 130,
+            Signal::Terminate => 143,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Signal::Interrupt => "SIGINT",
+            Signal::Terminate => "SIGTERM",
+        }
+    }
+}
+
+/// Error of a command that stopped early because of a signal.
+///
+/// The controller state was restored; `main` exits with
+/// [`Signal::exit_code`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interrupted(pub Signal);
+
+impl fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Interrupted by {}, controller state restored",
+            self.0.name()
+        )
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
+/// Request to stop, fired by the first signal.
+#[derive(Debug, Clone)]
+pub struct StopRequest {
+    receiver: watch::Receiver<Option<Signal>>,
+}
+
+impl StopRequest {
+    /// Resolves once a stop was requested; never if no signal arrives.
+    pub async fn requested(&self) {
+        let mut receiver = self.receiver.clone();
+        if receiver.wait_for(Option::is_some).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+
+    /// Returns the signal that requested the stop, if any.
+    pub fn signal(&self) -> Option<Signal> {
+        *self.receiver.borrow()
+    }
+}
+
+/// Creates a stop request and the sender firing it.
+pub(crate) fn stop_channel() -> (watch::Sender<Option<Signal>>, StopRequest) {
+    let (sender, receiver) = watch::channel(None);
+    (sender, StopRequest { receiver })
+}
+
+/// Runs `work` with a stop request fired by the first SIGINT or SIGTERM.
+///
+/// `work` must restore the controller state before returning, whether it
+/// stopped early or not.
+///
+/// # Errors
+///
+/// Returns the error of `work`, or [`Interrupted`] if `work` succeeded after
+/// a stop was requested.
+pub async fn run_interruptible<T>(
+    work: impl AsyncFnOnce(StopRequest) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let (sender, stop) = stop_channel();
+    let listener = tokio::spawn(listen(sender));
+    let result = finish(stop, work).await;
+    listener.abort();
+    result
+}
+
+/// Runs `work` and reports a stop it honoured as [`Interrupted`].
+pub(crate) async fn finish<T>(
+    stop: StopRequest,
+    work: impl AsyncFnOnce(StopRequest) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let value = work(stop.clone()).await?;
+    match stop.signal() {
+        Some(signal) => Err(Interrupted(signal).into()),
+        None => Ok(value),
+    }
+}
+
+/// Fires the stop request on the first signal and exits on the second.
+async fn listen(sender: watch::Sender<Option<Signal>>) {
+    let mut signals = Signals::new();
+    let first = signals.recv().await;
+    eprintln!(
+        "Received {}, restoring controller state (repeat to exit immediately)...",
+        first.name()
+    );
+    sender.send_replace(Some(first));
+
+    let second = signals.recv().await;
+    eprintln!(
+        "Received {} again, exiting without restoring",
+        second.name()
+    );
+    std::process::exit(second.exit_code());
+}
+
+/// Listener for SIGINT and, on Unix, SIGTERM.
+struct Signals {
+    #[cfg(unix)]
+    terminate: Option<tokio::signal::unix::Signal>,
+}
+
+impl Signals {
+    fn new() -> Self {
+        Self {
+            #[cfg(unix)]
+            terminate: tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .inspect_err(|e| log::warn!("Failed to install SIGTERM handler: {}", e))
+                .ok(),
+        }
+    }
+
+    async fn recv(&mut self) -> Signal {
+        #[cfg(unix)]
+        if let Some(terminate) = &mut self.terminate {
+            return tokio::select! {
+                _ = tokio::signal::ctrl_c() => Signal::Interrupt,
+                _ = terminate.recv() => Signal::Terminate,
+            };
+        }
+        if tokio::signal::ctrl_c().await.is_err() {
+            // Without a handler the signal keeps its default action
+            std::future::pending::<()>().await;
+        }
+        Signal::Interrupt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_finish_without_signal_returns_result() {
+        let (_sender, stop) = stop_channel();
+
+        let value = finish(stop, async |_| Ok(7)).await.unwrap();
+
+        assert_eq!(value, 7);
+    }
+
+    #[tokio::test]
+    async fn test_finish_reports_honoured_stop_as_interrupted() {
+        let (sender, stop) = stop_channel();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            sender.send_replace(Some(Signal::Terminate));
+        });
+
+        let err = finish(stop, async |stop| {
+            stop.requested().await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+        let interrupted = err.downcast::<Interrupted>().unwrap();
+        assert_eq!(interrupted, Interrupted(Signal::Terminate));
+        assert_eq!(interrupted.0.exit_code(), 143);
+    }
+
+    #[tokio::test]
+    async fn test_finish_prefers_work_error() {
+        let (sender, stop) = stop_channel();
+        sender.send_replace(Some(Signal::Interrupt));
+
+        let err = finish(stop, async |_| -> anyhow::Result<()> {
+            anyhow::bail!("restore failed")
+        })
+        .await
+        .unwrap_err();
+
+        assert!(!err.is::<Interrupted>());
+        assert_eq!(err.to_string(), "restore failed");
+    }
+
+    #[tokio::test]
+    async fn test_requested_waits_forever_without_sender() {
+        let (sender, stop) = stop_channel();
+        drop(sender);
+
+        let waited = tokio::time::timeout(Duration::from_millis(10), stop.requested()).await;
+
+        assert!(waited.is_err());
+        assert_eq!(stop.signal(), None);
+    }
+}
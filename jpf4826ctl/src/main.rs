@@ -10,14 +10,29 @@ use clap::Parser;
 
 mod cli;
 mod commands;
+mod interrupt;
+mod notify;
 mod output;
+#[cfg(feature = "otel")]
+mod telemetry;
+mod visual;
 
-use cli::{Cli, Commands};
-use jpf4826_driver::{Jpf4826Client, Jpf4826Error};
+use cli::{Cli, Commands, OutputFormat};
+use jpf4826_driver::manager::Jpf4826Manager;
+use jpf4826_driver::{ControllerConfig, Jpf4826Client, Jpf4826Error, PwmFrequency};
 
 #[tokio::main]
 async fn main() {
     if let Err(e) = run().await {
+        if e.is::<commands::status::ExpectationMismatch>() {
+            eprintln!("Error: {e}");
+            std::process::exit(commands::status::EXIT_MISMATCH);
+        }
+        if let Some(interrupt::Interrupted(signal)) = e.downcast_ref() {
+            eprintln!("{e}");
+            std::process::exit(signal.exit_code());
+        }
+
         let is_timeout = e.chain().any(|cause| {
             cause
                 .downcast_ref::<Jpf4826Error>()
@@ -56,15 +71,23 @@ async fn run() -> anyhow::Result<()> {
         unreachable!();
     }
 
+    // The schema is built into the binary, no controller needed
+    if let Some(Commands::Schema { document, output }) = &cli.command {
+        return commands::schema::execute(*document, output.as_deref());
+    }
+
     // Validate required global options
     let port = cli.get_port().map_err(|e| anyhow::anyhow!(e))?;
-    let addr = cli.get_addr().map_err(|e| anyhow::anyhow!(e))?;
+    let addrs = cli.get_addrs().map_err(|e| anyhow::anyhow!(e))?;
+    let addr = cli.get_addr();
     let timeout = cli.get_timeout();
 
     // Extract command (safe because we checked is_none above)
     let command = cli.command.expect("command must be present");
 
-    // If set command with no options, show help
+    // Built once here and used by both the multi-address and the single
+    // client path below
+    let mut set_args = None;
     if let Commands::Set {
         auto_speed,
         modbus_addr,
@@ -74,6 +97,9 @@ async fn run() -> anyhow::Result<()> {
         fan_qty,
         pwm_freq,
         manual_speed,
+        profile,
+        profile_file,
+        json,
     } = &command
     {
         let args = commands::set::SetArgs {
@@ -85,12 +111,29 @@ async fn run() -> anyhow::Result<()> {
             fan_qty: *fan_qty,
             pwm_freq: *pwm_freq,
             manual_speed: *manual_speed,
+            profile: profile.clone(),
+            profile_file: profile_file.clone(),
         };
+        // If set command with no options, show help
         if args.is_empty() {
             Cli::parse_from(["jpf4826ctl", "set", "--help"]);
             unreachable!();
         }
+
+        // An address list reports per controller instead of per change
+        if addrs.len() > 1 || *json {
+            log::debug!(
+                "Connecting to port: {}, addresses: {:?}, timeout: {:?}",
+                port,
+                addrs,
+                timeout
+            );
+            let manager = Jpf4826Manager::new().with_timeout(timeout);
+            return commands::set::execute_many(&manager, &port, &addrs, args, *json).await;
+        }
+        set_args = Some(args);
     }
+    let addr = addr.map_err(|e| anyhow::anyhow!(e))?;
 
     log::debug!(
         "Connecting to port: {}, address: {}, timeout: {:?}",
@@ -109,34 +152,85 @@ async fn run() -> anyhow::Result<()> {
     // Execute command
     log::debug!("Executing command: {:?}", command);
     match command {
-        Commands::Status { json, temp_unit } => {
-            commands::status::execute(&mut client, json, temp_unit).await?;
-        }
-        Commands::Set {
-            auto_speed,
-            modbus_addr,
-            low_temp,
-            high_temp,
-            eco,
-            fan_qty,
-            pwm_freq,
-            manual_speed,
+        Commands::Status {
+            json,
+            format,
+            temp_unit,
+            expect_fan_count,
+            expect_pwm,
+            expect_thresholds,
+            bars,
+            nominal_rpm,
         } => {
-            let args = commands::set::SetArgs {
-                auto_speed,
-                modbus_addr,
-                low_temp,
-                high_temp,
-                eco,
-                fan_qty,
-                pwm_freq,
-                manual_speed,
+            let format = if json { OutputFormat::Json } else { format };
+            let expected = ControllerConfig {
+                thresholds: expect_thresholds,
+                fan_count: expect_fan_count,
+                pwm_frequency: expect_pwm.and_then(PwmFrequency::from_hz),
+                ..ControllerConfig::default()
             };
+            let visuals = visual::Visuals::detect(bars, nominal_rpm);
+            commands::status::execute(&mut client, format, temp_unit, visuals.as_ref(), &expected)
+                .await?;
+        }
+        Commands::Get { key, temp_unit } => {
+            commands::get::execute(&mut client, &key, temp_unit).await?;
+        }
+        Commands::Set { .. } => {
+            let args = set_args.expect("set arguments are built before connecting");
             commands::set::execute(&mut client, args).await?;
         }
         Commands::Reset => {
             commands::reset::execute(&mut client).await?;
         }
+        Commands::Identify { duration } => {
+            commands::identify::execute(&mut client, duration).await?;
+        }
+        Commands::Watch {
+            interval,
+            json,
+            diff,
+            full_every,
+            temp_unit,
+            bars,
+            nominal_rpm,
+            #[cfg(feature = "otel")]
+            otel,
+            #[cfg(feature = "otel")]
+            otel_endpoint,
+        } => {
+            let args = commands::watch::WatchArgs {
+                interval: std::time::Duration::from_secs(interval),
+                json,
+                diff,
+                full_every,
+                temp_unit,
+                visuals: visual::Visuals::detect(bars, nominal_rpm),
+                #[cfg(feature = "otel")]
+                otel,
+                #[cfg(feature = "otel")]
+                otel_endpoint,
+            };
+            let mut notifier = notify::default_notifier();
+            commands::watch::execute(&mut client, &port, addr, args, &mut notifier).await?;
+        }
+        Commands::Schema { .. } => unreachable!("handled before connecting"),
+        #[cfg(feature = "dbus")]
+        Commands::Daemon {
+            command:
+                cli::DaemonCommands::Dbus {
+                    bus,
+                    address,
+                    interval,
+                },
+        } => {
+            let args = commands::daemon::DbusArgs {
+                bus,
+                address,
+                interval: std::time::Duration::from_secs(interval),
+            };
+            commands::daemon::execute_dbus(client, args).await?;
+        }
     }
 
     Ok(())
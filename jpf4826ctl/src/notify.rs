@@ -0,0 +1,125 @@
+//! Service manager notifications for long-running commands.
+//!
+//! The `watch` command reports readiness, liveness and shutdown through the
+//! [`Notifier`] trait. With the `systemd` feature on Unix, [`SystemdNotifier`]
+//! forwards these to systemd (`Type=notify`, `WatchdogSec=`); everywhere else
+//! [`NoopNotifier`] is used.
+
+// Rust guideline compliant 2026-10-16
+
+/// Receives lifecycle events from a long-running command.
+pub trait Notifier {
+    /// The first poll succeeded and the service is up.
+    fn ready(&mut self);
+
+    /// A poll cycle completed successfully.
+    fn watchdog(&mut self);
+
+    /// The service is shutting down.
+    fn stopping(&mut self);
+
+    /// Free-form status line describing the current state.
+    fn status(&mut self, message: &str);
+}
+
+/// Notifier that discards every event.
+#[derive(Debug, Default)]
+pub struct NoopNotifier;
+
+impl Notifier for NoopNotifier {
+    fn ready(&mut self) {}
+
+    fn watchdog(&mut self) {}
+
+    fn stopping(&mut self) {}
+
+    fn status(&mut self, _message: &str) {}
+}
+
+/// Notifier talking to systemd through `$NOTIFY_SOCKET`.
+///
+/// Sends nothing when not started by systemd, and only sends `WATCHDOG=1`
+/// when the unit has `WatchdogSec=` configured.
+#[cfg(all(feature = "systemd", unix))]
+#[derive(Debug)]
+pub struct SystemdNotifier {
+    watchdog_enabled: bool,
+}
+
+#[cfg(all(feature = "systemd", unix))]
+impl SystemdNotifier {
+    /// Creates a notifier for the current process.
+    pub fn new() -> Self {
+        let mut usec = 0;
+        let watchdog_enabled = sd_notify::watchdog_enabled(false, &mut usec);
+        if watchdog_enabled {
+            log::debug!("systemd watchdog enabled ({} µs)", usec);
+        }
+        Self { watchdog_enabled }
+    }
+
+    fn send(&self, state: &[sd_notify::NotifyState<'_>]) {
+        // Without NOTIFY_SOCKET this is a no-op returning Ok
+        if let Err(e) = sd_notify::notify(false, state) {
+            log::warn!("Failed to notify systemd: {}", e);
+        }
+    }
+}
+
+#[cfg(all(feature = "systemd", unix))]
+impl Default for SystemdNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(feature = "systemd", unix))]
+impl Notifier for SystemdNotifier {
+    fn ready(&mut self) {
+        self.send(&[sd_notify::NotifyState::Ready]);
+    }
+
+    fn watchdog(&mut self) {
+        if self.watchdog_enabled {
+            self.send(&[sd_notify::NotifyState::Watchdog]);
+        }
+    }
+
+    fn stopping(&mut self) {
+        self.send(&[sd_notify::NotifyState::Stopping]);
+    }
+
+    fn status(&mut self, message: &str) {
+        self.send(&[sd_notify::NotifyState::Status(message)]);
+    }
+}
+
+/// Returns the notifier for the current build and environment.
+///
+/// Uses [`SystemdNotifier`] only when built with the `systemd` feature and
+/// started by systemd with a notification socket.
+pub fn default_notifier() -> Box<dyn Notifier + Send> {
+    #[cfg(all(feature = "systemd", unix))]
+    if std::env::var_os("NOTIFY_SOCKET").is_some() {
+        return Box::new(SystemdNotifier::new());
+    }
+    Box::new(NoopNotifier)
+}
+
+impl<N: Notifier + ?Sized> Notifier for Box<N> {
+    fn ready(&mut self) {
+        (**self).ready();
+    }
+
+    fn watchdog(&mut self) {
+        (**self).watchdog();
+    }
+
+    fn stopping(&mut self) {
+        (**self).stopping();
+    }
+
+    fn status(&mut self, message: &str) {
+        (**self).status(message);
+    }
+}
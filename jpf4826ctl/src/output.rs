@@ -2,7 +2,14 @@
 
 // Rust guideline compliant 2026-01-16
 
-use jpf4826_driver::{ControllerStatus, FanStatus, Temperature, TemperatureUnit};
+use std::borrow::Cow;
+
+use crate::visual::{self, Visuals};
+
+use jpf4826_driver::{
+    Change, ControllerStatus, FanStatus, OperatingMode, StatusDiff, Temperature, TemperatureUnit,
+    WorkMode,
+};
 
 /// Column width for label alignment in text output.
 ///
@@ -11,16 +18,37 @@ use jpf4826_driver::{ControllerStatus, FanStatus, Temperature, TemperatureUnit};
 /// (e.g., "Low Threshold" with 4-space indent) consistently.
 const LABEL_WIDTH: usize = 22;
 
+/// Column width of the RPM value when a speed bar follows it.
+const RPM_WIDTH: usize = 6;
+
 /// Formats controller status as human-readable text.
 ///
 /// Output format matches the specification in README.md.
 pub fn format_status_text(status: &ControllerStatus) -> String {
+    format_status_text_with(status, None)
+}
+
+/// Formats controller status as text, with a speed bar per fan if
+/// `visuals` is given.
+///
+/// Bars are scaled to [`Visuals::scale`] of the fastest fan and left out
+/// if the terminal is too narrow for them.
+pub fn format_status_text_with(status: &ControllerStatus, visuals: Option<&Visuals>) -> String {
     let mut output = String::new();
 
     // Header section with fixed-width label column
     output.push_str(&format!(
         "{:<LABEL_WIDTH$}{}\n",
-        "ECO Mode", status.eco_mode
+        "Mode",
+        match status.manual_speed {
+            Some(speed) if status.mode == OperatingMode::Manual => format!("Manual ({}%)", speed),
+            _ => mode_label(status.mode).to_string(),
+        }
+    ));
+    output.push_str(&format!(
+        "{:<LABEL_WIDTH$}{}\n",
+        "Work Mode",
+        work_mode_label(status.work_mode)
     ));
     output.push_str(&format!(
         "{:<LABEL_WIDTH$}0x{:04X}\n",
@@ -37,11 +65,12 @@ pub fn format_status_text(status: &ControllerStatus) -> String {
     ));
 
     // Temperature section (4-space indent for nested items)
-    output.push_str(&format!(
-        "{:<LABEL_WIDTH$}{}\n",
-        "Temperature",
+    let current = if status.sensor_fault {
+        "SENSOR FAULT".to_string()
+    } else {
         format_temperature(&status.temperature_current)
-    ));
+    };
+    output.push_str(&format!("{:<LABEL_WIDTH$}{}\n", "Temperature", current));
     output.push_str(&format!(
         "    {:<18}{}\n",
         "Low Threshold",
@@ -54,25 +83,195 @@ pub fn format_status_text(status: &ControllerStatus) -> String {
     ));
 
     // Fan status section
+    let bar = visuals.and_then(|visuals| {
+        let max_rpm = status.fans.iter().map(|fan| fan.rpm).max().unwrap_or(0);
+        let width = visual::bar_width(visuals.width, 8 + 14 + RPM_WIDTH)?;
+        Some((visuals.scale(max_rpm), width))
+    });
     output.push_str("\nFan Status\n");
     for fan in &status.fans {
         output.push_str(&format!("    {}\n", fan.index));
-        let status_str = match fan.status {
-            FanStatus::Normal => "Normal",
-            FanStatus::Fault => "Fault",
+        output.push_str(&format!(
+            "        {:<14}{}\n",
+            "Status",
+            fan_status_label(fan.status)
+        ));
+        output.push_str(&format!(
+            "        {:<14}{}\n",
+            "Running",
+            if fan.is_running { "Yes" } else { "No" }
+        ));
+        match &bar {
+            Some((scale, width)) => output.push_str(&format!(
+                "        {:<14}{:<RPM_WIDTH$}{}\n",
+                "Speed (RPM)",
+                fan.rpm,
+                visual::rpm_bar(fan.rpm, *scale, *width)
+            )),
+            None => output.push_str(&format!("        {:<14}{}\n", "Speed (RPM)", fan.rpm)),
+        }
+    }
+
+    output
+}
+
+/// Formats the temperature history as a `Trend` line with a sparkline.
+///
+/// `samples` holds the current temperature of each snapshot, oldest first,
+/// with `None` for sensor faults. Returns an empty string if the terminal
+/// is too narrow or there are fewer than two samples.
+pub fn format_temperature_trend(samples: &[Option<i16>], visuals: &Visuals) -> String {
+    match visual::sparkline_width(visuals.width, LABEL_WIDTH) {
+        Some(width) if samples.len() > 1 => format!(
+            "{:<LABEL_WIDTH$}{}\n",
+            "Trend",
+            visual::sparkline(samples, width)
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Formats the fields that changed between two snapshots, one per line.
+///
+/// Uses the labels of [`format_status_text`]. Fans that develop a fault
+/// are prefixed with `!!` and shown as `FAULT` so they stand out; a
+/// temperature sensor fault is flagged the same way. Returns an empty
+/// string if nothing changed.
+pub fn format_status_diff_text(diff: &StatusDiff) -> String {
+    let mut output = String::new();
+    let mut line = |label: &str, from: String, to: String| {
+        output.push_str(&format!("{:<LABEL_WIDTH$}{} -> {}\n", label, from, to));
+    };
+
+    if let Some(change) = diff.mode {
+        line(
+            "Mode",
+            mode_label(change.from).into(),
+            mode_label(change.to).into(),
+        );
+    }
+    if let Some(Change { from, to }) = diff.manual_speed {
+        let label =
+            |speed: Option<u8>| speed.map_or("-".to_string(), |speed| format!("{}%", speed));
+        line("Manual Speed", label(from), label(to));
+    }
+    if let Some(change) = diff.work_mode {
+        line(
+            "Work Mode",
+            work_mode_label(change.from).into(),
+            work_mode_label(change.to).into(),
+        );
+    }
+    if let Some(change) = diff.modbus_address {
+        line(
+            "Modbus Address",
+            format!("0x{:04X}", change.from),
+            format!("0x{:04X}", change.to),
+        );
+    }
+    if let Some(change) = diff.pwm_frequency {
+        line(
+            "PWM Frequency",
+            format!("{} Hz", change.from.to_hz()),
+            format!("{} Hz", change.to.to_hz()),
+        );
+    }
+    if let Some(change) = diff.fan_count {
+        line(
+            "Fan Quantity",
+            change.from.to_string(),
+            change.to.to_string(),
+        );
+    }
+    if let Some(change) = diff.sensor_fault {
+        let label = if change.to {
+            "!! Temperature"
+        } else {
+            "Temperature"
         };
-        output.push_str(&format!("        {:<14}{}\n", "Status", status_str));
-        output.push_str(&format!("        {:<14}{}\n", "Speed (RPM)", fan.rpm));
+        line(
+            label,
+            sensor_label(change.from).into(),
+            sensor_label(change.to).into(),
+        );
+    }
+    if let Some(change) = diff.temperature_current {
+        line(
+            "Temperature",
+            format_temperature(&change.from),
+            format_temperature(&change.to),
+        );
+    }
+    let thresholds = [
+        ("    Low Threshold", diff.temperature_low_threshold),
+        ("    High Threshold", diff.temperature_high_threshold),
+    ];
+    for (label, change) in thresholds {
+        if let Some(Change { from, to }) = change {
+            line(label, format_temperature(&from), format_temperature(&to));
+        }
+    }
+    for fan in &diff.fans {
+        if let Some(change) = fan.status {
+            let label = if fan.fault_raised() {
+                format!("!! Fan {} Status", fan.index)
+            } else {
+                format!("Fan {} Status", fan.index)
+            };
+            let to = match change.to {
+                FanStatus::Fault => "FAULT".into(),
+                FanStatus::Normal => fan_status_label(change.to).into(),
+            };
+            line(&label, fan_status_label(change.from).into(), to);
+        }
+        if let Some(change) = fan.rpm {
+            line(
+                &format!("Fan {} Speed (RPM)", fan.index),
+                change.from.to_string(),
+                change.to.to_string(),
+            );
+        }
     }
 
     output
 }
 
+fn mode_label(mode: OperatingMode) -> &'static str {
+    match mode {
+        OperatingMode::Temperature => "Temperature",
+        OperatingMode::Manual => "Manual",
+        OperatingMode::Unknown => "Unknown",
+    }
+}
+
+fn work_mode_label(mode: WorkMode) -> &'static str {
+    match mode {
+        WorkMode::Shutdown => "Shutdown (ECO)",
+        WorkMode::MinimumSpeed => "Minimum Speed",
+    }
+}
+
+fn fan_status_label(status: FanStatus) -> &'static str {
+    match status {
+        FanStatus::Normal => "Normal",
+        FanStatus::Fault => "Fault",
+    }
+}
+
+fn sensor_label(fault: bool) -> &'static str {
+    if fault {
+        "SENSOR FAULT"
+    } else {
+        "Sensor OK"
+    }
+}
+
 /// Formats a temperature value with unit symbol.
 fn format_temperature(temp: &Temperature) -> String {
     let symbol = match temp.unit {
         TemperatureUnit::Celsius => "℃",
         TemperatureUnit::Fahrenheit => "℉",
+        TemperatureUnit::Kelvin => "K",
     };
     format!("{} {}", temp.value, symbol)
 }
@@ -84,35 +283,278 @@ pub fn format_status_json(status: &ControllerStatus) -> Result<String, serde_jso
     serde_json::to_string_pretty(status)
 }
 
-/// Converts temperatures from Celsius to Fahrenheit in status.
-pub fn convert_to_fahrenheit(mut status: ControllerStatus) -> ControllerStatus {
-    status.temperature_current = celsius_to_fahrenheit_temp(status.temperature_current);
-    status.temperature_low_threshold = celsius_to_fahrenheit_temp(status.temperature_low_threshold);
-    status.temperature_high_threshold =
-        celsius_to_fahrenheit_temp(status.temperature_high_threshold);
+/// Adapter line shown for the controller in sensors-style output.
+const SENSORS_ADAPTER: &str = "Modbus RTU adapter";
 
-    status
+/// Column width for the `label:` prefix in sensors-style text output.
+///
+/// Matches the alignment `sensors` uses for `tempN`/`fanN` features.
+const SENSORS_LABEL_WIDTH: usize = 13;
+
+/// Returns the libsensors chip name (`prefix-bus-address`) for the controller.
+fn sensors_chip_name(status: &ControllerStatus) -> String {
+    format!("jpf4826-modbus-{:04x}", status.modbus_address)
 }
 
-/// Converts a single temperature from Celsius to Fahrenheit.
-fn celsius_to_fahrenheit_temp(temp: Temperature) -> Temperature {
-    if temp.unit == TemperatureUnit::Celsius {
-        Temperature {
-            value: (temp.value * 9 / 5) + 32,
-            unit: TemperatureUnit::Fahrenheit,
-        }
+/// Returns the degree suffix `sensors` prints for a temperature unit.
+fn sensors_degree_suffix(unit: TemperatureUnit) -> &'static str {
+    match unit {
+        TemperatureUnit::Celsius => "°C",
+        TemperatureUnit::Fahrenheit => "°F",
+        TemperatureUnit::Kelvin => "K",
+    }
+}
+
+/// Formats controller status like the text output of lm-sensors `sensors`.
+///
+/// The controller is shown as one chip with a single temperature (`temp1`,
+/// with the thresholds as `low`/`high` limits) and one `fanN` per fan.
+/// Faulty fans are flagged with `ALARM`; a faulty sensor shows `FAULT` in
+/// place of the temperature.
+pub fn format_status_sensors(status: &ControllerStatus) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("{}\n", sensors_chip_name(status)));
+    output.push_str(&format!("Adapter: {}\n", SENSORS_ADAPTER));
+
+    let suffix = sensors_degree_suffix(status.temperature_current.unit);
+    let current = if status.sensor_fault {
+        format!("{:>8}", "FAULT")
     } else {
-        temp
+        format!(
+            "{:+6.1}{}",
+            f64::from(status.temperature_current.value),
+            suffix
+        )
+    };
+    output.push_str(&format!(
+        "{:<SENSORS_LABEL_WIDTH$}{}  ({:<4} = {:+5.1}{}, {:<4} = {:+5.1}{})\n",
+        "temp1:",
+        current,
+        "low",
+        f64::from(status.temperature_low_threshold.value),
+        suffix,
+        "high",
+        f64::from(status.temperature_high_threshold.value),
+        suffix
+    ));
+
+    for fan in &status.fans {
+        let alarm = match fan.status {
+            FanStatus::Normal => "",
+            FanStatus::Fault => "  ALARM",
+        };
+        output.push_str(&format!(
+            "{:<SENSORS_LABEL_WIDTH$}{:>4} RPM{}\n",
+            format!("fan{}:", fan.index),
+            fan.rpm,
+            alarm
+        ));
+    }
+
+    // `sensors` separates chips with an empty line
+    output.push('\n');
+    output
+}
+
+/// Formats controller status like the JSON output of `sensors -j`.
+///
+/// Uses the libsensors subfeature names (`temp1_input`, `temp1_min`,
+/// `temp1_max`, `fanN_input`, `fanN_alarm`) and the same layout and number
+/// formatting as `sensors -j`. A faulty sensor adds `temp1_fault`.
+pub fn format_status_sensors_json(status: &ControllerStatus) -> String {
+    let fault = if status.sensor_fault {
+        ",\n         \"temp1_fault\": 1.000"
+    } else {
+        ""
+    };
+    let mut features = vec![format!(
+        "      \"temp1\":{{\n         \"temp1_input\": {:.3},\n         \"temp1_min\": {:.3},\n         \"temp1_max\": {:.3}{}\n      }}",
+        f64::from(status.temperature_current.value),
+        f64::from(status.temperature_low_threshold.value),
+        f64::from(status.temperature_high_threshold.value),
+        fault
+    )];
+    for fan in &status.fans {
+        let alarm = match fan.status {
+            FanStatus::Normal => 0.0,
+            FanStatus::Fault => 1.0,
+        };
+        features.push(format!(
+            "      \"fan{0}\":{{\n         \"fan{0}_input\": {1:.3},\n         \"fan{0}_alarm\": {2:.3}\n      }}",
+            fan.index,
+            f64::from(fan.rpm),
+            alarm
+        ));
+    }
+
+    format!(
+        "{{\n   \"{}\":{{\n      \"Adapter\": \"{}\",\n{}\n   }}\n}}\n",
+        sensors_chip_name(status),
+        SENSORS_ADAPTER,
+        features.join(",\n")
+    )
+}
+
+/// Keys of the `kv` status format, in output order.
+///
+/// The key set is an interface for shell scripts: keys are only ever added,
+/// never renamed or removed. `fan.N.*` keys are printed for the fans
+/// present in the status.
+pub const STATUS_KEYS: [&str; 23] = [
+    "mode",
+    "manual_speed",
+    "work_mode",
+    "modbus_address",
+    "pwm_frequency_hz",
+    "fan_count",
+    "temperature.unit",
+    "temperature.current",
+    "temperature.sensor_fault",
+    "temperature.low_threshold",
+    "temperature.high_threshold",
+    "fan.1.status",
+    "fan.1.rpm",
+    "fan.1.running",
+    "fan.2.status",
+    "fan.2.rpm",
+    "fan.2.running",
+    "fan.3.status",
+    "fan.3.rpm",
+    "fan.3.running",
+    "fan.4.status",
+    "fan.4.rpm",
+    "fan.4.running",
+];
+
+/// Returns the `kv` fields of `status` as unquoted `(key, value)` pairs.
+///
+/// Values are lowercase identifiers, decimal numbers or `true`/`false`.
+/// `temperature.current` is empty while the sensor is faulty, so scripts
+/// cannot mistake the pegged reading for a real one.
+pub fn status_fields(status: &ControllerStatus) -> Vec<(String, String)> {
+    let mode = match status.mode {
+        OperatingMode::Temperature => "temperature",
+        OperatingMode::Manual => "manual",
+        OperatingMode::Unknown => "unknown",
+    };
+    let work_mode = match status.work_mode {
+        WorkMode::Shutdown => "shutdown",
+        WorkMode::MinimumSpeed => "minimum_speed",
+    };
+    let unit = match status.temperature_current.unit {
+        TemperatureUnit::Celsius => "celsius",
+        TemperatureUnit::Fahrenheit => "fahrenheit",
+        TemperatureUnit::Kelvin => "kelvin",
+    };
+    let current = if status.sensor_fault {
+        String::new()
+    } else {
+        status.temperature_current.value.to_string()
+    };
+
+    let mut fields = vec![
+        ("mode".to_string(), mode.to_string()),
+        (
+            "manual_speed".to_string(),
+            status
+                .manual_speed
+                .map_or(String::new(), |speed| speed.to_string()),
+        ),
+        ("work_mode".to_string(), work_mode.to_string()),
+        (
+            "modbus_address".to_string(),
+            status.modbus_address.to_string(),
+        ),
+        (
+            "pwm_frequency_hz".to_string(),
+            status.pwm_frequency.to_hz().to_string(),
+        ),
+        ("fan_count".to_string(), status.fan_count.to_string()),
+        ("temperature.unit".to_string(), unit.to_string()),
+        ("temperature.current".to_string(), current),
+        (
+            "temperature.sensor_fault".to_string(),
+            status.sensor_fault.to_string(),
+        ),
+        (
+            "temperature.low_threshold".to_string(),
+            status.temperature_low_threshold.value.to_string(),
+        ),
+        (
+            "temperature.high_threshold".to_string(),
+            status.temperature_high_threshold.value.to_string(),
+        ),
+    ];
+    for fan in &status.fans {
+        let fan_status = match fan.status {
+            FanStatus::Normal => "normal",
+            FanStatus::Fault => "fault",
+        };
+        fields.push((format!("fan.{}.status", fan.index), fan_status.to_string()));
+        fields.push((format!("fan.{}.rpm", fan.index), fan.rpm.to_string()));
+        fields.push((
+            format!("fan.{}.running", fan.index),
+            fan.is_running.to_string(),
+        ));
     }
+    fields
+}
+
+/// Returns the unquoted value of the `kv` field `key`, if present.
+pub fn status_field(status: &ControllerStatus, key: &str) -> Option<String> {
+    status_fields(status)
+        .into_iter()
+        .find(|(field, _)| field == key)
+        .map(|(_, value)| value)
+}
+
+/// Formats controller status as one `key=value` line per field.
+///
+/// See [`STATUS_KEYS`] for the keys. Values are single-quoted for POSIX
+/// shells only if they contain characters other than letters, digits and
+/// `._-+`, so the output can be `eval`ed or parsed with `IFS==`.
+pub fn format_status_kv(status: &ControllerStatus) -> String {
+    status_fields(status)
+        .into_iter()
+        .map(|(key, value)| format!("{}={}\n", key, shell_quote(&value)))
+        .collect()
+}
+
+/// Quotes `value` for a POSIX shell if it needs it.
+fn shell_quote(value: &str) -> Cow<'_, str> {
+    let plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+'));
+    if plain {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(format!("'{}'", value.replace('\'', "'\\''")))
+    }
+}
+
+/// Converts the temperatures in status to `unit`.
+pub fn convert_temperatures(
+    mut status: ControllerStatus,
+    unit: TemperatureUnit,
+) -> ControllerStatus {
+    status.temperature_current = status.temperature_current.to_unit(unit);
+    status.temperature_low_threshold = status.temperature_low_threshold.to_unit(unit);
+    status.temperature_high_threshold = status.temperature_high_threshold.to_unit(unit);
+
+    status
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use jpf4826_driver::{FanInfo, FanStatus, PwmFrequency};
+    use jpf4826_driver::{FanIndex, FanInfo, FanStatus, PwmFrequency};
 
     fn create_test_status() -> ControllerStatus {
+        #[allow(deprecated)]
         ControllerStatus {
+            mode: OperatingMode::Temperature,
+            manual_speed: None,
+            work_mode: WorkMode::Shutdown,
             eco_mode: true,
             modbus_address: 1,
             pwm_frequency: PwmFrequency::Hz25000,
@@ -121,6 +563,7 @@ mod tests {
                 value: 26,
                 unit: TemperatureUnit::Celsius,
             },
+            sensor_fault: false,
             temperature_low_threshold: Temperature {
                 value: 27,
                 unit: TemperatureUnit::Celsius,
@@ -131,14 +574,16 @@ mod tests {
             },
             fans: vec![
                 FanInfo {
-                    index: 1,
+                    index: FanIndex::FAN1,
                     status: FanStatus::Normal,
                     rpm: 1400,
+                    is_running: true,
                 },
                 FanInfo {
-                    index: 2,
+                    index: FanIndex::FAN2,
                     status: FanStatus::Fault,
                     rpm: 0,
+                    is_running: false,
                 },
             ],
         }
@@ -150,13 +595,43 @@ mod tests {
         let output = format_status_text(&status);
 
         // Verify fixed-width column alignment (22 chars for labels)
-        assert!(output.contains("ECO Mode              true"));
+        assert!(output.contains("Mode                  Temperature"));
+        assert!(output.contains("Work Mode             Shutdown (ECO)"));
         assert!(output.contains("Modbus Address        0x0001"));
         assert!(output.contains("PWM Frequency         25000 Hz"));
         assert!(output.contains("Fan Quantity          4"));
         assert!(output.contains("Temperature           26 ℃"));
         assert!(output.contains("Status        Normal"));
         assert!(output.contains("Status        Fault"));
+        assert!(output.contains("Running       Yes"));
+        assert!(output.contains("Running       No"));
+    }
+
+    #[test]
+    fn test_format_manual_speed() {
+        let before = create_test_status();
+        let mut status = before.clone();
+        status.mode = OperatingMode::Manual;
+        status.manual_speed = Some(40);
+
+        let output = format_status_text(&status);
+        assert!(output.contains("Mode                  Manual (40%)\n"));
+        assert_eq!(status_field(&status, "manual_speed").as_deref(), Some("40"));
+        assert_eq!(status_field(&before, "manual_speed").as_deref(), Some(""));
+
+        let diff = format_status_diff_text(&before.diff(&status));
+        assert!(diff.contains("Mode                  Temperature -> Manual\n"));
+        assert!(diff.contains("Manual Speed          - -> 40%\n"));
+    }
+
+    #[test]
+    fn test_format_text_sensor_fault() {
+        let mut status = create_test_status();
+        status.sensor_fault = true;
+        let output = format_status_text(&status);
+
+        assert!(output.contains("Temperature           SENSOR FAULT\n"));
+        assert!(output.contains("Low Threshold     27 ℃"));
     }
 
     #[test]
@@ -169,12 +644,166 @@ mod tests {
         assert!(json.contains("\"eco_mode\""));
     }
 
+    #[test]
+    fn test_format_text_bars_golden() {
+        let status = create_test_status();
+        let visuals = Visuals {
+            width: 40,
+            nominal_rpm: Some(2000),
+        };
+        let output = format_status_text_with(&status, Some(&visuals));
+
+        assert!(output.ends_with(
+            "\
+Fan Status
+    1
+        Status        Normal
+        Running       Yes
+        Speed (RPM)   1400  ████████░░░░
+    2
+        Status        Fault
+        Running       No
+        Speed (RPM)   0     ░░░░░░░░░░░░
+"
+        ));
+    }
+
+    #[test]
+    fn test_format_text_bars_narrow_terminal() {
+        let status = create_test_status();
+        let narrow = Visuals {
+            width: 32,
+            nominal_rpm: None,
+        };
+        let output = format_status_text_with(&status, Some(&narrow));
+        assert_eq!(output, format_status_text(&status));
+
+        let wide = Visuals {
+            width: 200,
+            ..narrow
+        };
+        let output = format_status_text_with(&status, Some(&wide));
+        let full_bar = "█".repeat(visual::MAX_BAR_WIDTH);
+        assert!(output.contains(&format!("Speed (RPM)   1400  {}\n", full_bar)));
+    }
+
+    #[test]
+    fn test_format_temperature_trend() {
+        let samples = [Some(30), Some(32), None, Some(34), Some(33)];
+        let visuals = Visuals {
+            width: 26,
+            nominal_rpm: None,
+        };
+        assert_eq!(
+            format_temperature_trend(&samples, &visuals),
+            "Trend                 ▁ █▄\n"
+        );
+        let visuals = Visuals {
+            width: 80,
+            ..visuals
+        };
+        assert_eq!(
+            format_temperature_trend(&samples, &visuals),
+            "Trend                 ▁▄ █▆\n"
+        );
+        assert_eq!(format_temperature_trend(&samples[..1], &visuals), "");
+        let visuals = Visuals {
+            width: 25,
+            ..visuals
+        };
+        assert_eq!(format_temperature_trend(&samples, &visuals), "");
+    }
+
+    #[test]
+    fn test_format_sensors_golden() {
+        let status = create_test_status();
+
+        let expected = "\
+jpf4826-modbus-0001
+Adapter: Modbus RTU adapter
+temp1:        +26.0°C  (low  = +27.0°C, high = +40.0°C)
+fan1:        1400 RPM
+fan2:           0 RPM  ALARM
+
+";
+        assert_eq!(format_status_sensors(&status), expected);
+    }
+
+    #[test]
+    fn test_format_sensors_fahrenheit_suffix() {
+        let status = convert_temperatures(create_test_status(), TemperatureUnit::Fahrenheit);
+
+        assert!(format_status_sensors(&status)
+            .contains("temp1:        +79.0°F  (low  = +81.0°F, high = +104.0°F)"));
+    }
+
+    #[test]
+    fn test_kelvin_conversion_and_rendering() {
+        let status = convert_temperatures(create_test_status(), TemperatureUnit::Kelvin);
+
+        assert_eq!(status.temperature_current.value, 299);
+        assert_eq!(status.temperature_current.unit, TemperatureUnit::Kelvin);
+        assert!(format_status_text(&status).contains("299 K"));
+        assert!(format_status_sensors(&status)
+            .contains("temp1:       +299.0K  (low  = +300.0K, high = +313.0K)"));
+        assert!(format_status_kv(&status).contains("temperature.unit=kelvin\n"));
+    }
+
+    #[test]
+    fn test_format_sensors_json_golden() {
+        let status = create_test_status();
+
+        let expected = r#"{
+   "jpf4826-modbus-0001":{
+      "Adapter": "Modbus RTU adapter",
+      "temp1":{
+         "temp1_input": 26.000,
+         "temp1_min": 27.000,
+         "temp1_max": 40.000
+      },
+      "fan1":{
+         "fan1_input": 1400.000,
+         "fan1_alarm": 0.000
+      },
+      "fan2":{
+         "fan2_input": 0.000,
+         "fan2_alarm": 1.000
+      }
+   }
+}
+"#;
+        assert_eq!(format_status_sensors_json(&status), expected);
+    }
+
+    #[test]
+    fn test_format_sensors_sensor_fault() {
+        let mut status = create_test_status();
+        status.sensor_fault = true;
+
+        assert!(format_status_sensors(&status)
+            .contains("temp1:          FAULT  (low  = +27.0°C, high = +40.0°C)"));
+        let parsed: serde_json::Value =
+            serde_json::from_str(&format_status_sensors_json(&status)).unwrap();
+        assert_eq!(parsed["jpf4826-modbus-0001"]["temp1"]["temp1_fault"], 1.0);
+    }
+
+    #[test]
+    fn test_format_sensors_json_is_valid_json() {
+        let status = create_test_status();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&format_status_sensors_json(&status)).unwrap();
+
+        let chip = &parsed["jpf4826-modbus-0001"];
+        assert_eq!(chip["temp1"]["temp1_input"], 26.0);
+        assert_eq!(chip["fan2"]["fan2_alarm"], 1.0);
+    }
+
     #[test]
     fn test_fahrenheit_conversion() {
         let status = create_test_status();
-        let converted = convert_to_fahrenheit(status);
+        let converted = convert_temperatures(status, TemperatureUnit::Fahrenheit);
 
-        assert_eq!(converted.temperature_current.value, 78); // 26°C = 78.8°F ≈ 78
+        assert_eq!(converted.temperature_current.value, 79); // 26°C = 78.8°F
         assert_eq!(
             converted.temperature_current.unit,
             TemperatureUnit::Fahrenheit
@@ -184,7 +813,11 @@ mod tests {
     #[test]
     fn test_json_output_matches_schema() {
         // Create a realistic status with all 4 fans
+        #[allow(deprecated)]
         let status = ControllerStatus {
+            mode: OperatingMode::Temperature,
+            manual_speed: None,
+            work_mode: WorkMode::Shutdown,
             eco_mode: true,
             modbus_address: 1,
             pwm_frequency: PwmFrequency::Hz25000,
@@ -193,6 +826,7 @@ mod tests {
                 value: 26,
                 unit: TemperatureUnit::Celsius,
             },
+            sensor_fault: false,
             temperature_low_threshold: Temperature {
                 value: 27,
                 unit: TemperatureUnit::Celsius,
@@ -203,24 +837,28 @@ mod tests {
             },
             fans: vec![
                 FanInfo {
-                    index: 1,
+                    index: FanIndex::FAN1,
                     status: FanStatus::Normal,
                     rpm: 1400,
+                    is_running: true,
                 },
                 FanInfo {
-                    index: 2,
+                    index: FanIndex::FAN2,
                     status: FanStatus::Fault,
                     rpm: 0,
+                    is_running: false,
                 },
                 FanInfo {
-                    index: 3,
+                    index: FanIndex::FAN3,
                     status: FanStatus::Normal,
                     rpm: 1400,
+                    is_running: true,
                 },
                 FanInfo {
-                    index: 4,
+                    index: FanIndex::FAN4,
                     status: FanStatus::Normal,
                     rpm: 1400,
+                    is_running: true,
                 },
             ],
         };
@@ -247,4 +885,87 @@ mod tests {
             panic!("JSON output does not match schema:\n{}", validation_error);
         }
     }
+
+    #[test]
+    fn test_format_kv_golden() {
+        let status = create_test_status();
+        let output = format_status_kv(&status);
+
+        assert_eq!(
+            output,
+            "mode=temperature\n\
+             manual_speed=\n\
+             work_mode=shutdown\n\
+             modbus_address=1\n\
+             pwm_frequency_hz=25000\n\
+             fan_count=4\n\
+             temperature.unit=celsius\n\
+             temperature.current=26\n\
+             temperature.sensor_fault=false\n\
+             temperature.low_threshold=27\n\
+             temperature.high_threshold=40\n\
+             fan.1.status=normal\n\
+             fan.1.rpm=1400\n\
+             fan.1.running=true\n\
+             fan.2.status=fault\n\
+             fan.2.rpm=0\n\
+             fan.2.running=false\n"
+        );
+    }
+
+    #[test]
+    fn test_format_kv_keys_are_documented() {
+        let mut status = create_test_status();
+        status.fans = FanIndex::ALL
+            .into_iter()
+            .map(|index| FanInfo {
+                index,
+                status: FanStatus::Normal,
+                rpm: 1200,
+                is_running: true,
+            })
+            .collect();
+
+        let keys: Vec<String> = status_fields(&status)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, STATUS_KEYS);
+    }
+
+    #[test]
+    fn test_format_kv_sensor_fault_and_fahrenheit() {
+        let mut status = convert_temperatures(create_test_status(), TemperatureUnit::Fahrenheit);
+        let output = format_status_kv(&status);
+        assert!(output.contains("temperature.unit=fahrenheit\n"));
+        assert!(output.contains("temperature.current=79\n"));
+
+        status.sensor_fault = true;
+        let output = format_status_kv(&status);
+        assert!(output.contains("temperature.current=\n"));
+        assert!(output.contains("temperature.sensor_fault=true\n"));
+    }
+
+    #[test]
+    fn test_status_field() {
+        let status = create_test_status();
+
+        assert_eq!(status_field(&status, "fan.1.rpm").as_deref(), Some("1400"));
+        assert_eq!(
+            status_field(&status, "work_mode").as_deref(),
+            Some("shutdown")
+        );
+        // Valid key, but the status has only two fans
+        assert_eq!(status_field(&status, "fan.3.rpm"), None);
+        assert_eq!(status_field(&status, "temperature"), None);
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("minimum_speed"), "minimum_speed");
+        assert_eq!(shell_quote("-20"), "-20");
+        assert_eq!(shell_quote(""), "");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
 }
@@ -0,0 +1,293 @@
+//! OpenTelemetry metrics for the `watch` command.
+//!
+//! Every poll records the controller state as OTLP gauges and counts poll
+//! outcomes. Metrics are pushed periodically to an OTLP/gRPC collector
+//! configured through the standard `OTEL_EXPORTER_OTLP_*`,
+//! `OTEL_METRIC_EXPORT_INTERVAL`, `OTEL_SERVICE_NAME` and
+//! `OTEL_RESOURCE_ATTRIBUTES` environment variables.
+//!
+//! # Instruments
+//!
+//! | Name                   | Kind    | Unit    | Attributes |
+//! |------------------------|---------|---------|------------|
+//! | `jpf4826.temperature`  | gauge   | `Cel`   |            |
+//! | `jpf4826.fan.speed`    | gauge   | `{rpm}` | `fan`      |
+//! | `jpf4826.fan.fault`    | gauge   | `1`     | `fan`      |
+//! | `jpf4826.polls`        | counter | `{poll}`| `result` (`success`, `error`) |
+//!
+//! The resource carries `jpf4826.port` and `jpf4826.address`.
+
+// Rust guideline compliant 2026-10-16
+
+use std::time::Duration;
+
+use jpf4826_driver::{ControllerStatus, FanStatus};
+use opentelemetry::metrics::{Counter, Gauge, Meter, MeterProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::error::OTelSdkResult;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
+use opentelemetry_sdk::metrics::{SdkMeterProvider, Temporality};
+use opentelemetry_sdk::Resource;
+
+/// Instrumentation scope name used for all instruments.
+const METER_NAME: &str = "jpf4826ctl";
+
+/// Instruments updated on every poll.
+pub struct PollMetrics {
+    temperature: Gauge<i64>,
+    fan_speed: Gauge<u64>,
+    fan_fault: Gauge<u64>,
+    polls: Counter<u64>,
+}
+
+impl PollMetrics {
+    /// Creates the instruments on `meter`.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            temperature: meter
+                .i64_gauge("jpf4826.temperature")
+                .with_description("Current controller temperature")
+                .with_unit("Cel")
+                .build(),
+            fan_speed: meter
+                .u64_gauge("jpf4826.fan.speed")
+                .with_description("Fan speed")
+                .with_unit("{rpm}")
+                .build(),
+            fan_fault: meter
+                .u64_gauge("jpf4826.fan.fault")
+                .with_description("Fan fault state (1 = fault)")
+                .with_unit("1")
+                .build(),
+            polls: meter
+                .u64_counter("jpf4826.polls")
+                .with_description("Status polls by result")
+                .with_unit("{poll}")
+                .build(),
+        }
+    }
+
+    /// Records a successful poll.
+    ///
+    /// Temperatures are recorded as reported by the controller, in Celsius.
+    /// No temperature is recorded while the sensor reports a fault.
+    pub fn record_status(&self, status: &ControllerStatus) {
+        if !status.sensor_fault {
+            self.temperature
+                .record(i64::from(status.temperature_current.value), &[]);
+        }
+        for fan in &status.fans {
+            let attributes = [KeyValue::new("fan", i64::from(fan.index.get()))];
+            self.fan_speed.record(u64::from(fan.rpm), &attributes);
+            let fault = u64::from(fan.status == FanStatus::Fault);
+            self.fan_fault.record(fault, &attributes);
+        }
+        self.polls.add(1, &[KeyValue::new("result", "success")]);
+    }
+
+    /// Records a failed poll.
+    pub fn record_error(&self) {
+        self.polls.add(1, &[KeyValue::new("result", "error")]);
+    }
+}
+
+/// Metric pipeline exporting over OTLP.
+///
+/// Shuts the pipeline down, flushing pending metrics, when dropped.
+pub struct Telemetry {
+    provider: SdkMeterProvider,
+    metrics: PollMetrics,
+}
+
+impl Telemetry {
+    /// Builds the OTLP pipeline for the controller at `port`/`addr`.
+    ///
+    /// `endpoint` overrides `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the exporter cannot be configured.
+    pub fn otlp(port: &str, addr: u8, endpoint: Option<&str>) -> anyhow::Result<Self> {
+        let mut builder = opentelemetry_otlp::MetricExporter::builder().with_tonic();
+        if let Some(endpoint) = endpoint {
+            builder = builder.with_endpoint(endpoint);
+        }
+        let exporter = LoggingExporter(builder.build()?);
+        Ok(Self::with_exporter(exporter, port, addr))
+    }
+
+    /// Builds the pipeline on top of any push exporter.
+    pub fn with_exporter<E: PushMetricExporter>(exporter: E, port: &str, addr: u8) -> Self {
+        let provider = SdkMeterProvider::builder()
+            .with_resource(resource(port, addr))
+            .with_periodic_exporter(exporter)
+            .build();
+        let metrics = PollMetrics::new(&provider.meter(METER_NAME));
+        Self { provider, metrics }
+    }
+
+    /// Returns the poll instruments.
+    pub fn metrics(&self) -> &PollMetrics {
+        &self.metrics
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        if let Err(e) = self.provider.shutdown() {
+            log::warn!("Failed to shut down metrics exporter: {}", e);
+        }
+    }
+}
+
+/// Builds the resource, keeping env-provided attributes.
+fn resource(port: &str, addr: u8) -> Resource {
+    let mut builder = Resource::builder().with_attributes([
+        KeyValue::new("jpf4826.port", port.to_string()),
+        KeyValue::new("jpf4826.address", i64::from(addr)),
+    ]);
+    if std::env::var_os("OTEL_SERVICE_NAME").is_none() {
+        builder = builder.with_service_name(METER_NAME);
+    }
+    builder.build()
+}
+
+/// Exporter wrapper logging failed exports.
+///
+/// The periodic reader drops export errors; logging them here keeps an
+/// unreachable collector visible without interrupting polling.
+struct LoggingExporter<E>(E);
+
+impl<E: PushMetricExporter> PushMetricExporter for LoggingExporter<E> {
+    async fn export(&self, metrics: &ResourceMetrics) -> OTelSdkResult {
+        let result = self.0.export(metrics).await;
+        if let Err(e) = &result {
+            log::warn!("Failed to export metrics: {}", e);
+        }
+        result
+    }
+
+    fn force_flush(&self) -> OTelSdkResult {
+        self.0.force_flush()
+    }
+
+    fn shutdown_with_timeout(&self, timeout: Duration) -> OTelSdkResult {
+        self.0.shutdown_with_timeout(timeout)
+    }
+
+    fn temporality(&self) -> Temporality {
+        self.0.temporality()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_sdk::metrics::data::{AggregatedMetrics, MetricData};
+    use opentelemetry_sdk::metrics::InMemoryMetricExporter;
+
+    fn test_status() -> ControllerStatus {
+        // 31°C, fan 3 faulty and stopped
+        ControllerStatus::from_registers(&[
+            71, 0x000F, 1, 0xFFFF, 0x465A, 1, 4, 1400, 1410, 0, 1430, 0x0005, 70, 90, 0x000B,
+        ])
+    }
+
+    /// Exported data points by metric name.
+    type Collected = Vec<(String, Vec<(Vec<KeyValue>, i128)>)>;
+
+    fn collect(exporter: &InMemoryMetricExporter) -> Collected {
+        let mut collected = Vec::new();
+        let resource_metrics = exporter.get_finished_metrics().unwrap();
+        let last = resource_metrics.last().expect("no metrics exported");
+        for scope in last.scope_metrics() {
+            for metric in scope.metrics() {
+                let points = match metric.data() {
+                    AggregatedMetrics::I64(MetricData::Gauge(gauge)) => gauge
+                        .data_points()
+                        .map(|p| (p.attributes().cloned().collect(), i128::from(p.value())))
+                        .collect(),
+                    AggregatedMetrics::U64(MetricData::Gauge(gauge)) => gauge
+                        .data_points()
+                        .map(|p| (p.attributes().cloned().collect(), i128::from(p.value())))
+                        .collect(),
+                    AggregatedMetrics::U64(MetricData::Sum(sum)) => sum
+                        .data_points()
+                        .map(|p| (p.attributes().cloned().collect(), i128::from(p.value())))
+                        .collect(),
+                    other => panic!("unexpected aggregation for {}: {:?}", metric.name(), other),
+                };
+                collected.push((metric.name().to_string(), points));
+            }
+        }
+        collected.sort_by(|a, b| a.0.cmp(&b.0));
+        collected
+    }
+
+    fn value_for(collected: &Collected, name: &str, attribute: Option<KeyValue>) -> i128 {
+        let (_, points) = collected
+            .iter()
+            .find(|(n, _)| n == name)
+            .unwrap_or_else(|| panic!("metric {} missing", name));
+        points
+            .iter()
+            .find(|(attrs, _)| match &attribute {
+                Some(kv) => attrs.contains(kv),
+                None => attrs.is_empty(),
+            })
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("data point {:?} missing for {}", attribute, name))
+    }
+
+    #[test]
+    fn test_records_status_gauges_and_poll_counter() {
+        let exporter = InMemoryMetricExporter::default();
+        let telemetry = Telemetry::with_exporter(exporter.clone(), "/dev/ttyUSB0", 3);
+
+        telemetry.metrics().record_status(&test_status());
+        telemetry.metrics().record_status(&test_status());
+        telemetry.metrics().record_error();
+        telemetry.provider.force_flush().unwrap();
+
+        let collected = collect(&exporter);
+        let names: Vec<_> = collected.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "jpf4826.fan.fault",
+                "jpf4826.fan.speed",
+                "jpf4826.polls",
+                "jpf4826.temperature"
+            ]
+        );
+        assert_eq!(value_for(&collected, "jpf4826.temperature", None), 31);
+        let fan = |index: i64| Some(KeyValue::new("fan", index));
+        assert_eq!(value_for(&collected, "jpf4826.fan.speed", fan(2)), 1410);
+        assert_eq!(value_for(&collected, "jpf4826.fan.speed", fan(3)), 0);
+        assert_eq!(value_for(&collected, "jpf4826.fan.fault", fan(3)), 1);
+        assert_eq!(value_for(&collected, "jpf4826.fan.fault", fan(1)), 0);
+        let result = |r: &'static str| Some(KeyValue::new("result", r));
+        assert_eq!(value_for(&collected, "jpf4826.polls", result("success")), 2);
+        assert_eq!(value_for(&collected, "jpf4826.polls", result("error")), 1);
+    }
+
+    #[test]
+    fn test_resource_attributes() {
+        let exporter = InMemoryMetricExporter::default();
+        let telemetry = Telemetry::with_exporter(exporter.clone(), "/dev/ttyUSB0", 3);
+
+        telemetry.metrics().record_error();
+        telemetry.provider.force_flush().unwrap();
+
+        let resource_metrics = exporter.get_finished_metrics().unwrap();
+        let resource = resource_metrics.last().unwrap().resource();
+        assert_eq!(
+            resource.get(&"jpf4826.port".into()),
+            Some(Value::from("/dev/ttyUSB0"))
+        );
+        assert_eq!(resource.get(&"jpf4826.address".into()), Some(Value::I64(3)));
+    }
+}
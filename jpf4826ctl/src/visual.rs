@@ -0,0 +1,196 @@
+//! Bars and sparklines for text output.
+//!
+//! The renderers are pure functions of their inputs, including the terminal
+//! width, so their output can be pinned by golden tests. [`Visuals::detect`]
+//! is the only place that looks at the environment.
+
+// Rust guideline compliant 2026-10-16
+
+use std::io::IsTerminal;
+
+use crate::cli::BarsMode;
+
+/// Width assumed when stdout is a terminal but `COLUMNS` is not set.
+const DEFAULT_WIDTH: usize = 80;
+
+/// Widest bar drawn, so bars stay comparable on very wide terminals.
+pub const MAX_BAR_WIDTH: usize = 30;
+
+/// Narrowest bar worth drawing; below this the bar is left out.
+pub const MIN_BAR_WIDTH: usize = 5;
+
+/// Most samples a sparkline shows.
+pub const MAX_SPARKLINE_WIDTH: usize = 60;
+
+/// Fewest samples worth a sparkline; below this it is left out.
+pub const MIN_SPARKLINE_WIDTH: usize = 4;
+
+const BAR_FILLED: char = '█';
+const BAR_EMPTY: char = '░';
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Settings for bars and sparklines in text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visuals {
+    /// Terminal width in columns.
+    pub width: usize,
+    /// RPM drawn as a full bar; the fastest fan if `None`.
+    pub nominal_rpm: Option<u16>,
+}
+
+impl Visuals {
+    /// Returns the visuals to draw for `mode`, or `None` for plain output.
+    ///
+    /// [`BarsMode::Auto`] draws only when stdout is a terminal. The width is
+    /// taken from `COLUMNS`, defaulting to 80.
+    pub fn detect(mode: BarsMode, nominal_rpm: Option<u16>) -> Option<Self> {
+        let enabled = match mode {
+            BarsMode::Always => true,
+            BarsMode::Never => false,
+            BarsMode::Auto => std::io::stdout().is_terminal(),
+        };
+        enabled.then(|| Self {
+            width: terminal_width(std::env::var("COLUMNS").ok().as_deref()),
+            nominal_rpm,
+        })
+    }
+
+    /// Returns the RPM drawn as a full bar, given the fastest fan.
+    pub fn scale(&self, max_rpm: u16) -> u16 {
+        self.nominal_rpm.unwrap_or(max_rpm)
+    }
+}
+
+/// Parses the `COLUMNS` value, falling back to the default width.
+fn terminal_width(columns: Option<&str>) -> usize {
+    columns
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|&width| width > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Returns the width of a bar following `used` columns, or `None` if the
+/// terminal is too narrow for one.
+pub fn bar_width(terminal_width: usize, used: usize) -> Option<usize> {
+    let available = terminal_width.saturating_sub(used).min(MAX_BAR_WIDTH);
+    (available >= MIN_BAR_WIDTH).then_some(available)
+}
+
+/// Draws `rpm` as a bar of `width` cells, full at `scale`.
+///
+/// Speeds above `scale` draw a full bar; a `scale` of 0 an empty one.
+pub fn rpm_bar(rpm: u16, scale: u16, width: usize) -> String {
+    let filled = if scale == 0 {
+        0
+    } else {
+        let cells = (u64::from(rpm) * width as u64 + u64::from(scale) / 2) / u64::from(scale);
+        (cells as usize).min(width)
+    };
+    let mut bar = String::with_capacity(width * BAR_FILLED.len_utf8());
+    bar.extend(std::iter::repeat_n(BAR_FILLED, filled));
+    bar.extend(std::iter::repeat_n(BAR_EMPTY, width - filled));
+    bar
+}
+
+/// Returns how many samples a sparkline following `used` columns shows, or
+/// `None` if the terminal is too narrow for one.
+pub fn sparkline_width(terminal_width: usize, used: usize) -> Option<usize> {
+    let available = terminal_width.saturating_sub(used).min(MAX_SPARKLINE_WIDTH);
+    (available >= MIN_SPARKLINE_WIDTH).then_some(available)
+}
+
+/// Draws the last `width` samples as a sparkline, scaled between their
+/// minimum and maximum.
+///
+/// Missing samples, such as readings during a sensor fault, are drawn as
+/// spaces. A flat history is drawn at the lowest level.
+pub fn sparkline(samples: &[Option<i16>], width: usize) -> String {
+    let recent = &samples[samples.len().saturating_sub(width)..];
+    let values = recent.iter().flatten().copied();
+    let (Some(min), Some(max)) = (values.clone().min(), values.max()) else {
+        return " ".repeat(recent.len());
+    };
+    let range = i32::from(max) - i32::from(min);
+    let top = SPARK_LEVELS.len() as i32 - 1;
+
+    recent
+        .iter()
+        .map(|sample| match sample {
+            Some(value) if range > 0 => {
+                let level = (i32::from(*value) - i32::from(min)) * top / range;
+                SPARK_LEVELS[level as usize]
+            }
+            Some(_) => SPARK_LEVELS[0],
+            None => ' ',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpm_bar_proportions() {
+        assert_eq!(rpm_bar(1400, 2000, 10), "███████░░░");
+        assert_eq!(rpm_bar(0, 2000, 10), "░░░░░░░░░░");
+        assert_eq!(rpm_bar(2000, 2000, 10), "██████████");
+        assert_eq!(rpm_bar(2600, 2000, 10), "██████████");
+        assert_eq!(rpm_bar(1400, 0, 5), "░░░░░");
+        // Rounded to the nearest cell
+        assert_eq!(rpm_bar(1099, 2000, 10), "█████░░░░░");
+        assert_eq!(rpm_bar(1100, 2000, 10), "██████░░░░");
+    }
+
+    #[test]
+    fn test_bar_width_truncates_on_narrow_terminals() {
+        assert_eq!(bar_width(120, 28), Some(MAX_BAR_WIDTH));
+        assert_eq!(bar_width(40, 28), Some(12));
+        assert_eq!(bar_width(33, 28), Some(5));
+        assert_eq!(bar_width(32, 28), None);
+        assert_eq!(bar_width(10, 28), None);
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_history() {
+        let samples: Vec<_> = [30, 31, 32, 33, 34, 35, 36, 37].map(Some).into();
+        assert_eq!(sparkline(&samples, 8), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&samples, 3), "▁▄█");
+        assert_eq!(sparkline(&[Some(31); 4], 10), "▁▁▁▁");
+        assert_eq!(sparkline(&[Some(20), None, Some(48)], 10), "▁ █");
+        assert_eq!(sparkline(&[None, None], 10), "  ");
+        assert_eq!(sparkline(&[], 10), "");
+    }
+
+    #[test]
+    fn test_sparkline_width_truncates_on_narrow_terminals() {
+        assert_eq!(sparkline_width(200, 22), Some(MAX_SPARKLINE_WIDTH));
+        assert_eq!(sparkline_width(30, 22), Some(8));
+        assert_eq!(sparkline_width(25, 22), None);
+    }
+
+    #[test]
+    fn test_terminal_width_from_columns() {
+        assert_eq!(terminal_width(Some("132")), 132);
+        assert_eq!(terminal_width(Some(" 40\n")), 40);
+        assert_eq!(terminal_width(Some("0")), DEFAULT_WIDTH);
+        assert_eq!(terminal_width(Some("wide")), DEFAULT_WIDTH);
+        assert_eq!(terminal_width(None), DEFAULT_WIDTH);
+    }
+
+    #[test]
+    fn test_visuals_scale_prefers_nominal_rpm() {
+        let visuals = Visuals {
+            width: 80,
+            nominal_rpm: Some(3000),
+        };
+        assert_eq!(visuals.scale(1400), 3000);
+        let visuals = Visuals {
+            nominal_rpm: None,
+            ..visuals
+        };
+        assert_eq!(visuals.scale(1400), 1400);
+        assert_eq!(Visuals::detect(BarsMode::Never, None), None);
+        assert!(Visuals::detect(BarsMode::Always, None).unwrap().width > 0);
+    }
+}